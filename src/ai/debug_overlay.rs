@@ -0,0 +1,376 @@
+//! Debug overlay showing what the AI is currently planning.
+//!
+//! Toggle with F4. The overlay re-runs the planner against the current
+//! `AiSnapshot` and lists each AI nation's top goal, first civilian task and
+//! resource buffer targets. F5 selects the next AI nation and F7 switches its
+//! economy log on or off; the latest log lines are shown under that nation.
+
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+use crate::ai::execute::AiEconomyLog;
+use crate::ai::markers::{AiEconomyLogging, AiNation};
+use crate::ai::planner::{CivilianTask, NationGoal, plan_nation};
+use crate::ai::snapshot::{AiSnapshot, resource_target_days};
+use crate::economy::goods::Good;
+use crate::economy::market::MARKET_RESOURCES;
+
+/// Lines of economic reasoning kept per AI nation
+pub const ECONOMY_LOG_LINES: usize = 8;
+
+/// Runtime toggle for the AI debug overlay.
+#[derive(Resource, Default)]
+pub struct AiDebugSettings {
+    pub enabled: bool,
+    /// AI nation whose economy logging F7 switches
+    pub selected: Option<Entity>,
+}
+
+/// Latest [`AiEconomyLog`] lines per nation, oldest first
+#[derive(Resource, Default)]
+pub struct AiEconomyLogBook {
+    pub lines: HashMap<Entity, VecDeque<String>>,
+}
+
+/// Marker for the overlay's text node
+#[derive(Component)]
+pub struct AiDebugOverlayText;
+
+/// Resource buffer status for a single good
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceTarget {
+    pub good: Good,
+    pub available: u32,
+    pub target_days: f32,
+}
+
+/// Everything the overlay shows for one AI nation
+#[derive(Debug, Clone)]
+pub struct AiDebugEntry {
+    pub nation: Entity,
+    pub top_goal: Option<NationGoal>,
+    /// First civilian (in snapshot order) with an assigned task
+    pub top_task: Option<(Entity, CivilianTask)>,
+    pub resource_targets: Vec<ResourceTarget>,
+}
+
+/// Build overlay entries for every nation in the snapshot, sorted by entity for a stable layout.
+pub fn extract_ai_debug_entries(snapshot: &AiSnapshot) -> Vec<AiDebugEntry> {
+    let mut entries: Vec<AiDebugEntry> = snapshot
+        .nations
+        .values()
+        .map(|nation| {
+            let plan = plan_nation(nation, snapshot);
+            let top_task = nation.civilians.iter().find_map(|civilian| {
+                plan.civilian_tasks
+                    .get(&civilian.entity)
+                    .map(|task| (civilian.entity, task.clone()))
+            });
+            let resource_targets = MARKET_RESOURCES
+                .iter()
+                .map(|&good| ResourceTarget {
+                    good,
+                    available: nation.available_amount(good),
+                    target_days: resource_target_days(good),
+                })
+                .collect();
+
+            AiDebugEntry {
+                nation: nation.entity,
+                top_goal: plan.goals.first().cloned(),
+                top_task,
+                resource_targets,
+            }
+        })
+        .collect();
+
+    entries.sort_by_key(|entry| entry.nation);
+    entries
+}
+
+fn format_entry(entry: &AiDebugEntry, name: &str) -> String {
+    let mut text = format!("{}\n", name);
+    match &entry.top_goal {
+        Some(goal) => text.push_str(&format!("  Goal: {:?}\n", goal)),
+        None => text.push_str("  Goal: none\n"),
+    }
+    match &entry.top_task {
+        Some((civilian, task)) => text.push_str(&format!("  Task: {:?} -> {:?}\n", civilian, task)),
+        None => text.push_str("  Task: none\n"),
+    }
+    for target in &entry.resource_targets {
+        text.push_str(&format!(
+            "  {:?}: {} / {:.0}\n",
+            target.good, target.available, target.target_days
+        ));
+    }
+    text
+}
+
+/// Keep the newest economy log lines for each nation that sent them
+pub fn record_ai_economy_log(
+    mut messages: MessageReader<AiEconomyLog>,
+    mut book: ResMut<AiEconomyLogBook>,
+) {
+    for AiEconomyLog { nation, text } in messages.read() {
+        let lines = book.lines.entry(*nation).or_default();
+        lines.push_back(text.clone());
+        while lines.len() > ECONOMY_LOG_LINES {
+            lines.pop_front();
+        }
+    }
+}
+
+pub fn toggle_ai_debug(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<AiDebugSettings>,
+    ai_nations: Query<(Entity, Has<AiEconomyLogging>), With<AiNation>>,
+) {
+    if keys.just_pressed(KeyCode::F5) {
+        let mut nations: Vec<Entity> = ai_nations.iter().map(|(nation, _)| nation).collect();
+        nations.sort();
+        settings.selected = match settings.selected {
+            Some(current) => nations
+                .iter()
+                .position(|&nation| nation == current)
+                .and_then(|index| nations.get(index + 1))
+                .or(nations.first())
+                .copied(),
+            None => nations.first().copied(),
+        };
+    }
+    if keys.just_pressed(KeyCode::F7)
+        && let Some((nation, logging)) = settings
+            .selected
+            .and_then(|selected| ai_nations.get(selected).ok())
+    {
+        if logging {
+            commands.entity(nation).remove::<AiEconomyLogging>();
+        } else {
+            commands.entity(nation).insert(AiEconomyLogging);
+        }
+        settings.set_changed();
+        info!(
+            "AI economy logging for {:?}: {}",
+            nation,
+            if logging { "disabled" } else { "enabled" }
+        );
+    }
+    if keys.just_pressed(KeyCode::F4) {
+        settings.enabled = !settings.enabled;
+        info!(
+            "AI debug overlay: {}",
+            if settings.enabled {
+                "enabled"
+            } else {
+                "disabled"
+            }
+        );
+    }
+}
+
+pub fn render_ai_debug(
+    mut commands: Commands,
+    settings: Res<AiDebugSettings>,
+    snapshot: Res<AiSnapshot>,
+    book: Res<AiEconomyLogBook>,
+    names: Query<&Name>,
+    logging: Query<(), With<AiEconomyLogging>>,
+    existing: Query<Entity, With<AiDebugOverlayText>>,
+) {
+    if !settings.enabled {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    // The snapshot is rebuilt once per enemy turn, so only redraw when it, the log or the
+    // toggles change
+    if !settings.is_changed() && !snapshot.is_changed() && !book.is_changed() {
+        return;
+    }
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let entries = extract_ai_debug_entries(&snapshot);
+    let mut text = format!("AI snapshot (turn {})\n", snapshot.turn);
+    if entries.is_empty() {
+        text.push_str("No AI nations in snapshot yet\n");
+    }
+    for entry in &entries {
+        let name = names
+            .get(entry.nation)
+            .map(|name| name.as_str().to_string())
+            .unwrap_or_else(|_| format!("{:?}", entry.nation));
+        text.push_str(&format_entry(entry, &name));
+
+        if settings.selected == Some(entry.nation) {
+            let state = if logging.contains(entry.nation) {
+                "on"
+            } else {
+                "off"
+            };
+            text.push_str(&format!("  [selected] Economy log {} (F7)\n", state));
+            for line in book.lines.get(&entry.nation).into_iter().flatten() {
+                text.push_str(&format!("    {}\n", line));
+            }
+        }
+    }
+
+    commands.spawn((
+        AiDebugOverlayText,
+        Text::new(text),
+        TextFont {
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.9, 0.9, 0.6)),
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            right: Val::Px(8.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        },
+        ZIndex(100),
+    ));
+}
+
+/// Registers the AI debug overlay (only compiled with the `debug` feature)
+pub struct AiDebugOverlayPlugin;
+
+impl Plugin for AiDebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AiDebugSettings>()
+            .init_resource::<AiEconomyLogBook>()
+            .add_systems(
+                Update,
+                (toggle_ai_debug, record_ai_economy_log, render_ai_debug).chain(),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy_ecs_tilemap::prelude::TilePos;
+    use std::collections::{HashMap, HashSet};
+
+    use crate::ai::debug_overlay::{
+        AiEconomyLogBook, ECONOMY_LOG_LINES, extract_ai_debug_entries, record_ai_economy_log,
+    };
+    use crate::ai::execute::AiEconomyLog;
+    use crate::ai::planner::{CivilianTask, NationGoal};
+    use crate::ai::snapshot::{AiSnapshot, CivilianSnapshot, NationSnapshot, resource_target_days};
+    use crate::civilians::types::CivilianKind;
+    use crate::economy::goods::Good;
+    use crate::economy::market::MARKET_RESOURCES;
+
+    #[test]
+    fn extractor_reports_top_goal_task_and_targets() {
+        let nation = Entity::from_bits(1);
+        let engineer = Entity::from_bits(2);
+        let engineer_pos = TilePos::new(5, 5);
+        let target = TilePos::new(6, 5);
+
+        let owned_tiles: HashSet<TilePos> = [engineer_pos, target].into_iter().collect();
+        let tile_terrain = owned_tiles
+            .iter()
+            .map(|&pos| (pos, crate::map::tiles::TerrainType::Grass))
+            .collect();
+
+        let nation_snapshot = NationSnapshot {
+            entity: nation,
+            capital_pos: engineer_pos,
+            treasury: 0,
+            stockpile: HashMap::new(),
+            civilians: vec![CivilianSnapshot {
+                entity: engineer,
+                kind: CivilianKind::Engineer,
+                position: engineer_pos,
+                has_moved: false,
+            }],
+            connected_tiles: [engineer_pos].into_iter().collect(),
+            unconnected_depots: vec![],
+            suggested_depots: vec![crate::ai::snapshot::SuggestedDepot {
+                position: target,
+                covers_count: 7,
+                distance_from_capital: 1,
+            }],
+            improvable_tiles: vec![],
+            owned_tiles,
+            depot_positions: HashSet::new(),
+            prospectable_tiles: vec![],
+            tile_terrain,
+            technologies: crate::economy::technology::Technologies::new(),
+            rail_constructions: vec![],
+            trade_capacity_total: 0,
+            trade_capacity_used: 0,
+            has_trade_partner: false,
+            buildings: HashMap::new(),
+        };
+
+        let mut snapshot = AiSnapshot::default();
+        snapshot.nations.insert(nation, nation_snapshot);
+
+        let entries = extract_ai_debug_entries(&snapshot);
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.nation, nation);
+
+        // A fully covering, adjacent depot (0.85) outranks buying from an empty stockpile (0.8)
+        assert!(
+            matches!(entry.top_goal, Some(NationGoal::BuildDepotAt { tile, .. }) if tile == target),
+            "unexpected goal {:?}",
+            entry.top_goal
+        );
+        assert!(
+            matches!(
+                entry.top_task,
+                Some((civilian, CivilianTask::BuildRailTo { target: t })) if civilian == engineer && t == target
+            ),
+            "unexpected task {:?}",
+            entry.top_task
+        );
+
+        assert_eq!(entry.resource_targets.len(), MARKET_RESOURCES.len());
+        let grain = entry
+            .resource_targets
+            .iter()
+            .find(|t| t.good == Good::Grain)
+            .expect("grain is a market resource");
+        assert_eq!(grain.available, 0);
+        assert_eq!(grain.target_days, resource_target_days(Good::Grain));
+    }
+
+    #[test]
+    fn economy_log_keeps_the_latest_lines_per_nation() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.init_resource::<Messages<AiEconomyLog>>();
+        world.init_resource::<AiEconomyLogBook>();
+        let nation = world.spawn_empty().id();
+
+        for i in 0..ECONOMY_LOG_LINES + 2 {
+            world.write_message(AiEconomyLog {
+                nation,
+                text: format!("line {}", i),
+            });
+        }
+        world.run_system_once(record_ai_economy_log).unwrap();
+
+        let lines = &world.resource::<AiEconomyLogBook>().lines[&nation];
+        assert_eq!(lines.len(), ECONOMY_LOG_LINES);
+        assert_eq!(lines.front().map(String::as_str), Some("line 2"));
+        assert_eq!(
+            lines.back(),
+            Some(&format!("line {}", ECONOMY_LOG_LINES + 1))
+        );
+    }
+}
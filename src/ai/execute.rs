@@ -3,15 +3,71 @@
 //! This module converts AI plans into concrete game orders (events).
 
 use bevy::prelude::*;
+use std::collections::VecDeque;
 
-use crate::ai::markers::AiNation;
-use crate::ai::planner::{CivilianTask, NationPlan, plan_nation};
+use crate::ai::markers::{AiEconomyLogging, AiNation};
+use crate::ai::planner::{CivilianTask, NationPlan, ProspectingClaims, plan_nation_with_claims};
 use crate::ai::snapshot::AiSnapshot;
 use crate::civilians::types::CivilianOrderKind;
 use crate::economy::NationInstance;
 use crate::economy::production::Buildings;
 use crate::messages::civilians::CivilianCommand;
 use crate::messages::{AdjustMarketOrder, AdjustProduction, HireCivilian, MarketInterest};
+use crate::turn_system::EnemyTurnBacklog;
+
+/// Maximum number of AI civilian orders dispatched per frame.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct AiTurnBudget {
+    pub civilians_per_frame: usize,
+}
+
+impl Default for AiTurnBudget {
+    fn default() -> Self {
+        Self {
+            civilians_per_frame: 32,
+        }
+    }
+}
+
+/// Civilian orders planned this enemy turn but not yet sent, in execution order.
+#[derive(Resource, Debug, Default)]
+pub struct PendingAiOrders {
+    pub queue: VecDeque<CivilianCommand>,
+}
+
+/// One line of an AI nation's economic reasoning, sent only for nations flagged
+/// with [`AiEconomyLogging`]
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct AiEconomyLog {
+    pub nation: Entity,
+    pub text: String,
+}
+
+/// Describe the economic side of a plan: its top goal, market orders and production
+fn describe_economy_plan(plan: &NationPlan) -> Vec<String> {
+    let mut lines = vec![format!(
+        "Economy plan: {} buys, {} sells, {} production orders",
+        plan.market_buys.len(),
+        plan.market_sells.len(),
+        plan.production_orders.len()
+    )];
+    if let Some(goal) = plan.goals.first() {
+        lines.push(format!("Top goal: {:?}", goal));
+    }
+    for (good, qty) in &plan.market_buys {
+        lines.push(format!("Buying {} {}", qty, good));
+    }
+    for (good, qty) in &plan.market_sells {
+        lines.push(format!("Selling {} {}", qty, good));
+    }
+    for order in &plan.production_orders {
+        lines.push(format!(
+            "Producing {} {} in {:?}",
+            order.qty, order.output, order.building
+        ));
+    }
+    lines
+}
 
 /// Main AI execution system - runs once per EnemyTurn.
 ///
@@ -22,23 +78,65 @@ use crate::messages::{AdjustMarketOrder, AdjustProduction, HireCivilian, MarketI
 pub fn execute_ai_turn(
     mut commands: Commands,
     snapshot: Res<AiSnapshot>,
-    ai_nations: Query<(NationInstance, &Buildings), With<AiNation>>,
+    mut pending: ResMut<PendingAiOrders>,
+    mut claims: ResMut<ProspectingClaims>,
+    mut economy_log: MessageWriter<AiEconomyLog>,
+    ai_nations: Query<(NationInstance, &Buildings, Has<AiEconomyLogging>), With<AiNation>>,
 ) {
-    for (nation, buildings) in ai_nations.iter() {
+    claims.clear();
+
+    // Stable nation order keeps the queued civilian orders deterministic
+    let mut nations: Vec<_> = ai_nations.iter().collect();
+    nations.sort_by_key(|(nation, _, _)| nation.entity());
+
+    for (nation, buildings, logging) in nations {
         let Some(nation_snapshot) = snapshot.get_nation(nation.entity()) else {
             continue;
         };
 
         // Generate the plan
-        let plan = plan_nation(nation_snapshot, &snapshot);
+        let plan = plan_nation_with_claims(nation_snapshot, &snapshot, &mut claims);
+
+        if logging {
+            for text in describe_economy_plan(&plan) {
+                info!("AI {:?}: {}", nation.entity(), text);
+                economy_log.write(AiEconomyLog {
+                    nation: nation.entity(),
+                    text,
+                });
+            }
+        }
 
         // Execute the plan
-        execute_plan(&mut commands, &snapshot, &plan, nation, buildings);
+        execute_plan(
+            &mut commands,
+            &mut pending,
+            &snapshot,
+            &plan,
+            nation,
+            buildings,
+        );
+    }
+}
+
+/// Send queued AI civilian orders, at most `AiTurnBudget::civilians_per_frame` per call.
+/// Runs once at the end of OnEnter(EnemyTurn) and then every frame until the queue is empty.
+pub fn dispatch_ai_civilian_orders(
+    mut commands: Commands,
+    budget: Res<AiTurnBudget>,
+    mut pending: ResMut<PendingAiOrders>,
+    mut backlog: ResMut<EnemyTurnBacklog>,
+) {
+    let batch = budget.civilians_per_frame.max(1).min(pending.queue.len());
+    for command in pending.queue.drain(..batch) {
+        commands.trigger(command);
     }
+    backlog.pending = pending.queue.len();
 }
 
 fn execute_plan(
     commands: &mut Commands,
+    pending: &mut PendingAiOrders,
     snapshot: &AiSnapshot,
     plan: &NationPlan,
     nation: NationInstance,
@@ -57,10 +155,10 @@ fn execute_plan(
     let execution_order =
         sort_civilian_tasks_topologically(&plan.civilian_tasks, &current_positions);
 
-    // Send civilian orders in sorted order
+    // Queue civilian orders in sorted order; they are dispatched under the per-frame budget
     for (civilian_entity, task) in execution_order {
         if let Some(order) = task_to_order(&task) {
-            commands.trigger(CivilianCommand {
+            pending.queue.push_back(CivilianCommand {
                 civilian: civilian_entity,
                 order,
             });
@@ -214,6 +312,7 @@ mod tests {
     use super::*;
     use crate::civilians::types::CivilianOrderKind;
     use bevy_ecs_tilemap::prelude::TilePos;
+    use std::collections::HashSet;
 
     #[test]
     fn test_task_to_order_conversion() {
@@ -272,4 +371,120 @@ mod tests {
         assert!(idx3 < idx2, "e3 should execute before e2");
         assert!(idx2 < idx1, "e2 should execute before e1");
     }
+
+    #[test]
+    fn small_budget_still_dispatches_every_order_in_order() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        #[derive(Resource, Default)]
+        struct Received(Vec<Entity>);
+
+        let mut world = World::new();
+        world.insert_resource(AiTurnBudget {
+            civilians_per_frame: 2,
+        });
+        world.init_resource::<PendingAiOrders>();
+        world.init_resource::<EnemyTurnBacklog>();
+        world.init_resource::<Received>();
+        world.add_observer(
+            |command: On<CivilianCommand>, mut received: ResMut<Received>| {
+                received.0.push(command.event().civilian);
+            },
+        );
+
+        let civilians: Vec<Entity> = (1..=5).map(Entity::from_bits).collect();
+        for (i, &civilian) in civilians.iter().enumerate() {
+            world
+                .resource_mut::<PendingAiOrders>()
+                .queue
+                .push_back(CivilianCommand {
+                    civilian,
+                    order: CivilianOrderKind::Move {
+                        to: TilePos::new(i as u32, 0),
+                    },
+                });
+        }
+
+        let mut frames = 0;
+        loop {
+            world.run_system_once(dispatch_ai_civilian_orders).unwrap();
+            world.flush();
+            frames += 1;
+            if world.resource::<EnemyTurnBacklog>().pending == 0 {
+                break;
+            }
+            assert!(frames < 10, "dispatch never drained the queue");
+        }
+
+        assert_eq!(frames, 3);
+        assert_eq!(world.resource::<Received>().0, civilians);
+        assert!(world.resource::<PendingAiOrders>().queue.is_empty());
+    }
+
+    fn quiet_nation_snapshot(entity: Entity) -> crate::ai::snapshot::NationSnapshot {
+        crate::ai::snapshot::NationSnapshot {
+            entity,
+            capital_pos: TilePos::new(0, 0),
+            treasury: 1_000,
+            stockpile: std::collections::HashMap::new(),
+            civilians: vec![],
+            connected_tiles: HashSet::new(),
+            unconnected_depots: vec![],
+            suggested_depots: vec![],
+            improvable_tiles: vec![],
+            owned_tiles: HashSet::new(),
+            depot_positions: HashSet::new(),
+            prospectable_tiles: vec![],
+            tile_terrain: std::collections::HashMap::new(),
+            technologies: crate::economy::technology::Technologies::new(),
+            rail_constructions: vec![],
+            trade_capacity_total: 0,
+            trade_capacity_used: 0,
+            has_trade_partner: false,
+            buildings: std::collections::HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn economy_logging_only_reports_flagged_nations() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        use crate::economy::Nation;
+
+        let mut world = World::new();
+        world.init_resource::<PendingAiOrders>();
+        world.init_resource::<ProspectingClaims>();
+        world.init_resource::<Messages<AiEconomyLog>>();
+
+        let verbose = world
+            .spawn((Nation, AiNation, Buildings::new(), AiEconomyLogging))
+            .id();
+        let quiet = world.spawn((Nation, AiNation, Buildings::new())).id();
+        let mut snapshot = AiSnapshot::default();
+        for nation in [verbose, quiet] {
+            snapshot
+                .nations
+                .insert(nation, quiet_nation_snapshot(nation));
+        }
+        world.insert_resource(snapshot);
+
+        world.run_system_once(execute_ai_turn).unwrap();
+        let logs: Vec<AiEconomyLog> = world
+            .resource_mut::<Messages<AiEconomyLog>>()
+            .drain()
+            .collect();
+        assert!(!logs.is_empty());
+        assert!(logs.iter().all(|log| log.nation == verbose));
+        assert!(logs[0].text.starts_with("Economy plan:"));
+
+        world.entity_mut(verbose).remove::<AiEconomyLogging>();
+        world.run_system_once(execute_ai_turn).unwrap();
+        assert!(
+            world
+                .resource_mut::<Messages<AiEconomyLog>>()
+                .drain()
+                .next()
+                .is_none()
+        );
+    }
 }
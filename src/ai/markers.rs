@@ -5,6 +5,12 @@ use bevy::prelude::*;
 #[reflect(Component)]
 pub struct AiNation;
 
+/// Opt-in flag on an [`AiNation`] whose economic reasoning should be logged.
+/// Only flagged nations send [`AiEconomyLog`](crate::ai::execute::AiEconomyLog) messages;
+/// the AI debug overlay toggles the flag (F7) and shows the latest lines.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct AiEconomyLogging;
+
 /// Marks a civilian unit that is controlled by the AI.
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
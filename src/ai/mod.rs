@@ -1,15 +1,17 @@
 use bevy::prelude::*;
 
-use crate::turn_system::{EnemyTurnSet, TurnPhase};
+use crate::turn_system::{EnemyTurnSet, TurnPhase, finish_deferred_enemy_turn};
 
 // Simplified AI architecture
+#[cfg(feature = "debug")]
+pub mod debug_overlay;
 pub mod execute;
 pub mod markers;
 pub mod planner;
 pub mod snapshot;
 
 // Public exports
-pub use markers::{AiControlledCivilian, AiNation};
+pub use markers::{AiControlledCivilian, AiEconomyLogging, AiNation};
 pub use planner::{CivilianTask, NationGoal, NationPlan};
 pub use snapshot::{AiSnapshot, NationSnapshot};
 
@@ -23,7 +25,11 @@ pub struct AiPlugin;
 
 impl Plugin for AiPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<snapshot::AiSnapshot>();
+        app.init_resource::<snapshot::AiSnapshot>()
+            .init_resource::<execute::AiTurnBudget>()
+            .init_resource::<execute::PendingAiOrders>()
+            .init_resource::<planner::ProspectingClaims>()
+            .add_message::<execute::AiEconomyLog>();
 
         // NOTE: build_ai_snapshot has a complex function signature that causes issues
         // when trying to use it in chains or tuples. We register it separately and ensure
@@ -37,5 +43,18 @@ impl Plugin for AiPlugin {
             OnEnter(TurnPhase::EnemyTurn),
             execute::execute_ai_turn.in_set(EnemyTurnSet::Actions),
         );
+
+        // Civilian orders are sent under a per-frame budget: the first batch right away,
+        // the rest on following frames before the enemy turn ends
+        app.add_systems(
+            OnEnter(TurnPhase::EnemyTurn),
+            execute::dispatch_ai_civilian_orders.in_set(EnemyTurnSet::Orders),
+        );
+        app.add_systems(
+            Update,
+            execute::dispatch_ai_civilian_orders
+                .before(finish_deferred_enemy_turn)
+                .run_if(in_state(TurnPhase::EnemyTurn)),
+        );
     }
 }
@@ -7,12 +7,19 @@ use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::TilePos;
 use std::collections::{HashMap, HashSet};
 
+use crate::map::neighbor_cache::NeighborCache;
 use crate::map::tile_pos::TilePosExt;
 
 use crate::ai::snapshot::{AiSnapshot, NationSnapshot, resource_target_days};
+use crate::civilians::hiring::hire_cost;
+use crate::civilians::order_validation::validate_depot_site;
 use crate::civilians::types::CivilianKind;
 use crate::economy::goods::Good;
 use crate::economy::market::MARKET_RESOURCES;
+use crate::economy::production::{
+    RecipeVariantInfo, building_for_output, input_requirement_per_unit, production_chain,
+    production_recipe,
+};
 
 /// A goal that a nation wants to accomplish.
 #[derive(Debug, Clone)]
@@ -110,14 +117,51 @@ const BUY_SHORTAGE_THRESHOLD: u32 = 12;
 const SELL_RESERVE: u32 = 8;
 const SELL_MAX_PER_GOOD: u32 = 8;
 
+/// Finished goods the AI keeps in stock by running its own production chain.
+const STOCKED_FINISHED_GOODS: &[Good] = &[Good::Clothing, Good::Furniture, Good::CannedFood];
+/// Units of each stocked finished good the AI tries to keep on hand.
+const FINISHED_GOOD_RESERVE: u32 = 4;
+
+/// Tiles already picked as prospecting targets during the current enemy turn.
+///
+/// Cleared at the start of every enemy turn; planning a nation claims the tiles its
+/// prospectors are sent to, so no other prospector is assigned the same tile.
+#[derive(Resource, Debug, Default)]
+pub struct ProspectingClaims {
+    tiles: HashSet<TilePos>,
+}
+
+impl ProspectingClaims {
+    pub fn claim(&mut self, tile: TilePos) -> bool {
+        self.tiles.insert(tile)
+    }
+
+    pub fn is_claimed(&self, tile: TilePos) -> bool {
+        self.tiles.contains(&tile)
+    }
+
+    pub fn clear(&mut self) {
+        self.tiles.clear();
+    }
+}
+
 /// Generate a complete plan for an AI nation.
 pub fn plan_nation(nation: &NationSnapshot, snapshot: &AiSnapshot) -> NationPlan {
+    plan_nation_with_claims(nation, snapshot, &mut ProspectingClaims::default())
+}
+
+/// Generate a plan for an AI nation, skipping prospecting targets claimed earlier this turn.
+pub fn plan_nation_with_claims(
+    nation: &NationSnapshot,
+    snapshot: &AiSnapshot,
+    claims: &mut ProspectingClaims,
+) -> NationPlan {
     let mut plan = NationPlan::default();
 
     // 1. Generate all goals
     generate_market_goals(nation, snapshot, &mut plan.goals);
     generate_value_added_trade(nation, snapshot, &mut plan);
-    generate_infrastructure_goals(nation, &mut plan.goals);
+    generate_infrastructure_goals(nation, snapshot, &mut plan.goals);
     generate_improvement_goals(nation, &mut plan.goals);
     generate_prospecting_goals(nation, &mut plan.goals);
     generate_hiring_goals(nation, &mut plan.goals);
@@ -131,7 +175,13 @@ pub fn plan_nation(nation: &NationSnapshot, snapshot: &AiSnapshot) -> NationPlan
     });
 
     // 3. Assign civilians to goals
-    assign_civilians_to_goals(nation, snapshot, &plan.goals, &mut plan.civilian_tasks);
+    assign_civilians_to_goals(
+        nation,
+        snapshot,
+        &plan.goals,
+        claims,
+        &mut plan.civilian_tasks,
+    );
 
     // 4. Generate concrete orders from goals
     for goal in &plan.goals {
@@ -165,6 +215,8 @@ pub fn plan_nation(nation: &NationSnapshot, snapshot: &AiSnapshot) -> NationPlan
     }
 
     // 5. Generate transport allocations and production orders
+    generate_upstream_production(nation, &mut plan);
+    generate_surplus_sales(nation, &mut plan);
     generate_transport_allocations(nation, &mut plan);
     generate_production_orders(nation, &mut plan);
 
@@ -180,27 +232,20 @@ fn generate_market_goals(
         let available = nation.available_amount(good);
         let target = resource_target_days(good).round() as u32;
 
-        // Buy if shortage
-        if available < BUY_SHORTAGE_THRESHOLD && available < target {
-            let qty = (target - available).min(10);
-            let urgency = 1.0 - (available as f32 / target as f32).min(1.0);
-
-            // Adjust priority based on price (lower priority if expensive)
-            let base_price = 100u32;
-            let current_price = snapshot.market.price_for(good);
-            let price_factor = if current_price > base_price * 12 / 10 {
-                0.5 // Expensive, reduce priority
-            } else if current_price < base_price * 8 / 10 {
-                1.2 // Cheap, increase priority
-            } else {
-                1.0
-            };
-
-            goals.push(NationGoal::BuyResource {
+        // Buy if shortage, unless the nation can make the good itself this turn
+        if !can_make(nation, good) {
+            let delivered_price = snapshot.market.delivered_price_for(
                 good,
-                qty,
-                priority: urgency * price_factor * 0.8, // Market goals cap at 0.8
-            });
+                nation.trade_capacity(),
+                nation.has_trade_partner,
+            );
+            if let Some((qty, priority)) = recommended_buy(available, target, delivered_price) {
+                goals.push(NationGoal::BuyResource {
+                    good,
+                    qty,
+                    priority,
+                });
+            }
         }
 
         // Sell if surplus
@@ -215,6 +260,31 @@ fn generate_market_goals(
     }
 }
 
+/// Quantity and priority of a market buy for a good with `available` units on hand,
+/// a buffer `target` and the given delivered price. `None` when there is no shortage.
+/// Depends only on its inputs, so the same market always yields the same order.
+pub fn recommended_buy(available: u32, target: u32, delivered_price: u32) -> Option<(u32, f32)> {
+    if available >= BUY_SHORTAGE_THRESHOLD || available >= target {
+        return None;
+    }
+
+    let qty = (target - available).min(10);
+    let urgency = 1.0 - (available as f32 / target as f32).min(1.0);
+
+    // Adjust priority based on price (lower priority if expensive)
+    let base_price = 100u32;
+    let price_factor = if delivered_price > base_price * 12 / 10 {
+        0.5 // Expensive, reduce priority
+    } else if delivered_price < base_price * 8 / 10 {
+        1.2 // Cheap, increase priority
+    } else {
+        1.0
+    };
+
+    // Market goals cap at 0.8
+    Some((qty, urgency * price_factor * 0.8))
+}
+
 fn generate_value_added_trade(
     nation: &NationSnapshot,
     snapshot: &AiSnapshot,
@@ -295,7 +365,136 @@ fn generate_value_added_trade(
     plan.market_sells.push((Good::Hardware, desired_hardware));
 }
 
-fn generate_infrastructure_goals(nation: &NationSnapshot, goals: &mut Vec<NationGoal>) {
+/// Keep finished goods stocked by producing them, and the intermediate goods feeding
+/// them, in the nation's own buildings. Only raw inputs are bought on the market.
+fn generate_upstream_production(nation: &NationSnapshot, plan: &mut NationPlan) {
+    for &good in STOCKED_FINISHED_GOODS {
+        let shortfall = FINISHED_GOOD_RESERVE.saturating_sub(nation.available_amount(good));
+        if shortfall == 0 {
+            continue;
+        }
+
+        // Only worth it when the nation owns a building for every step of the chain
+        let owns_chain = production_chain(good)
+            .into_iter()
+            .filter_map(building_for_output)
+            .all(|kind| nation.buildings.contains_key(&kind));
+        if owns_chain {
+            queue_chain_production(nation, good, shortfall, plan);
+        }
+    }
+}
+
+fn owns_building_for(nation: &NationSnapshot, good: Good) -> bool {
+    building_for_output(good).is_some_and(|kind| nation.buildings.contains_key(&kind))
+}
+
+/// Whether the nation's own buildings can make `good` right now: the building has
+/// capacity and the inputs for at least one unit of some variant are on hand
+fn can_make(nation: &NationSnapshot, good: Good) -> bool {
+    let Some(kind) = building_for_output(good) else {
+        return false;
+    };
+    let (Some(building), Some(recipe)) = (nation.buildings.get(&kind), production_recipe(kind))
+    else {
+        return false;
+    };
+    building.capacity > 0
+        && recipe.variants_for_output(good).iter().any(|info| {
+            info.variant
+                .inputs()
+                .iter()
+                .all(|ingredient| nation.available_amount(ingredient.good) >= ingredient.amount)
+        })
+}
+
+/// Sell whatever the nation's own buildings have made beyond its target stock and
+/// the inputs this turn's production orders will use.
+fn generate_surplus_sales(nation: &NationSnapshot, plan: &mut NationPlan) {
+    for &good in MARKET_RESOURCES {
+        if !owns_building_for(nation, good) || plan.market_sells.iter().any(|(g, _)| *g == good) {
+            continue;
+        }
+
+        let consumed: u32 = plan
+            .production_orders
+            .iter()
+            .filter_map(|order| {
+                let kind = building_for_output(order.output)?;
+                Some(input_requirement_per_unit(kind, order.output, good)? * order.qty)
+            })
+            .sum();
+        let target = resource_target_days(good).round() as u32;
+        let surplus = nation
+            .available_amount(good)
+            .saturating_sub(target + consumed);
+        if surplus > 0 {
+            plan.market_sells.push((good, surplus));
+        }
+    }
+}
+
+/// Order `qty` of `good` and, for each missing input, either order it from the
+/// building that makes it (it arrives in time for a later turn) or buy it.
+fn queue_chain_production(nation: &NationSnapshot, good: Good, qty: u32, plan: &mut NationPlan) {
+    let Some(kind) = building_for_output(good) else {
+        return;
+    };
+    let (Some(building), Some(recipe)) = (nation.buildings.get(&kind), production_recipe(kind))
+    else {
+        return;
+    };
+    let qty = qty.min(building.capacity);
+    if qty == 0 || plan.production_orders.iter().any(|o| o.output == good) {
+        return;
+    }
+    plan.production_orders.push(ProductionOrder {
+        building: nation.entity,
+        output: good,
+        qty,
+    });
+
+    // Feed the variant the nation can best supply (e.g. Wool when it has no Cotton)
+    let batches_possible = |info: &RecipeVariantInfo| {
+        info.variant
+            .inputs()
+            .iter()
+            .map(|ingredient| nation.available_amount(ingredient.good) / ingredient.amount.max(1))
+            .min()
+            .unwrap_or(u32::MAX)
+    };
+    let mut best: Option<(u32, RecipeVariantInfo)> = None;
+    for info in recipe.variants_for_output(good) {
+        let score = batches_possible(&info);
+        if best.is_none_or(|(best_score, _)| score > best_score) {
+            best = Some((score, info));
+        }
+    }
+    let Some((_, info)) = best else {
+        return;
+    };
+
+    for ingredient in info.variant.inputs() {
+        let missing =
+            (ingredient.amount * qty).saturating_sub(nation.available_amount(ingredient.good));
+        if missing == 0 {
+            continue;
+        }
+        if building_for_output(ingredient.good).is_some() {
+            queue_chain_production(nation, ingredient.good, missing, plan);
+        } else if MARKET_RESOURCES.contains(&ingredient.good)
+            && !plan.market_buys.iter().any(|(g, _)| *g == ingredient.good)
+        {
+            plan.market_buys.push((ingredient.good, missing));
+        }
+    }
+}
+
+fn generate_infrastructure_goals(
+    nation: &NationSnapshot,
+    snapshot: &AiSnapshot,
+    goals: &mut Vec<NationGoal>,
+) {
     // Add goals for building depots at optimal locations (calculated via greedy set-cover)
     for depot in &nation.suggested_depots {
         // Priority factors:
@@ -313,6 +512,12 @@ fn generate_infrastructure_goals(nation: &NationSnapshot, goals: &mut Vec<Nation
 
     // Add goals for connecting existing unconnected depots
     for depot in &nation.unconnected_depots {
+        // Depots that already reach the network by rail only need their flag refreshed;
+        // more track would run parallel to the existing line
+        if reaches_network_by_rail(depot.position, nation, &snapshot.rails, &snapshot.neighbors) {
+            continue;
+        }
+
         // Priority decreases with distance, but existing depots are important
         let priority = (1.2 / (1.0 + depot.distance_from_capital as f32 * 0.1)).clamp(0.4, 0.95);
         goals.push(NationGoal::ConnectDepot {
@@ -330,7 +535,8 @@ fn generate_improvement_goals(nation: &NationSnapshot, goals: &mut Vec<NationGoa
             crate::resources::DevelopmentLevel::Lv0 => 1.0,
             crate::resources::DevelopmentLevel::Lv1 => 0.7,
             crate::resources::DevelopmentLevel::Lv2 => 0.4,
-            crate::resources::DevelopmentLevel::Lv3 => 0.0, // Already max
+            crate::resources::DevelopmentLevel::Lv3 => 0.2,
+            crate::resources::DevelopmentLevel::Lv4 => 0.0, // Already max
         };
 
         let priority = distance_factor * development_factor * 0.6;
@@ -363,7 +569,7 @@ fn generate_hiring_goals(nation: &NationSnapshot, goals: &mut Vec<NationGoal>) {
         let current = nation.civilian_count(kind);
         if current < target {
             // Check if we can afford it
-            let cost = kind.hiring_cost();
+            let cost = hire_cost(kind, current);
             if nation.treasury >= cost {
                 goals.push(NationGoal::HireCivilian {
                     kind,
@@ -438,6 +644,7 @@ fn assign_civilians_to_goals(
     nation: &NationSnapshot,
     snapshot: &AiSnapshot, // Added snapshot for global occupied_tiles
     goals: &[NationGoal],
+    claims: &mut ProspectingClaims,
     tasks: &mut HashMap<Entity, CivilianTask>,
 ) {
     // Track positions of friendly units that haven't been assigned a task yet
@@ -454,6 +661,12 @@ fn assign_civilians_to_goals(
 
     // Iterate goals by priority (already sorted)
     for goal in goals {
+        if let NationGoal::ProspectTile { tile, .. } = goal
+            && claims.is_claimed(*tile)
+        {
+            continue;
+        }
+
         // Find best candidate for this goal
         let mut best_candidate: Option<(Entity, CivilianTask)> = None;
         let mut min_distance = u32::MAX; // Score: lower is better (distance to action)
@@ -465,22 +678,32 @@ fn assign_civilians_to_goals(
 
             let task_opt = match goal {
                 NationGoal::BuildDepotAt { tile, .. }
-                    if civilian.kind == CivilianKind::Engineer =>
+                    if civilian.kind.capabilities().builds_depots =>
                 {
-                    plan_engineer_depot_task(nation, &tracker, civilian.position, *tile)
+                    plan_engineer_depot_task(
+                        nation,
+                        &tracker,
+                        &snapshot.neighbors,
+                        civilian.position,
+                        *tile,
+                    )
                 }
                 NationGoal::ConnectDepot { tile, .. }
-                    if civilian.kind == CivilianKind::Engineer =>
+                    if civilian.kind.capabilities().builds_rails =>
                 {
                     plan_engineer_rail_task(nation, snapshot, &tracker, civilian.position, *tile)
                 }
-                NationGoal::ProspectTile { tile, .. }
-                    if civilian.kind == CivilianKind::Prospector =>
-                {
+                NationGoal::ProspectTile { tile, .. } if civilian.kind.capabilities().prospects => {
                     if civilian.position == *tile || is_adjacent(civilian.position, *tile) {
                         Some(CivilianTask::ProspectTile { target: *tile })
                     } else {
-                        plan_move_toward_goal(nation, &tracker, civilian.position, *tile)
+                        plan_move_toward_goal(
+                            nation,
+                            &tracker,
+                            &snapshot.neighbors,
+                            civilian.position,
+                            *tile,
+                        )
                     }
                 }
                 NationGoal::ImproveTile {
@@ -491,7 +714,13 @@ fn assign_civilians_to_goals(
                     if civilian.position == *tile || is_adjacent(civilian.position, *tile) {
                         Some(CivilianTask::ImproveTile { target: *tile })
                     } else {
-                        plan_move_toward_goal(nation, &tracker, civilian.position, *tile)
+                        plan_move_toward_goal(
+                            nation,
+                            &tracker,
+                            &snapshot.neighbors,
+                            civilian.position,
+                            *tile,
+                        )
                     }
                 }
                 _ => None,
@@ -515,6 +744,9 @@ fn assign_civilians_to_goals(
         // Assign best candidate
         if let Some((entity, task)) = best_candidate {
             tasks.insert(entity, task.clone());
+            if let NationGoal::ProspectTile { tile, .. } = goal {
+                claims.claim(*tile);
+            }
 
             // Update reservation state
             let current_pos = unplanned_positions.remove(&entity).unwrap();
@@ -543,6 +775,7 @@ fn assign_civilians_to_goals(
 fn plan_move_toward_goal(
     nation: &NationSnapshot,
     tracker: &ReservationTracker,
+    neighbors: &NeighborCache,
     from: TilePos,
     goal: TilePos,
 ) -> Option<CivilianTask> {
@@ -550,7 +783,7 @@ fn plan_move_toward_goal(
         return None;
     }
 
-    find_step_toward(from, goal, &nation.owned_tiles, tracker)
+    find_step_toward(from, goal, &nation.owned_tiles, tracker, neighbors)
         .map(|step| CivilianTask::MoveTo { target: step })
 }
 
@@ -572,16 +805,21 @@ fn score_task_distance(civilian_pos: TilePos, goal: &NationGoal, task: &Civilian
 fn plan_engineer_depot_task(
     nation: &NationSnapshot,
     occupied_tracker: &ReservationTracker,
+    neighbors: &NeighborCache,
     engineer_pos: TilePos,
     target: TilePos,
 ) -> Option<CivilianTask> {
+    // Distances along owned tiles, so a route that bends around foreign land still
+    // reads as progress after every segment
+    let to_target = route_distances(target, &nation.owned_tiles, neighbors);
+
     // 1. Find the bridgehead: the connected tile closest to target
     let bridgehead = nation
         .connected_tiles
         .iter()
         .min_by_key(|t| {
             (
-                t.to_hex().distance_to(target.to_hex()),
+                route_key(**t, target, &to_target),
                 if **t == engineer_pos { 0 } else { 1 }, // Prefer current tile if tied for distance
                 t.x,                                     // Consistent tie-breaking
                 t.y,
@@ -603,9 +841,14 @@ fn plan_engineer_depot_task(
     }
 
     // 4. We are at the bridgehead but not at the target. Build rail towards target.
-    if let Some(next_tile) =
-        find_step_toward(bridgehead, target, &nation.owned_tiles, occupied_tracker)
-    {
+    if let Some(next_tile) = find_route_step(
+        bridgehead,
+        target,
+        &to_target,
+        &nation.owned_tiles,
+        occupied_tracker,
+        neighbors,
+    ) {
         // next_tile MUST be unconnected if bridgehead was the closest connected tile.
         if !nation.connected_tiles.contains(&next_tile) {
             // Check if this rail segment is already being built
@@ -633,13 +876,18 @@ fn plan_engineer_rail_task(
     engineer_pos: TilePos,
     depot_pos: TilePos,
 ) -> Option<CivilianTask> {
+    if reaches_network_by_rail(depot_pos, nation, &snapshot.rails, &snapshot.neighbors) {
+        return None;
+    }
+
     // 1. Find the bridgehead: the connected tile closest to depot_pos
+    let to_depot = route_distances(depot_pos, &nation.owned_tiles, &snapshot.neighbors);
     let bridgehead = nation
         .connected_tiles
         .iter()
         .min_by_key(|t| {
             (
-                t.to_hex().distance_to(depot_pos.to_hex()),
+                route_key(**t, depot_pos, &to_depot),
                 if **t == engineer_pos { 0 } else { 1 }, // Prefer current tile if tied for distance
                 t.x,                                     // Consistent tie-breaking
                 t.y,
@@ -652,7 +900,14 @@ fn plan_engineer_rail_task(
     // We want the engineer to go to the END of this local network (closest to bridgehead)
     // and extend it.
 
-    let depot_frontier = find_rail_frontier(depot_pos, bridgehead, &snapshot.rails);
+    let to_bridgehead = route_distances(bridgehead, &nation.owned_tiles, &snapshot.neighbors);
+    let depot_frontier = find_rail_frontier(
+        depot_pos,
+        bridgehead,
+        &to_bridgehead,
+        &snapshot.rails,
+        &snapshot.neighbors,
+    );
 
     // 3. Logic: Coordinate Movement to Frontier
     // If we are NOT at the frontier, go there.
@@ -664,6 +919,7 @@ fn plan_engineer_rail_task(
             depot_frontier,
             &nation.owned_tiles,
             avoid_tracker,
+            &snapshot.neighbors,
         ) {
             return Some(CivilianTask::MoveTo { target: step });
         }
@@ -671,11 +927,13 @@ fn plan_engineer_rail_task(
     }
 
     // 4. We are at the frontier. Build towards Bridgehead.
-    if let Some(next_tile) = find_step_toward(
+    if let Some(next_tile) = find_route_step(
         depot_frontier,
         bridgehead,
+        &to_bridgehead,
         &nation.owned_tiles,
         avoid_tracker,
+        &snapshot.neighbors,
     ) {
         // If next_tile does not have rail, build it
         if !can_move_on_rail(depot_frontier, next_tile, snapshot) {
@@ -700,9 +958,10 @@ fn plan_engineer_rail_task(
 fn find_rail_frontier(
     start: TilePos,
     target: TilePos,
+    to_target: &HashMap<TilePos, u32>,
     rails: &std::collections::HashSet<(TilePos, TilePos)>,
+    neighbors: &NeighborCache,
 ) -> TilePos {
-    use crate::map::tile_pos::HexExt;
     use std::collections::{HashSet, VecDeque};
 
     let mut visited = HashSet::new();
@@ -712,22 +971,18 @@ fn find_rail_frontier(
     queue.push_back(start);
 
     let mut best_tile = start;
-    let mut min_dist = start.to_hex().distance_to(target.to_hex());
+    let mut min_dist = route_key(start, target, to_target);
 
     while let Some(current) = queue.pop_front() {
         // Update best if this tile is closer to target
-        let dist = current.to_hex().distance_to(target.to_hex());
+        let dist = route_key(current, target, to_target);
         if dist < min_dist {
             min_dist = dist;
             best_tile = current;
         }
 
         // Explore neighbors connected by rail
-        for neighbor_hex in current.to_hex().all_neighbors() {
-            let Some(neighbor) = neighbor_hex.to_tile_pos() else {
-                continue;
-            };
-
+        for &neighbor in neighbors.neighbors(current) {
             if visited.contains(&neighbor) {
                 continue;
             }
@@ -743,6 +998,100 @@ fn find_rail_frontier(
     best_tile
 }
 
+/// Rail hops searched from a depot when checking whether it already reaches the network
+const REDUNDANT_RAIL_HOP_BUDGET: u32 = 4;
+
+/// Returns true if `target` already reaches the nation's connected network over existing
+/// rails within [`REDUNDANT_RAIL_HOP_BUDGET`] hops.
+fn reaches_network_by_rail(
+    target: TilePos,
+    nation: &NationSnapshot,
+    rails: &std::collections::HashSet<(TilePos, TilePos)>,
+    neighbors: &NeighborCache,
+) -> bool {
+    use std::collections::{HashSet, VecDeque};
+
+    let mut visited = HashSet::from([target]);
+    let mut queue = VecDeque::from([(target, 0)]);
+
+    while let Some((current, hops)) = queue.pop_front() {
+        if nation.connected_tiles.contains(&current) {
+            return true;
+        }
+        if hops == REDUNDANT_RAIL_HOP_BUDGET {
+            continue;
+        }
+        for &neighbor in neighbors.neighbors(current) {
+            let edge = crate::economy::transport::ordered_edge(current, neighbor);
+            if rails.contains(&edge) && visited.insert(neighbor) {
+                queue.push_back((neighbor, hops + 1));
+            }
+        }
+    }
+
+    false
+}
+
+/// Walking distance to `target` for every tile that reaches it without leaving `allowed`.
+fn route_distances(
+    target: TilePos,
+    allowed: &HashSet<TilePos>,
+    neighbors: &NeighborCache,
+) -> HashMap<TilePos, u32> {
+    use std::collections::VecDeque;
+
+    let mut distances = HashMap::from([(target, 0)]);
+    let mut queue = VecDeque::from([target]);
+
+    while let Some(current) = queue.pop_front() {
+        let next = distances[&current] + 1;
+        for &neighbor in neighbors.neighbors(current) {
+            if allowed.contains(&neighbor) && !distances.contains_key(&neighbor) {
+                distances.insert(neighbor, next);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Ordering key for "closer to `target`": walking distance first, straight-line distance
+/// for tiles that can't walk there.
+fn route_key(pos: TilePos, target: TilePos, to_target: &HashMap<TilePos, u32>) -> (u32, i32) {
+    (
+        to_target.get(&pos).copied().unwrap_or(u32::MAX),
+        pos.to_hex().distance_to(target.to_hex()),
+    )
+}
+
+/// Next tile on the shortest walking route from `from` to `to`. Every step lowers the
+/// walking distance, so consecutive rail segments never double back; falls back to the
+/// straight-line step when `from` has no route.
+fn find_route_step(
+    from: TilePos,
+    to: TilePos,
+    to_target: &HashMap<TilePos, u32>,
+    allowed_tiles: &HashSet<TilePos>,
+    avoid_tracker: &ReservationTracker,
+    neighbors: &NeighborCache,
+) -> Option<TilePos> {
+    let Some(&distance) = to_target.get(&from) else {
+        return find_step_toward(from, to, allowed_tiles, avoid_tracker, neighbors);
+    };
+    if distance == 0 {
+        return None;
+    }
+
+    neighbors
+        .neighbors(from)
+        .iter()
+        .copied()
+        .filter(|pos| to_target.get(pos) == Some(&(distance - 1)))
+        .filter(|pos| !avoid_tracker.is_occupied(*pos))
+        .min_by_key(|pos| (pos.x, pos.y))
+}
+
 /// Check if movement between two adjacent tiles can be done via rail
 fn can_move_on_rail(a: TilePos, b: TilePos, snapshot: &AiSnapshot) -> bool {
     let edge = crate::economy::transport::ordered_edge(a, b);
@@ -756,17 +1105,15 @@ fn find_step_toward(
     to: TilePos,
     allowed_tiles: &std::collections::HashSet<TilePos>,
     avoid_tracker: &ReservationTracker,
+    neighbors: &NeighborCache,
 ) -> Option<TilePos> {
-    use crate::map::tile_pos::HexExt;
-
-    let from_hex = from.to_hex();
     let to_hex = to.to_hex();
 
     // Find the neighbor that minimizes distance to target
-    from_hex
-        .all_neighbors()
-        .into_iter()
-        .filter_map(|hex| hex.to_tile_pos())
+    neighbors
+        .neighbors(from)
+        .iter()
+        .copied()
         .filter(|pos| allowed_tiles.contains(pos))
         .filter(|pos| !avoid_tracker.is_occupied(*pos)) // Avoid occupied tiles
         .min_by_key(|pos| {
@@ -814,8 +1161,7 @@ fn can_build_depot_here(tile_pos: TilePos, nation: &NationSnapshot) -> bool {
     nation
         .tile_terrain
         .get(&tile_pos)
-        .map(crate::economy::transport::can_build_depot_on_terrain)
-        .unwrap_or(false)
+        .is_some_and(|terrain| validate_depot_site(terrain).is_ok())
 }
 
 /// Generate transport allocations based on available resources and capacity.
@@ -874,373 +1220,4 @@ fn generate_production_orders(nation: &NationSnapshot, _plan: &mut NationPlan) {
 }
 
 #[cfg(test)]
-mod tests {
-    use bevy::prelude::*;
-    use bevy_ecs_tilemap::prelude::TilePos;
-    use std::collections::HashMap;
-
-    use super::*;
-    use crate::ai::snapshot::NationSnapshot;
-
-    #[test]
-    fn test_goal_priority_ordering() {
-        let goals = vec![
-            NationGoal::BuyResource {
-                good: Good::Coal,
-                qty: 5,
-                priority: 0.5,
-            },
-            NationGoal::ConnectDepot {
-                tile: TilePos::new(0, 0),
-                priority: 0.8,
-            },
-            NationGoal::HireCivilian {
-                kind: CivilianKind::Engineer,
-                priority: 0.3,
-            },
-        ];
-
-        let mut sorted = goals.clone();
-        sorted.sort_by(|a, b| {
-            b.priority()
-                .partial_cmp(&a.priority())
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-
-        assert!(sorted[0].priority() > sorted[1].priority());
-        assert!(sorted[1].priority() > sorted[2].priority());
-    }
-
-    #[test]
-    fn test_engineer_moves_directly_to_connected_tile() {
-        use std::collections::HashSet;
-
-        // Engineer is far from connected tiles, should move directly to closest one
-        let engineer_pos = TilePos::new(10, 10);
-        let connected_tile = TilePos::new(5, 5);
-        let target = TilePos::new(8, 8);
-
-        let mut connected_tiles = HashSet::new();
-        connected_tiles.insert(connected_tile);
-
-        let mut owned_tiles = HashSet::new();
-        owned_tiles.insert(engineer_pos);
-        owned_tiles.insert(connected_tile);
-        owned_tiles.insert(target);
-
-        // Create terrain map with buildable terrain (Grass)
-        let mut tile_terrain = HashMap::new();
-        for &pos in &owned_tiles {
-            tile_terrain.insert(pos, crate::map::tiles::TerrainType::Grass);
-        }
-
-        let snapshot = NationSnapshot {
-            entity: Entity::PLACEHOLDER,
-            capital_pos: TilePos::new(0, 0),
-            treasury: 1000,
-            stockpile: HashMap::new(),
-            civilians: vec![],
-            connected_tiles,
-            unconnected_depots: vec![],
-            suggested_depots: vec![],
-            improvable_tiles: vec![],
-            owned_tiles,
-            depot_positions: HashSet::new(),
-            prospectable_tiles: vec![],
-            tile_terrain,
-            technologies: crate::economy::technology::Technologies::new(),
-            rail_constructions: vec![],
-            trade_capacity_total: 3,
-            trade_capacity_used: 0,
-            buildings: HashMap::new(),
-        };
-
-        let occupied_tracker = ReservationTracker::new();
-        let task = plan_engineer_depot_task(&snapshot, &occupied_tracker, engineer_pos, target);
-
-        // Should move directly to connected tile, not incremental step
-        assert!(matches!(task, Some(CivilianTask::MoveTo { target: t }) if t == connected_tile));
-    }
-
-    #[test]
-    fn test_engineer_builds_rail_when_on_connected_tile() {
-        use std::collections::HashSet;
-
-        // Engineer is on a connected tile, should build rail toward target
-        let engineer_pos = TilePos::new(5, 5);
-        let target = TilePos::new(8, 8);
-        let next_step = TilePos::new(6, 5); // Adjacent tile toward target
-
-        let mut connected_tiles = HashSet::new();
-        connected_tiles.insert(engineer_pos);
-
-        let mut owned_tiles = HashSet::new();
-        owned_tiles.insert(engineer_pos);
-        owned_tiles.insert(next_step);
-        owned_tiles.insert(target);
-
-        // Create terrain map with buildable terrain (Grass)
-        let mut tile_terrain = HashMap::new();
-        for &pos in &owned_tiles {
-            tile_terrain.insert(pos, crate::map::tiles::TerrainType::Grass);
-        }
-
-        let snapshot = NationSnapshot {
-            entity: Entity::PLACEHOLDER,
-            capital_pos: TilePos::new(0, 0),
-            treasury: 1000,
-            stockpile: HashMap::new(),
-            civilians: vec![],
-            connected_tiles,
-            unconnected_depots: vec![],
-            suggested_depots: vec![],
-            improvable_tiles: vec![],
-            owned_tiles,
-            depot_positions: HashSet::new(),
-            prospectable_tiles: vec![],
-            tile_terrain,
-            technologies: crate::economy::technology::Technologies::new(),
-            rail_constructions: vec![],
-            trade_capacity_total: 3,
-            trade_capacity_used: 0,
-            buildings: HashMap::new(),
-        };
-
-        let occupied_tracker = ReservationTracker::new();
-        let task = plan_engineer_depot_task(&snapshot, &occupied_tracker, engineer_pos, target);
-
-        // Should build rail to adjacent tile toward target
-        assert!(matches!(task, Some(CivilianTask::BuildRailTo { target: t }) if t == next_step));
-    }
-
-    /// Benchmark for `assign_civilians_to_goals`.
-    ///
-    /// Performance History:
-    /// - Before optimization (nested loop set cloning): ~1.89s for 200 civilians / 50 goals.
-    /// - After optimization (hoisted set construction): ~44.6ms for 200 civilians / 50 goals.
-    /// - Speedup: ~42x
-    #[test]
-    fn test_performance_assign_civilians() {
-        use std::collections::HashSet;
-        use std::time::Instant;
-
-        // Setup large scenario
-        let num_civilians = 200;
-        let num_goals = 50;
-
-        let mut civilians = Vec::new();
-        let mut owned_tiles = HashSet::new();
-
-        // Create civilians and tiles
-        for i in 0..num_civilians {
-            let entity = Entity::from_bits((i + 1) as u64);
-            let pos = TilePos::new(i as u32 % 50, i as u32 / 50);
-            civilians.push(crate::ai::snapshot::CivilianSnapshot {
-                entity,
-                kind: CivilianKind::Engineer,
-                position: pos,
-                has_moved: false,
-            });
-            owned_tiles.insert(pos);
-        }
-
-        let mut goals = Vec::new();
-        for i in 0..num_goals {
-            goals.push(NationGoal::BuildDepotAt {
-                tile: TilePos::new((i % 50) as u32, (i / 50 + 10) as u32),
-                priority: 1.0,
-            });
-        }
-
-        let snapshot = NationSnapshot {
-            entity: Entity::PLACEHOLDER,
-            capital_pos: TilePos::new(0, 0),
-            treasury: 1000,
-            stockpile: HashMap::new(),
-            civilians,
-            connected_tiles: HashSet::new(),
-            unconnected_depots: vec![],
-            suggested_depots: vec![],
-            improvable_tiles: vec![],
-            owned_tiles: owned_tiles.clone(),
-            depot_positions: HashSet::new(),
-            prospectable_tiles: vec![],
-            tile_terrain: HashMap::new(),
-            technologies: crate::economy::technology::Technologies::new(),
-            rail_constructions: vec![],
-            trade_capacity_total: 1000,
-            trade_capacity_used: 0,
-            buildings: HashMap::new(),
-        };
-
-        // Create empty AI snapshot for collision checking
-        let ai_snapshot = AiSnapshot {
-            occupied_tiles: HashSet::new(),
-            rails: HashSet::new(),
-            ..Default::default()
-        };
-
-        let mut tasks = HashMap::new();
-
-        // Benchmark
-        let start = Instant::now();
-        assign_civilians_to_goals(&snapshot, &ai_snapshot, &goals, &mut tasks);
-        let duration = start.elapsed();
-
-        println!(
-            "Performance Benchmark: assigned tasks for {} civilians and {} goals in {:?}",
-            num_civilians, num_goals, duration
-        );
-    }
-
-    #[test]
-    fn test_engineer_bridgehead_loop() {
-        use std::collections::HashSet;
-
-        // Two connected tiles (0,0) and (0,1) equally close to target (1,1)
-        // Capital at (0,0), another connected tile at (0,1).
-        // Hub at (1,1).
-        let capital_pos = TilePos::new(0, 0);
-        let pos_0_1 = TilePos::new(0, 1);
-        let target = TilePos::new(1, 1);
-
-        let mut connected_tiles = HashSet::new();
-        connected_tiles.insert(capital_pos);
-        connected_tiles.insert(pos_0_1);
-
-        let mut owned_tiles = HashSet::new();
-        owned_tiles.insert(capital_pos);
-        owned_tiles.insert(pos_0_1);
-        owned_tiles.insert(target);
-
-        let mut tile_terrain = HashMap::new();
-        for &pos in &owned_tiles {
-            tile_terrain.insert(pos, crate::map::tiles::TerrainType::Grass);
-        }
-
-        let snapshot = NationSnapshot {
-            entity: Entity::PLACEHOLDER,
-            capital_pos,
-            treasury: 1000,
-            stockpile: HashMap::new(),
-            civilians: vec![],
-            connected_tiles,
-            unconnected_depots: vec![],
-            suggested_depots: vec![],
-            improvable_tiles: vec![],
-            owned_tiles,
-            depot_positions: HashSet::new(),
-            prospectable_tiles: vec![],
-            tile_terrain,
-            technologies: crate::economy::technology::Technologies::new(),
-            rail_constructions: vec![],
-            trade_capacity_total: 3,
-            trade_capacity_used: 0,
-            buildings: HashMap::new(),
-        };
-
-        let occupied_tiles = HashSet::new();
-        let ai_snapshot = AiSnapshot {
-            occupied_tiles: occupied_tiles.clone(),
-            rails: HashSet::new(),
-            ..Default::default()
-        };
-
-        let occupied_tracker = ReservationTracker::new();
-
-        // If bridgehead logic picks (0,0) as better than (0,1) due to tie-breaking,
-        // and engineer is at (0,1), it will MoveTo (0,0).
-        let task =
-            plan_engineer_rail_task(&snapshot, &ai_snapshot, &occupied_tracker, pos_0_1, target);
-
-        // This is fine if it leads to progress.
-        // But if then it tries to move from (0,0) to (0,1), it's a loop.
-        if let Some(CivilianTask::MoveTo { target: t }) = task
-            && t == capital_pos
-        {
-            // Now check what happens at (0,0)
-            let task2 = plan_engineer_rail_task(
-                &snapshot,
-                &ai_snapshot,
-                &occupied_tracker,
-                capital_pos,
-                target,
-            );
-            // If task2 is MoveTo(0,1), we have a loop!
-            assert!(
-                !matches!(task2, Some(CivilianTask::MoveTo { target: next }) if next == pos_0_1),
-                "Loop detected: (0,1) -> (0,0) -> (0,1)"
-            );
-        }
-    }
-
-    #[test]
-    fn test_prospector_pathfinding() {
-        use std::collections::HashSet;
-
-        let prospector_pos = TilePos::new(0, 0);
-        let target = TilePos::new(0, 5);
-
-        let mut owned_tiles = HashSet::new();
-        for y in 0..=5 {
-            owned_tiles.insert(TilePos::new(0, y));
-        }
-
-        let civilians = vec![crate::ai::snapshot::CivilianSnapshot {
-            entity: Entity::from_bits(1),
-            kind: CivilianKind::Prospector,
-            position: prospector_pos,
-            has_moved: false,
-        }];
-
-        let mut tile_terrain = HashMap::new();
-        for pos in &owned_tiles {
-            tile_terrain.insert(*pos, crate::map::tiles::TerrainType::Grass);
-        }
-
-        let snapshot = NationSnapshot {
-            entity: Entity::PLACEHOLDER,
-            capital_pos: TilePos::new(0, 0),
-            treasury: 1000,
-            stockpile: HashMap::new(),
-            civilians,
-            connected_tiles: HashSet::new(),
-            unconnected_depots: vec![],
-            suggested_depots: vec![],
-            improvable_tiles: vec![],
-            owned_tiles: owned_tiles.clone(),
-            depot_positions: HashSet::new(),
-            prospectable_tiles: vec![],
-            tile_terrain,
-            technologies: crate::economy::technology::Technologies::new(),
-            rail_constructions: vec![],
-            trade_capacity_total: 10,
-            trade_capacity_used: 0,
-            buildings: HashMap::new(),
-        };
-
-        let goals = vec![NationGoal::ProspectTile {
-            tile: target,
-            priority: 1.0,
-        }];
-
-        let ai_snapshot = AiSnapshot {
-            occupied_tiles: HashSet::new(),
-            rails: HashSet::new(),
-            ..Default::default()
-        };
-
-        let mut tasks = HashMap::new();
-        assign_civilians_to_goals(&snapshot, &ai_snapshot, &goals, &mut tasks);
-
-        let task = tasks.get(&Entity::from_bits(1));
-
-        // Expect move to (0,1)
-        let expected_step = TilePos::new(0, 1);
-        assert!(
-            matches!(task, Some(CivilianTask::MoveTo { target: t }) if *t == expected_step),
-            "Expected move to step (0,1), got {:?}",
-            task
-        );
-    }
-}
+mod tests;
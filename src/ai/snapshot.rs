@@ -4,19 +4,26 @@
 //! a consistent view for both nation-level and civilian-level decisions.
 
 use bevy::prelude::*;
-use bevy_ecs_tilemap::prelude::{TilePos, TileStorage};
+use bevy_ecs_tilemap::prelude::TilePos;
+use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 
 use crate::ai::markers::AiNation;
+use crate::civilians::order_validation::{validate_depot_site, validate_improvement_target};
 use crate::civilians::types::{Civilian, CivilianKind, ProspectingKnowledge};
+use crate::diplomacy::DiplomacyState;
 use crate::economy::goods::Good;
-use crate::economy::market::{MARKET_RESOURCES, MarketPriceModel, MarketVolume};
-use crate::economy::nation::{Capital, Nation};
-use crate::economy::stockpile::{Stockpile, StockpileEntry};
+use crate::economy::market::{
+    MARKET_RESOURCES, MarketPriceModel, MarketVolume, partner_delivered_price,
+};
+use crate::economy::nation::{Capital, Nation, NationInstance};
+use crate::economy::stockpile::{Stockpile, StockpileEntry, StockpileReserves};
+use crate::economy::trade_capacity::TradeCapacitySnapshot;
 use crate::economy::transport::{Depot, Rails};
 use crate::economy::treasury::Treasury;
+use crate::map::neighbor_cache::NeighborCache;
 use crate::map::prospecting::PotentialMineral;
-use crate::map::province::Province;
+use crate::map::spatial_index::SpatialIndex;
 use crate::map::tile_pos::{HexExt, TilePosExt};
 use crate::resources::{DevelopmentLevel, TileResource};
 use crate::turn_system::TurnCounter;
@@ -30,6 +37,8 @@ pub struct AiSnapshot {
     /// All tiles currently occupied by any civilian (friendly or enemy)
     pub occupied_tiles: std::collections::HashSet<TilePos>,
     pub rails: std::collections::HashSet<(TilePos, TilePos)>,
+    /// Copy of the map's neighbor table, so planning BFS skips hex math
+    pub neighbors: NeighborCache,
 }
 
 impl AiSnapshot {
@@ -64,6 +73,8 @@ pub struct NationSnapshot {
     /// Trade capacity information.
     pub trade_capacity_total: u32,
     pub trade_capacity_used: u32,
+    /// Whether the nation has a trade agreement, sharing shipping with a partner
+    pub has_trade_partner: bool,
     /// Buildings owned by this nation.
     pub buildings:
         HashMap<crate::economy::production::BuildingKind, crate::economy::production::Building>,
@@ -108,6 +119,14 @@ impl NationSnapshot {
         }
         self.trade_capacity_used as f32 / self.trade_capacity_total as f32
     }
+
+    /// Trade capacity in the form used by market price views.
+    pub fn trade_capacity(&self) -> TradeCapacitySnapshot {
+        TradeCapacitySnapshot {
+            total: self.trade_capacity_total,
+            used: self.trade_capacity_used,
+        }
+    }
 }
 
 /// Snapshot of a civilian unit.
@@ -182,8 +201,7 @@ pub fn calculate_suggested_depots(
                 // Filter out tiles with invalid terrain
                 tile_terrain
                     .get(pos)
-                    .map(crate::economy::transport::can_build_depot_on_terrain)
-                    .unwrap_or(false)
+                    .is_some_and(|terrain| validate_depot_site(terrain).is_ok())
             })
             .map(|&pos| {
                 let covers_count = depot_coverage(pos)
@@ -193,7 +211,9 @@ pub fn calculate_suggested_depots(
                 (pos, covers_count, distance)
             })
             .filter(|(_, count, _)| *count > 0) // Must cover at least 1 resource
-            .max_by_key(|(_, count, dist)| (*count * 100, u32::MAX - dist)); // Prefer more coverage, then closer
+            // Prefer more coverage, then closer, then the lowest position so ties don't
+            // depend on hash set iteration order
+            .max_by_key(|(pos, count, dist)| (*count, Reverse(*dist), Reverse((pos.x, pos.y))));
 
         if let Some((pos, covers_count, distance)) = best {
             // Mark covered tiles as handled
@@ -210,8 +230,15 @@ pub fn calculate_suggested_depots(
         }
     }
 
-    // Sort by distance (closest first, with coverage as tiebreaker)
-    suggestions.sort_by_key(|s| (s.distance_from_capital, u32::MAX - s.covers_count));
+    // Sort by distance (closest first, with coverage and then position as tiebreakers)
+    suggestions.sort_by_key(|s| {
+        (
+            s.distance_from_capital,
+            Reverse(s.covers_count),
+            s.position.x,
+            s.position.y,
+        )
+    });
 
     suggestions
 }
@@ -243,6 +270,17 @@ impl MarketSnapshot {
     pub fn price_for(&self, good: Good) -> u32 {
         self.prices.get(&good).copied().unwrap_or(100)
     }
+
+    /// Price including the transport surcharge for a nation with the given trade capacity,
+    /// reduced when it can ship with a trade agreement partner.
+    pub fn delivered_price_for(
+        &self,
+        good: Good,
+        capacity: TradeCapacitySnapshot,
+        trade_agreement: bool,
+    ) -> u32 {
+        partner_delivered_price(self.price_for(good), capacity, trade_agreement)
+    }
 }
 
 /// Target buffer the AI aims to maintain for tradable resources.
@@ -266,12 +304,13 @@ pub fn build_ai_snapshot(
     trade_capacity: Res<crate::economy::trade_capacity::TradeCapacity>,
     ai_nations: Query<
         (
-            Entity,
+            NationInstance,
             &Capital,
             &Stockpile,
             &Treasury,
             &crate::economy::technology::Technologies,
             &crate::economy::production::Buildings,
+            &StockpileReserves,
         ),
         (With<AiNation>, With<Nation>),
     >,
@@ -279,12 +318,13 @@ pub fn build_ai_snapshot(
     civilian_jobs: Query<&crate::civilians::types::CivilianJob>,
     rail_constructions: Query<&crate::economy::transport::RailConstruction>,
     depots: Query<&Depot>,
-    provinces: Query<&Province>,
-    tile_storage: Query<&TileStorage>,
+    spatial_index: Res<SpatialIndex>,
+    neighbor_cache: Res<NeighborCache>,
     tile_resources: Query<&TileResource>,
     tile_terrain: Query<&crate::map::tiles::TerrainType>,
     potential_minerals: Query<&PotentialMineral>,
     prospecting: Option<Res<ProspectingKnowledge>>,
+    diplomacy: Option<Res<DiplomacyState>>,
 ) {
     snapshot.turn = turn.current;
 
@@ -296,6 +336,9 @@ pub fn build_ai_snapshot(
 
     // Capture rail network
     snapshot.rails = rails.0.clone();
+    if snapshot.neighbors.size() != neighbor_cache.size() {
+        snapshot.neighbors = neighbor_cache.clone();
+    }
 
     snapshot.nations.clear();
     // Build market snapshot
@@ -305,31 +348,30 @@ pub fn build_ai_snapshot(
         snapshot.market.prices.insert(good, price);
     }
 
-    let Ok(storage) = tile_storage.single() else {
-        return;
-    };
-
     // Build per-nation snapshots
-    for (entity, capital, stockpile, treasury, technologies, buildings) in ai_nations.iter() {
+    for (nation, capital, stockpile, treasury, technologies, buildings, reserves) in
+        ai_nations.iter()
+    {
+        let entity = nation.entity();
         let capital_pos = capital.0;
         let capital_hex = capital_pos.to_hex();
 
-        // Collect stockpile entries
+        // Collect stockpile entries; goods held back by reserve floors are not available
+        // to the planner, so it neither sells nor consumes them and buys to stay above them
         let stockpile_map: HashMap<Good, StockpileEntry> = stockpile
             .entries()
-            .map(|entry| (entry.good, entry))
+            .map(|mut entry| {
+                entry.available = reserves.spendable(stockpile, entry.good);
+                (entry.good, entry)
+            })
             .collect();
 
-        // Find owned tiles from provinces
-        let mut owned_tiles = HashSet::new();
-        for province in provinces.iter() {
-            if province.owner == Some(entity) {
-                owned_tiles.extend(province.tiles.iter().copied());
-            }
-        }
+        // Owned tiles come from the spatial index instead of scanning every province
+        let owned_tiles = spatial_index.tiles_of(entity).cloned().unwrap_or_default();
 
         // Compute connected tiles via BFS from capital along rails
-        let connected_tiles = compute_connected_tiles(capital_pos, entity, &owned_tiles, &rails);
+        let connected_tiles =
+            compute_connected_tiles(capital_pos, &owned_tiles, &rails, &neighbor_cache);
 
         // Collect all depot positions for this nation
         let depot_positions: HashSet<TilePos> = depots
@@ -352,11 +394,12 @@ pub fn build_ai_snapshot(
             .collect();
         unconnected_depots.sort_by_key(|d| d.distance_from_capital);
 
-        // Find resource tiles and improvable tiles
+        // Find resource tiles and improvable tiles; tile entities come from the spatial
+        // index so depot selection never touches tiles outside the nation
         let mut resource_tiles = HashSet::new();
         let mut improvable_tiles = Vec::new();
         for &tile_pos in &owned_tiles {
-            let Some(tile_entity) = storage.get(&tile_pos) else {
+            let Some(tile_entity) = spatial_index.tile_at(tile_pos) else {
                 continue;
             };
             let Ok(resource) = tile_resources.get(tile_entity) else {
@@ -366,24 +409,18 @@ pub fn build_ai_snapshot(
                 continue;
             }
             // Check prospecting knowledge for minerals
-            let prospected = if resource.requires_prospecting() {
-                if let Some(ref knowledge) = prospecting {
-                    knowledge.is_discovered_by(tile_entity, entity)
-                } else {
-                    false
-                }
-            } else {
-                true
-            };
-            if !prospected {
+            let prospected = prospecting
+                .as_ref()
+                .is_some_and(|knowledge| knowledge.is_discovered_by(tile_entity, entity));
+            if resource.requires_prospecting() && !prospected {
                 continue;
             }
             // Track all discovered resource tiles for depot coverage calculation
             resource_tiles.insert(tile_pos);
 
-            // Track improvable tiles (not at max development)
-            if resource.development < DevelopmentLevel::Lv3
-                && let Some(improver_kind) = improver_for_resource(&resource.resource_type)
+            // Track improvable tiles the player would be allowed to improve as well
+            if let Some(improver_kind) =
+                improvement_target(resource, prospected, technologies.development_cap())
             {
                 let distance = capital_hex.distance_to(tile_pos.to_hex()) as u32;
                 improvable_tiles.push(ImprovableTile {
@@ -400,7 +437,7 @@ pub fn build_ai_snapshot(
         // Find prospectable tiles (owned tiles with PotentialMineral not yet prospected by this nation)
         let mut prospectable_tiles = Vec::new();
         for &tile_pos in &owned_tiles {
-            let Some(tile_entity) = storage.get(&tile_pos) else {
+            let Some(tile_entity) = spatial_index.tile_at(tile_pos) else {
                 continue;
             };
             // Check if tile has potential minerals
@@ -423,7 +460,7 @@ pub fn build_ai_snapshot(
         // Collect terrain information for owned tiles
         let mut tile_terrain_map = HashMap::new();
         for &tile_pos in &owned_tiles {
-            if let Some(tile_entity) = storage.get(&tile_pos)
+            if let Some(tile_entity) = spatial_index.tile_at(tile_pos)
                 && let Ok(terrain) = tile_terrain.get(tile_entity)
             {
                 tile_terrain_map.insert(tile_pos, *terrain);
@@ -478,6 +515,12 @@ pub fn build_ai_snapshot(
 
         // Get trade capacity
         let capacity_snapshot = trade_capacity.snapshot(entity);
+        let has_trade_partner = diplomacy.as_ref().is_some_and(|diplomacy| {
+            diplomacy
+                .relations_for(nation)
+                .into_iter()
+                .any(|(other, _)| diplomacy.has_trade_agreement(nation, other))
+        });
 
         snapshot.nations.insert(
             entity,
@@ -499,6 +542,7 @@ pub fn build_ai_snapshot(
                 rail_constructions: nation_rail_constructions,
                 trade_capacity_total: capacity_snapshot.total,
                 trade_capacity_used: capacity_snapshot.used,
+                has_trade_partner,
                 buildings: buildings.buildings.clone(),
             },
         );
@@ -508,9 +552,9 @@ pub fn build_ai_snapshot(
 /// Compute tiles connected to capital via rails.
 fn compute_connected_tiles(
     capital: TilePos,
-    _owner: Entity,
     owned_tiles: &HashSet<TilePos>,
     rails: &Rails,
+    neighbor_cache: &NeighborCache,
 ) -> HashSet<TilePos> {
     use std::collections::VecDeque;
 
@@ -521,11 +565,7 @@ fn compute_connected_tiles(
     queue.push_back(capital);
 
     while let Some(current) = queue.pop_front() {
-        for neighbor_hex in current.to_hex().all_neighbors() {
-            let Some(neighbor_pos) = neighbor_hex.to_tile_pos() else {
-                continue;
-            };
-
+        for &neighbor_pos in neighbor_cache.neighbors(current) {
             // Must be owned
             if !owned_tiles.contains(&neighbor_pos) {
                 continue;
@@ -546,6 +586,18 @@ fn compute_connected_tiles(
     connected
 }
 
+/// Civilian kind that may improve `resource` right now, checked with the same validator as
+/// player orders so the AI never plans an improvement the order would be rejected for.
+/// `cap` is the highest development level the nation's technology allows.
+pub fn improvement_target(
+    resource: &TileResource,
+    prospected: bool,
+    cap: DevelopmentLevel,
+) -> Option<CivilianKind> {
+    improver_for_resource(&resource.resource_type)
+        .filter(|&kind| validate_improvement_target(kind, resource, prospected, cap).is_ok())
+}
+
 /// Determine which civilian kind can improve a resource type.
 fn improver_for_resource(resource_type: &crate::resources::ResourceType) -> Option<CivilianKind> {
     use crate::resources::ResourceType;
@@ -575,6 +627,62 @@ mod tests {
         assert_eq!(resource_target_days(Good::Steel), 20.0);
     }
 
+    #[test]
+    fn ai_improvement_targets_follow_player_order_validation() {
+        use crate::civilians::order_validation::validate_improvement_target;
+        use crate::messages::civilians::CivilianCommandError;
+        use crate::resources::ResourceType;
+
+        let cap = DevelopmentLevel::MAX;
+
+        // A hidden coal seam nobody prospected: the player's order is rejected, so the AI skips it
+        let hidden = TileResource::hidden_mineral(ResourceType::Coal);
+        assert_eq!(
+            validate_improvement_target(CivilianKind::Miner, &hidden, false, cap),
+            Err(CivilianCommandError::ResourceNotProspected)
+        );
+        assert_eq!(improvement_target(&hidden, false, cap), None);
+
+        // Discovered by another nation but not by this one
+        let mut seen_elsewhere = hidden;
+        seen_elsewhere.discovered = true;
+        assert!(
+            validate_improvement_target(CivilianKind::Miner, &seen_elsewhere, false, cap).is_err()
+        );
+        assert_eq!(improvement_target(&seen_elsewhere, false, cap), None);
+
+        // Fully developed farms are rejected by both
+        let mut developed = TileResource::visible(ResourceType::Grain);
+        developed.development = DevelopmentLevel::MAX;
+        assert!(validate_improvement_target(CivilianKind::Farmer, &developed, true, cap).is_err());
+        assert_eq!(improvement_target(&developed, true, cap), None);
+
+        // Farms at the technology cap are rejected by both until the cap rises
+        let mut capped = TileResource::visible(ResourceType::Grain);
+        capped.development = DevelopmentLevel::Lv2;
+        assert_eq!(
+            validate_improvement_target(CivilianKind::Farmer, &capped, true, DevelopmentLevel::Lv2),
+            Err(CivilianCommandError::DevelopmentCapReached)
+        );
+        assert_eq!(
+            improvement_target(&capped, true, DevelopmentLevel::Lv2),
+            None
+        );
+        assert_eq!(
+            improvement_target(&capped, true, DevelopmentLevel::Lv3),
+            Some(CivilianKind::Farmer)
+        );
+
+        // Once prospected, the AI picks a civilian whose order the validator accepts
+        let kind =
+            improvement_target(&seen_elsewhere, true, cap).expect("prospected coal is improvable");
+        assert_eq!(kind, CivilianKind::Miner);
+        assert_eq!(
+            validate_improvement_target(kind, &seen_elsewhere, true, cap),
+            Ok(())
+        );
+    }
+
     #[test]
     fn depot_coverage_returns_seven_tiles() {
         let pos = TilePos::new(5, 5);
@@ -587,6 +695,52 @@ mod tests {
         assert!(coverage.len() <= 7, "coverage should be at most 7 tiles");
     }
 
+    #[test]
+    fn tied_depot_sites_resolve_independently_of_tile_order() {
+        let capital_pos = TilePos::new(0, 0);
+        let resource = TilePos::new(6, 6);
+        let mut layout: Vec<TilePos> = depot_coverage(resource).collect();
+        layout.sort_by_key(|pos| (pos.x, pos.y));
+
+        // (5, 6) and (6, 5) are both 11 tiles from the capital and cover the resource
+        let closest = layout
+            .iter()
+            .map(|pos| capital_pos.to_hex().distance_to(pos.to_hex()))
+            .min()
+            .unwrap();
+        let tied: Vec<TilePos> = layout
+            .iter()
+            .copied()
+            .filter(|pos| capital_pos.to_hex().distance_to(pos.to_hex()) == closest)
+            .collect();
+        assert!(tied.len() > 1, "scenario must contain a tie");
+
+        let pick = |tiles: &[TilePos]| {
+            let owned_tiles: HashSet<TilePos> = tiles.iter().copied().collect();
+            let tile_terrain: HashMap<TilePos, crate::map::tiles::TerrainType> = tiles
+                .iter()
+                .map(|&pos| (pos, crate::map::tiles::TerrainType::Grass))
+                .collect();
+            let suggestions = calculate_suggested_depots(
+                &[resource].into_iter().collect(),
+                &owned_tiles,
+                &HashSet::new(),
+                capital_pos,
+                &tile_terrain,
+            );
+            assert_eq!(suggestions.len(), 1);
+            suggestions[0].position
+        };
+
+        let forward = pick(&layout);
+        let mut reversed = layout.clone();
+        reversed.reverse();
+        for _ in 0..8 {
+            assert_eq!(pick(&reversed), forward);
+        }
+        assert_eq!(forward, tied[0]);
+    }
+
     #[test]
     fn adjacent_resources_get_single_depot_suggestion() {
         // Get adjacent positions using hex neighbors
@@ -893,6 +1047,7 @@ mod tests {
             rail_constructions: vec![],
             trade_capacity_total: 3,
             trade_capacity_used: 0,
+            has_trade_partner: false,
             buildings: HashMap::new(),
         };
 
@@ -13,8 +13,8 @@ use rust_imperialism::LogicPlugins;
 use rust_imperialism::ai::AiControlledCivilian;
 use rust_imperialism::ai::AiNation;
 use rust_imperialism::civilians::Civilian;
-use rust_imperialism::constants::TERRAIN_SEED;
-use rust_imperialism::economy::nation::NationColor;
+use rust_imperialism::constants::{TERRAIN_SEED, hex_map_type};
+use rust_imperialism::economy::nation::{NationColor, NationColorSlot};
 use rust_imperialism::map::TerrainType;
 use rust_imperialism::map::prospecting::PotentialMineral;
 use rust_imperialism::map::province::Province;
@@ -207,7 +207,7 @@ fn setup_mock_tilemap(
 
     commands.entity(tilemap_entity).insert((
         TilemapGridSize { x: 16.0, y: 16.0 },
-        TilemapType::Hexagon(HexCoordSystem::Row),
+        hex_map_type(),
         map_size,
         tile_storage,
         TilemapTileSize { x: 16.0, y: 16.0 },
@@ -221,7 +221,12 @@ fn ensure_red_nation_for_target_tile(
     tile_storage_query: Query<&TileStorage>,
     tile_provinces: Query<&TileProvince>,
     provinces: Query<&Province>,
-    mut nations: Query<(Entity, &mut NationColor, Option<&AiNation>)>,
+    mut nations: Query<(
+        Entity,
+        &mut NationColor,
+        &mut NationColorSlot,
+        Option<&AiNation>,
+    )>,
     civilians: Query<(Entity, &Civilian)>,
     mut done: Local<bool>,
 ) {
@@ -248,37 +253,37 @@ fn ensure_red_nation_for_target_tile(
         return;
     };
 
-    let red_color = Color::srgb(0.8, 0.2, 0.2);
+    // The Red nation occupies palette slot 1
+    let red_slot = NationColorSlot(1);
     let mut red_entity = None;
     let mut target_owner_color = None;
 
-    for (entity, color, _) in nations.iter() {
-        let linear = color.0.to_linear();
-        let expected = red_color.to_linear();
-        if (linear.red - expected.red).abs() < 0.01
-            && (linear.green - expected.green).abs() < 0.01
-            && (linear.blue - expected.blue).abs() < 0.01
-        {
-            red_entity = Some(entity);
+    for (entity, color, slot, _) in nations.iter() {
+        if *slot == red_slot {
+            red_entity = Some((entity, color.0));
         }
         if entity == target_owner {
-            target_owner_color = Some(color.0);
+            target_owner_color = Some((color.0, *slot));
         }
     }
 
-    let Some(target_owner_color) = target_owner_color else {
+    let Some((target_owner_color, target_owner_slot)) = target_owner_color else {
         return;
     };
 
-    if red_entity != Some(target_owner) {
-        if let Some(red_entity) = red_entity
-            && let Ok((_, mut color, _)) = nations.get_mut(red_entity)
+    if red_entity.map(|(entity, _)| entity) != Some(target_owner) {
+        let mut red_color = Color::srgb(0.8, 0.2, 0.2);
+        if let Some((red_entity, color)) = red_entity
+            && let Ok((_, mut nation_color, mut slot, _)) = nations.get_mut(red_entity)
         {
-            color.0 = target_owner_color;
+            red_color = color;
+            nation_color.0 = target_owner_color;
+            *slot = target_owner_slot;
         }
 
-        if let Ok((_, mut color, ai_marker)) = nations.get_mut(target_owner) {
+        if let Ok((_, mut color, mut slot, ai_marker)) = nations.get_mut(target_owner) {
             color.0 = red_color;
+            *slot = red_slot;
             if ai_marker.is_none() {
                 commands.entity(target_owner).insert(AiNation);
             }
@@ -289,7 +294,7 @@ fn ensure_red_nation_for_target_tile(
                 commands.entity(entity).insert(AiControlledCivilian);
             }
         }
-    } else if let Ok((_, _, ai_marker)) = nations.get_mut(target_owner)
+    } else if let Ok((_, _, _, ai_marker)) = nations.get_mut(target_owner)
         && ai_marker.is_none()
     {
         commands.entity(target_owner).insert(AiNation);
@@ -9,7 +9,7 @@ use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured, save_to_dis
 use bevy::window::{Window, WindowPlugin, WindowResolution};
 use bevy_ecs_tilemap::prelude::*;
 use moonshine_save::prelude::*;
-use rust_imperialism::constants::{MAP_SIZE, TILE_SIZE, get_hex_grid_size};
+use rust_imperialism::constants::{MAP_SIZE, TILE_SIZE, get_hex_grid_size, hex_map_type};
 use rust_imperialism::map::rendering::terrain_atlas::TerrainAtlas;
 use rust_imperialism::ui::components::MapTilemap;
 use rust_imperialism::ui::menu::AppState;
@@ -150,7 +150,7 @@ fn build_tilemap_from_fixture(
         y: TILE_SIZE,
     };
     let grid_size = get_hex_grid_size();
-    let map_type = TilemapType::Hexagon(HexCoordSystem::Row);
+    let map_type = hex_map_type();
 
     let tilemap_entity = commands
         .spawn((
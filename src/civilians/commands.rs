@@ -1,9 +1,56 @@
 use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::TilePos;
 
-/// UI-only resource tracking which civilian is currently selected.
-/// This resource exists only while a civilian is selected.
-#[derive(Resource, Debug, Clone, Copy)]
-pub struct SelectedCivilian(pub Entity);
+/// UI-only resource tracking which civilians are currently selected.
+/// This resource exists only while at least one civilian is selected.
+/// `.0` is the primary unit whose orders are shown; box selection adds the
+/// rest of the group to `.1`.
+#[derive(Resource, Debug, Clone)]
+pub struct SelectedCivilian(pub Entity, pub Vec<Entity>);
+
+impl SelectedCivilian {
+    /// Select a single civilian
+    pub fn single(entity: Entity) -> Self {
+        Self(entity, Vec::new())
+    }
+
+    /// Select a group; the first entity becomes the primary one.
+    /// Returns `None` for an empty group.
+    pub fn group(entities: impl IntoIterator<Item = Entity>) -> Option<Self> {
+        let mut entities = entities.into_iter();
+        let primary = entities.next()?;
+        let mut rest = Vec::new();
+        for entity in entities {
+            if entity != primary && !rest.contains(&entity) {
+                rest.push(entity);
+            }
+        }
+        Some(Self(primary, rest))
+    }
+
+    /// Every selected civilian, primary first
+    pub fn members(&self) -> impl Iterator<Item = Entity> + '_ {
+        std::iter::once(self.0).chain(self.1.iter().copied())
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.0 == entity || self.1.contains(&entity)
+    }
+
+    /// True when more than one civilian is selected
+    pub fn is_group(&self) -> bool {
+        !self.1.is_empty()
+    }
+}
+
+/// UI-only resource tracking a box selection dragged with the left mouse button
+#[derive(Resource, Debug, Default)]
+pub struct BoxSelection {
+    /// Screen position where the button was pressed
+    pub start: Option<Vec2>,
+    /// Whether the cursor has moved far enough to count as a drag rather than a click
+    pub dragging: bool,
+}
 
 /// Message: Player selects a civilian unit
 #[derive(Event, Debug, Clone, Copy)]
@@ -11,6 +58,18 @@ pub struct SelectCivilian {
     pub entity: Entity,
 }
 
+/// Message: Player selects several civilian units at once (box selection)
+#[derive(Event, Debug, Clone)]
+pub struct SelectCivilians {
+    pub entities: Vec<Entity>,
+}
+
+/// Message: Move every selected civilian toward a tile
+#[derive(Event, Debug, Clone, Copy)]
+pub struct MoveSelectedCivilians {
+    pub to: TilePos,
+}
+
 /// Message: Deselect the currently selected civilian
 #[derive(Event, Debug)]
 pub struct DeselectCivilian;
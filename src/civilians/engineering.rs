@@ -1,16 +1,20 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::{TilePos, TileStorage, TilemapSize};
+use std::collections::VecDeque;
 
 use crate::civilians::commands::DeselectCivilian;
-use crate::civilians::order_validation::tile_owned_by_nation;
+use crate::civilians::jobs::{JobInputOwners, reserve_job_inputs};
+use crate::civilians::order_validation::{tile_owned_by_nation, validate_improvement_target};
 use crate::civilians::types::{
-    ActionTurn, Civilian, CivilianJob, CivilianKind, CivilianOrder, CivilianOrderKind, JobType,
-    PreviousPosition, ProspectingKnowledge,
+    ActionTurn, AreaImprovementQueue, Civilian, CivilianJob, CivilianKind, CivilianOrder,
+    CivilianOrderKind, JobType, MAX_IMPROVE_AREA_RADIUS, PreviousPosition, ProspectingKnowledge,
 };
+use crate::economy::technology::{BASE_DEVELOPMENT_CAP, Technologies};
 use crate::economy::transport::{Rails, ordered_edge};
 use crate::economy::{ImprovementKind, PlaceImprovement};
 use crate::map::province::{Province, TileProvince};
-use crate::resources::{DevelopmentLevel, TileResource};
+use crate::map::tiles::TerrainType;
+use crate::resources::TileResource;
 use crate::turn_system::TurnCounter;
 
 /// Execute Engineer orders (building infrastructure)
@@ -22,6 +26,7 @@ pub fn execute_engineer_orders(
     tile_storage_query: Query<(&TileStorage, &TilemapSize)>,
     tile_provinces: Query<&TileProvince>,
     provinces: Query<&Province>,
+    terrain: Query<&TerrainType>,
 ) {
     for (entity, mut civilian, order) in engineers.iter_mut() {
         // Only process Engineer units
@@ -66,13 +71,20 @@ pub fn execute_engineer_orders(
                     &tile_storage_query,
                     &tile_provinces,
                     &provinces,
+                    &terrain,
                 );
             }
             CivilianOrderKind::BuildDepot => {
-                handle_build_depot_order(&mut commands, entity, &mut civilian, &turn);
+                let site = terrain_at(civilian.position, &tile_storage_query, &terrain);
+                handle_build_depot_order(&mut commands, entity, &mut civilian, site, &turn);
             }
             CivilianOrderKind::BuildPort => {
-                handle_build_port_order(&mut commands, entity, &mut civilian, &turn);
+                let site = terrain_at(civilian.position, &tile_storage_query, &terrain);
+                handle_build_port_order(&mut commands, entity, &mut civilian, site, &turn);
+            }
+            CivilianOrderKind::BuildBridge { to } => {
+                let site = terrain_at(civilian.position, &tile_storage_query, &terrain);
+                handle_build_bridge_order(&mut commands, entity, &mut civilian, to, site, &turn);
             }
             CivilianOrderKind::Move { .. } => {
                 // Move orders are handled by execute_move_orders for all civilians
@@ -87,6 +99,20 @@ pub fn execute_engineer_orders(
     }
 }
 
+/// Terrain of the tile at `pos`, if the map has one there
+fn terrain_at(
+    pos: TilePos,
+    tile_storage_query: &Query<(&TileStorage, &TilemapSize)>,
+    terrain: &Query<&TerrainType>,
+) -> Option<TerrainType> {
+    tile_storage_query
+        .iter()
+        .next()
+        .and_then(|(tile_storage, _)| tile_storage.get(&pos))
+        .and_then(|tile| terrain.get(tile).ok())
+        .copied()
+}
+
 fn handle_build_rail_order(
     commands: &mut Commands,
     entity: Entity,
@@ -97,6 +123,7 @@ fn handle_build_rail_order(
     tile_storage_query: &Query<(&TileStorage, &TilemapSize)>,
     tile_provinces: &Query<&TileProvince>,
     provinces: &Query<&Province>,
+    terrain: &Query<&TerrainType>,
 ) {
     // Also check target tile ownership
     let target_owned = tile_storage_query
@@ -156,14 +183,15 @@ fn handle_build_rail_order(
         civilian.position = to;
         civilian.has_moved = true;
         commands.trigger(DeselectCivilian); // Auto-deselect after action
-        // Add job to lock Engineer and previous position for rescinding
+        // Add job to lock Engineer and previous position for rescinding;
+        // the rail takes as long as its harder endpoint allows
         let job_type = JobType::BuildingRail;
+        let site = [previous_pos, to]
+            .into_iter()
+            .filter_map(|pos| terrain_at(pos, tile_storage_query, terrain))
+            .max_by_key(|terrain| terrain.build_difficulty());
         commands.entity(entity).insert((
-            CivilianJob {
-                job_type,
-                turns_remaining: job_type.duration(),
-                target: to,
-            },
+            CivilianJob::on_terrain(job_type, to, site).hastened(civilian.job_speedup()),
             PreviousPosition(previous_pos),
             ActionTurn(turn.current),
         ));
@@ -174,6 +202,7 @@ fn handle_build_depot_order(
     commands: &mut Commands,
     entity: Entity,
     civilian: &mut Civilian,
+    site: Option<TerrainType>,
     turn: &Res<TurnCounter>,
 ) {
     // Store previous position for potential undo
@@ -191,11 +220,7 @@ fn handle_build_depot_order(
     // Add job to lock Engineer and previous position for rescinding
     let job_type = JobType::BuildingDepot;
     commands.entity(entity).insert((
-        CivilianJob {
-            job_type,
-            turns_remaining: job_type.duration(),
-            target: civilian.position,
-        },
+        CivilianJob::on_terrain(job_type, civilian.position, site).hastened(civilian.job_speedup()),
         PreviousPosition(previous_pos),
         ActionTurn(turn.current),
     ));
@@ -205,6 +230,7 @@ fn handle_build_port_order(
     commands: &mut Commands,
     entity: Entity,
     civilian: &mut Civilian,
+    site: Option<TerrainType>,
     turn: &Res<TurnCounter>,
 ) {
     // Store previous position for potential undo
@@ -222,11 +248,38 @@ fn handle_build_port_order(
     // Add job to lock Engineer and previous position for rescinding
     let job_type = JobType::BuildingPort;
     commands.entity(entity).insert((
-        CivilianJob {
-            job_type,
-            turns_remaining: job_type.duration(),
-            target: civilian.position,
-        },
+        CivilianJob::on_terrain(job_type, civilian.position, site).hastened(civilian.job_speedup()),
+        PreviousPosition(previous_pos),
+        ActionTurn(turn.current),
+    ));
+}
+
+fn handle_build_bridge_order(
+    commands: &mut Commands,
+    entity: Entity,
+    civilian: &mut Civilian,
+    to: TilePos,
+    site: Option<TerrainType>,
+    turn: &Res<TurnCounter>,
+) {
+    // Store previous position for potential undo
+    let previous_pos = civilian.position;
+
+    // The engineer stays on the bank; the bridge spans the edge towards `to`
+    commands.trigger(PlaceImprovement {
+        a: civilian.position,
+        b: to,
+        kind: ImprovementKind::Bridge,
+        nation: Some(civilian.owner),
+        engineer: Some(entity),
+    });
+    civilian.has_moved = true;
+    commands.trigger(DeselectCivilian); // Auto-deselect after action
+    // Add job to lock Engineer and previous position for rescinding; like rail jobs,
+    // the target is the far end of the edge so a rescind can find the bridge again
+    let job_type = JobType::BuildingBridge;
+    commands.entity(entity).insert((
+        CivilianJob::on_terrain(job_type, to, site).hastened(civilian.job_speedup()),
         PreviousPosition(previous_pos),
         ActionTurn(turn.current),
     ));
@@ -315,11 +368,7 @@ pub fn execute_prospector_orders(
                         .unwrap_or(JobType::Prospecting);
 
                     commands.entity(entity).insert((
-                        CivilianJob {
-                            job_type,
-                            turns_remaining: job_type.duration(),
-                            target: to,
-                        },
+                        CivilianJob::new(job_type, to).hastened(civilian.job_speedup()),
                         PreviousPosition(previous_pos),
                         ActionTurn(turn.current),
                     ));
@@ -353,6 +402,9 @@ pub fn execute_civilian_improvement_orders(
     provinces: Query<&Province>,
     tile_resources: Query<&TileResource>,
     prospecting_knowledge: Res<ProspectingKnowledge>,
+    terrain: Query<&TerrainType>,
+    technologies: Query<&Technologies>,
+    mut owners: JobInputOwners,
 ) {
     for (entity, mut civilian, order) in civilians.iter_mut() {
         // Only process civilians that support tile improvements
@@ -360,13 +412,27 @@ pub fn execute_civilian_improvement_orders(
             continue;
         }
 
-        let Some(resource_predicate) = civilian.kind.improvement_predicate() else {
-            continue;
-        };
         let Some(job_type) = civilian.kind.improvement_job() else {
             continue;
         };
 
+        // Area orders are broken into single-tile orders by `queue_next_area_improvement`
+        if let CivilianOrderKind::ImproveArea { center, radius } = order.target {
+            let tiles = area_improvement_tiles(center, radius);
+            info!(
+                "{:?} queued {} tiles around ({}, {}) for improvement",
+                civilian.kind,
+                tiles.len(),
+                center.x,
+                center.y
+            );
+            commands
+                .entity(entity)
+                .insert(AreaImprovementQueue { tiles })
+                .remove::<CivilianOrder>();
+            continue;
+        }
+
         // Extract target position from order
         let target_pos = match order.target {
             CivilianOrderKind::ImproveTile { to }
@@ -409,77 +475,75 @@ pub fn execute_civilian_improvement_orders(
             && let Some(tile_entity) = tile_storage.get(&target_pos)
         {
             if let Ok(resource) = tile_resources.get(tile_entity) {
-                if resource.requires_prospecting()
-                    && !prospecting_knowledge.is_discovered_by(tile_entity, civilian.owner)
+                let prospected =
+                    prospecting_knowledge.is_discovered_by(tile_entity, civilian.owner);
+                let cap = technologies
+                    .get(civilian.owner)
+                    .map_or(BASE_DEVELOPMENT_CAP, Technologies::development_cap);
+                if let Err(reason) =
+                    validate_improvement_target(civilian.kind, resource, prospected, cap)
                 {
                     info!(
-                        "{:?} must have this tile prospected before improving it",
-                        civilian.kind
+                        "{:?} cannot improve {:?} at ({}, {}): {}",
+                        civilian.kind,
+                        resource.resource_type,
+                        target_pos.x,
+                        target_pos.y,
+                        reason.describe()
                     );
                     commands.entity(entity).remove::<CivilianOrder>();
                     continue;
                 }
 
-                if !resource.discovered {
+                if let Err(reason) =
+                    reserve_job_inputs(entity, civilian.owner, job_type, &mut owners)
+                {
                     info!(
-                        "{:?} must have this tile prospected before improving it",
-                        civilian.kind
+                        "{:?} cannot start work at ({}, {}): {}",
+                        civilian.kind, target_pos.x, target_pos.y, reason
                     );
                     commands.entity(entity).remove::<CivilianOrder>();
                     continue;
                 }
 
-                let can_improve = resource_predicate(resource);
+                // Store previous position for potential undo
+                let previous_pos = civilian.position;
 
-                if can_improve && resource.development < DevelopmentLevel::Lv3 {
-                    // Store previous position for potential undo
-                    let previous_pos = civilian.position;
+                // Move to target tile
+                civilian.position = target_pos;
 
-                    // Move to target tile
-                    civilian.position = target_pos;
-
-                    // Start improvement job
-                    let job = CivilianJob {
-                        job_type,
-                        turns_remaining: job_type.duration(),
-                        target: target_pos,
-                    };
-                    info!(
-                        "CREATING JOB: {:?} for entity {:?} - {:?} at ({}, {}), {} turns",
-                        job.job_type,
-                        entity,
-                        resource.resource_type,
-                        target_pos.x,
-                        target_pos.y,
-                        job.turns_remaining
-                    );
-                    commands.entity(entity).insert((
-                        job,
-                        PreviousPosition(previous_pos),
-                        ActionTurn(turn.current),
-                    ));
+                // Start improvement job
+                let job = CivilianJob::on_terrain(
+                    job_type,
+                    target_pos,
+                    terrain.get(tile_entity).ok().copied(),
+                )
+                .hastened(civilian.job_speedup());
+                info!(
+                    "CREATING JOB: {:?} for entity {:?} - {:?} at ({}, {}), {} turns",
+                    job.job_type,
+                    entity,
+                    resource.resource_type,
+                    target_pos.x,
+                    target_pos.y,
+                    job.turns_remaining
+                );
+                commands.entity(entity).insert((
+                    job,
+                    PreviousPosition(previous_pos),
+                    ActionTurn(turn.current),
+                ));
 
-                    info!(
-                        "{:?} moved to ({}, {}) and started improving {:?} - {} turns remaining",
-                        civilian.kind,
-                        target_pos.x,
-                        target_pos.y,
-                        resource.resource_type,
-                        job_type.duration()
-                    );
-                    civilian.has_moved = true;
-                    commands.trigger(DeselectCivilian); // Auto-deselect after action
-                } else if !can_improve {
-                    info!(
-                        "{:?} cannot improve {:?} at ({}, {})",
-                        civilian.kind, resource.resource_type, target_pos.x, target_pos.y
-                    );
-                } else if resource.development >= DevelopmentLevel::Lv3 {
-                    info!(
-                        "Resource already at max development at ({}, {})",
-                        target_pos.x, target_pos.y
-                    );
-                }
+                info!(
+                    "{:?} moved to ({}, {}) and started improving {:?} - {} turns remaining",
+                    civilian.kind,
+                    target_pos.x,
+                    target_pos.y,
+                    resource.resource_type,
+                    job_type.duration()
+                );
+                civilian.has_moved = true;
+                commands.trigger(DeselectCivilian); // Auto-deselect after action
             } else {
                 info!(
                     "No improvable resource at ({}, {})",
@@ -491,3 +555,42 @@ pub fn execute_civilian_improvement_orders(
         commands.entity(entity).remove::<CivilianOrder>();
     }
 }
+
+/// Tiles covered by an area order, centre first and then ring by ring outwards
+pub fn area_improvement_tiles(center: TilePos, radius: u32) -> VecDeque<TilePos> {
+    use crate::map::tile_pos::{HexExt, TilePosExt};
+
+    let center_hex = center.to_hex();
+    std::iter::once(center_hex)
+        .chain((1..=radius.min(MAX_IMPROVE_AREA_RADIUS)).flat_map(|r| center_hex.ring(r)))
+        .filter_map(|hex| hex.to_tile_pos())
+        .collect()
+}
+
+/// Hand the next queued tile of an area order to an idle civilian.
+/// Tiles that fail validation are dropped by the improvement executor,
+/// so the following frame simply moves on to the next one.
+pub fn queue_next_area_improvement(
+    mut commands: Commands,
+    mut civilians: Query<
+        (Entity, &Civilian, &mut AreaImprovementQueue),
+        (Without<CivilianJob>, Without<CivilianOrder>),
+    >,
+) {
+    for (entity, civilian, mut queue) in civilians.iter_mut() {
+        if civilian.has_moved {
+            continue;
+        }
+        match queue.tiles.pop_front() {
+            Some(to) => {
+                commands.entity(entity).insert(CivilianOrder {
+                    target: CivilianOrderKind::ImproveTile { to },
+                });
+            }
+            None => {
+                info!("{:?} finished its area improvement order", civilian.kind);
+                commands.entity(entity).remove::<AreaImprovementQueue>();
+            }
+        }
+    }
+}
@@ -2,9 +2,32 @@ use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::{TilePos, TileStorage};
 
 use crate::civilians::Civilian;
-use crate::economy::{Capital, OwnedBy, Treasury};
+use crate::civilians::commands::{DeselectCivilian, SelectedCivilian};
+use crate::civilians::jobs::release_job_inputs;
+use crate::civilians::types::CivilianKind;
+use crate::economy::{Capital, FinancialAction, FinancialRejection, OwnedBy, Treasury};
 use crate::map::tile_pos::TilePosExt;
-use crate::messages::civilians::HireCivilian;
+use crate::messages::civilians::{DisbandCivilian, HireCivilian};
+
+/// Each civilian a nation already has of a kind raises the next one's cost by
+/// this share of the base cost
+pub const HIRE_COST_GROWTH_PERCENT: i64 = 25;
+
+/// Cost of hiring another civilian of `kind` when the nation already has
+/// `existing_count` of them
+pub fn hire_cost(kind: CivilianKind, existing_count: usize) -> i64 {
+    let base = kind.hiring_cost();
+    base + base * HIRE_COST_GROWTH_PERCENT * existing_count as i64 / 100
+}
+
+/// Share of the hire cost returned when a civilian is disbanded
+pub const DISBAND_REFUND_PERCENT: i64 = 50;
+
+/// Refund for disbanding a civilian of `kind`, leaving `remaining_count` of
+/// that kind behind. Mirrors what the disbanded unit would cost to rehire.
+pub fn disband_refund(kind: CivilianKind, remaining_count: usize) -> i64 {
+    hire_cost(kind, remaining_count) * DISBAND_REFUND_PERCENT / 100
+}
 
 /// Handles [`HireCivilian`] messages for any nation.
 ///
@@ -20,6 +43,7 @@ pub fn spawn_hired_civilian(
     tile_storage_query: Query<&TileStorage>,
     civilians: Query<&Civilian>,
     mut next_id: ResMut<crate::civilians::types::NextCivilianId>,
+    mut rejections: MessageWriter<FinancialRejection>,
 ) {
     let event = trigger.event();
     let nation_entity = event.nation.entity();
@@ -45,7 +69,11 @@ pub fn spawn_hired_civilian(
         return;
     };
 
-    let cost = event.kind.hiring_cost();
+    let existing = civilians
+        .iter()
+        .filter(|c| c.owner == nation_entity && c.kind == event.kind)
+        .count();
+    let cost = hire_cost(event.kind, existing);
     if treasury.available() < cost {
         info!(
             "Not enough money to hire {:?} for {:?} (need ${}, have ${})",
@@ -54,6 +82,12 @@ pub fn spawn_hired_civilian(
             cost,
             treasury.available()
         );
+        rejections.write(FinancialRejection::new(
+            nation_entity,
+            FinancialAction::HireCivilian,
+            cost,
+            treasury.available(),
+        ));
         return;
     }
 
@@ -69,6 +103,7 @@ pub fn spawn_hired_civilian(
             owner: nation_entity,
             civilian_id,
             has_moved: false,
+            experience: 0,
         },
         OwnedBy(nation_entity),
         Name::new(name.clone()),
@@ -80,6 +115,56 @@ pub fn spawn_hired_civilian(
     );
 }
 
+/// Handles [`DisbandCivilian`] messages.
+///
+/// Despawning the unit drops any [`CivilianJob`](crate::civilians::CivilianJob)
+/// and pending order with it, so a job in progress is simply abandoned. Inputs
+/// reserved for a job started this turn go back to the owner's stockpile.
+pub fn disband_civilian(
+    trigger: On<DisbandCivilian>,
+    mut commands: Commands,
+    civilians: Query<&Civilian>,
+    mut treasuries: Query<&mut Treasury>,
+    selected: Option<Res<SelectedCivilian>>,
+) {
+    let entity = trigger.event().civilian;
+    let Ok(civilian) = civilians.get(entity) else {
+        return;
+    };
+
+    let remaining = civilians
+        .iter()
+        .filter(|c| c.owner == civilian.owner && c.kind == civilian.kind)
+        .count()
+        .saturating_sub(1);
+    let refund = disband_refund(civilian.kind, remaining);
+    if let Ok(mut treasury) = treasuries.get_mut(civilian.owner) {
+        treasury.add(refund);
+    }
+
+    dismiss_civilian(&mut commands, entity, civilian.owner, selected.as_deref());
+
+    info!(
+        "Disbanded {:?} (CivilianId({})) for {:?}, refunded ${}",
+        civilian.kind, civilian.civilian_id.0, civilian.owner, refund
+    );
+}
+
+/// Take a civilian of `owner` out of play without any refund. Inputs reserved
+/// for a job it started this turn still go back to the owner's stockpile.
+pub fn dismiss_civilian(
+    commands: &mut Commands,
+    entity: Entity,
+    owner: Entity,
+    selected: Option<&SelectedCivilian>,
+) {
+    if selected.is_some_and(|selected| selected.contains(entity)) {
+        commands.trigger(DeselectCivilian);
+    }
+    commands.queue(move |world: &mut World| release_job_inputs(world, owner, entity));
+    commands.entity(entity).despawn();
+}
+
 fn find_unoccupied_tile_near(
     center: TilePos,
     tile_storage_query: &Query<&TileStorage>,
@@ -4,9 +4,73 @@ use bevy_ecs_tilemap::prelude::TileStorage;
 use crate::civilians::types::{
     ActionTurn, Civilian, CivilianJob, JobType, PreviousPosition, ProspectingKnowledge,
 };
+use crate::economy::allocation::Allocations;
+use crate::economy::reservation::{ReservationError, ReservationSystem};
+use crate::economy::stockpile::Stockpile;
+use crate::economy::technology::{BASE_DEVELOPMENT_CAP, Technologies};
+use crate::economy::treasury::Treasury;
+use crate::economy::workforce::Workforce;
 use crate::resources::TileResource;
 use crate::turn_system::TurnCounter;
 
+/// Owner components needed to reserve or release a job's inputs
+pub type JobInputOwners<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Allocations,
+        &'static mut ReservationSystem,
+        &'static mut Stockpile,
+        &'static mut Workforce,
+        &'static mut Treasury,
+    ),
+>;
+
+/// Reserve the goods a job of `job_type` uses from `owner`, filed under `civilian`.
+/// They are committed with the owner's other allocations at turn end.
+pub fn reserve_job_inputs(
+    civilian: Entity,
+    owner: Entity,
+    job_type: JobType,
+    owners: &mut JobInputOwners,
+) -> Result<(), ReservationError> {
+    let inputs = job_type.inputs();
+    let Some(&(good, needed)) = inputs.first() else {
+        return Ok(());
+    };
+    let Ok((mut allocations, mut reservations, mut stockpile, mut workforce, mut treasury)) =
+        owners.get_mut(owner)
+    else {
+        return Err(ReservationError::InsufficientGood {
+            good,
+            needed,
+            available: 0,
+        });
+    };
+    let id = reservations.reserve(inputs, 0, 0, &mut stockpile, &mut workforce, &mut treasury)?;
+    allocations.jobs.insert(civilian, id);
+    Ok(())
+}
+
+/// Hand back the inputs reserved for `civilian`'s job if they have not been committed yet
+pub fn release_job_inputs(world: &mut World, owner: Entity, civilian: Entity) {
+    let mut owners = world.query::<(
+        &mut Allocations,
+        &mut ReservationSystem,
+        &mut Stockpile,
+        &mut Workforce,
+        &mut Treasury,
+    )>();
+    let Ok((mut allocations, mut reservations, mut stockpile, mut workforce, mut treasury)) =
+        owners.get_mut(world, owner)
+    else {
+        return;
+    };
+    if let Some(id) = allocations.jobs.remove(&civilian) {
+        reservations.release(id, &mut stockpile, &mut workforce, &mut treasury);
+    }
+}
+
 /// Reset civilian movement at start of player turn.
 ///
 /// Note: Runs via OnEnter(TurnPhase::PlayerTurn) in CivilianJobSet::Reset.
@@ -52,13 +116,14 @@ pub fn advance_civilian_jobs(
 /// Complete improvement jobs when they finish
 pub fn complete_improvement_jobs(
     mut commands: Commands,
-    mut civilians_with_jobs: Query<(Entity, &Civilian, &mut CivilianJob)>,
+    mut civilians_with_jobs: Query<(Entity, &mut Civilian, &mut CivilianJob)>,
     tile_storage_query: Query<&TileStorage>,
     mut tile_resources: Query<&mut TileResource>,
     potential_minerals: Query<&crate::map::PotentialMineral>,
     mut prospecting_knowledge: ResMut<ProspectingKnowledge>,
+    technologies: Query<&Technologies>,
 ) {
-    for (civ_entity, civilian, job) in civilians_with_jobs.iter_mut() {
+    for (civ_entity, mut civilian, job) in civilians_with_jobs.iter_mut() {
         info!(
             "complete_improvement_jobs: checking {:?} {:?} job {:?} turns_remaining={}",
             civ_entity, civilian.kind, job.job_type, job.turns_remaining
@@ -81,7 +146,11 @@ pub fn complete_improvement_jobs(
                     && let Some(tile_entity) = tile_storage.get(&job.target)
                     && let Ok(mut resource) = tile_resources.get_mut(tile_entity)
                 {
-                    if resource.improve() {
+                    // Never develop past what the owner's technology allows
+                    let cap = technologies
+                        .get(civilian.owner)
+                        .map_or(BASE_DEVELOPMENT_CAP, Technologies::development_cap);
+                    if resource.improve(cap) {
                         let action = match job.job_type {
                             JobType::Mining => "mining",
                             JobType::Drilling => "drilling",
@@ -152,11 +221,17 @@ pub fn complete_improvement_jobs(
                     }
                 }
             }
-            JobType::BuildingRail | JobType::BuildingDepot | JobType::BuildingPort => {
+            JobType::BuildingRail
+            | JobType::BuildingDepot
+            | JobType::BuildingPort
+            | JobType::BuildingBridge => {
                 // These are handled by the transport construction system
             }
         }
 
+        // Every finished job counts towards veterancy
+        civilian.experience += 1;
+
         // Remove the completed job and associated components
         commands
             .entity(civ_entity)
@@ -5,7 +5,7 @@ use crate::ui::mode::GameMode;
 
 // Re-exports for public API
 pub use crate::messages::civilians::{
-    CivilianCommand, CivilianCommandError, CivilianCommandRejected, HireCivilian,
+    CivilianCommand, CivilianCommandError, CivilianCommandRejected, DisbandCivilian, HireCivilian,
 };
 pub use commands::*;
 pub use jobs::{advance_civilian_jobs, complete_improvement_jobs, reset_civilian_actions};
@@ -71,12 +71,16 @@ impl Plugin for CivilianLogicPlugin {
             // Register observers
             .add_observer(systems::handle_civilian_commands)
             .add_observer(hiring::spawn_hired_civilian)
+            .add_observer(hiring::disband_civilian)
             .add_observer(systems::handle_civilian_selection)
+            .add_observer(systems::handle_group_selection)
+            .add_observer(systems::handle_group_move)
             .add_observer(systems::handle_deselection)
             .add_observer(systems::handle_rescind_orders)
             .add_systems(
                 Update,
                 (
+                    engineering::queue_next_area_improvement,
                     // Apply deferred commands so CivilianOrder is visible to execution systems
                     bevy::ecs::schedule::ApplyDeferred,
                     systems::execute_move_orders,
@@ -4,8 +4,11 @@ use bevy_ecs_tilemap::prelude::{TilePos, TileStorage, TilemapSize};
 use crate::civilians::types::{
     Civilian, CivilianJob, CivilianKind, CivilianOrder, CivilianOrderKind,
 };
+use crate::economy::transport::can_build_depot_on_terrain;
 use crate::map::province::{Province, TileProvince};
+use crate::map::tiles::TerrainType;
 use crate::messages::civilians::CivilianCommandError;
+use crate::resources::{DevelopmentLevel, TileResource};
 
 /// Returns true if the tile at `tile_pos` is owned by `nation_entity`.
 /// Returns false if the position is out of bounds or not owned.
@@ -75,7 +78,7 @@ pub fn validate_command(
             }
             Ok(())
         }
-        CivilianOrderKind::BuildRail { to } => {
+        CivilianOrderKind::BuildRail { to } | CivilianOrderKind::BuildBridge { to } => {
             require_engineer(civilian)?;
             storage
                 .get(to)
@@ -99,7 +102,7 @@ pub fn validate_command(
         }
         CivilianOrderKind::SkipTurn | CivilianOrderKind::Sleep => Ok(()), // No validation needed
         CivilianOrderKind::Prospect { to } => {
-            if civilian.kind != CivilianKind::Prospector {
+            if !civilian.kind.capabilities().prospects {
                 return Err(CivilianCommandError::RequiresProspector);
             }
             storage
@@ -136,6 +139,26 @@ pub fn validate_command(
             }
             Ok(())
         }
+        CivilianOrderKind::ImproveArea { center, .. } => {
+            if !civilian.kind.supports_improvements() {
+                return Err(CivilianCommandError::RequiresImprover);
+            }
+            storage
+                .get(center)
+                .ok_or(CivilianCommandError::MissingTargetTile(*center))?;
+            if !tile_owned_by_nation(
+                *center,
+                civilian.owner,
+                storage,
+                map_size,
+                tile_provinces,
+                provinces,
+            ) {
+                return Err(CivilianCommandError::TargetTileUnowned);
+            }
+            // Remaining tiles are checked one by one as the civilian reaches them
+            Ok(())
+        }
         CivilianOrderKind::ImproveTile { to }
         | CivilianOrderKind::BuildFarm { to }
         | CivilianOrderKind::BuildOrchard { to } => {
@@ -167,6 +190,42 @@ pub fn validate_command(
     }
 }
 
+/// Checks whether a civilian of `kind` may start improving `resource`.
+/// `prospected` tells whether the owning nation has prospected the tile; it only matters
+/// for minerals. `cap` is the highest level the nation's technology allows.
+/// Shared by player orders and the AI so both follow the same rules.
+pub fn validate_improvement_target(
+    kind: CivilianKind,
+    resource: &TileResource,
+    prospected: bool,
+    cap: DevelopmentLevel,
+) -> Result<(), CivilianCommandError> {
+    let predicate = kind
+        .improvement_predicate()
+        .ok_or(CivilianCommandError::RequiresImprover)?;
+    if !resource.discovered || (resource.requires_prospecting() && !prospected) {
+        return Err(CivilianCommandError::ResourceNotProspected);
+    }
+    if !predicate(resource) {
+        return Err(CivilianCommandError::CannotImproveResource);
+    }
+    if resource.development >= DevelopmentLevel::MAX {
+        return Err(CivilianCommandError::ResourceFullyDeveloped);
+    }
+    if resource.development >= cap {
+        return Err(CivilianCommandError::DevelopmentCapReached);
+    }
+    Ok(())
+}
+
+/// Checks whether a depot may be placed on a tile with the given terrain
+pub fn validate_depot_site(terrain: &TerrainType) -> Result<(), CivilianCommandError> {
+    if !can_build_depot_on_terrain(terrain) {
+        return Err(CivilianCommandError::InvalidDepotTerrain);
+    }
+    Ok(())
+}
+
 fn ensure_current_tile_owned(
     civilian: &Civilian,
     storage: &TileStorage,
@@ -227,6 +286,7 @@ mod tests {
             owner: Entity::PLACEHOLDER,
             civilian_id: CivilianId(0),
             has_moved: false,
+            experience: 0,
         };
 
         let order = CivilianOrderKind::BuildDepot;
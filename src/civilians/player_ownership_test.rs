@@ -1,9 +1,14 @@
 use bevy::prelude::*;
 
-use crate::civilians::commands::{SelectCivilian, SelectedCivilian};
-use crate::civilians::systems::handle_civilian_selection;
-use crate::civilians::types::{Civilian, CivilianId, CivilianKind};
+use crate::civilians::commands::{
+    MoveSelectedCivilians, SelectCivilian, SelectCivilians, SelectedCivilian,
+};
+use crate::civilians::systems::{
+    handle_civilian_selection, handle_group_move, handle_group_selection,
+};
+use crate::civilians::types::{Civilian, CivilianId, CivilianKind, CivilianOrderKind};
 use crate::economy::{Nation, PlayerNation};
+use crate::messages::civilians::CivilianCommand;
 use bevy_ecs_tilemap::prelude::TilePos;
 
 #[test]
@@ -29,6 +34,7 @@ fn test_cannot_select_enemy_units() {
             owner: enemy_nation_entity,
             civilian_id: CivilianId(0),
             has_moved: false,
+            experience: 0,
         })
         .id();
 
@@ -65,6 +71,7 @@ fn test_can_select_own_units() {
             owner: player_nation_entity,
             civilian_id: CivilianId(0),
             has_moved: false,
+            experience: 0,
         })
         .id();
 
@@ -104,6 +111,7 @@ fn test_selecting_player_unit_deselects_others() {
             owner: player_nation_entity,
             civilian_id: CivilianId(0),
             has_moved: false,
+            experience: 0,
         })
         .id();
 
@@ -115,6 +123,7 @@ fn test_selecting_player_unit_deselects_others() {
             owner: player_nation_entity,
             civilian_id: CivilianId(1),
             has_moved: false,
+            experience: 0,
         })
         .id();
 
@@ -143,3 +152,80 @@ fn test_selecting_player_unit_deselects_others() {
         "First unit should be deselected when second unit is selected"
     );
 }
+
+#[derive(Resource, Default)]
+struct IssuedCommands(Vec<CivilianCommand>);
+
+#[test]
+fn group_move_issues_a_command_for_each_selected_civilian() {
+    let mut world = World::new();
+    world.init_resource::<IssuedCommands>();
+    world.add_observer(handle_group_selection);
+    world.add_observer(handle_group_move);
+    world.add_observer(
+        |trigger: On<CivilianCommand>, mut issued: ResMut<IssuedCommands>| {
+            issued.0.push(*trigger.event());
+        },
+    );
+
+    let player_nation_entity = world.spawn(Nation).id();
+    let player_instance =
+        moonshine_kind::Instance::<Nation>::from_entity(world.entity(player_nation_entity))
+            .unwrap();
+    world.insert_resource(PlayerNation::new(player_instance));
+    let enemy_nation_entity = world.spawn(Nation).id();
+
+    let mut spawn_civilian = |owner, x, id| {
+        world
+            .spawn(Civilian {
+                kind: CivilianKind::Farmer,
+                position: TilePos { x, y: 0 },
+                owner,
+                civilian_id: CivilianId(id),
+                has_moved: false,
+                experience: 0,
+            })
+            .id()
+    };
+    let first = spawn_civilian(player_nation_entity, 0, 0);
+    let second = spawn_civilian(player_nation_entity, 1, 1);
+    let enemy = spawn_civilian(enemy_nation_entity, 2, 2);
+
+    world.trigger(SelectCivilians {
+        entities: vec![first, second, enemy],
+    });
+    world.flush();
+
+    let selected = world
+        .get_resource::<SelectedCivilian>()
+        .expect("Box selection should select the player's units");
+    assert!(selected.contains(first) && selected.contains(second));
+    assert!(
+        !selected.contains(enemy),
+        "Enemy units should be left out of a group selection"
+    );
+
+    world.trigger(MoveSelectedCivilians {
+        to: TilePos { x: 5, y: 5 },
+    });
+    world.flush();
+
+    let issued = &world.resource::<IssuedCommands>().0;
+    let mut commanded: Vec<Entity> = issued.iter().map(|command| command.civilian).collect();
+    commanded.sort();
+    let mut expected = vec![first, second];
+    expected.sort();
+    assert_eq!(commanded, expected, "each selected civilian gets an order");
+
+    let targets: Vec<TilePos> = issued
+        .iter()
+        .map(|command| match command.order {
+            CivilianOrderKind::Move { to } => to,
+            other => panic!("expected a move order, got {other:?}"),
+        })
+        .collect();
+    assert_ne!(
+        targets[0], targets[1],
+        "group members should not be sent to the same tile"
+    );
+}
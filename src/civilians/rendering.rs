@@ -5,11 +5,19 @@ use crate::assets::civilian_asset_path;
 use crate::civilians::commands::SelectedCivilian;
 use crate::civilians::systems::handle_civilian_click;
 use crate::civilians::types::{Civilian, CivilianJob};
-use crate::map::rendering::{MapVisual, MapVisualFor};
+use crate::map::rendering::{ColorPalette, MapVisual, MapVisualFor};
 use crate::map::tile_pos::TilePosExt;
 
 const ENGINEER_SIZE: f32 = 64.0; // Match tile size
-const ENGINEER_SELECTED_COLOR: Color = Color::srgb(1.0, 0.8, 0.0); // Yellow/gold tint for selected units
+const PROGRESS_BAR_WIDTH: f32 = 48.0;
+const PROGRESS_BAR_HEIGHT: f32 = 6.0;
+
+/// Job progress bar drawn under a working civilian's sprite.
+/// Spawned as a child of the civilian visual so it despawns along with it.
+#[derive(Component)]
+pub struct JobProgressBar {
+    pub civilian: Entity,
+}
 
 /// Create visual sprites for civilians that don't yet have one.
 /// Uses relationship pattern - sprite automatically despawns when civilian is removed.
@@ -70,8 +78,9 @@ pub fn update_civilian_visual_colors(
     civilians: Query<(Entity, &Civilian, Option<&CivilianJob>, Option<&MapVisual>)>,
     mut visuals: Query<(&mut Sprite, &mut Transform)>,
     time: Res<Time>,
+    palette: Res<ColorPalette>,
 ) {
-    let selected_entity = selected.as_deref().map(|s| s.0);
+    let selected = selected.as_deref();
     // Calculate blink factor for working civilians (oscillates between 0.5 and 1.0)
     let blink_factor = (time.elapsed_secs() * 2.0).sin() * 0.25 + 0.75;
 
@@ -82,16 +91,21 @@ pub fn update_civilian_visual_colors(
             && let Ok((mut sprite, mut transform)) = visuals.get_mut(visual.entity())
         {
             // Determine color based on state priority:
-            // 1. Selected (yellow)
-            // 2. Working on job (green blink)
+            // 1. Selected (palette selection tint)
+            // 2. Working on job (blinking palette working tint)
             // 3. Moved this turn (desaturated)
             // 4. Default (white)
-            let is_selected = selected_entity == Some(civilian_entity);
+            let is_selected = selected.is_some_and(|s| s.contains(civilian_entity));
             let color = if is_selected {
-                ENGINEER_SELECTED_COLOR
+                palette.civilian_selected_tint()
             } else if job.is_some() {
-                // Working: blink green
-                Color::srgb(0.3 * blink_factor, 1.0 * blink_factor, 0.3 * blink_factor)
+                // Working: blink
+                let working = palette.civilian_working_tint().to_srgba();
+                Color::srgb(
+                    working.red * blink_factor,
+                    working.green * blink_factor,
+                    working.blue * blink_factor,
+                )
             } else if civilian.has_moved {
                 // Moved: desaturated (gray)
                 Color::srgb(0.6, 0.6, 0.6)
@@ -106,3 +120,64 @@ pub fn update_civilian_visual_colors(
         }
     }
 }
+
+/// Show a progress bar under civilians that are working on a job.
+/// Only does work when a job starts, advances or ends, or a civilian gets its sprite.
+pub fn update_civilian_job_progress_bars(
+    mut commands: Commands,
+    civilians: Query<(Entity, Option<Ref<CivilianJob>>, Option<&MapVisual>), With<Civilian>>,
+    changed_jobs: Query<(), Changed<CivilianJob>>,
+    new_visuals: Query<(), (With<Civilian>, Added<MapVisual>)>,
+    mut removed_jobs: RemovedComponents<CivilianJob>,
+    mut bars: Query<(Entity, &JobProgressBar, &mut Sprite, &mut Transform)>,
+) {
+    let jobs_removed = removed_jobs.read().count() > 0;
+    if changed_jobs.is_empty() && new_visuals.is_empty() && !jobs_removed {
+        return;
+    }
+
+    let mut has_bar = std::collections::HashSet::new();
+
+    for (bar_entity, bar, mut sprite, mut transform) in bars.iter_mut() {
+        let Ok((_, Some(job), _)) = civilians.get(bar.civilian) else {
+            commands.entity(bar_entity).despawn();
+            continue;
+        };
+        has_bar.insert(bar.civilian);
+        if !job.is_changed() {
+            continue;
+        }
+
+        let width = PROGRESS_BAR_WIDTH * job.progress().max(0.05);
+        sprite.custom_size = Some(Vec2::new(width, PROGRESS_BAR_HEIGHT));
+        // Keep the bar left-aligned as it grows
+        transform.translation.x = (width - PROGRESS_BAR_WIDTH) / 2.0;
+    }
+
+    for (civilian_entity, job, visual) in civilians.iter() {
+        let (Some(job), Some(visual)) = (job, visual) else {
+            continue;
+        };
+        if has_bar.contains(&civilian_entity) {
+            continue;
+        }
+
+        let width = PROGRESS_BAR_WIDTH * job.progress().max(0.05);
+        commands.spawn((
+            Sprite {
+                color: Color::srgb(0.3, 0.85, 0.35),
+                custom_size: Some(Vec2::new(width, PROGRESS_BAR_HEIGHT)),
+                ..default()
+            },
+            Transform::from_xyz(
+                (width - PROGRESS_BAR_WIDTH) / 2.0,
+                -ENGINEER_SIZE / 2.0,
+                0.1,
+            ),
+            JobProgressBar {
+                civilian: civilian_entity,
+            },
+            ChildOf(visual.entity()),
+        ));
+    }
+}
@@ -36,6 +36,7 @@ fn test_move_order_rejected_if_tile_occupied() {
         owner: nation,
         civilian_id: CivilianId(1),
         has_moved: false,
+        experience: 0,
     };
     let c1_entity = world.spawn(civilian1).id();
 
@@ -46,6 +47,7 @@ fn test_move_order_rejected_if_tile_occupied() {
         owner: nation,
         civilian_id: CivilianId(2),
         has_moved: false,
+        experience: 0,
     };
     world.spawn(civilian2);
 
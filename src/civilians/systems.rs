@@ -1,16 +1,22 @@
 use bevy::prelude::*;
-use bevy_ecs_tilemap::prelude::{TileStorage, TilemapSize};
+use bevy::window::PrimaryWindow;
+use bevy_ecs_tilemap::prelude::{TilePos, TileStorage, TilemapSize};
 
 use crate::civilians::commands::{
-    DeselectCivilian, RescindOrders, SelectCivilian, SelectedCivilian,
+    BoxSelection, DeselectCivilian, MoveSelectedCivilians, RescindOrders, SelectCivilian,
+    SelectCivilians, SelectedCivilian,
 };
+use crate::civilians::jobs::release_job_inputs;
 use crate::civilians::order_validation::validate_command;
 use crate::civilians::types::{
-    ActionTurn, Civilian, CivilianJob, CivilianOrder, CivilianOrderKind, PreviousPosition,
+    ActionTurn, AreaImprovementQueue, Civilian, CivilianJob, CivilianOrder, CivilianOrderKind,
+    PreviousPosition,
 };
+use crate::economy::transport::{Bridges, RailConstruction, ordered_edge};
 use crate::economy::treasury::Treasury;
 use crate::map::province::{Province, TileProvince};
 use crate::map::rendering::MapVisualFor;
+use crate::map::tile_pos::{HexExt, TilePosExt};
 use crate::messages::civilians::{CivilianCommand, CivilianCommandError, CivilianCommandRejected};
 use crate::turn_system::TurnCounter;
 
@@ -89,17 +95,141 @@ pub fn handle_civilian_selection(
         return;
     }
 
-    // If this unit is already selected, do nothing
-    if selected.map(|s| s.0) == Some(event.entity) {
+    // If this unit is already the only one selected, do nothing
+    if selected.is_some_and(|s| !s.is_group() && s.0 == event.entity) {
         info!("Civilian {:?} is already selected", event.entity);
         return;
     }
 
     // Select the new civilian (automatically deselects any previously selected one)
-    commands.insert_resource(SelectedCivilian(event.entity));
+    commands.insert_resource(SelectedCivilian::single(event.entity));
     info!("Selected civilian {:?}", event.entity);
 }
 
+/// Handle group selection events; units the player does not own are skipped
+pub fn handle_group_selection(
+    trigger: On<SelectCivilians>,
+    mut commands: Commands,
+    player_nation: Option<Res<crate::economy::PlayerNation>>,
+    civilians: Query<&Civilian>,
+) {
+    let Some(player) = player_nation else {
+        return;
+    };
+
+    let owned = trigger.event().entities.iter().copied().filter(|&entity| {
+        civilians
+            .get(entity)
+            .is_ok_and(|civilian| civilian.owner == player.entity())
+    });
+
+    if let Some(selection) = SelectedCivilian::group(owned) {
+        info!("Selected {} civilians", selection.members().count());
+        commands.insert_resource(selection);
+    }
+}
+
+/// How many rings around the target a group move may spread over
+const GROUP_MOVE_SPREAD: u32 = 2;
+
+/// Send every selected civilian toward a tile.
+/// Units fan out over the nearest free tiles so they do not all compete for the same one.
+pub fn handle_group_move(
+    trigger: On<MoveSelectedCivilians>,
+    mut commands: Commands,
+    selected: Option<Res<SelectedCivilian>>,
+    civilians: Query<&Civilian>,
+) {
+    let Some(selected) = selected else {
+        return;
+    };
+    let to = trigger.event().to;
+    let occupied: Vec<TilePos> = civilians.iter().map(|civilian| civilian.position).collect();
+
+    let center = to.to_hex();
+    let mut targets = std::iter::once(center)
+        .chain((1..=GROUP_MOVE_SPREAD).flat_map(|radius| center.ring(radius)))
+        .filter_map(|hex| hex.to_tile_pos())
+        .filter(|pos| !occupied.contains(pos));
+
+    for civilian in selected.members() {
+        let Some(target) = targets.next() else {
+            info!("No free tiles left near ({}, {}) for the group", to.x, to.y);
+            break;
+        };
+        commands.trigger(CivilianCommand {
+            civilian,
+            order: CivilianOrderKind::Move { to: target },
+        });
+    }
+}
+
+/// Cursor travel, in pixels, before a left-button press becomes a box selection
+const BOX_SELECT_MIN_DRAG: f32 = 8.0;
+
+/// Drag with the left mouse button to select every player civilian inside the box
+pub fn box_select_civilians(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    civilians: Query<(Entity, &Civilian)>,
+    mut selection: ResMut<BoxSelection>,
+    mut gizmos: Gizmos,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = cameras.single() else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+
+    if mouse.just_pressed(MouseButton::Left) {
+        *selection = BoxSelection {
+            start: Some(cursor),
+            dragging: false,
+        };
+        return;
+    }
+    let Some(start) = selection.start else {
+        return;
+    };
+    if !selection.dragging && start.distance(cursor) < BOX_SELECT_MIN_DRAG {
+        if !mouse.pressed(MouseButton::Left) {
+            selection.start = None;
+        }
+        return;
+    }
+    // Tile clicks check this flag so releasing a drag does not also issue an order
+    selection.dragging = true;
+
+    let (Ok(a), Ok(b)) = (
+        camera.viewport_to_world_2d(camera_transform, start),
+        camera.viewport_to_world_2d(camera_transform, cursor),
+    ) else {
+        return;
+    };
+    let area = Rect::from_corners(a, b);
+
+    if mouse.pressed(MouseButton::Left) {
+        gizmos.rect_2d(area.center(), area.size(), Color::srgb(1.0, 0.9, 0.3));
+        return;
+    }
+
+    *selection = BoxSelection::default();
+    let entities: Vec<Entity> = civilians
+        .iter()
+        .filter(|(_, civilian)| area.contains(civilian.position.to_world_pos()))
+        .map(|(entity, _)| entity)
+        .collect();
+    if !entities.is_empty() {
+        commands.trigger(SelectCivilians { entities });
+    }
+}
+
 /// Handle civilian command events and validate them before attaching orders
 pub fn handle_civilian_commands(
     trigger: On<CivilianCommand>,
@@ -268,6 +398,9 @@ pub fn handle_rescind_orders(
             return;
         };
 
+        // Rescinding also abandons the rest of an area improvement order
+        world.entity_mut(entity).remove::<AreaImprovementQueue>();
+
         let prev_pos = if let Some(pp) = world.get::<PreviousPosition>(entity) {
             pp.0
         } else {
@@ -276,6 +409,7 @@ pub fn handle_rescind_orders(
 
         let action_turn = world.get::<ActionTurn>(entity).map(|at| at.0);
         let job_type = world.get::<CivilianJob>(entity).map(|j| j.job_type);
+        let job_target = world.get::<CivilianJob>(entity).map(|j| j.target);
 
         // Determine refund
         let should_refund = action_turn.map(|at| at == current_turn).unwrap_or(false);
@@ -285,6 +419,7 @@ pub fn handle_rescind_orders(
                 crate::civilians::types::JobType::BuildingRail => Some(50),
                 crate::civilians::types::JobType::BuildingDepot => Some(100),
                 crate::civilians::types::JobType::BuildingPort => Some(150),
+                crate::civilians::types::JobType::BuildingBridge => Some(200),
                 _ => None,
             })
         } else {
@@ -297,6 +432,9 @@ pub fn handle_rescind_orders(
             civilian.has_moved = false;
         }
 
+        // Hand back goods reserved for the abandoned job
+        release_job_inputs(world, owner, entity);
+
         // Remove components
         world.entity_mut(entity)
             .remove::<CivilianJob>()
@@ -304,6 +442,29 @@ pub fn handle_rescind_orders(
             .remove::<PreviousPosition>()
             .remove::<ActionTurn>();
 
+        // The rail segment the engineer was laying is abandoned with the job
+        if job_type == Some(crate::civilians::types::JobType::BuildingRail) {
+            let constructions: Vec<Entity> = world
+                .query::<(Entity, &RailConstruction)>()
+                .iter(world)
+                .filter(|(_, construction)| construction.engineer == entity)
+                .map(|(construction_entity, _)| construction_entity)
+                .collect();
+            for construction in constructions {
+                world.despawn(construction);
+            }
+        }
+
+        // A bridge refunded the same turn is taken down again; the engineer stays on
+        // the bank, so the bridge spans its position and the job target
+        if should_refund
+            && job_type == Some(crate::civilians::types::JobType::BuildingBridge)
+            && let Some(far_bank) = job_target
+            && let Some(mut bridges) = world.get_resource_mut::<Bridges>()
+        {
+            bridges.0.remove(&ordered_edge(old_pos, far_bank));
+        }
+
         // Apply refund
         let mut log_msg = String::new();
         if let Some(amount) = refund_amount {
@@ -5,18 +5,45 @@ use crate::civilians::engineering::{
 use crate::civilians::jobs::complete_improvement_jobs;
 use crate::civilians::systems::handle_rescind_orders;
 use crate::civilians::types::{
-    Civilian, CivilianId, CivilianJob, CivilianKind, CivilianOrder, CivilianOrderKind, JobType,
-    PreviousPosition, ProspectingKnowledge,
+    ActionTurn, Civilian, CivilianId, CivilianJob, CivilianKind, CivilianOrder, CivilianOrderKind,
+    IMPROVEMENT_TIMBER, JobType, PreviousPosition, ProspectingKnowledge,
 };
+use crate::economy::Good;
+use crate::economy::allocation::Allocations;
 use crate::economy::nation::Nation;
-use crate::economy::transport::{Rails, ordered_edge};
+use crate::economy::reservation::ReservationSystem;
+use crate::economy::stockpile::Stockpile;
+use crate::economy::technology::{Technologies, Technology};
+use crate::economy::transport::{
+    Bridges, CancelRailConstruction, RailConnectivityCache, RailConstruction, Rails,
+    advance_rail_construction, apply_improvements, cancel_rail_construction, ordered_edge,
+};
+use crate::economy::treasury::Treasury;
+use crate::economy::workforce::Workforce;
 use crate::map::province::{Province, ProvinceId, TileProvince};
+use crate::map::tiles::TerrainType;
 use crate::resources::{DevelopmentLevel, ResourceType, TileResource};
 use crate::turn_system::TurnCounter;
 use bevy::ecs::system::{RunSystemOnce, SystemState};
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::{TilePos, TileStorage, TilemapSize};
 
+/// Nation with the economy components civilian jobs reserve their inputs from
+fn spawn_stocked_nation(world: &mut World, timber: u32) -> Entity {
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Timber, timber);
+    world
+        .spawn((
+            Nation,
+            Allocations::default(),
+            ReservationSystem::default(),
+            stockpile,
+            Workforce::new(),
+            Treasury::new(0),
+        ))
+        .id()
+}
+
 #[test]
 fn test_engineer_does_not_start_job_on_existing_rail() {
     let mut world = World::new();
@@ -57,6 +84,7 @@ fn test_engineer_does_not_start_job_on_existing_rail() {
                 owner: nation,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::BuildRail { to: target_pos },
@@ -133,6 +161,7 @@ fn test_engineer_starts_job_on_new_rail() {
                 owner: nation,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::BuildRail { to: target_pos },
@@ -177,6 +206,226 @@ fn test_engineer_starts_job_on_new_rail() {
     );
 }
 
+#[test]
+fn veteran_engineer_lays_rail_faster_than_rookie() {
+    let mut world = World::new();
+    world.init_resource::<Rails>();
+    world.init_resource::<Bridges>();
+    world.init_resource::<TurnCounter>();
+    world.init_resource::<ProspectingKnowledge>();
+    world.add_observer(apply_improvements);
+
+    let nation = world
+        .spawn((Nation, Treasury::new(1_000), Technologies::new()))
+        .id();
+    let province_id = ProvinceId(1);
+    let rookie_start = TilePos { x: 0, y: 0 };
+    let rookie_target = TilePos { x: 1, y: 0 };
+    let veteran_start = TilePos { x: 4, y: 0 };
+    let veteran_target = TilePos { x: 5, y: 0 };
+    let tiles = vec![rookie_start, rookie_target, veteran_start, veteran_target];
+    world.spawn(Province {
+        id: province_id,
+        owner: Some(nation),
+        tiles: tiles.clone(),
+        city_tile: rookie_start,
+    });
+
+    let map_size = TilemapSize { x: 10, y: 10 };
+    let mut tile_storage = TileStorage::empty(map_size);
+    for pos in tiles {
+        let tile = world
+            .spawn((TileProvince { province_id }, TerrainType::Grass))
+            .id();
+        tile_storage.set(&pos, tile);
+    }
+    world.spawn((tile_storage, map_size));
+
+    let mut spawn_engineer = |position, to, experience, id| {
+        world
+            .spawn((
+                Civilian {
+                    kind: CivilianKind::Engineer,
+                    position,
+                    owner: nation,
+                    civilian_id: CivilianId(id),
+                    has_moved: false,
+                    experience,
+                },
+                CivilianOrder {
+                    target: CivilianOrderKind::BuildRail { to },
+                },
+            ))
+            .id()
+    };
+    let rookie = spawn_engineer(rookie_start, rookie_target, 0, 0);
+    let veteran = spawn_engineer(veteran_start, veteran_target, 5, 1);
+
+    let _ = world.run_system_once(execute_engineer_orders);
+    world.flush();
+
+    let rookie_turns = world.get::<CivilianJob>(rookie).unwrap().turns_remaining;
+    let veteran_turns = world.get::<CivilianJob>(veteran).unwrap().turns_remaining;
+    assert!(
+        veteran_turns < rookie_turns,
+        "veteran ({veteran_turns} turns) should beat rookie ({rookie_turns} turns)"
+    );
+
+    // The rail segments themselves follow the engineers' pace
+    let mut constructions = world.query::<&RailConstruction>();
+    let mut segment_turns = |engineer| {
+        constructions
+            .iter(&world)
+            .find(|construction| construction.engineer == engineer)
+            .map(|construction| construction.turns_remaining)
+            .expect("rail construction started")
+    };
+    assert_eq!(segment_turns(rookie), rookie_turns);
+    assert_eq!(segment_turns(veteran), veteran_turns);
+}
+
+#[test]
+fn canceling_rail_construction_drops_the_edge_and_frees_the_engineer() {
+    let mut world = World::new();
+    world.init_resource::<Rails>();
+    world.init_resource::<Bridges>();
+    world.init_resource::<RailConnectivityCache>();
+    world.init_resource::<TurnCounter>();
+    world.init_resource::<ProspectingKnowledge>();
+    world.add_observer(apply_improvements);
+    world.add_observer(cancel_rail_construction);
+
+    let nation = world
+        .spawn((Nation, Treasury::new(1_000), Technologies::new()))
+        .id();
+    let province_id = ProvinceId(1);
+    let start = TilePos { x: 0, y: 0 };
+    let target = TilePos { x: 1, y: 0 };
+    world.spawn(Province {
+        id: province_id,
+        owner: Some(nation),
+        tiles: vec![start, target],
+        city_tile: start,
+    });
+
+    let map_size = TilemapSize { x: 4, y: 4 };
+    let mut tile_storage = TileStorage::empty(map_size);
+    for pos in [start, target] {
+        let tile = world
+            .spawn((TileProvince { province_id }, TerrainType::Grass))
+            .id();
+        tile_storage.set(&pos, tile);
+    }
+    world.spawn((tile_storage, map_size));
+
+    let engineer = world
+        .spawn((
+            Civilian {
+                kind: CivilianKind::Engineer,
+                position: start,
+                owner: nation,
+                civilian_id: CivilianId(0),
+                has_moved: false,
+                experience: 0,
+            },
+            CivilianOrder {
+                target: CivilianOrderKind::BuildRail { to: target },
+            },
+        ))
+        .id();
+
+    let _ = world.run_system_once(execute_engineer_orders);
+    world.flush();
+    let _ = world.run_system_once(advance_rail_construction);
+    world.flush();
+
+    let construction = world
+        .query_filtered::<Entity, With<RailConstruction>>()
+        .single(&world)
+        .expect("rail construction in progress");
+    assert!(world.get::<CivilianJob>(engineer).is_some());
+    assert!(world.get::<PreviousPosition>(engineer).is_some());
+
+    world.trigger(CancelRailConstruction { construction });
+    world.flush();
+
+    assert!(world.get_entity(construction).is_err());
+    assert!(world.get::<CivilianJob>(engineer).is_none());
+    assert!(world.get::<CivilianOrder>(engineer).is_none());
+    assert!(world.get::<PreviousPosition>(engineer).is_none());
+    assert!(world.get::<ActionTurn>(engineer).is_none());
+
+    // Later turns never finish the abandoned segment
+    for _ in 0..3 {
+        let _ = world.run_system_once(advance_rail_construction);
+        world.flush();
+    }
+    assert!(
+        !world
+            .resource::<Rails>()
+            .0
+            .contains(&ordered_edge(start, target))
+    );
+}
+
+#[test]
+fn depot_takes_longer_to_build_on_hills_than_on_plains() {
+    let mut world = World::new();
+    world.init_resource::<Rails>();
+    world.init_resource::<TurnCounter>();
+    world.init_resource::<ProspectingKnowledge>();
+
+    let nation = world.spawn(Nation).id();
+    let province_id = ProvinceId(1);
+    let plains = TilePos { x: 0, y: 0 };
+    let hill = TilePos { x: 3, y: 0 };
+    world.spawn(Province {
+        id: province_id,
+        owner: Some(nation),
+        tiles: vec![plains, hill],
+        city_tile: plains,
+    });
+
+    let map_size = TilemapSize { x: 10, y: 10 };
+    let mut tile_storage = TileStorage::empty(map_size);
+    for (pos, terrain) in [(plains, TerrainType::Grass), (hill, TerrainType::Hills)] {
+        let tile = world.spawn((TileProvince { province_id }, terrain)).id();
+        tile_storage.set(&pos, tile);
+    }
+    world.spawn((tile_storage, map_size));
+
+    let mut spawn_engineer = |position, id| {
+        world
+            .spawn((
+                Civilian {
+                    kind: CivilianKind::Engineer,
+                    position,
+                    owner: nation,
+                    civilian_id: CivilianId(id),
+                    has_moved: false,
+                    experience: 0,
+                },
+                CivilianOrder {
+                    target: CivilianOrderKind::BuildDepot,
+                },
+            ))
+            .id()
+    };
+    let on_plains = spawn_engineer(plains, 0);
+    let on_hill = spawn_engineer(hill, 1);
+
+    let _ = world.run_system_once(execute_engineer_orders);
+    world.flush();
+
+    let plains_turns = world.get::<CivilianJob>(on_plains).unwrap().turns_remaining;
+    let hill_turns = world.get::<CivilianJob>(on_hill).unwrap().turns_remaining;
+    assert_eq!(plains_turns, JobType::BuildingDepot.duration());
+    assert!(
+        hill_turns > plains_turns,
+        "hills ({hill_turns} turns) should be slower than plains ({plains_turns} turns)"
+    );
+}
+
 #[test]
 fn test_prospector_metadata_has_prospect_action() {
     let definition = CivilianKind::Prospector.definition();
@@ -279,6 +528,7 @@ fn test_prospector_starts_prospecting_job() {
                 owner: nation,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Prospect { to: tile_pos },
@@ -332,10 +582,12 @@ fn test_prospecting_job_reveals_resource_on_completion() {
                 owner,
                 civilian_id: CivilianId(0),
                 has_moved: true,
+                experience: 0,
             },
             CivilianJob {
                 job_type: JobType::Prospecting,
                 turns_remaining: 0,
+                total_turns: 1,
                 target: tile_pos,
             },
         ))
@@ -403,6 +655,7 @@ fn miner_requires_discovery_before_mining() {
                 owner: nation,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Mine { to: tile_pos },
@@ -471,6 +724,7 @@ fn new_owner_must_reprospect_before_mining() {
                 owner: nation_a,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Prospect { to: tile_pos },
@@ -523,6 +777,7 @@ fn new_owner_must_reprospect_before_mining() {
                 owner: nation_b,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Mine { to: tile_pos },
@@ -565,6 +820,7 @@ fn test_cannot_assign_order_if_order_already_exists() {
         owner: Entity::PLACEHOLDER,
         civilian_id: CivilianId(0),
         has_moved: false,
+        experience: 0,
     };
 
     // Create an existing order
@@ -631,6 +887,7 @@ fn test_can_assign_order_when_no_existing_order() {
         owner: Entity::PLACEHOLDER,
         civilian_id: CivilianId(0),
         has_moved: false,
+        experience: 0,
     };
 
     let tile_pos = TilePos { x: 1, y: 1 };
@@ -693,6 +950,7 @@ fn test_rescind_orders_removes_civilian_order_component() {
                 owner: nation,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::ImproveTile { to: tile_pos },
@@ -763,10 +1021,12 @@ fn test_rescind_orders_removes_civilian_job_and_order() {
                 owner: nation,
                 civilian_id: CivilianId(0),
                 has_moved: true,
+                experience: 0,
             },
             CivilianJob {
                 job_type: JobType::BuildingRail,
                 turns_remaining: 2,
+                total_turns: 2,
                 target: tile_pos,
             },
             CivilianOrder {
@@ -809,6 +1069,52 @@ fn test_rescind_orders_removes_civilian_job_and_order() {
     assert_eq!(treasury.total(), 1050, "Should refund BuildRail cost (50)");
 }
 
+#[test]
+fn rescinding_a_bridge_the_same_turn_takes_it_down() {
+    let mut world = World::new();
+    world.insert_resource(TurnCounter::new(1));
+    world.init_resource::<Bridges>();
+    world.add_observer(handle_rescind_orders);
+
+    let nation = world.spawn((Nation, Treasury::new(800))).id();
+    let bank = TilePos { x: 5, y: 4 };
+    let river = TilePos { x: 5, y: 5 };
+    let other_bridge = ordered_edge(TilePos { x: 2, y: 4 }, TilePos { x: 2, y: 5 });
+    {
+        let mut bridges = world.resource_mut::<Bridges>();
+        bridges.0.insert(ordered_edge(bank, river));
+        bridges.0.insert(other_bridge);
+    }
+    let engineer = world
+        .spawn((
+            Civilian {
+                kind: CivilianKind::Engineer,
+                position: bank,
+                owner: nation,
+                civilian_id: CivilianId(0),
+                has_moved: true,
+                experience: 0,
+            },
+            CivilianJob {
+                job_type: JobType::BuildingBridge,
+                turns_remaining: 2,
+                total_turns: 2,
+                target: river,
+            },
+            PreviousPosition(bank),
+            ActionTurn(1),
+        ))
+        .id();
+
+    world.trigger(RescindOrders { entity: engineer });
+    world.flush();
+
+    let bridges = &world.resource::<Bridges>().0;
+    assert!(!bridges.contains(&ordered_edge(bank, river)));
+    assert!(bridges.contains(&other_bridge), "other bridges stay up");
+    assert_eq!(world.get::<Treasury>(nation).unwrap().total(), 1_000);
+}
+
 #[test]
 fn test_skip_turn_removes_order_after_one_turn() {
     use crate::civilians::systems::execute_skip_and_sleep_orders;
@@ -824,6 +1130,7 @@ fn test_skip_turn_removes_order_after_one_turn() {
                 owner: Entity::PLACEHOLDER,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::SkipTurn,
@@ -863,6 +1170,7 @@ fn test_sleep_order_persists_across_turns() {
                 owner: Entity::PLACEHOLDER,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Sleep,
@@ -905,6 +1213,7 @@ fn test_rescind_wakes_sleeping_civilian() {
                 owner: Entity::PLACEHOLDER,
                 civilian_id: CivilianId(0),
                 has_moved: true, // Sleeping civilians are marked as moved
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Sleep,
@@ -933,13 +1242,67 @@ fn test_rescind_wakes_sleeping_civilian() {
     );
 }
 
+#[test]
+fn development_past_lv2_requires_technology() {
+    let mut world = World::new();
+    world.init_resource::<ProspectingKnowledge>();
+
+    let mut tile_storage = TileStorage::empty(TilemapSize { x: 3, y: 3 });
+    let mut farms = Vec::new();
+    for (x, techs) in [
+        (0, Technologies::new()),
+        (1, Technologies([Technology::ImprovedTools].into())),
+    ] {
+        let tile_pos = TilePos { x, y: 0 };
+        let mut grain = TileResource::visible(ResourceType::Grain);
+        grain.development = DevelopmentLevel::Lv2;
+        let tile_entity = world.spawn(grain).id();
+        tile_storage.set(&tile_pos, tile_entity);
+
+        let owner = world.spawn((Nation, techs)).id();
+        world.spawn((
+            Civilian {
+                kind: CivilianKind::Farmer,
+                position: tile_pos,
+                owner,
+                civilian_id: CivilianId(x),
+                has_moved: true,
+                experience: 0,
+            },
+            CivilianJob {
+                job_type: JobType::ImprovingTile,
+                turns_remaining: 0,
+                total_turns: 3,
+                target: tile_pos,
+            },
+        ));
+        farms.push(tile_entity);
+    }
+    world.spawn(tile_storage);
+
+    let _ = world.run_system_once(complete_improvement_jobs);
+
+    let level = |tile| world.get::<TileResource>(tile).unwrap().development;
+    assert_eq!(
+        level(farms[0]),
+        DevelopmentLevel::Lv2,
+        "without Improved Tools the farm stays at Lv2"
+    );
+    assert_eq!(level(farms[1]), DevelopmentLevel::Lv3);
+}
+
 #[test]
 fn miner_respects_max_development_level() {
     let mut world = World::new();
     world.init_resource::<TurnCounter>();
     world.init_resource::<ProspectingKnowledge>();
 
-    let nation = world.spawn(Nation).id();
+    let nation = world
+        .spawn((
+            Nation,
+            Technologies([Technology::ImprovedTools, Technology::Mechanization].into()),
+        ))
+        .id();
     let province_id = ProvinceId(6);
     world.spawn(Province {
         id: province_id,
@@ -952,7 +1315,7 @@ fn miner_respects_max_development_level() {
     let tile_pos = TilePos { x: 0, y: 0 };
     let mut resource = TileResource::hidden_mineral(ResourceType::Iron);
     resource.discovered = true;
-    resource.development = DevelopmentLevel::Lv3;
+    resource.development = DevelopmentLevel::MAX;
     let tile_entity = world.spawn((TileProvince { province_id }, resource)).id();
     tile_storage.set(&tile_pos, tile_entity);
     world.spawn(tile_storage);
@@ -965,6 +1328,7 @@ fn miner_respects_max_development_level() {
                 owner: nation,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Mine { to: tile_pos },
@@ -993,7 +1357,7 @@ fn farmer_starts_improvement_job_on_visible_resource() {
     world.init_resource::<TurnCounter>();
     world.init_resource::<ProspectingKnowledge>();
 
-    let nation = world.spawn(Nation).id();
+    let nation = spawn_stocked_nation(&mut world, 5);
     let province_id = ProvinceId(7);
     world.spawn(Province {
         id: province_id,
@@ -1022,6 +1386,7 @@ fn farmer_starts_improvement_job_on_visible_resource() {
                 owner: nation,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::ImproveTile { to: tile_pos },
@@ -1043,6 +1408,63 @@ fn farmer_starts_improvement_job_on_visible_resource() {
         civilian.has_moved,
         "Farmer should consume its action when starting an improvement"
     );
+    assert_eq!(
+        world
+            .get::<Stockpile>(nation)
+            .unwrap()
+            .get_reserved(Good::Timber),
+        IMPROVEMENT_TIMBER
+    );
+}
+
+#[test]
+fn farmer_without_timber_does_not_start_improvement() {
+    let mut world = World::new();
+    world.init_resource::<TurnCounter>();
+    world.init_resource::<ProspectingKnowledge>();
+
+    let nation = spawn_stocked_nation(&mut world, 0);
+    let province_id = ProvinceId(7);
+    world.spawn(Province {
+        id: province_id,
+        owner: Some(nation),
+        tiles: vec![TilePos { x: 0, y: 0 }],
+        city_tile: TilePos { x: 0, y: 0 },
+    });
+
+    let map_size = TilemapSize { x: 3, y: 3 };
+    let mut tile_storage = TileStorage::empty(map_size);
+    let tile_pos = TilePos { x: 0, y: 0 };
+    let tile_entity = world
+        .spawn((
+            TileProvince { province_id },
+            TileResource::visible(ResourceType::Grain),
+        ))
+        .id();
+    tile_storage.set(&tile_pos, tile_entity);
+    world.spawn((tile_storage, map_size));
+
+    let farmer = world
+        .spawn((
+            Civilian {
+                kind: CivilianKind::Farmer,
+                position: tile_pos,
+                owner: nation,
+                civilian_id: CivilianId(0),
+                has_moved: false,
+                experience: 0,
+            },
+            CivilianOrder {
+                target: CivilianOrderKind::ImproveTile { to: tile_pos },
+            },
+        ))
+        .id();
+
+    let _ = world.run_system_once(execute_civilian_improvement_orders);
+    world.flush();
+
+    assert!(world.get::<CivilianJob>(farmer).is_none());
+    assert!(!world.get::<Civilian>(farmer).unwrap().has_moved);
 }
 
 #[test]
@@ -1081,6 +1503,7 @@ fn prospecting_knowledge_is_nation_private() {
                 owner: nation_a,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Prospect { to: tile_pos },
@@ -1201,6 +1624,7 @@ fn prospecting_markers_filtered_by_player_nation() {
                 owner: nation_a,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Prospect { to: tile_pos_1 },
@@ -1291,6 +1715,7 @@ fn multiple_nations_can_prospect_same_tile_independently() {
                 owner: nation_a,
                 civilian_id: CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Prospect { to: tile_pos },
@@ -1339,6 +1764,7 @@ fn multiple_nations_can_prospect_same_tile_independently() {
                 owner: nation_b,
                 civilian_id: CivilianId(1),
                 has_moved: false,
+                experience: 0,
             },
             CivilianOrder {
                 target: CivilianOrderKind::Prospect { to: tile_pos },
@@ -1389,6 +1815,7 @@ fn test_civilian_requires_name() {
             owner: Entity::PLACEHOLDER,
             civilian_id: CivilianId(0),
             has_moved: false,
+            experience: 0,
         })
         .id();
 
@@ -1398,3 +1825,406 @@ fn test_civilian_requires_name() {
         .expect("Name component should be required by Civilian");
     assert_eq!(name.as_str(), "");
 }
+
+#[test]
+fn test_hire_cost_rises_with_each_civilian_and_rejects_unaffordable_hires() {
+    use crate::civilians::hiring::{hire_cost, spawn_hired_civilian};
+    use crate::civilians::types::NextCivilianId;
+    use crate::economy::{Capital, FinancialRejection, NationInstance, Treasury};
+    use crate::messages::civilians::HireCivilian;
+
+    let first = hire_cost(CivilianKind::Engineer, 0);
+    let second = hire_cost(CivilianKind::Engineer, 1);
+    assert_eq!(first, CivilianKind::Engineer.hiring_cost());
+    assert!(second > first);
+
+    let mut world = World::new();
+    world.init_resource::<NextCivilianId>();
+    world.init_resource::<Messages<FinancialRejection>>();
+    world.add_observer(spawn_hired_civilian);
+
+    let map_size = TilemapSize { x: 5, y: 5 };
+    let mut tile_storage = TileStorage::empty(map_size);
+    for x in 0..map_size.x {
+        for y in 0..map_size.y {
+            let pos = TilePos { x, y };
+            let tile = world.spawn_empty().id();
+            tile_storage.set(&pos, tile);
+        }
+    }
+    world.spawn((tile_storage, map_size));
+
+    let capital = TilePos { x: 2, y: 2 };
+    let nation = world
+        .spawn((
+            Nation,
+            Capital(capital),
+            Treasury::new((first + second + second - 1) as u32),
+        ))
+        .id();
+    let nation_instance = NationInstance::from_entity(world.entity(nation)).unwrap();
+
+    let hire = HireCivilian {
+        nation: nation_instance,
+        kind: CivilianKind::Engineer,
+    };
+    world.trigger(hire);
+    world.flush();
+    world.trigger(hire);
+    world.flush();
+
+    let treasury = world.get::<Treasury>(nation).unwrap();
+    assert_eq!(treasury.total(), second - 1);
+
+    // A third engineer costs more than the second, which the treasury cannot cover
+    world.trigger(hire);
+    world.flush();
+
+    let engineers = world
+        .query::<&Civilian>()
+        .iter(&world)
+        .filter(|c| c.owner == nation && c.kind == CivilianKind::Engineer)
+        .count();
+    assert_eq!(engineers, 2);
+    assert_eq!(world.get::<Treasury>(nation).unwrap().total(), second - 1);
+}
+
+#[test]
+fn test_disbanding_civilian_refunds_and_drops_its_job() {
+    use crate::civilians::hiring::{DISBAND_REFUND_PERCENT, disband_civilian, hire_cost};
+    use crate::economy::Treasury;
+    use crate::messages::civilians::DisbandCivilian;
+
+    let mut world = World::new();
+    world.add_observer(disband_civilian);
+
+    let nation = spawn_stocked_nation(&mut world, 4);
+    let engineer = world
+        .spawn((
+            Civilian {
+                kind: CivilianKind::Engineer,
+                position: TilePos { x: 0, y: 0 },
+                owner: nation,
+                civilian_id: CivilianId(0),
+                has_moved: true,
+                experience: 0,
+            },
+            CivilianJob {
+                job_type: JobType::BuildingRail,
+                turns_remaining: 2,
+                total_turns: 2,
+                target: TilePos { x: 1, y: 0 },
+            },
+        ))
+        .id();
+
+    // Inputs reserved for the job go back to the stockpile with the unit
+    let _ = world.run_system_once(move |mut owners: crate::civilians::jobs::JobInputOwners| {
+        crate::civilians::jobs::reserve_job_inputs(
+            engineer,
+            nation,
+            JobType::ImprovingTile,
+            &mut owners,
+        )
+    });
+    assert_eq!(
+        world
+            .get::<Stockpile>(nation)
+            .unwrap()
+            .get_reserved(Good::Timber),
+        IMPROVEMENT_TIMBER
+    );
+
+    world.trigger(DisbandCivilian { civilian: engineer });
+    world.flush();
+
+    assert!(world.get_entity(engineer).is_err());
+    assert_eq!(
+        world
+            .get::<Stockpile>(nation)
+            .unwrap()
+            .get_reserved(Good::Timber),
+        0
+    );
+    assert_eq!(world.query::<&CivilianJob>().iter(&world).count(), 0);
+
+    let expected = hire_cost(CivilianKind::Engineer, 0) * DISBAND_REFUND_PERCENT / 100;
+    assert_eq!(world.get::<Treasury>(nation).unwrap().total(), expected);
+}
+
+#[test]
+fn test_civilian_capabilities_are_consistent() {
+    let origin = TilePos { x: 0, y: 0 };
+
+    for kind in CivilianKind::ALL {
+        let capabilities = kind.capabilities();
+
+        assert_eq!(
+            capabilities.improvement_job.is_some(),
+            capabilities.improvement_predicate.is_some(),
+            "{kind:?} improvement job and predicate disagree"
+        );
+        assert_eq!(
+            kind.supports_order(&CivilianOrderKind::BuildRail { to: origin }),
+            capabilities.builds_rails
+        );
+        assert_eq!(
+            kind.supports_order(&CivilianOrderKind::BuildDepot),
+            capabilities.builds_depots
+        );
+        assert_eq!(
+            kind.supports_order(&CivilianOrderKind::BuildPort),
+            capabilities.builds_ports
+        );
+        assert_eq!(
+            kind.supports_order(&CivilianOrderKind::Prospect { to: origin }),
+            capabilities.prospects
+        );
+        if let Some(action) = capabilities.tile_action {
+            assert!(
+                kind.supports_order(&action(origin)),
+                "{kind:?} tile action is not one of its orders"
+            );
+        }
+    }
+
+    let engineer = CivilianKind::Engineer.capabilities();
+    assert!(engineer.builds_rails);
+    assert!(engineer.builds_depots);
+    assert!(engineer.builds_ports);
+    assert!(!engineer.prospects);
+}
+
+#[test]
+fn test_job_progress_advances_each_turn() {
+    use crate::civilians::jobs::advance_civilian_jobs;
+
+    let mut world = World::new();
+    world.init_resource::<TurnCounter>();
+
+    let nation = world.spawn(Nation).id();
+    let civilian = world
+        .spawn((
+            Civilian {
+                kind: CivilianKind::Engineer,
+                position: TilePos { x: 0, y: 0 },
+                owner: nation,
+                civilian_id: CivilianId(0),
+                has_moved: true,
+                experience: 0,
+            },
+            CivilianJob {
+                job_type: JobType::BuildingRail,
+                turns_remaining: 3,
+                total_turns: 3,
+                target: TilePos { x: 1, y: 0 },
+            },
+        ))
+        .id();
+
+    let progress = |world: &World| world.get::<CivilianJob>(civilian).unwrap().progress();
+    assert_eq!(progress(&world), 0.0);
+
+    let mut previous = 0.0;
+    for _ in 0..3 {
+        let _ = world.run_system_once(advance_civilian_jobs);
+        let current = progress(&world);
+        assert!((current - previous - 1.0 / 3.0).abs() < 0.01);
+        previous = current;
+    }
+    assert_eq!(previous, 1.0);
+}
+
+#[test]
+fn test_rescinding_job_releases_reserved_goods() {
+    let mut world = World::new();
+    world.insert_resource(TurnCounter::new(2));
+    world.init_resource::<ProspectingKnowledge>();
+    world.add_observer(handle_rescind_orders);
+
+    let nation = spawn_stocked_nation(&mut world, 10);
+    let province_id = ProvinceId(3);
+    let start = TilePos { x: 2, y: 3 };
+    let tile_pos = TilePos { x: 3, y: 3 };
+    world.spawn(Province {
+        id: province_id,
+        owner: Some(nation),
+        tiles: vec![start, tile_pos],
+        city_tile: start,
+    });
+    let map_size = TilemapSize { x: 5, y: 5 };
+    let mut tile_storage = TileStorage::empty(map_size);
+    let tile_entity = world
+        .spawn((
+            TileProvince { province_id },
+            TileResource::visible(ResourceType::Timber),
+        ))
+        .id();
+    tile_storage.set(&tile_pos, tile_entity);
+    world.spawn((tile_storage, map_size));
+
+    let civilian_entity = world
+        .spawn((
+            Civilian {
+                kind: CivilianKind::Forester,
+                position: start,
+                owner: nation,
+                civilian_id: CivilianId(0),
+                has_moved: false,
+                experience: 0,
+            },
+            CivilianOrder {
+                target: CivilianOrderKind::ImproveTile { to: tile_pos },
+            },
+        ))
+        .id();
+
+    let _ = world.run_system_once(execute_civilian_improvement_orders);
+    world.flush();
+    assert!(world.get::<CivilianJob>(civilian_entity).is_some());
+    assert_eq!(
+        world
+            .get::<Stockpile>(nation)
+            .unwrap()
+            .get_reserved(Good::Timber),
+        IMPROVEMENT_TIMBER
+    );
+
+    world.trigger(RescindOrders {
+        entity: civilian_entity,
+    });
+    world.flush();
+
+    let stockpile = world.get::<Stockpile>(nation).unwrap();
+    assert_eq!(stockpile.get_available(Good::Timber), 10);
+    assert_eq!(stockpile.get_reserved(Good::Timber), 0);
+    assert!(world.get::<Allocations>(nation).unwrap().jobs.is_empty());
+    assert_eq!(world.get::<ReservationSystem>(nation).unwrap().count(), 0);
+    assert!(world.get::<CivilianJob>(civilian_entity).is_none());
+    let civilian = world.get::<Civilian>(civilian_entity).unwrap();
+    assert!(!civilian.has_moved);
+    assert_eq!(civilian.position, start);
+}
+
+#[test]
+fn test_area_improvement_works_one_tile_per_turn_and_skips_invalid_tiles() {
+    use crate::civilians::engineering::{area_improvement_tiles, queue_next_area_improvement};
+    use crate::civilians::jobs::{advance_civilian_jobs, reset_civilian_actions};
+    use crate::civilians::types::AreaImprovementQueue;
+
+    let mut world = World::new();
+    world.init_resource::<TurnCounter>();
+    world.init_resource::<ProspectingKnowledge>();
+
+    let nation = spawn_stocked_nation(&mut world, 5);
+    let rival = world.spawn(Nation).id();
+
+    let center = TilePos { x: 2, y: 2 };
+    let ring: Vec<TilePos> = area_improvement_tiles(center, 1)
+        .into_iter()
+        .skip(1)
+        .collect();
+    let grain_neighbor = ring[0];
+    let coal_neighbor = ring[1];
+    let rival_neighbor = ring[2];
+
+    let owned = ProvinceId(1);
+    let foreign = ProvinceId(2);
+    world.spawn(Province {
+        id: owned,
+        owner: Some(nation),
+        tiles: vec![center, grain_neighbor, coal_neighbor],
+        city_tile: center,
+    });
+    world.spawn(Province {
+        id: foreign,
+        owner: Some(rival),
+        tiles: vec![rival_neighbor],
+        city_tile: rival_neighbor,
+    });
+
+    let map_size = TilemapSize { x: 5, y: 5 };
+    let mut tile_storage = TileStorage::empty(map_size);
+    let mut spawn_tile = |world: &mut World, pos: TilePos, province, resource| {
+        let tile = world
+            .spawn((
+                TileProvince {
+                    province_id: province,
+                },
+                resource,
+            ))
+            .id();
+        tile_storage.set(&pos, tile);
+        tile
+    };
+    let center_tile = spawn_tile(
+        &mut world,
+        center,
+        owned,
+        TileResource::visible(ResourceType::Grain),
+    );
+    let grain_tile = spawn_tile(
+        &mut world,
+        grain_neighbor,
+        owned,
+        TileResource::visible(ResourceType::Grain),
+    );
+    let coal_tile = spawn_tile(
+        &mut world,
+        coal_neighbor,
+        owned,
+        TileResource::visible(ResourceType::Coal),
+    );
+    let rival_tile = spawn_tile(
+        &mut world,
+        rival_neighbor,
+        foreign,
+        TileResource::visible(ResourceType::Grain),
+    );
+    world.spawn((tile_storage, map_size));
+
+    let farmer = world
+        .spawn((
+            Civilian {
+                kind: CivilianKind::Farmer,
+                position: center,
+                owner: nation,
+                civilian_id: CivilianId(0),
+                has_moved: false,
+                experience: 0,
+            },
+            CivilianOrder {
+                target: CivilianOrderKind::ImproveArea { center, radius: 1 },
+            },
+        ))
+        .id();
+
+    let development =
+        |world: &World, tile: Entity| world.get::<TileResource>(tile).unwrap().development;
+
+    let mut improved_per_turn = Vec::new();
+    for _ in 0..4 {
+        // Let the area queue feed tiles until one job starts or the queue runs dry
+        for _ in 0..8 {
+            let _ = world.run_system_once(queue_next_area_improvement);
+            let _ = world.run_system_once(execute_civilian_improvement_orders);
+        }
+        let _ = world.run_system_once(advance_civilian_jobs);
+        let _ = world.run_system_once(complete_improvement_jobs);
+        let _ = world.run_system_once(reset_civilian_actions);
+
+        improved_per_turn.push(
+            [center_tile, grain_tile]
+                .iter()
+                .filter(|&&tile| development(&world, tile) == DevelopmentLevel::Lv1)
+                .count(),
+        );
+    }
+
+    // One tile per turn: the centre first, then the valid neighbour
+    assert_eq!(&improved_per_turn[..2], &[1, 2]);
+    assert_eq!(development(&world, coal_tile), DevelopmentLevel::Lv0);
+    assert_eq!(development(&world, rival_tile), DevelopmentLevel::Lv0);
+    assert!(world.get::<AreaImprovementQueue>(farmer).is_none());
+    assert!(world.get::<CivilianJob>(farmer).is_none());
+}
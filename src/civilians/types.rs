@@ -3,9 +3,11 @@ use bevy::ecs::reflect::ReflectMapEntities;
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::TilePos;
 use moonshine_save::prelude::Save;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::mem;
 
+use crate::economy::Good;
+use crate::map::tiles::TerrainType;
 use crate::resources::TileResource;
 
 /// Unique identifier for a civilian (stable across saves)
@@ -87,6 +89,7 @@ pub enum JobType {
     BuildingRail,
     BuildingDepot,
     BuildingPort,
+    BuildingBridge,
     Mining,
     Drilling,
     Prospecting,
@@ -100,12 +103,35 @@ impl JobType {
             JobType::BuildingRail => 2,
             JobType::BuildingDepot => 1,
             JobType::BuildingPort => 2,
+            JobType::BuildingBridge => 2,
             JobType::Mining => 2,
             JobType::Drilling => 2,
             JobType::Prospecting => 1,
             JobType::ImprovingTile => 1, // Was 1, then 2, now 1 turn again
         }
     }
+
+    /// Goods the job uses up, reserved from the owner's stockpile when it starts
+    pub fn inputs(&self) -> &'static [(Good, u32)] {
+        match self {
+            JobType::ImprovingTile | JobType::Mining | JobType::Drilling => {
+                &[(Good::Timber, IMPROVEMENT_TIMBER)]
+            }
+            _ => &[],
+        }
+    }
+}
+
+/// Timber used by every tile improvement (fencing, pit props, derricks)
+pub const IMPROVEMENT_TIMBER: u32 = 1;
+
+/// Turns a job of `kind` takes on `terrain`: rough ground slows building,
+/// while surveying a tile takes the same time everywhere
+pub fn build_time(kind: JobType, terrain: TerrainType) -> u32 {
+    match kind {
+        JobType::Prospecting => kind.duration(),
+        _ => kind.duration() + terrain.build_difficulty(),
+    }
 }
 
 /// How an order is executed once issued
@@ -149,6 +175,24 @@ pub struct CivilianKindDefinition {
     pub resource_predicate: Option<ResourcePredicate>,
     pub improvement_job: Option<JobType>,
     pub show_orders_panel: bool,
+    /// Whether clicking an adjacent tile lays rail towards it
+    pub builds_rails: bool,
+    /// Order issued when the player clicks the tile the civilian stands on
+    pub tile_action: Option<fn(TilePos) -> CivilianOrderKind>,
+}
+
+/// What a civilian kind can do, gathered in one place so UI, AI and input
+/// handling do not need to match on [`CivilianKind`] themselves
+#[derive(Debug, Clone, Copy)]
+pub struct CivilianCapabilities {
+    pub builds_rails: bool,
+    pub builds_depots: bool,
+    pub builds_ports: bool,
+    pub prospects: bool,
+    pub improvement_job: Option<JobType>,
+    pub improvement_predicate: Option<ResourcePredicate>,
+    pub tile_action: Option<fn(TilePos) -> CivilianOrderKind>,
+    pub shows_orders_panel: bool,
 }
 
 /// Type of civilian unit
@@ -165,6 +209,17 @@ pub enum CivilianKind {
 }
 
 impl CivilianKind {
+    pub const ALL: [CivilianKind; 8] = [
+        CivilianKind::Prospector,
+        CivilianKind::Miner,
+        CivilianKind::Farmer,
+        CivilianKind::Rancher,
+        CivilianKind::Forester,
+        CivilianKind::Driller,
+        CivilianKind::Engineer,
+        CivilianKind::Developer,
+    ];
+
     /// Lookup table for civilian metadata
     pub fn definition(&self) -> &'static CivilianKindDefinition {
         const BUILD_DEPOT_ORDER: CivilianOrderDefinition = CivilianOrderDefinition {
@@ -207,6 +262,16 @@ impl CivilianKind {
             },
             execution: CivilianOrderExecution::StartJob(JobType::Prospecting),
         };
+        fn improve_tile(to: TilePos) -> CivilianOrderKind {
+            CivilianOrderKind::ImproveTile { to }
+        }
+        fn mine(to: TilePos) -> CivilianOrderKind {
+            CivilianOrderKind::Mine { to }
+        }
+        fn prospect(to: TilePos) -> CivilianOrderKind {
+            CivilianOrderKind::Prospect { to }
+        }
+
         const ENGINEER_ORDERS: &[CivilianOrderDefinition] = &[BUILD_DEPOT_ORDER, BUILD_PORT_ORDER];
         const FARMER_ORDERS: &[CivilianOrderDefinition] = &[IMPROVE_TILE_ORDER];
         const RANCHER_ORDERS: &[CivilianOrderDefinition] = &[IMPROVE_TILE_ORDER];
@@ -222,6 +287,8 @@ impl CivilianKind {
             resource_predicate: None,
             improvement_job: None,
             show_orders_panel: true,
+            builds_rails: true,
+            tile_action: None,
         };
         const FARMER_DEFINITION: CivilianKindDefinition = CivilianKindDefinition {
             display_name: "Farmer",
@@ -229,6 +296,8 @@ impl CivilianKind {
             resource_predicate: Some(TileResource::improvable_by_farmer),
             improvement_job: Some(JobType::ImprovingTile),
             show_orders_panel: false,
+            builds_rails: false,
+            tile_action: Some(improve_tile),
         };
         const RANCHER_DEFINITION: CivilianKindDefinition = CivilianKindDefinition {
             display_name: "Rancher",
@@ -236,6 +305,8 @@ impl CivilianKind {
             resource_predicate: Some(TileResource::improvable_by_rancher),
             improvement_job: Some(JobType::ImprovingTile),
             show_orders_panel: false,
+            builds_rails: false,
+            tile_action: Some(improve_tile),
         };
         const FORESTER_DEFINITION: CivilianKindDefinition = CivilianKindDefinition {
             display_name: "Forester",
@@ -243,6 +314,8 @@ impl CivilianKind {
             resource_predicate: Some(TileResource::improvable_by_forester),
             improvement_job: Some(JobType::ImprovingTile),
             show_orders_panel: false,
+            builds_rails: false,
+            tile_action: Some(improve_tile),
         };
         const MINER_DEFINITION: CivilianKindDefinition = CivilianKindDefinition {
             display_name: "Miner",
@@ -250,6 +323,8 @@ impl CivilianKind {
             resource_predicate: Some(TileResource::improvable_by_miner),
             improvement_job: Some(JobType::Mining),
             show_orders_panel: false,
+            builds_rails: false,
+            tile_action: Some(mine),
         };
         const DRILLER_DEFINITION: CivilianKindDefinition = CivilianKindDefinition {
             display_name: "Driller",
@@ -257,6 +332,8 @@ impl CivilianKind {
             resource_predicate: Some(TileResource::improvable_by_driller),
             improvement_job: Some(JobType::Drilling),
             show_orders_panel: false,
+            builds_rails: false,
+            tile_action: Some(improve_tile),
         };
         const PROSPECTOR_DEFINITION: CivilianKindDefinition = CivilianKindDefinition {
             display_name: "Prospector",
@@ -264,6 +341,8 @@ impl CivilianKind {
             resource_predicate: None,
             improvement_job: None,
             show_orders_panel: false,
+            builds_rails: false,
+            tile_action: Some(prospect),
         };
         const DEVELOPER_DEFINITION: CivilianKindDefinition = CivilianKindDefinition {
             display_name: "Developer",
@@ -271,6 +350,8 @@ impl CivilianKind {
             resource_predicate: None,
             improvement_job: None,
             show_orders_panel: false,
+            builds_rails: false,
+            tile_action: None,
         };
 
         match self {
@@ -285,7 +366,31 @@ impl CivilianKind {
         }
     }
 
-    /// Monetary cost to hire this civilian type.
+    /// Everything this kind can do, derived from its [`definition`](Self::definition)
+    pub fn capabilities(&self) -> CivilianCapabilities {
+        let definition = self.definition();
+        let has_order = |order: CivilianOrderKind| {
+            definition
+                .orders
+                .iter()
+                .any(|candidate| candidate.matches(&order))
+        };
+        let origin = TilePos { x: 0, y: 0 };
+
+        CivilianCapabilities {
+            builds_rails: definition.builds_rails,
+            builds_depots: has_order(CivilianOrderKind::BuildDepot),
+            builds_ports: has_order(CivilianOrderKind::BuildPort),
+            prospects: has_order(CivilianOrderKind::Prospect { to: origin }),
+            improvement_job: definition.improvement_job,
+            improvement_predicate: definition.resource_predicate,
+            tile_action: definition.tile_action,
+            shows_orders_panel: definition.show_orders_panel,
+        }
+    }
+
+    /// Monetary cost to hire the first civilian of this type.
+    /// See [`hire_cost`](crate::civilians::hiring::hire_cost) for later ones.
     pub fn hiring_cost(&self) -> i64 {
         match self {
             CivilianKind::Engineer => 200,
@@ -298,36 +403,28 @@ impl CivilianKind {
 
     /// Returns true if the civilian can improve tile resources
     pub fn supports_improvements(&self) -> bool {
-        self.definition().improvement_job.is_some()
+        self.capabilities().improvement_job.is_some()
     }
 
     /// Returns true if the civilian should show an orders panel when selected
     pub fn shows_orders_panel(&self) -> bool {
-        self.definition().show_orders_panel
+        self.capabilities().shows_orders_panel
     }
 
     /// Returns the order kind to issue when clicking a tile
     /// The returned function takes a target tile position
     pub fn default_tile_action_order(&self, to: TilePos) -> Option<CivilianOrderKind> {
-        match self {
-            CivilianKind::Prospector => Some(CivilianOrderKind::Prospect { to }),
-            CivilianKind::Miner => Some(CivilianOrderKind::Mine { to }),
-            CivilianKind::Farmer
-            | CivilianKind::Rancher
-            | CivilianKind::Forester
-            | CivilianKind::Driller => Some(CivilianOrderKind::ImproveTile { to }),
-            _ => None,
-        }
+        self.capabilities().tile_action.map(|action| action(to))
     }
 
     /// Get the resource predicate used to validate improvements
     pub fn improvement_predicate(&self) -> Option<ResourcePredicate> {
-        self.definition().resource_predicate
+        self.capabilities().improvement_predicate
     }
 
     /// Get the job type started when issuing an improvement order
     pub fn improvement_job(&self) -> Option<JobType> {
-        self.definition().improvement_job
+        self.capabilities().improvement_job
     }
 
     /// All orders that the civilian exposes to the UI/AI
@@ -349,7 +446,10 @@ impl CivilianKind {
     pub fn supports_order(&self, order: &CivilianOrderKind) -> bool {
         match order {
             CivilianOrderKind::Move { .. } => true,
-            CivilianOrderKind::BuildRail { .. } => *self == CivilianKind::Engineer,
+            CivilianOrderKind::BuildRail { .. } | CivilianOrderKind::BuildBridge { .. } => {
+                self.capabilities().builds_rails
+            }
+            CivilianOrderKind::ImproveArea { .. } => self.supports_improvements(),
             _ => self.order_definition(order).is_some(),
         }
     }
@@ -366,6 +466,26 @@ pub struct Civilian {
     pub owner: Entity, // Nation entity that owns this unit (remapped via MapEntities)
     pub civilian_id: CivilianId,
     pub has_moved: bool, // True if unit has used its action this turn
+    /// Jobs completed over the unit's career; veterans finish jobs sooner
+    pub experience: u32,
+}
+
+/// Completed jobs after which a civilian works one turn faster
+pub const VETERAN_EXPERIENCE: u32 = 3;
+/// Completed jobs after which a civilian works two turns faster
+pub const ELITE_EXPERIENCE: u32 = 8;
+
+impl Civilian {
+    /// Turns this civilian's experience shaves off each job
+    pub fn job_speedup(&self) -> u32 {
+        if self.experience >= ELITE_EXPERIENCE {
+            2
+        } else if self.experience >= VETERAN_EXPERIENCE {
+            1
+        } else {
+            0
+        }
+    }
 }
 
 /// Pending order for a civilian unit
@@ -381,9 +501,59 @@ pub struct CivilianOrder {
 pub struct CivilianJob {
     pub job_type: JobType,
     pub turns_remaining: u32,
+    /// Turns the job took when it started; used for progress display
+    pub total_turns: u32,
     pub target: TilePos, // Where the job is happening
 }
 
+impl CivilianJob {
+    /// Start a job of `job_type` at `target`, lasting its full duration
+    pub fn new(job_type: JobType, target: TilePos) -> Self {
+        Self::with_duration(job_type, target, job_type.duration())
+    }
+
+    /// Start a job at `target`, taking longer on difficult terrain
+    pub fn on_terrain(job_type: JobType, target: TilePos, terrain: Option<TerrainType>) -> Self {
+        let duration = terrain.map_or(job_type.duration(), |terrain| build_time(job_type, terrain));
+        Self::with_duration(job_type, target, duration)
+    }
+
+    /// Shorten the job by `turns`, never below a single turn
+    pub fn hastened(self, turns: u32) -> Self {
+        let duration = self.total_turns.saturating_sub(turns).max(1);
+        Self::with_duration(self.job_type, self.target, duration)
+    }
+
+    fn with_duration(job_type: JobType, target: TilePos, duration: u32) -> Self {
+        Self {
+            job_type,
+            turns_remaining: duration,
+            total_turns: duration,
+            target,
+        }
+    }
+
+    /// Share of the job already done, from 0.0 to 1.0
+    pub fn progress(&self) -> f32 {
+        if self.total_turns == 0 {
+            return 1.0;
+        }
+        let done = self.total_turns.saturating_sub(self.turns_remaining);
+        done as f32 / self.total_turns as f32
+    }
+}
+
+/// Largest radius accepted for [`CivilianOrderKind::ImproveArea`]
+pub const MAX_IMPROVE_AREA_RADIUS: u32 = 2;
+
+/// Tiles still to visit for an area improvement order.
+/// Each one becomes an `ImproveTile` order once the civilian is free again.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct AreaImprovementQueue {
+    pub tiles: VecDeque<TilePos>,
+}
+
 /// Visual marker for civilian unit sprites
 #[derive(Component)]
 pub struct CivilianVisual(pub Entity); // Points to the Civilian entity
@@ -402,15 +572,17 @@ pub struct ActionTurn(pub u32);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum CivilianOrderKind {
-    BuildRail { to: TilePos },    // Build rail to adjacent tile
-    BuildDepot,                   // Build depot at current position
-    BuildPort,                    // Build port at current position
-    Move { to: TilePos },         // Move to target tile (no other action)
-    Prospect { to: TilePos },     // Move to tile and reveal minerals (Prospector)
-    Mine { to: TilePos },         // Move to tile and upgrade mine (Miner)
+    BuildRail { to: TilePos },                    // Build rail to adjacent tile
+    BuildDepot,                                   // Build depot at current position
+    BuildPort,                                    // Build port at current position
+    BuildBridge { to: TilePos },                  // Bridge the river edge to an adjacent tile
+    Move { to: TilePos },                         // Move to target tile (no other action)
+    Prospect { to: TilePos },                     // Move to tile and reveal minerals (Prospector)
+    Mine { to: TilePos },                         // Move to tile and upgrade mine (Miner)
     ImproveTile { to: TilePos }, // Move to tile and improve resource (Farmer/Rancher/Forester/Driller)
     BuildFarm { to: TilePos },   // Move to tile and build farm on grain/fruit/cotton (Farmer)
     BuildOrchard { to: TilePos }, // Move to tile and build orchard on fruit (Farmer)
+    ImproveArea { center: TilePos, radius: u32 }, // Improve owned tiles around center, one per turn
     SkipTurn,                    // Skip only this turn, then become available again
     Sleep,                       // Keep skipping turns until explicitly woken up (rescinded)
 }
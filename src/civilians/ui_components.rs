@@ -3,8 +3,9 @@ use bevy::ui::widget::Button as OldButton;
 use bevy::ui_widgets::{Activate, Button};
 
 use crate::civilians::commands::{DeselectCivilian, RescindOrders, SelectCivilian};
-use crate::civilians::types::{Civilian, PreviousPosition};
-use crate::messages::civilians::CivilianCommand;
+use crate::civilians::types::{Civilian, CivilianJob, JobType, PreviousPosition};
+use crate::economy::transport::{CancelRailConstruction, RailConstruction};
+use crate::messages::civilians::{CivilianCommand, DisbandCivilian};
 use crate::ui::button_style::*;
 
 /// Marker for civilian orders UI panel
@@ -150,6 +151,33 @@ pub fn show_civilian_orders_ui(
                     ));
                 });
         }
+
+        parent
+            .spawn((
+                Button,
+                OldButton,
+                Node {
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(NORMAL_DANGER),
+                DangerButton,
+            ))
+            .observe(move |_: On<Activate>, mut commands: Commands| {
+                commands.trigger(DisbandCivilian {
+                    civilian: civilian_entity,
+                });
+            })
+            .with_children(|button_parent| {
+                button_parent.spawn((
+                    Text::new("Disband"),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.95, 1.0)),
+                ));
+            });
     });
 }
 
@@ -169,7 +197,7 @@ pub fn show_rescind_orders_ui(
     trigger: On<SelectCivilian>,
     mut commands: Commands,
     player_nation: Option<Res<crate::economy::PlayerNation>>,
-    civilians_with_prev: Query<(&Civilian, &PreviousPosition)>,
+    civilians_with_prev: Query<(&Civilian, &PreviousPosition, Option<&CivilianJob>)>,
     existing_panel: Query<Entity, With<RescindOrdersPanel>>,
 ) {
     // Early exit if no player nation set
@@ -184,7 +212,7 @@ pub fn show_rescind_orders_ui(
 
     let event = trigger.event();
 
-    if let Ok((civilian, prev_pos)) = civilians_with_prev.get(event.entity) {
+    if let Ok((civilian, prev_pos, job)) = civilians_with_prev.get(event.entity) {
         // Only show UI for player-owned units
         if civilian.owner != player.entity() {
             return;
@@ -202,6 +230,10 @@ pub fn show_rescind_orders_ui(
         );
         let civilian_entity = event.entity;
         let prev_pos = *prev_pos;
+        let job_line = job
+            .map(|job| format!("\n{:?}: {:.0}% done", job.job_type, job.progress() * 100.0))
+            .unwrap_or_default();
+        let building_rail = job.is_some_and(|job| job.job_type == JobType::BuildingRail);
 
         commands
             .spawn((
@@ -221,8 +253,8 @@ pub fn show_rescind_orders_ui(
             .with_children(|parent| {
                 parent.spawn((
                     Text::new(format!(
-                        "Undo Action\nWas at: ({}, {})",
-                        prev_pos.0.x, prev_pos.0.y
+                        "Undo Action\nWas at: ({}, {}){}",
+                        prev_pos.0.x, prev_pos.0.y, job_line
                     )),
                     TextFont {
                         font_size: 13.0,
@@ -262,6 +294,43 @@ pub fn show_rescind_orders_ui(
                         ));
                     });
 
+                // A rail segment can also be abandoned where it stands, keeping the
+                // engineer on the far tile
+                if building_rail {
+                    parent
+                        .spawn((
+                            Button,
+                            OldButton,
+                            Node {
+                                padding: UiRect::all(Val::Px(8.0)),
+                                ..default()
+                            },
+                            BackgroundColor(NORMAL_BUTTON),
+                        ))
+                        .observe(
+                            move |_: On<Activate>,
+                                  mut commands: Commands,
+                                  constructions: Query<(Entity, &RailConstruction)>| {
+                                for (construction, rail) in constructions.iter() {
+                                    if rail.engineer == civilian_entity {
+                                        commands.trigger(CancelRailConstruction { construction });
+                                    }
+                                }
+                                commands.trigger(DeselectCivilian);
+                            },
+                        )
+                        .with_children(|button_parent| {
+                            button_parent.spawn((
+                                Text::new("Stop Construction"),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.9, 0.95, 1.0)),
+                            ));
+                        });
+                }
+
                 parent.spawn((
                     Text::new("(Refund if same turn)"),
                     TextFont {
@@ -36,6 +36,7 @@ fn test_ui_not_shown_for_enemy_units() {
             owner: enemy_nation_entity,
             civilian_id: CivilianId(0),
             has_moved: false,
+            experience: 0,
         })
         .id();
 
@@ -75,6 +76,7 @@ fn test_ui_shown_for_player_units() {
             owner: player_nation_entity,
             civilian_id: CivilianId(0),
             has_moved: false,
+            experience: 0,
         })
         .id();
 
@@ -112,6 +114,7 @@ fn test_ui_not_shown_without_player_nation() {
             owner: nation_entity,
             civilian_id: CivilianId(0),
             has_moved: false,
+            experience: 0,
         })
         .id();
 
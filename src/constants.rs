@@ -11,11 +11,45 @@ pub const MAP_SIZE: u32 = 32;
 /// Map generation seed for terrain generator
 pub const TERRAIN_SEED: u32 = 12345;
 
+/// Maximum number of nations generated for a map
+pub const MAX_NATIONS: usize = 5;
+
+/// Hex coordinate system shared by the tilemap, world-position conversions and
+/// neighbor lookups. Must be an axial system (`Row` for pointy-top hexes,
+/// `Column` for flat-top), since `TilePos` is mapped to axial `Hex` directly.
+pub const HEX_COORD_SYSTEM: bevy_ecs_tilemap::prelude::HexCoordSystem =
+    bevy_ecs_tilemap::prelude::HexCoordSystem::Row;
+
+const _: () = assert!(matches!(
+    HEX_COORD_SYSTEM,
+    bevy_ecs_tilemap::prelude::HexCoordSystem::Row
+        | bevy_ecs_tilemap::prelude::HexCoordSystem::Column
+));
+
+/// Tilemap type for the game map, built from [`HEX_COORD_SYSTEM`]
+pub fn hex_map_type() -> bevy_ecs_tilemap::prelude::TilemapType {
+    bevy_ecs_tilemap::prelude::TilemapType::Hexagon(HEX_COORD_SYSTEM)
+}
+
+/// How much wider hexes are spaced across their overlapping axis, so the full
+/// square tile art stays visible
+const HEX_SPACING_STRETCH: f32 = 1.3;
+
 /// Get the grid size for hexagonal tilemap
-/// Slightly increase vertical spacing so full square tiles are visible
+/// Pointy-top rows overlap vertically and flat-top columns horizontally, so the
+/// spacing is stretched along whichever axis [`HEX_COORD_SYSTEM`] stacks hexes on
 pub fn get_hex_grid_size() -> bevy_ecs_tilemap::prelude::TilemapGridSize {
-    bevy_ecs_tilemap::prelude::TilemapGridSize {
-        x: TILE_SIZE,
-        y: TILE_SIZE * 1.3,
+    let stretched = TILE_SIZE * HEX_SPACING_STRETCH;
+    match HEX_COORD_SYSTEM {
+        bevy_ecs_tilemap::prelude::HexCoordSystem::Column => {
+            bevy_ecs_tilemap::prelude::TilemapGridSize {
+                x: stretched,
+                y: TILE_SIZE,
+            }
+        }
+        _ => bevy_ecs_tilemap::prelude::TilemapGridSize {
+            x: TILE_SIZE,
+            y: stretched,
+        },
     }
 }
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::diplomacy::DiplomacyState;
+use crate::economy::production::ProductionResult;
+use crate::economy::{Good, NationInstance, Stockpile, Treasury};
+use crate::turn_system::TurnCounter;
+
+/// Goods listed in a consulate report
+const CONSULATE_TOP_GOODS: usize = 3;
+/// Goods listed in an embassy report
+const EMBASSY_TOP_GOODS: usize = 5;
+/// Consulate treasury estimates are off by up to this share either way
+const CONSULATE_TREASURY_ERROR: f32 = 0.2;
+/// Consulate figures are rounded to multiples of these
+const CONSULATE_TREASURY_STEP: i64 = 100;
+const CONSULATE_GOODS_STEP: u32 = 10;
+/// Consulate output estimates are off by up to this share either way
+const CONSULATE_PRODUCTION_ERROR: f32 = 0.25;
+/// Consulate output estimates are rounded to multiples of this
+const CONSULATE_PRODUCTION_STEP: u32 = 5;
+/// Consulate military estimates are off by up to this share either way
+const CONSULATE_MILITARY_ERROR: f32 = 0.3;
+/// Consulate military estimates are rounded to multiples of this
+const CONSULATE_MILITARY_STEP: u32 = 5;
+/// Stockpiled goods that equip troops, counted toward a nation's military strength
+const MILITARY_GOODS: [Good; 2] = [Good::Arms, Good::Horses];
+/// Dollars of economic power credited per unit of last turn's industrial output
+pub const PRODUCTION_POWER_VALUE: i64 = 100;
+
+/// How a nation gathers intelligence on another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntelSource {
+    /// Rough estimates from trade officials
+    Consulate,
+    /// Exact figures from an ambassador
+    Embassy,
+}
+
+/// What one nation knows about another's economy
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntelReport {
+    pub source: IntelSource,
+    pub treasury_min: i64,
+    pub treasury_max: i64,
+    /// Largest stockpiles, biggest first
+    pub top_goods: Vec<(Good, u32)>,
+    /// Industrial output last turn, as a range for consulate estimates
+    pub production_min: u32,
+    pub production_max: u32,
+    /// Arms and horses on hand to equip troops, as a range for consulate estimates
+    pub military_min: u32,
+    pub military_max: u32,
+    /// Turn the report was compiled
+    pub turn: u32,
+}
+
+impl IntelReport {
+    pub fn compile(
+        source: IntelSource,
+        treasury: &Treasury,
+        stockpile: &Stockpile,
+        production: u32,
+        turn: u32,
+    ) -> Self {
+        let total = treasury.total();
+        let (treasury_min, treasury_max, goods_shown, goods_step) = match source {
+            IntelSource::Embassy => (total, total, EMBASSY_TOP_GOODS, 1),
+            IntelSource::Consulate => {
+                let error = (total as f32 * CONSULATE_TREASURY_ERROR) as i64;
+                let min =
+                    (total - error).max(0) / CONSULATE_TREASURY_STEP * CONSULATE_TREASURY_STEP;
+                let max =
+                    (total + error).div_ceil(CONSULATE_TREASURY_STEP) * CONSULATE_TREASURY_STEP;
+                (min, max, CONSULATE_TOP_GOODS, CONSULATE_GOODS_STEP)
+            }
+        };
+        let (production_min, production_max) = match source {
+            IntelSource::Embassy => (production, production),
+            IntelSource::Consulate => estimate_range(
+                production,
+                CONSULATE_PRODUCTION_ERROR,
+                CONSULATE_PRODUCTION_STEP,
+            ),
+        };
+        let military: u32 = MILITARY_GOODS.iter().map(|good| stockpile.get(*good)).sum();
+        let (military_min, military_max) = match source {
+            IntelSource::Embassy => (military, military),
+            IntelSource::Consulate => {
+                estimate_range(military, CONSULATE_MILITARY_ERROR, CONSULATE_MILITARY_STEP)
+            }
+        };
+
+        let mut goods: Vec<(Good, u32)> = stockpile
+            .entries()
+            .filter(|entry| entry.total > 0)
+            .map(|entry| (entry.good, entry.total))
+            .collect();
+        // Ties keep `Good::ALL` order so reports are stable between turns
+        goods.sort_by_key(|&(_, amount)| std::cmp::Reverse(amount));
+        goods.truncate(goods_shown);
+        for (_, amount) in &mut goods {
+            *amount = *amount / goods_step * goods_step;
+        }
+
+        Self {
+            source,
+            treasury_min,
+            treasury_max,
+            top_goods: goods,
+            production_min,
+            production_max,
+            military_min,
+            military_max,
+            turn,
+        }
+    }
+
+    /// Estimated economic power (treasury plus valued output) as a range;
+    /// exact for embassy reports
+    pub fn power_range(&self) -> (i64, i64) {
+        (
+            self.treasury_min + self.production_min as i64 * PRODUCTION_POWER_VALUE,
+            self.treasury_max + self.production_max as i64 * PRODUCTION_POWER_VALUE,
+        )
+    }
+
+    /// Midpoint of [`Self::power_range`], used for ranking
+    pub fn power_estimate(&self) -> i64 {
+        let (min, max) = self.power_range();
+        (min + max) / 2
+    }
+
+    /// Treasury as shown in the diplomacy screen
+    pub fn treasury_label(&self) -> String {
+        if self.treasury_min == self.treasury_max {
+            format!("${}", self.treasury_min)
+        } else {
+            format!("${}-${}", self.treasury_min, self.treasury_max)
+        }
+    }
+
+    /// Military strength as shown in the diplomacy screen
+    pub fn military_label(&self) -> String {
+        if self.military_min == self.military_max {
+            format!("{}", self.military_min)
+        } else {
+            format!("{}-{}", self.military_min, self.military_max)
+        }
+    }
+
+    pub fn power_label(&self) -> String {
+        match self.power_range() {
+            (min, max) if min == max => format!("${}", min),
+            (min, max) => format!("${}-${}", min, max),
+        }
+    }
+}
+
+/// Widen `value` by `error` either way and round outward to multiples of `step`
+fn estimate_range(value: u32, error: f32, step: u32) -> (u32, u32) {
+    let error = (value as f32 * error) as u32;
+    (
+        value.saturating_sub(error) / step * step,
+        (value + error).div_ceil(step) * step,
+    )
+}
+
+/// Intel reports keyed by (observer, target)
+#[derive(Resource, Default, Debug)]
+pub struct IntelReports {
+    reports: HashMap<(NationInstance, NationInstance), IntelReport>,
+}
+
+impl IntelReports {
+    pub fn get(&self, observer: NationInstance, target: NationInstance) -> Option<&IntelReport> {
+        self.reports.get(&(observer, target))
+    }
+
+    /// Nations `observer` has a consulate or embassy with, strongest estimated economy first
+    pub fn power_ranking(&self, observer: NationInstance) -> Vec<(NationInstance, &IntelReport)> {
+        let mut ranking: Vec<(NationInstance, &IntelReport)> = self
+            .reports
+            .iter()
+            .filter(|((from, _), _)| *from == observer)
+            .map(|((_, target), report)| (*target, report))
+            .collect();
+        ranking.sort_by_key(|(target, report)| {
+            (std::cmp::Reverse(report.power_estimate()), target.entity())
+        });
+        ranking
+    }
+}
+
+/// Rebuild every nation's reports on the nations it has a consulate or embassy with
+pub fn update_intel_reports(
+    state: Res<DiplomacyState>,
+    turn: Res<TurnCounter>,
+    nations: Query<(NationInstance, &Treasury, &Stockpile, &ProductionResult)>,
+    mut intel: ResMut<IntelReports>,
+) {
+    intel.reports.clear();
+    for (observer, _, _, _) in nations.iter() {
+        for (target, relation) in state.relations_for(observer) {
+            let source = if relation.treaty.embassy {
+                IntelSource::Embassy
+            } else if relation.treaty.consulate {
+                IntelSource::Consulate
+            } else {
+                continue;
+            };
+            let Ok((_, treasury, stockpile, production)) = nations.get(target.entity()) else {
+                continue;
+            };
+            // Units the target's buildings made when its last turn was finalized
+            let output = production
+                .reports
+                .values()
+                .map(|report| report.produced)
+                .sum();
+            intel.reports.insert(
+                (observer, target),
+                IntelReport::compile(source, treasury, stockpile, output, turn.current),
+            );
+        }
+    }
+}
@@ -1,12 +1,29 @@
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 
 use bevy::prelude::*;
 
-use crate::economy::{NationInstance, Treasury};
-pub use crate::messages::diplomacy::{DiplomaticOrder, DiplomaticOrderKind};
-use crate::turn_system::{PlayerTurnSet, TurnPhase};
+use crate::ai::AiNation;
+use crate::economy::nation::Capital;
+use crate::economy::trade::{TradeLedger, resolve_market_orders};
+use crate::economy::transport::{Depot, Port, RecomputeConnectivity};
+use crate::economy::{
+    FinancialAction, FinancialRejection, Good, NationInstance, ResearchProgress, Stockpile,
+    Treasury,
+};
+use crate::map::province::{Province, ProvinceId};
+pub use crate::messages::diplomacy::{
+    DiplomaticOrder, DiplomaticOrderKind, GoodSwapSettlement, ProvinceCession, ResearchGift,
+};
+use crate::turn_system::{EnemyTurnSet, PlayerTurnSet, TurnCounter, TurnPhase};
 use crate::ui::menu::AppState;
 
+pub mod intel;
+pub use intel::{IntelReport, IntelReports, IntelSource};
+
+pub mod persistence;
+pub use persistence::SavedDiplomacy;
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 struct DiplomacyPair(NationInstance, NationInstance);
 
@@ -20,6 +37,10 @@ impl DiplomacyPair {
         }
     }
 
+    fn sort_key(&self) -> (u64, u64) {
+        (self.0.entity().to_bits(), self.1.entity().to_bits())
+    }
+
     fn contains(&self, nation: NationInstance) -> bool {
         self.0 == nation || self.1 == nation
     }
@@ -35,6 +56,19 @@ impl DiplomacyPair {
     }
 }
 
+// Ordered so that iterating relations is reproducible between runs
+impl Ord for DiplomacyPair {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl PartialOrd for DiplomacyPair {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 /// Relationship tiers used for UI labelling and thresholds.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RelationshipBand {
@@ -89,13 +123,15 @@ impl DiplomaticRelation {
 }
 
 /// Treaty flags following Imperialism's diplomacy flow.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Reflect)]
 pub struct TreatyState {
     pub at_war: bool,
     pub consulate: bool,
     pub embassy: bool,
     pub non_aggression_pact: bool,
     pub alliance: bool,
+    /// Shared shipping lowers the transport surcharge on goods traded between the two
+    pub trade_agreement: bool,
 }
 
 impl TreatyState {
@@ -106,16 +142,24 @@ impl TreatyState {
             embassy: false,
             non_aggression_pact: false,
             alliance: false,
+            trade_agreement: false,
         }
     }
 }
 
 /// All relationships between nations.
+/// Stored in a `BTreeMap` so relation-driven outcomes (alliance calls, opinion shifts)
+/// happen in the same order every run.
 #[derive(Resource, Default)]
 pub struct DiplomacyState {
-    relations: HashMap<DiplomacyPair, DiplomaticRelation>,
+    relations: BTreeMap<DiplomacyPair, DiplomaticRelation>,
+    /// Turn on which each (caller, ally, enemy) call to arms was last issued
+    alliance_calls: HashMap<(NationInstance, NationInstance, NationInstance), u32>,
 }
 
+/// Turns before the same ally can be called into the same war again
+pub const ALLIANCE_CALL_COOLDOWN_TURNS: u32 = 5;
+
 impl DiplomacyState {
     pub fn relation(&self, a: NationInstance, b: NationInstance) -> Option<&DiplomaticRelation> {
         self.relations.get(&DiplomacyPair::new(a, b))
@@ -167,6 +211,47 @@ impl DiplomacyState {
             .collect()
     }
 
+    pub fn has_trade_agreement(&self, a: NationInstance, b: NationInstance) -> bool {
+        self.relation(a, b)
+            .is_some_and(|r| r.treaty.trade_agreement && !r.treaty.at_war)
+    }
+
+    pub fn are_allied(&self, a: NationInstance, b: NationInstance) -> bool {
+        self.relation(a, b)
+            .is_some_and(|r| r.treaty.alliance && !r.treaty.at_war)
+    }
+
+    /// Whether `caller` asked `ally` to fight `enemy` within the cooldown window
+    pub fn alliance_call_on_cooldown(
+        &self,
+        caller: NationInstance,
+        ally: NationInstance,
+        enemy: NationInstance,
+        turn: u32,
+    ) -> bool {
+        self.alliance_calls
+            .get(&(caller, ally, enemy))
+            .is_some_and(|&issued| turn < issued + ALLIANCE_CALL_COOLDOWN_TURNS)
+    }
+
+    fn record_alliance_call(
+        &mut self,
+        caller: NationInstance,
+        ally: NationInstance,
+        enemy: NationInstance,
+        turn: u32,
+    ) {
+        self.alliance_calls.insert((caller, ally, enemy), turn);
+    }
+
+    /// Forget calls to arms tied to the war between `a` and `b`
+    fn clear_alliance_calls(&mut self, a: NationInstance, b: NationInstance) {
+        self.alliance_calls.retain(|&(caller, ally, enemy), _| {
+            let side = |nation| nation == a || nation == b;
+            !((side(caller) || side(ally)) && side(enemy))
+        });
+    }
+
     pub fn adjust_all_relations(&mut self, nation: NationInstance, delta: i32) {
         for (pair, relation) in self.relations.iter_mut() {
             if pair.contains(nation) {
@@ -241,7 +326,10 @@ impl DiplomaticOffer {
 
 #[derive(Clone, Debug)]
 pub enum DiplomaticOfferKind {
-    OfferPeace,
+    /// Peace where the accepting nation pays `reparations` to the one offering it
+    OfferPeace {
+        reparations: i64,
+    },
     Alliance,
     NonAggressionPact,
     ForeignAid {
@@ -252,6 +340,21 @@ pub enum DiplomaticOfferKind {
         enemy: NationInstance,
         defensive: bool,
     },
+    TradeAgreement,
+    /// The offering nation hands `province` over to the recipient
+    CedeProvince {
+        province: ProvinceId,
+    },
+    /// Barter outside the world market: the offering nation gives `give`
+    /// and receives `receive` from the recipient
+    GoodSwap {
+        give: (Good, u32),
+        receive: (Good, u32),
+    },
+    /// The offering ally gifts `points` of research to the recipient
+    ShareResearch {
+        points: u32,
+    },
 }
 
 #[derive(Resource, Default)]
@@ -307,7 +410,11 @@ impl Plugin for DiplomacyPlugin {
             .init_resource::<ForeignAidLedger>()
             .init_resource::<DiplomaticOffers>()
             .init_resource::<DiplomacySelection>()
-            .add_observer(process_diplomatic_orders);
+            .init_resource::<IntelReports>()
+            .add_observer(process_diplomatic_orders)
+            .add_observer(apply_province_cession)
+            .add_observer(apply_good_swap)
+            .add_observer(apply_research_gift);
 
         // Sync diplomatic pairs once when game starts (nations are static after setup)
         app.add_systems(OnEnter(AppState::InGame), sync_diplomatic_pairs);
@@ -317,6 +424,31 @@ impl Plugin for DiplomacyPlugin {
             OnEnter(TurnPhase::PlayerTurn),
             (apply_recurring_aid, decay_relationships).in_set(PlayerTurnSet::Maintenance),
         );
+
+        // Intel is refreshed every turn and whenever consulates or embassies change
+        app.add_systems(
+            OnEnter(TurnPhase::PlayerTurn),
+            intel::update_intel_reports.in_set(PlayerTurnSet::Ui),
+        )
+        .add_systems(
+            Update,
+            intel::update_intel_reports
+                .run_if(in_state(AppState::InGame).and(resource_changed::<DiplomacyState>)),
+        );
+
+        // AI nations at war put peace on the table during their turn
+        app.add_systems(
+            OnEnter(TurnPhase::EnemyTurn),
+            offer_ai_peace.in_set(EnemyTurnSet::Actions),
+        );
+
+        // Market: reward pairs that traded with each other this turn
+        app.add_systems(
+            OnEnter(TurnPhase::PlayerTurn),
+            apply_trade_goodwill
+                .in_set(PlayerTurnSet::Market)
+                .after(resolve_market_orders),
+        );
     }
 }
 
@@ -332,6 +464,9 @@ fn process_diplomatic_orders(
     mut offers: ResMut<DiplomaticOffers>,
     nations: Query<(NationInstance, &Name)>,
     mut treasuries: Query<&mut Treasury>,
+    provinces: Query<&Province>,
+    turn: Res<TurnCounter>,
+    mut rejections: MessageWriter<FinancialRejection>,
 ) {
     let (instance_to_name, nation_instances) = collect_nation_lookup(&nations);
     let order = trigger.event();
@@ -362,6 +497,7 @@ fn process_diplomatic_orders(
                 t.at_war = true;
                 t.non_aggression_pact = false;
                 t.alliance = false;
+                t.trade_agreement = false;
             });
             state.adjust_score(order.actor, order.target, -40);
             ledger.cancel(order.actor, order.target);
@@ -419,6 +555,7 @@ fn process_diplomatic_orders(
                 order.target,
                 order.actor,
                 true,
+                turn.current,
             );
             queue_alliance_calls(
                 &mut state,
@@ -427,9 +564,10 @@ fn process_diplomatic_orders(
                 order.actor,
                 order.target,
                 false,
+                turn.current,
             );
         }
-        DiplomaticOrderKind::OfferPeace => {
+        DiplomaticOrderKind::OfferPeace { reparations } => {
             let at_war = state
                 .relation(order.actor, order.target)
                 .map(|r| r.treaty.at_war)
@@ -443,16 +581,26 @@ fn process_diplomatic_orders(
                 return;
             }
 
+            let reparations = (*reparations).max(0);
             offers.push(DiplomaticOffer::new(
                 order.actor,
                 order.target,
-                DiplomaticOfferKind::OfferPeace,
+                DiplomaticOfferKind::OfferPeace { reparations },
             ));
-            info!(
-                "{} offered peace to {}.",
-                display_name(&instance_to_name, order.actor),
-                display_name(&instance_to_name, order.target)
-            );
+            if reparations > 0 {
+                info!(
+                    "{} offered peace to {} in exchange for ${} in reparations.",
+                    display_name(&instance_to_name, order.actor),
+                    display_name(&instance_to_name, order.target),
+                    reparations
+                );
+            } else {
+                info!(
+                    "{} offered peace to {}.",
+                    display_name(&instance_to_name, order.actor),
+                    display_name(&instance_to_name, order.target)
+                );
+            }
         }
         DiplomaticOrderKind::EstablishConsulate => {
             if state
@@ -491,6 +639,12 @@ fn process_diplomatic_orders(
                         display_name(&instance_to_name, order.actor),
                         display_name(&instance_to_name, order.target)
                     );
+                    rejections.write(FinancialRejection::new(
+                        actor_entity,
+                        FinancialAction::Consulate,
+                        500,
+                        treasury.available(),
+                    ));
                     false
                 } else {
                     treasury.subtract(500);
@@ -550,6 +704,12 @@ fn process_diplomatic_orders(
                         display_name(&instance_to_name, order.actor),
                         display_name(&instance_to_name, order.target)
                     );
+                    rejections.write(FinancialRejection::new(
+                        actor_entity,
+                        FinancialAction::Embassy,
+                        5_000,
+                        treasury.available(),
+                    ));
                     false
                 } else {
                     treasury.subtract(5_000);
@@ -607,6 +767,43 @@ fn process_diplomatic_orders(
                 display_name(&instance_to_name, order.target)
             );
         }
+        DiplomaticOrderKind::ProposeTradeAgreement => {
+            let relation = state.relation(order.actor, order.target).cloned();
+            let Some(relation) = relation else { return };
+            if relation.treaty.at_war {
+                info!(
+                    "Cannot agree on trade while at war with {}.",
+                    display_name(&instance_to_name, order.target)
+                );
+                return;
+            }
+            if !relation.treaty.consulate {
+                info!(
+                    "A consulate in {} is required before a trade agreement.",
+                    display_name(&instance_to_name, order.target)
+                );
+                return;
+            }
+            if relation.treaty.trade_agreement {
+                info!(
+                    "{} already has a trade agreement with {}.",
+                    display_name(&instance_to_name, order.actor),
+                    display_name(&instance_to_name, order.target)
+                );
+                return;
+            }
+
+            offers.push(DiplomaticOffer::new(
+                order.actor,
+                order.target,
+                DiplomaticOfferKind::TradeAgreement,
+            ));
+            info!(
+                "{} proposed a trade agreement to {}.",
+                display_name(&instance_to_name, order.actor),
+                display_name(&instance_to_name, order.target)
+            );
+        }
         DiplomaticOrderKind::FormAlliance => {
             let relation = state.relation(order.actor, order.target).cloned();
             let Some(relation) = relation else { return };
@@ -678,6 +875,12 @@ fn process_diplomatic_orders(
                         amount,
                         display_name(&instance_to_name, order.target)
                     );
+                    rejections.write(FinancialRejection::new(
+                        actor_entity,
+                        FinancialAction::ForeignAid,
+                        amount,
+                        donor_treasury.available(),
+                    ));
                     false
                 } else {
                     donor_treasury.subtract(amount);
@@ -721,6 +924,86 @@ fn process_diplomatic_orders(
                 );
             }
         }
+        DiplomaticOrderKind::CedeProvince { province } => {
+            let owns_province = provinces
+                .iter()
+                .any(|p| p.id == *province && p.owner == Some(actor_entity));
+            if !owns_province {
+                info!(
+                    "{} cannot cede a province it does not own.",
+                    display_name(&instance_to_name, order.actor)
+                );
+                return;
+            }
+
+            offers.push(DiplomaticOffer::new(
+                order.actor,
+                order.target,
+                DiplomaticOfferKind::CedeProvince {
+                    province: *province,
+                },
+            ));
+            info!(
+                "{} offered to cede a province to {}.",
+                display_name(&instance_to_name, order.actor),
+                display_name(&instance_to_name, order.target)
+            );
+        }
+        DiplomaticOrderKind::ProposeGoodSwap { give, receive } => {
+            if give.1 == 0 || receive.1 == 0 || give.0 == receive.0 {
+                info!("A swap must exchange two different goods in positive amounts.");
+                return;
+            }
+            if !state.has_trade_agreement(order.actor, order.target) {
+                info!(
+                    "A trade agreement with {} is required before bartering.",
+                    display_name(&instance_to_name, order.target)
+                );
+                return;
+            }
+
+            offers.push(DiplomaticOffer::new(
+                order.actor,
+                order.target,
+                DiplomaticOfferKind::GoodSwap {
+                    give: *give,
+                    receive: *receive,
+                },
+            ));
+            info!(
+                "{} offered {} {} to {} for {} {}.",
+                display_name(&instance_to_name, order.actor),
+                give.1,
+                give.0,
+                display_name(&instance_to_name, order.target),
+                receive.1,
+                receive.0
+            );
+        }
+        DiplomaticOrderKind::ShareResearch { points } => {
+            if *points == 0 {
+                return;
+            }
+            if !state.are_allied(order.actor, order.target) {
+                info!(
+                    "An alliance with {} is required to share research.",
+                    display_name(&instance_to_name, order.target)
+                );
+                return;
+            }
+
+            offers.push(DiplomaticOffer::new(
+                order.actor,
+                order.target,
+                DiplomaticOfferKind::ShareResearch { points: *points },
+            ));
+            info!(
+                "{} offered {} research points to {}.",
+                display_name(&instance_to_name, order.actor),
+                points,
+                display_name(&instance_to_name, order.target)
+            );
+        }
     }
 }
 
@@ -731,6 +1014,7 @@ pub fn resolve_offer_response(
     ledger: &mut ForeignAidLedger,
     nations: &Query<(NationInstance, &Name)>,
     treasuries: &mut Query<&mut Treasury>,
+    commands: &mut Commands,
 ) {
     let (instance_to_name, _) = collect_nation_lookup(nations);
 
@@ -739,17 +1023,43 @@ pub fn resolve_offer_response(
 
     if accept {
         match offer.kind {
-            DiplomaticOfferKind::OfferPeace => {
+            DiplomaticOfferKind::OfferPeace { reparations } => {
                 state.set_treaty(offer.from, offer.to, |t| {
                     t.at_war = false;
                     t.non_aggression_pact = false;
                 });
+                state.clear_alliance_calls(offer.from, offer.to);
                 state.adjust_score(offer.from, offer.to, 15);
-                info!(
-                    "{} accepted peace with {}.",
-                    display_name(&instance_to_name, offer.to),
-                    display_name(&instance_to_name, offer.from)
-                );
+
+                // The loser pays what it can; peace holds even if the treasury falls short
+                let paid = match treasuries.get_mut(to_entity) {
+                    Ok(mut loser) => {
+                        let paid = reparations.min(loser.available()).max(0);
+                        loser.subtract(paid);
+                        paid
+                    }
+                    Err(_) => 0,
+                };
+                if paid > 0
+                    && let Ok(mut winner) = treasuries.get_mut(from_entity)
+                {
+                    winner.add(paid);
+                }
+
+                if paid > 0 {
+                    info!(
+                        "{} accepted peace with {} and paid ${} in reparations.",
+                        display_name(&instance_to_name, offer.to),
+                        display_name(&instance_to_name, offer.from),
+                        paid
+                    );
+                } else {
+                    info!(
+                        "{} accepted peace with {}.",
+                        display_name(&instance_to_name, offer.to),
+                        display_name(&instance_to_name, offer.from)
+                    );
+                }
             }
             DiplomaticOfferKind::Alliance => {
                 state.set_treaty(offer.from, offer.to, |t| {
@@ -815,6 +1125,7 @@ pub fn resolve_offer_response(
                     t.at_war = true;
                     t.non_aggression_pact = false;
                     t.alliance = false;
+                    t.trade_agreement = false;
                 });
                 state.adjust_score(offer.to, enemy, -40);
                 ledger.cancel(offer.to, enemy);
@@ -832,10 +1143,44 @@ pub fn resolve_offer_response(
                     }
                 );
             }
+            DiplomaticOfferKind::TradeAgreement => {
+                state.set_treaty(offer.from, offer.to, |t| {
+                    t.trade_agreement = true;
+                });
+                state.adjust_score(offer.from, offer.to, 5);
+                info!(
+                    "{} signed a trade agreement with {}.",
+                    display_name(&instance_to_name, offer.to),
+                    display_name(&instance_to_name, offer.from)
+                );
+            }
+            DiplomaticOfferKind::CedeProvince { province } => {
+                state.adjust_score(offer.from, offer.to, 10);
+                commands.trigger(ProvinceCession {
+                    province,
+                    from: offer.from,
+                    to: offer.to,
+                });
+            }
+            DiplomaticOfferKind::GoodSwap { give, receive } => {
+                commands.trigger(GoodSwapSettlement {
+                    from: offer.from,
+                    to: offer.to,
+                    give,
+                    receive,
+                });
+            }
+            DiplomaticOfferKind::ShareResearch { points } => {
+                commands.trigger(ResearchGift {
+                    from: offer.from,
+                    to: offer.to,
+                    points,
+                });
+            }
         }
     } else {
         match offer.kind {
-            DiplomaticOfferKind::OfferPeace => {
+            DiplomaticOfferKind::OfferPeace { .. } => {
                 state.adjust_score(offer.from, offer.to, -10);
                 info!(
                     "{} refused peace with {}.",
@@ -890,10 +1235,143 @@ pub fn resolve_offer_response(
                     );
                 }
             }
+            DiplomaticOfferKind::TradeAgreement => {
+                state.adjust_score(offer.from, offer.to, -3);
+                info!(
+                    "{} declined a trade agreement with {}.",
+                    display_name(&instance_to_name, offer.to),
+                    display_name(&instance_to_name, offer.from)
+                );
+            }
+            DiplomaticOfferKind::CedeProvince { .. } => {
+                info!(
+                    "{} declined a province offered by {}.",
+                    display_name(&instance_to_name, offer.to),
+                    display_name(&instance_to_name, offer.from)
+                );
+            }
+            DiplomaticOfferKind::GoodSwap { .. } => {
+                info!(
+                    "{} declined a swap offered by {}.",
+                    display_name(&instance_to_name, offer.to),
+                    display_name(&instance_to_name, offer.from)
+                );
+            }
+            DiplomaticOfferKind::ShareResearch { .. } => {
+                info!(
+                    "{} declined research offered by {}.",
+                    display_name(&instance_to_name, offer.to),
+                    display_name(&instance_to_name, offer.from)
+                );
+            }
         }
     }
 }
 
+/// Hand a province over to its new owner once a cession is accepted.
+/// Depots and ports inside it change hands too, so rail connectivity is recomputed
+/// against the new owner's network.
+pub fn apply_province_cession(
+    trigger: On<ProvinceCession>,
+    mut commands: Commands,
+    mut provinces: Query<&mut Province>,
+    capitals: Query<&Capital>,
+    mut depots: Query<&mut Depot>,
+    mut ports: Query<&mut Port>,
+) {
+    let cession = trigger.event();
+    let from = cession.from.entity();
+    let to = cession.to.entity();
+
+    let Some(mut province) = provinces.iter_mut().find(|p| p.id == cession.province) else {
+        return;
+    };
+    // Ownership may have changed since the offer was made
+    if province.owner != Some(from) {
+        info!("Cession cancelled: province is no longer owned by the ceding nation.");
+        return;
+    }
+    if capitals
+        .get(from)
+        .is_ok_and(|capital| province.tiles.contains(&capital.0))
+    {
+        info!("Cession cancelled: a nation cannot cede its capital province.");
+        return;
+    }
+
+    province.owner = Some(to);
+
+    for mut depot in depots.iter_mut() {
+        if depot.owner == from && province.tiles.contains(&depot.position) {
+            depot.owner = to;
+        }
+    }
+    for mut port in ports.iter_mut() {
+        if port.owner == from && province.tiles.contains(&port.position) {
+            port.owner = to;
+        }
+    }
+
+    info!("Province {} changed hands.", cession.province.0);
+    commands.trigger(RecomputeConnectivity);
+}
+
+/// Move bartered goods between the two stockpiles once a swap is accepted.
+/// Either side may have spent the goods since the offer was made, in which
+/// case nothing changes hands and no goodwill is earned.
+pub fn apply_good_swap(
+    trigger: On<GoodSwapSettlement>,
+    mut state: ResMut<DiplomacyState>,
+    mut stockpiles: Query<&mut Stockpile>,
+) {
+    let swap = trigger.event();
+    let Ok([mut from, mut to]) =
+        stockpiles.get_disjoint_mut([swap.from.entity(), swap.to.entity()])
+    else {
+        return;
+    };
+
+    let (give_good, give_qty) = swap.give;
+    let (receive_good, receive_qty) = swap.receive;
+    if !from.has_available(give_good, give_qty) || !to.has_available(receive_good, receive_qty) {
+        info!("Swap cancelled: one side no longer has the goods.");
+        return;
+    }
+
+    from.take_up_to(give_good, give_qty);
+    to.add(give_good, give_qty);
+    to.take_up_to(receive_good, receive_qty);
+    from.add(receive_good, receive_qty);
+    state.adjust_score(swap.from, swap.to, 2);
+}
+
+/// Move gifted research points from donor to recipient once a gift is accepted.
+/// The gift lapses if the alliance ended or the donor spent the points meanwhile;
+/// only a delivered gift earns goodwill.
+pub fn apply_research_gift(
+    trigger: On<ResearchGift>,
+    mut state: ResMut<DiplomacyState>,
+    mut progress: Query<&mut ResearchProgress>,
+) {
+    let gift = trigger.event();
+    if !state.are_allied(gift.from, gift.to) {
+        info!("Research gift cancelled: the nations are no longer allied.");
+        return;
+    }
+    let Ok([mut donor, mut recipient]) =
+        progress.get_disjoint_mut([gift.from.entity(), gift.to.entity()])
+    else {
+        return;
+    };
+
+    if !donor.spend(gift.points) {
+        info!("Research gift cancelled: the donor no longer has the points.");
+        return;
+    }
+    recipient.add(gift.points);
+    state.adjust_score(gift.from, gift.to, 3);
+}
+
 fn queue_alliance_calls(
     state: &mut DiplomacyState,
     offers: &mut ResMut<DiplomaticOffers>,
@@ -901,6 +1379,7 @@ fn queue_alliance_calls(
     belligerent: NationInstance,
     enemy: NationInstance,
     defensive: bool,
+    turn: u32,
 ) {
     let allies: Vec<NationInstance> = state
         .relations_for(belligerent)
@@ -912,6 +1391,7 @@ fn queue_alliance_calls(
                     .relation(ally, enemy)
                     .map(|r| r.treaty.at_war)
                     .unwrap_or(false)
+                && !state.alliance_call_on_cooldown(belligerent, ally, enemy, turn)
             {
                 Some(ally)
             } else {
@@ -921,6 +1401,7 @@ fn queue_alliance_calls(
         .collect();
 
     for ally in allies {
+        state.record_alliance_call(belligerent, ally, enemy, turn);
         offers.push(DiplomaticOffer::new(
             belligerent,
             ally,
@@ -1004,6 +1485,104 @@ fn decay_relationships(mut state: ResMut<DiplomacyState>) {
     }
 }
 
+/// Units traded between two nations in one turn that earn one relation point
+pub const TRADE_GOODWILL_UNITS_PER_POINT: u32 = 5;
+/// Most relation points a single pair can gain from trade in one turn
+pub const MAX_TRADE_GOODWILL_PER_TURN: i32 = 3;
+
+/// Nations that traded heavily in this turn's market warm to each other
+fn apply_trade_goodwill(
+    ledger: Res<TradeLedger>,
+    mut state: ResMut<DiplomacyState>,
+    nations: Query<NationInstance>,
+) {
+    for ((a, b), units) in ledger.pairs() {
+        let points = (units / TRADE_GOODWILL_UNITS_PER_POINT) as i32;
+        if points == 0 {
+            continue;
+        }
+        let (Ok(a), Ok(b)) = (nations.get(a), nations.get(b)) else {
+            continue;
+        };
+        state.adjust_score(a, b, points.min(MAX_TRADE_GOODWILL_PER_TURN));
+    }
+}
+
+/// Largest share of the loser's treasury a victorious AI asks for when offering peace
+pub const MAX_REPARATIONS_SHARE: f32 = 0.5;
+
+/// Reparations an AI demands in a peace offer. `war_success` runs from 0.0 (stalemate or
+/// losing) to 1.0 (decisive victory) and scales the share of the loser's treasury asked for.
+pub fn demanded_reparations(war_success: f32, loser_treasury: i64) -> i64 {
+    let share = war_success.clamp(0.0, 1.0) * MAX_REPARATIONS_SHARE;
+    (loser_treasury.max(0) as f32 * share).round() as i64
+}
+
+/// How decisively `winner` is doing against `loser`, from 0.0 (even or behind) to 1.0,
+/// judged by how many more provinces it holds.
+pub fn war_success(winner: Entity, loser: Entity, provinces: &Query<&Province>) -> f32 {
+    let held = |nation: Entity| {
+        provinces
+            .iter()
+            .filter(|province| province.owner == Some(nation))
+            .count() as f32
+    };
+    let (own, enemy) = (held(winner), held(loser));
+    if own == 0.0 {
+        return 0.0;
+    }
+    ((own - enemy) / own).clamp(0.0, 1.0)
+}
+
+/// Reparations `winner` asks of `loser` for peace, given the current war situation
+pub fn peace_reparations(
+    winner: NationInstance,
+    loser: NationInstance,
+    provinces: &Query<&Province>,
+    treasuries: &Query<&Treasury>,
+) -> i64 {
+    let loser_treasury = treasuries
+        .get(loser.entity())
+        .map(|treasury| treasury.available())
+        .unwrap_or(0);
+    demanded_reparations(
+        war_success(winner.entity(), loser.entity(), provinces),
+        loser_treasury,
+    )
+}
+
+/// Each AI nation at war offers peace to its enemies, demanding reparations when it is
+/// ahead. An offer still waiting for an answer is not repeated.
+fn offer_ai_peace(
+    mut commands: Commands,
+    state: Res<DiplomacyState>,
+    offers: Res<DiplomaticOffers>,
+    ai_nations: Query<NationInstance, With<AiNation>>,
+    provinces: Query<&Province>,
+    treasuries: Query<&Treasury>,
+) {
+    for nation in ai_nations.iter() {
+        for (enemy, relation) in state.relations_for(nation) {
+            if !relation.treaty.at_war {
+                continue;
+            }
+            let already_offered = offers.iter_for(enemy).any(|offer| {
+                offer.from == nation && matches!(offer.kind, DiplomaticOfferKind::OfferPeace { .. })
+            });
+            if already_offered {
+                continue;
+            }
+            commands.trigger(DiplomaticOrder {
+                actor: nation,
+                target: enemy,
+                kind: DiplomaticOrderKind::OfferPeace {
+                    reparations: peace_reparations(nation, enemy, &provinces, &treasuries),
+                },
+            });
+        }
+    }
+}
+
 fn war_reaction_delta(opinion_of_target: i32) -> i32 {
     match opinion_of_target {
         ..=-60 => 12,
@@ -0,0 +1,288 @@
+//! Save-game snapshot of the diplomacy resources.
+//!
+//! Entity ids change between sessions, so every nation reference is stored as its
+//! [`NationId`] and resolved back to the reloaded entities after a load.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::diplomacy::{
+    DiplomacyPair, DiplomacyState, DiplomaticOffer, DiplomaticOfferKind, DiplomaticOffers,
+    DiplomaticRelation, ForeignAidLedger, OfferId, RecurringGrant, TreatyState,
+};
+use crate::economy::nation::NationId;
+use crate::economy::{Good, NationInstance};
+use crate::map::province::ProvinceId;
+
+/// Reflectable copy of `DiplomacyState`, `ForeignAidLedger` and `DiplomaticOffers`
+#[derive(Resource, Reflect, Default, Clone, Debug)]
+#[reflect(Resource)]
+pub struct SavedDiplomacy {
+    pub relations: Vec<SavedRelation>,
+    pub alliance_calls: Vec<SavedAllianceCall>,
+    pub grants: Vec<SavedGrant>,
+    pub offers: Vec<SavedOffer>,
+    pub next_offer_id: u32,
+}
+
+#[derive(Reflect, Clone, Debug)]
+pub struct SavedRelation {
+    pub a: NationId,
+    pub b: NationId,
+    pub score: i32,
+    pub treaty: TreatyState,
+}
+
+#[derive(Reflect, Clone, Debug)]
+pub struct SavedAllianceCall {
+    pub caller: NationId,
+    pub ally: NationId,
+    pub enemy: NationId,
+    pub turn: u32,
+}
+
+#[derive(Reflect, Clone, Debug)]
+pub struct SavedGrant {
+    pub from: NationId,
+    pub to: NationId,
+    pub amount: i32,
+}
+
+#[derive(Reflect, Clone, Debug)]
+pub struct SavedOffer {
+    pub id: u32,
+    pub from: NationId,
+    pub to: NationId,
+    pub kind: SavedOfferKind,
+}
+
+/// `DiplomaticOfferKind` with nation references replaced by ids
+#[derive(Reflect, Clone, Debug)]
+pub enum SavedOfferKind {
+    OfferPeace {
+        reparations: i64,
+    },
+    Alliance,
+    NonAggressionPact,
+    ForeignAid {
+        amount: i32,
+        locked: bool,
+    },
+    JoinWar {
+        enemy: NationId,
+        defensive: bool,
+    },
+    TradeAgreement,
+    CedeProvince {
+        province: ProvinceId,
+    },
+    GoodSwap {
+        give: (Good, u32),
+        receive: (Good, u32),
+    },
+    ShareResearch {
+        points: u32,
+    },
+}
+
+impl SavedOfferKind {
+    fn capture(
+        kind: &DiplomaticOfferKind,
+        ids: &HashMap<NationInstance, NationId>,
+    ) -> Option<Self> {
+        Some(match kind {
+            DiplomaticOfferKind::OfferPeace { reparations } => Self::OfferPeace {
+                reparations: *reparations,
+            },
+            DiplomaticOfferKind::Alliance => Self::Alliance,
+            DiplomaticOfferKind::NonAggressionPact => Self::NonAggressionPact,
+            DiplomaticOfferKind::ForeignAid { amount, locked } => Self::ForeignAid {
+                amount: *amount,
+                locked: *locked,
+            },
+            DiplomaticOfferKind::JoinWar { enemy, defensive } => Self::JoinWar {
+                enemy: *ids.get(enemy)?,
+                defensive: *defensive,
+            },
+            DiplomaticOfferKind::TradeAgreement => Self::TradeAgreement,
+            DiplomaticOfferKind::CedeProvince { province } => Self::CedeProvince {
+                province: *province,
+            },
+            DiplomaticOfferKind::GoodSwap { give, receive } => Self::GoodSwap {
+                give: *give,
+                receive: *receive,
+            },
+            DiplomaticOfferKind::ShareResearch { points } => {
+                Self::ShareResearch { points: *points }
+            }
+        })
+    }
+
+    fn restore(&self, nations: &HashMap<NationId, NationInstance>) -> Option<DiplomaticOfferKind> {
+        Some(match self {
+            Self::OfferPeace { reparations } => DiplomaticOfferKind::OfferPeace {
+                reparations: *reparations,
+            },
+            Self::Alliance => DiplomaticOfferKind::Alliance,
+            Self::NonAggressionPact => DiplomaticOfferKind::NonAggressionPact,
+            Self::ForeignAid { amount, locked } => DiplomaticOfferKind::ForeignAid {
+                amount: *amount,
+                locked: *locked,
+            },
+            Self::JoinWar { enemy, defensive } => DiplomaticOfferKind::JoinWar {
+                enemy: *nations.get(enemy)?,
+                defensive: *defensive,
+            },
+            Self::TradeAgreement => DiplomaticOfferKind::TradeAgreement,
+            Self::CedeProvince { province } => DiplomaticOfferKind::CedeProvince {
+                province: *province,
+            },
+            Self::GoodSwap { give, receive } => DiplomaticOfferKind::GoodSwap {
+                give: *give,
+                receive: *receive,
+            },
+            Self::ShareResearch { points } => {
+                DiplomaticOfferKind::ShareResearch { points: *points }
+            }
+        })
+    }
+}
+
+impl SavedDiplomacy {
+    /// Snapshot the live resources. Entries naming a nation without a `NationId` are dropped.
+    pub fn capture(
+        state: &DiplomacyState,
+        ledger: &ForeignAidLedger,
+        offers: &DiplomaticOffers,
+        ids: &HashMap<NationInstance, NationId>,
+    ) -> Self {
+        let relations = state
+            .relations
+            .iter()
+            .filter_map(|(pair, relation)| {
+                Some(SavedRelation {
+                    a: *ids.get(&pair.0)?,
+                    b: *ids.get(&pair.1)?,
+                    score: relation.score,
+                    treaty: relation.treaty.clone(),
+                })
+            })
+            .collect();
+
+        let mut alliance_calls: Vec<SavedAllianceCall> = state
+            .alliance_calls
+            .iter()
+            .filter_map(|(&(caller, ally, enemy), &turn)| {
+                Some(SavedAllianceCall {
+                    caller: *ids.get(&caller)?,
+                    ally: *ids.get(&ally)?,
+                    enemy: *ids.get(&enemy)?,
+                    turn,
+                })
+            })
+            .collect();
+        // HashMap order is arbitrary; keep save files stable
+        alliance_calls.sort_by_key(|call| (call.caller.0, call.ally.0, call.enemy.0));
+
+        let grants = ledger
+            .recurring
+            .iter()
+            .filter_map(|grant| {
+                Some(SavedGrant {
+                    from: *ids.get(&grant.from)?,
+                    to: *ids.get(&grant.to)?,
+                    amount: grant.amount,
+                })
+            })
+            .collect();
+
+        let saved_offers = offers
+            .pending
+            .iter()
+            .filter_map(|offer| {
+                Some(SavedOffer {
+                    id: offer.id.raw(),
+                    from: *ids.get(&offer.from)?,
+                    to: *ids.get(&offer.to)?,
+                    kind: SavedOfferKind::capture(&offer.kind, ids)?,
+                })
+            })
+            .collect();
+
+        Self {
+            relations,
+            alliance_calls,
+            grants,
+            offers: saved_offers,
+            next_offer_id: offers.next_id,
+        }
+    }
+
+    /// Rebuild the live resources against the reloaded nation entities
+    pub fn restore(
+        &self,
+        nations: &HashMap<NationId, NationInstance>,
+    ) -> (DiplomacyState, ForeignAidLedger, DiplomaticOffers) {
+        let mut state = DiplomacyState::default();
+        for saved in &self.relations {
+            let (Some(&a), Some(&b)) = (nations.get(&saved.a), nations.get(&saved.b)) else {
+                continue;
+            };
+            state.relations.insert(
+                DiplomacyPair::new(a, b),
+                DiplomaticRelation {
+                    score: saved.score,
+                    treaty: saved.treaty.clone(),
+                },
+            );
+        }
+        for call in &self.alliance_calls {
+            let (Some(&caller), Some(&ally), Some(&enemy)) = (
+                nations.get(&call.caller),
+                nations.get(&call.ally),
+                nations.get(&call.enemy),
+            ) else {
+                continue;
+            };
+            state
+                .alliance_calls
+                .insert((caller, ally, enemy), call.turn);
+        }
+
+        let mut ledger = ForeignAidLedger::default();
+        for grant in &self.grants {
+            let (Some(&from), Some(&to)) = (nations.get(&grant.from), nations.get(&grant.to))
+            else {
+                continue;
+            };
+            ledger.upsert(RecurringGrant {
+                from,
+                to,
+                amount: grant.amount,
+            });
+        }
+
+        let mut offers = DiplomaticOffers {
+            next_id: self.next_offer_id,
+            pending: Vec::new(),
+        };
+        for saved in &self.offers {
+            let (Some(&from), Some(&to), Some(kind)) = (
+                nations.get(&saved.from),
+                nations.get(&saved.to),
+                saved.kind.restore(nations),
+            ) else {
+                continue;
+            };
+            offers.pending.push(DiplomaticOffer {
+                id: OfferId(saved.id),
+                from,
+                to,
+                kind,
+            });
+        }
+
+        (state, ledger, offers)
+    }
+}
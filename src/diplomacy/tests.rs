@@ -1,13 +1,31 @@
 use bevy::ecs::system::RunSystemOnce;
 use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::TilePos;
 use moonshine_kind::Instance;
+use std::collections::HashMap;
 
+use crate::ai::AiNation;
+use crate::diplomacy::intel::update_intel_reports;
 use crate::diplomacy::{
-    DiplomacyState, DiplomaticOffer, DiplomaticOfferKind, DiplomaticOffers, DiplomaticOrder,
-    DiplomaticOrderKind, ForeignAidLedger, apply_recurring_aid, decay_relationships,
-    process_diplomatic_orders, resolve_offer_response, sync_diplomatic_pairs,
+    ALLIANCE_CALL_COOLDOWN_TURNS, DiplomacyState, DiplomaticOffer, DiplomaticOfferKind,
+    DiplomaticOffers, DiplomaticOrder, DiplomaticOrderKind, ForeignAidLedger, IntelReports,
+    IntelSource, MAX_TRADE_GOODWILL_PER_TURN, apply_good_swap, apply_province_cession,
+    apply_recurring_aid, apply_research_gift, apply_trade_goodwill, decay_relationships,
+    demanded_reparations, offer_ai_peace, process_diplomatic_orders, queue_alliance_calls,
+    resolve_offer_response, sync_diplomatic_pairs, war_success,
 };
-use crate::economy::{Nation, NationInstance, Treasury};
+use crate::economy::market::{delivered_price, partner_delivered_price};
+use crate::economy::nation::Capital;
+use crate::economy::production::{BuildingKind, ProductionReport, ProductionResult};
+use crate::economy::trade::TradeLedger;
+use crate::economy::trade_capacity::TradeCapacitySnapshot;
+use crate::economy::transport::{
+    Depot, RailConnectivityCache, Rails, RecomputeConnectivity, compute_rail_connectivity,
+    ordered_edge,
+};
+use crate::economy::{FinancialAction, FinancialRejection, Nation, NationInstance, Treasury};
+use crate::economy::{Good, ResearchProgress, Stockpile};
+use crate::map::province::{Province, ProvinceId};
 use crate::turn_system::TurnCounter;
 
 fn setup_world() -> World {
@@ -17,6 +35,7 @@ fn setup_world() -> World {
     world.insert_resource(DiplomacyState::default());
     world.insert_resource(ForeignAidLedger::default());
     world.insert_resource(DiplomaticOffers::default());
+    world.init_resource::<Messages<FinancialRejection>>();
     world
 }
 
@@ -207,6 +226,58 @@ fn embassy_requires_consulate_and_relations() {
     );
 }
 
+#[test]
+fn unaffordable_embassy_reports_the_shortfall() {
+    let mut world = setup_world();
+
+    let empire = world
+        .spawn((Nation, Name::new("Empire"), Treasury::new(3_200)))
+        .id();
+    let neighbor = world
+        .spawn((Nation, Name::new("Neighbor"), Treasury::new(0)))
+        .id();
+
+    let empire_inst = nation_instance(&world, empire);
+    let neighbor_inst = nation_instance(&world, neighbor);
+
+    let _ = world.run_system_once(sync_diplomatic_pairs);
+    world
+        .resource_mut::<DiplomacyState>()
+        .set_treaty(empire_inst, neighbor_inst, |t| t.consulate = true);
+    world
+        .resource_mut::<DiplomacyState>()
+        .adjust_score(empire_inst, neighbor_inst, 35);
+
+    world.trigger(DiplomaticOrder {
+        actor: empire_inst,
+        target: neighbor_inst,
+        kind: DiplomaticOrderKind::OpenEmbassy,
+    });
+
+    let rejections: Vec<FinancialRejection> = world
+        .resource_mut::<Messages<FinancialRejection>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        rejections,
+        vec![FinancialRejection {
+            nation: empire,
+            action: FinancialAction::Embassy,
+            cost: 5_000,
+            shortfall: 1_800,
+        }]
+    );
+    assert_eq!(world.get::<Treasury>(empire).unwrap().total(), 3_200);
+    assert!(
+        !world
+            .resource::<DiplomacyState>()
+            .relation(empire_inst, neighbor_inst)
+            .unwrap()
+            .treaty
+            .embassy
+    );
+}
+
 #[test]
 fn declare_war_shifts_world_opinion() {
     let mut world = setup_world();
@@ -296,7 +367,7 @@ fn offer_peace_creates_pending_offer() {
     world.trigger(DiplomaticOrder {
         actor: player_inst,
         target: foe_inst,
-        kind: DiplomaticOrderKind::OfferPeace,
+        kind: DiplomaticOrderKind::OfferPeace { reparations: 0 },
     });
 
     let relation = world
@@ -360,13 +431,18 @@ fn accepting_peace_offer_sets_peace() {
             t.at_war = true;
         });
 
-    let offer = DiplomaticOffer::new(foe_inst, player_inst, DiplomaticOfferKind::OfferPeace);
+    let offer = DiplomaticOffer::new(
+        foe_inst,
+        player_inst,
+        DiplomaticOfferKind::OfferPeace { reparations: 0 },
+    );
 
     let _ = world.run_system_once(
         move |mut state: ResMut<DiplomacyState>,
               mut ledger: ResMut<ForeignAidLedger>,
               nations: Query<(NationInstance, &Name)>,
-              mut treasuries: Query<&mut Treasury>| {
+              mut treasuries: Query<&mut Treasury>,
+              mut commands: Commands| {
             resolve_offer_response(
                 offer.clone(),
                 true,
@@ -374,6 +450,7 @@ fn accepting_peace_offer_sets_peace() {
                 &mut ledger,
                 &nations,
                 &mut treasuries,
+                &mut commands,
             );
         },
     );
@@ -386,6 +463,201 @@ fn accepting_peace_offer_sets_peace() {
     assert!(relation.score >= 10);
 }
 
+#[test]
+fn accepting_peace_with_reparations_pays_the_winner() {
+    let mut world = setup_world();
+
+    let winner = world
+        .spawn((Nation, Name::new("Winner"), Treasury::new(1_000)))
+        .id();
+    let loser = world
+        .spawn((Nation, Name::new("Loser"), Treasury::new(2_000)))
+        .id();
+
+    let winner_inst = nation_instance(&world, winner);
+    let loser_inst = nation_instance(&world, loser);
+
+    world
+        .resource_mut::<DiplomacyState>()
+        .set_treaty(winner_inst, loser_inst, |t| {
+            t.at_war = true;
+        });
+
+    // A decisive victory asks for half of the loser's treasury
+    let reparations = demanded_reparations(1.0, 2_000);
+    assert_eq!(reparations, 1_000);
+    assert_eq!(demanded_reparations(0.0, 2_000), 0);
+
+    world.trigger(DiplomaticOrder {
+        actor: winner_inst,
+        target: loser_inst,
+        kind: DiplomaticOrderKind::OfferPeace { reparations },
+    });
+    let offer = world
+        .resource::<DiplomaticOffers>()
+        .iter_for(loser_inst)
+        .next()
+        .cloned()
+        .expect("peace offer queued");
+    assert!(matches!(
+        offer.kind,
+        DiplomaticOfferKind::OfferPeace { reparations: 1_000 }
+    ));
+
+    let _ = world.run_system_once(
+        move |mut state: ResMut<DiplomacyState>,
+              mut ledger: ResMut<ForeignAidLedger>,
+              nations: Query<(NationInstance, &Name)>,
+              mut treasuries: Query<&mut Treasury>,
+              mut commands: Commands| {
+            resolve_offer_response(
+                offer.clone(),
+                true,
+                &mut state,
+                &mut ledger,
+                &nations,
+                &mut treasuries,
+                &mut commands,
+            );
+        },
+    );
+
+    let relation = world
+        .resource::<DiplomacyState>()
+        .relation(winner_inst, loser_inst)
+        .unwrap();
+    assert!(!relation.treaty.at_war);
+    assert_eq!(world.get::<Treasury>(winner).unwrap().total(), 2_000);
+    assert_eq!(world.get::<Treasury>(loser).unwrap().total(), 1_000);
+}
+
+#[test]
+fn ai_at_war_offers_peace_with_reparations_scaled_by_provinces() {
+    let mut world = setup_world();
+
+    let ai = world
+        .spawn((Nation, AiNation, Name::new("Ai"), Treasury::new(1_000)))
+        .id();
+    let foe = world
+        .spawn((Nation, Name::new("Foe"), Treasury::new(2_000)))
+        .id();
+    let ai_inst = nation_instance(&world, ai);
+    let foe_inst = nation_instance(&world, foe);
+
+    // Holding four provinces to the foe's one is a 75% war success
+    for (id, owner) in [(0, ai), (1, ai), (2, ai), (3, ai), (4, foe)] {
+        let mut province = Province::new(ProvinceId(id), vec![], TilePos::new(id, 0));
+        province.owner = Some(owner);
+        world.spawn(province);
+    }
+    world
+        .resource_mut::<DiplomacyState>()
+        .set_treaty(ai_inst, foe_inst, |t| {
+            t.at_war = true;
+        });
+
+    let _ = world.run_system_once(offer_ai_peace);
+    let offer = world
+        .resource::<DiplomaticOffers>()
+        .iter_for(foe_inst)
+        .next()
+        .cloned()
+        .expect("AI offered peace");
+    assert_eq!(offer.from, ai_inst);
+    assert!(matches!(
+        offer.kind,
+        DiplomaticOfferKind::OfferPeace { reparations: 750 }
+    ));
+
+    // The pending offer is not repeated, and the losing side has nothing to demand
+    let _ = world.run_system_once(offer_ai_peace);
+    assert_eq!(world.resource::<DiplomaticOffers>().len(), 1);
+    let _ = world.run_system_once(|provinces: Query<&Province>| {
+        assert_eq!(war_success(foe, ai, &provinces), 0.0);
+    });
+}
+
+#[test]
+fn declined_alliance_call_is_not_reissued_next_turn() {
+    let mut world = setup_world();
+
+    let attacker = world
+        .spawn((Nation, Name::new("Attacker"), Treasury::new(1_000)))
+        .id();
+    let victim = world
+        .spawn((Nation, Name::new("Victim"), Treasury::new(1_000)))
+        .id();
+    let ally = world
+        .spawn((Nation, Name::new("Ally"), Treasury::new(1_000)))
+        .id();
+
+    let attacker_inst = nation_instance(&world, attacker);
+    let victim_inst = nation_instance(&world, victim);
+    let ally_inst = nation_instance(&world, ally);
+
+    let _ = world.run_system_once(sync_diplomatic_pairs);
+    world
+        .resource_mut::<DiplomacyState>()
+        .set_treaty(attacker_inst, ally_inst, |t| {
+            t.alliance = true;
+            t.embassy = true;
+        });
+
+    world.trigger(DiplomaticOrder {
+        actor: attacker_inst,
+        target: victim_inst,
+        kind: DiplomaticOrderKind::DeclareWar,
+    });
+    let call = world
+        .resource::<DiplomaticOffers>()
+        .iter_for(ally_inst)
+        .next()
+        .map(|offer| offer.id)
+        .expect("ally called to arms");
+    let call = world.resource_mut::<DiplomaticOffers>().take(call).unwrap();
+
+    let _ = world.run_system_once(
+        move |mut state: ResMut<DiplomacyState>,
+              mut ledger: ResMut<ForeignAidLedger>,
+              nations: Query<(NationInstance, &Name)>,
+              mut treasuries: Query<&mut Treasury>,
+              mut commands: Commands| {
+            resolve_offer_response(
+                call.clone(),
+                false,
+                &mut state,
+                &mut ledger,
+                &nations,
+                &mut treasuries,
+                &mut commands,
+            );
+        },
+    );
+
+    // The attacker tries to rally its allies again on each of the following turns
+    let call_again = move |turn: u32| {
+        move |mut state: ResMut<DiplomacyState>, mut offers: ResMut<DiplomaticOffers>| {
+            queue_alliance_calls(
+                &mut state,
+                &mut offers,
+                &HashMap::new(),
+                attacker_inst,
+                victim_inst,
+                false,
+                turn,
+            );
+        }
+    };
+
+    world.resource_mut::<TurnCounter>().current += 1;
+    let next_turn = world.resource::<TurnCounter>().current;
+    let _ = world.run_system_once(call_again(next_turn));
+    assert!(world.resource::<DiplomaticOffers>().is_empty());
+
+    let _ = world.run_system_once(call_again(next_turn + ALLIANCE_CALL_COOLDOWN_TURNS));
+    assert_eq!(world.resource::<DiplomaticOffers>().len(), 1);
+}
+
 #[test]
 fn declare_war_triggers_alliance_calls() {
     let mut world = setup_world();
@@ -486,7 +758,8 @@ fn accepting_locked_aid_creates_grant() {
         move |mut state: ResMut<DiplomacyState>,
               mut ledger: ResMut<ForeignAidLedger>,
               nations: Query<(NationInstance, &Name)>,
-              mut treasuries: Query<&mut Treasury>| {
+              mut treasuries: Query<&mut Treasury>,
+              mut commands: Commands| {
             resolve_offer_response(
                 offer.clone(),
                 true,
@@ -494,6 +767,7 @@ fn accepting_locked_aid_creates_grant() {
                 &mut ledger,
                 &nations,
                 &mut treasuries,
+                &mut commands,
             );
         },
     );
@@ -548,7 +822,8 @@ fn accepting_defensive_join_war_sets_war() {
         move |mut state: ResMut<DiplomacyState>,
               mut ledger: ResMut<ForeignAidLedger>,
               nations: Query<(NationInstance, &Name)>,
-              mut treasuries: Query<&mut Treasury>| {
+              mut treasuries: Query<&mut Treasury>,
+              mut commands: Commands| {
             resolve_offer_response(
                 offer.clone(),
                 true,
@@ -556,6 +831,7 @@ fn accepting_defensive_join_war_sets_war() {
                 &mut ledger,
                 &nations,
                 &mut treasuries,
+                &mut commands,
             );
         },
     );
@@ -614,7 +890,8 @@ fn declining_defensive_join_war_penalizes() {
         move |mut state: ResMut<DiplomacyState>,
               mut ledger: ResMut<ForeignAidLedger>,
               nations: Query<(NationInstance, &Name)>,
-              mut treasuries: Query<&mut Treasury>| {
+              mut treasuries: Query<&mut Treasury>,
+              mut commands: Commands| {
             resolve_offer_response(
                 offer.clone(),
                 false,
@@ -622,6 +899,7 @@ fn declining_defensive_join_war_penalizes() {
                 &mut ledger,
                 &nations,
                 &mut treasuries,
+                &mut commands,
             );
         },
     );
@@ -677,7 +955,8 @@ fn declining_offensive_join_war_preserves_alliance() {
         move |mut state: ResMut<DiplomacyState>,
               mut ledger: ResMut<ForeignAidLedger>,
               nations: Query<(NationInstance, &Name)>,
-              mut treasuries: Query<&mut Treasury>| {
+              mut treasuries: Query<&mut Treasury>,
+              mut commands: Commands| {
             resolve_offer_response(
                 offer.clone(),
                 false,
@@ -685,6 +964,7 @@ fn declining_offensive_join_war_preserves_alliance() {
                 &mut ledger,
                 &nations,
                 &mut treasuries,
+                &mut commands,
             );
         },
     );
@@ -695,3 +975,640 @@ fn declining_offensive_join_war_preserves_alliance() {
         .expect("alliance relation");
     assert!(relation.treaty.alliance);
 }
+
+/// Declares war on a nation with several allies and records who was called to arms, in order.
+fn run_alliance_call_scenario() -> Vec<(String, bool)> {
+    let mut world = setup_world();
+
+    let attacker = world
+        .spawn((Nation, Name::new("Attacker"), Treasury::new(1_000)))
+        .id();
+    let victim = world
+        .spawn((Nation, Name::new("Victim"), Treasury::new(1_000)))
+        .id();
+    let allies: Vec<Entity> = (0..4)
+        .map(|i| {
+            world
+                .spawn((
+                    Nation,
+                    Name::new(format!("Ally {}", i)),
+                    Treasury::new(1_000),
+                ))
+                .id()
+        })
+        .collect();
+
+    let attacker_inst = nation_instance(&world, attacker);
+    let victim_inst = nation_instance(&world, victim);
+    let ally_insts: Vec<NationInstance> = allies
+        .iter()
+        .map(|&ally| nation_instance(&world, ally))
+        .collect();
+
+    let _ = world.run_system_once(sync_diplomatic_pairs);
+
+    {
+        let mut state = world.resource_mut::<DiplomacyState>();
+        for (i, &ally) in ally_insts.iter().enumerate() {
+            // Alternate sides so both belligerents call on allies
+            let side = if i % 2 == 0 {
+                victim_inst
+            } else {
+                attacker_inst
+            };
+            state.set_treaty(side, ally, |t| {
+                t.alliance = true;
+                t.non_aggression_pact = true;
+                t.embassy = true;
+            });
+        }
+    }
+
+    world.trigger(DiplomaticOrder {
+        actor: attacker_inst,
+        target: victim_inst,
+        kind: DiplomaticOrderKind::DeclareWar,
+    });
+
+    let mut calls: Vec<(u32, String, bool)> = Vec::new();
+    let offers = world.resource::<DiplomaticOffers>();
+    for &ally in &ally_insts {
+        for offer in offers.iter_for(ally) {
+            if let DiplomaticOfferKind::JoinWar { defensive, .. } = offer.kind {
+                let name = world.get::<Name>(ally.entity()).unwrap().to_string();
+                calls.push((offer.id.raw(), name, defensive));
+            }
+        }
+    }
+    calls.sort_by_key(|(id, _, _)| *id);
+    calls
+        .into_iter()
+        .map(|(_, name, defensive)| (name, defensive))
+        .collect()
+}
+
+#[test]
+fn alliance_calls_are_issued_in_the_same_order_every_run() {
+    let first = run_alliance_call_scenario();
+    assert_eq!(first.len(), 4);
+
+    // Each run builds a fresh relations map; a hash-ordered map would shuffle the calls
+    for _ in 0..8 {
+        assert_eq!(run_alliance_call_scenario(), first);
+    }
+}
+
+#[test]
+fn accepting_cession_moves_province_and_its_depot() {
+    let mut world = setup_world();
+    world.init_resource::<Rails>();
+    world.init_resource::<RailConnectivityCache>();
+    world.add_observer(apply_province_cession);
+    world.add_observer(compute_rail_connectivity);
+
+    let giver = world
+        .spawn((
+            Nation,
+            Name::new("Giver"),
+            Treasury::new(1_000),
+            Capital(TilePos::new(0, 0)),
+        ))
+        .id();
+    let receiver = world
+        .spawn((
+            Nation,
+            Name::new("Receiver"),
+            Treasury::new(1_000),
+            Capital(TilePos::new(10, 10)),
+        ))
+        .id();
+    let giver_inst = nation_instance(&world, giver);
+    let receiver_inst = nation_instance(&world, receiver);
+    let _ = world.run_system_once(sync_diplomatic_pairs);
+
+    // The province's depot is linked to the giver's capital by rail
+    let depot_pos = TilePos::new(2, 0);
+    let mut province = Province::new(
+        ProvinceId(7),
+        vec![TilePos::new(1, 0), depot_pos],
+        TilePos::new(1, 0),
+    );
+    province.owner = Some(giver);
+    let province = world.spawn(province).id();
+    let depot = world
+        .spawn(Depot {
+            position: depot_pos,
+            owner: giver,
+            connected: false,
+        })
+        .id();
+    {
+        let mut rails = world.resource_mut::<Rails>();
+        rails
+            .0
+            .insert(ordered_edge(TilePos::new(0, 0), TilePos::new(1, 0)));
+        rails.0.insert(ordered_edge(TilePos::new(1, 0), depot_pos));
+    }
+    world.trigger(RecomputeConnectivity);
+    assert!(world.get::<Depot>(depot).unwrap().connected);
+
+    // Only the owner can offer a province
+    world.trigger(DiplomaticOrder {
+        actor: receiver_inst,
+        target: giver_inst,
+        kind: DiplomaticOrderKind::CedeProvince {
+            province: ProvinceId(7),
+        },
+    });
+    assert!(world.resource::<DiplomaticOffers>().is_empty());
+
+    world.trigger(DiplomaticOrder {
+        actor: giver_inst,
+        target: receiver_inst,
+        kind: DiplomaticOrderKind::CedeProvince {
+            province: ProvinceId(7),
+        },
+    });
+    let offer = world
+        .resource::<DiplomaticOffers>()
+        .iter_for(receiver_inst)
+        .next()
+        .cloned()
+        .expect("cession offer queued");
+
+    let _ = world.run_system_once(
+        move |mut state: ResMut<DiplomacyState>,
+              mut ledger: ResMut<ForeignAidLedger>,
+              nations: Query<(NationInstance, &Name)>,
+              mut treasuries: Query<&mut Treasury>,
+              mut commands: Commands| {
+            resolve_offer_response(
+                offer.clone(),
+                true,
+                &mut state,
+                &mut ledger,
+                &nations,
+                &mut treasuries,
+                &mut commands,
+            );
+        },
+    );
+
+    assert_eq!(
+        world.get::<Province>(province).unwrap().owner,
+        Some(receiver)
+    );
+    let depot = world.get::<Depot>(depot).unwrap();
+    assert_eq!(depot.owner, receiver);
+    // The receiver's capital has no rail link to the depot, and the giver no longer owns it
+    assert!(!depot.connected);
+}
+
+#[test]
+fn heavy_trade_improves_relations_between_partners_only() {
+    let mut world = setup_world();
+
+    let exporter = world
+        .spawn((Nation, Name::new("Exporter"), Treasury::new(1_000)))
+        .id();
+    let importer = world
+        .spawn((Nation, Name::new("Importer"), Treasury::new(1_000)))
+        .id();
+    let bystander = world
+        .spawn((Nation, Name::new("Bystander"), Treasury::new(1_000)))
+        .id();
+
+    let exporter_inst = nation_instance(&world, exporter);
+    let importer_inst = nation_instance(&world, importer);
+    let bystander_inst = nation_instance(&world, bystander);
+    let _ = world.run_system_once(sync_diplomatic_pairs);
+
+    let mut ledger = TradeLedger::default();
+    ledger.record(exporter, importer, 60);
+    ledger.record(importer, exporter, 40);
+    // A single unit is not enough to matter
+    ledger.record(exporter, bystander, 1);
+    world.insert_resource(ledger);
+
+    let _ = world.run_system_once(apply_trade_goodwill);
+
+    let state = world.resource::<DiplomacyState>();
+    let score = |a, b| state.relation(a, b).unwrap().score;
+    // 100 units would be worth 20 points, but the gain is capped per turn
+    assert_eq!(
+        score(exporter_inst, importer_inst),
+        MAX_TRADE_GOODWILL_PER_TURN
+    );
+    assert_eq!(score(exporter_inst, bystander_inst), 0);
+    assert_eq!(score(importer_inst, bystander_inst), 0);
+}
+
+#[test]
+fn embassy_reports_target_treasury_and_stockpiles() {
+    let mut world = setup_world();
+    world.init_resource::<IntelReports>();
+
+    let observer = world
+        .spawn((
+            Nation,
+            Name::new("Observer"),
+            Treasury::new(10_000),
+            Stockpile::default(),
+            ProductionResult::default(),
+        ))
+        .id();
+    let mut target_stockpile = Stockpile::default();
+    target_stockpile.add(Good::Coal, 42);
+    target_stockpile.add(Good::Grain, 17);
+    target_stockpile.add(Good::Iron, 3);
+    target_stockpile.add(Good::Arms, 2);
+    target_stockpile.add(Good::Horses, 1);
+    let target = world
+        .spawn((
+            Nation,
+            Name::new("Target"),
+            Treasury::new(3_456),
+            target_stockpile,
+            ProductionResult::default(),
+        ))
+        .id();
+
+    let observer_inst = nation_instance(&world, observer);
+    let target_inst = nation_instance(&world, target);
+    let _ = world.run_system_once(sync_diplomatic_pairs);
+
+    world
+        .resource_mut::<DiplomacyState>()
+        .set_treaty(observer_inst, target_inst, |t| t.consulate = true);
+    let _ = world.run_system_once(update_intel_reports);
+
+    // A consulate only gives a rough picture
+    let rough = world
+        .resource::<IntelReports>()
+        .get(observer_inst, target_inst)
+        .cloned()
+        .expect("consulate report");
+    assert_eq!(rough.source, IntelSource::Consulate);
+    assert!(rough.treasury_min <= 3_456 && 3_456 <= rough.treasury_max);
+    assert!(rough.treasury_min < rough.treasury_max);
+    assert_eq!(rough.top_goods[0], (Good::Coal, 40));
+    assert!(rough.military_min <= 3 && 3 <= rough.military_max);
+
+    world
+        .resource_mut::<DiplomacyState>()
+        .adjust_score(observer_inst, target_inst, 40);
+    world.trigger(DiplomaticOrder {
+        actor: observer_inst,
+        target: target_inst,
+        kind: DiplomaticOrderKind::OpenEmbassy,
+    });
+    assert!(
+        world
+            .resource::<DiplomacyState>()
+            .relation(observer_inst, target_inst)
+            .unwrap()
+            .treaty
+            .embassy
+    );
+    let _ = world.run_system_once(update_intel_reports);
+
+    let report = world
+        .resource::<IntelReports>()
+        .get(observer_inst, target_inst)
+        .cloned()
+        .expect("embassy report");
+    assert_eq!(report.source, IntelSource::Embassy);
+    assert_eq!((report.treasury_min, report.treasury_max), (3_456, 3_456));
+    assert_eq!(
+        report.top_goods,
+        vec![
+            (Good::Coal, 42),
+            (Good::Grain, 17),
+            (Good::Iron, 3),
+            (Good::Arms, 2),
+            (Good::Horses, 1)
+        ]
+    );
+    assert_eq!((report.military_min, report.military_max), (3, 3));
+}
+
+#[test]
+fn power_ranking_orders_by_revealed_power_and_skips_unknown_nations() {
+    let mut world = setup_world();
+    world.init_resource::<IntelReports>();
+
+    let observer = world
+        .spawn((
+            Nation,
+            Treasury::new(10_000),
+            Stockpile::default(),
+            ProductionResult::default(),
+        ))
+        .id();
+    let mut output = ProductionResult::default();
+    output.reports.insert(
+        BuildingKind::SteelMill,
+        ProductionReport {
+            target: 40,
+            produced: 40,
+            consumed: 80,
+            limiting_input: None,
+        },
+    );
+    // Poorer treasury, but its industry puts it ahead
+    let industrial = world
+        .spawn((Nation, Treasury::new(2_000), Stockpile::default(), output))
+        .id();
+    let wealthy = world
+        .spawn((
+            Nation,
+            Treasury::new(5_000),
+            Stockpile::default(),
+            ProductionResult::default(),
+        ))
+        .id();
+    let unknown = world
+        .spawn((
+            Nation,
+            Treasury::new(50_000),
+            Stockpile::default(),
+            ProductionResult::default(),
+        ))
+        .id();
+
+    let observer_inst = nation_instance(&world, observer);
+    let industrial_inst = nation_instance(&world, industrial);
+    let wealthy_inst = nation_instance(&world, wealthy);
+    let unknown_inst = nation_instance(&world, unknown);
+    let _ = world.run_system_once(sync_diplomatic_pairs);
+    {
+        let mut state = world.resource_mut::<DiplomacyState>();
+        state.set_treaty(observer_inst, industrial_inst, |t| {
+            t.consulate = true;
+            t.embassy = true;
+        });
+        state.set_treaty(observer_inst, wealthy_inst, |t| t.consulate = true);
+    }
+    let _ = world.run_system_once(update_intel_reports);
+
+    let intel = world.resource::<IntelReports>();
+    let ranking = intel.power_ranking(observer_inst);
+    let order: Vec<NationInstance> = ranking.iter().map(|(nation, _)| *nation).collect();
+    assert_eq!(order, vec![industrial_inst, wealthy_inst]);
+    assert!(!order.contains(&unknown_inst));
+
+    let (_, industrial_report) = ranking[0];
+    assert_eq!(industrial_report.power_range(), (6_000, 6_000));
+    let (min, max) = ranking[1].1.power_range();
+    assert!(min <= 5_000 && 5_000 <= max && min < max);
+}
+
+#[test]
+fn trade_agreement_lowers_delivered_price_between_partners() {
+    let mut world = setup_world();
+
+    let buyer = world
+        .spawn((Nation, Name::new("Buyer"), Treasury::new(1_000)))
+        .id();
+    let seller = world
+        .spawn((Nation, Name::new("Seller"), Treasury::new(1_000)))
+        .id();
+    let outsider = world
+        .spawn((Nation, Name::new("Outsider"), Treasury::new(1_000)))
+        .id();
+
+    let buyer_inst = nation_instance(&world, buyer);
+    let seller_inst = nation_instance(&world, seller);
+    let outsider_inst = nation_instance(&world, outsider);
+
+    let _ = world.run_system_once(sync_diplomatic_pairs);
+    world
+        .resource_mut::<DiplomacyState>()
+        .set_treaty(buyer_inst, seller_inst, |t| t.consulate = true);
+
+    world.trigger(DiplomaticOrder {
+        actor: buyer_inst,
+        target: seller_inst,
+        kind: DiplomaticOrderKind::ProposeTradeAgreement,
+    });
+
+    let offer = world
+        .resource::<DiplomaticOffers>()
+        .iter_for(seller_inst)
+        .next()
+        .cloned()
+        .expect("trade agreement offer present");
+    assert!(matches!(offer.kind, DiplomaticOfferKind::TradeAgreement));
+
+    let _ = world.run_system_once(
+        move |mut state: ResMut<DiplomacyState>,
+              mut ledger: ResMut<ForeignAidLedger>,
+              nations: Query<(NationInstance, &Name)>,
+              mut treasuries: Query<&mut Treasury>,
+              mut commands: Commands| {
+            resolve_offer_response(
+                offer.clone(),
+                true,
+                &mut state,
+                &mut ledger,
+                &nations,
+                &mut treasuries,
+                &mut commands,
+            );
+        },
+    );
+
+    let state = world.resource::<DiplomacyState>();
+    assert!(state.has_trade_agreement(buyer_inst, seller_inst));
+
+    let capacity = TradeCapacitySnapshot { total: 4, used: 4 };
+    let price_from = |seller| {
+        partner_delivered_price(100, capacity, state.has_trade_agreement(buyer_inst, seller))
+    };
+    let partner_price = price_from(seller_inst);
+    let outsider_price = price_from(outsider_inst);
+    assert!(partner_price < outsider_price);
+    assert_eq!(outsider_price, delivered_price(100, capacity));
+}
+
+fn accept_offer(world: &mut World, offer: DiplomaticOffer) {
+    let _ = world.run_system_once(
+        move |mut state: ResMut<DiplomacyState>,
+              mut ledger: ResMut<ForeignAidLedger>,
+              nations: Query<(NationInstance, &Name)>,
+              mut treasuries: Query<&mut Treasury>,
+              mut commands: Commands| {
+            resolve_offer_response(
+                offer.clone(),
+                true,
+                &mut state,
+                &mut ledger,
+                &nations,
+                &mut treasuries,
+                &mut commands,
+            );
+        },
+    );
+}
+
+#[test]
+fn accepted_good_swap_moves_goods_only_if_offerer_still_has_them() {
+    let mut world = setup_world();
+    world.add_observer(apply_good_swap);
+
+    let mut offerer_stock = Stockpile::default();
+    offerer_stock.add(Good::Coal, 20);
+    let mut partner_stock = Stockpile::default();
+    partner_stock.add(Good::Grain, 30);
+
+    let offerer = world
+        .spawn((
+            Nation,
+            Name::new("Offerer"),
+            Treasury::new(1_000),
+            offerer_stock,
+        ))
+        .id();
+    let partner = world
+        .spawn((
+            Nation,
+            Name::new("Partner"),
+            Treasury::new(1_000),
+            partner_stock,
+        ))
+        .id();
+
+    let offerer_inst = nation_instance(&world, offerer);
+    let partner_inst = nation_instance(&world, partner);
+    let _ = world.run_system_once(sync_diplomatic_pairs);
+    world
+        .resource_mut::<DiplomacyState>()
+        .set_treaty(offerer_inst, partner_inst, |t| {
+            t.consulate = true;
+            t.trade_agreement = true;
+        });
+
+    world.trigger(DiplomaticOrder {
+        actor: offerer_inst,
+        target: partner_inst,
+        kind: DiplomaticOrderKind::ProposeGoodSwap {
+            give: (Good::Coal, 15),
+            receive: (Good::Grain, 10),
+        },
+    });
+    let offer = world
+        .resource::<DiplomaticOffers>()
+        .iter_for(partner_inst)
+        .next()
+        .cloned()
+        .expect("swap offer present");
+    assert!(matches!(offer.kind, DiplomaticOfferKind::GoodSwap { .. }));
+
+    let score = |world: &World| {
+        world
+            .resource::<DiplomacyState>()
+            .relation(offerer_inst, partner_inst)
+            .unwrap()
+            .score
+    };
+    let before = score(&world);
+    accept_offer(&mut world, offer.clone());
+    assert_eq!(score(&world), before + 2);
+
+    let offerer_stock = world.get::<Stockpile>(offerer).unwrap();
+    assert_eq!(offerer_stock.get(Good::Coal), 5);
+    assert_eq!(offerer_stock.get(Good::Grain), 10);
+    let partner_stock = world.get::<Stockpile>(partner).unwrap();
+    assert_eq!(partner_stock.get(Good::Coal), 15);
+    assert_eq!(partner_stock.get(Good::Grain), 20);
+
+    // Accepting the same offer again fails: only 5 coal are left
+    accept_offer(&mut world, offer);
+    assert_eq!(score(&world), before + 2, "a failed swap earns no goodwill");
+
+    let offerer_stock = world.get::<Stockpile>(offerer).unwrap();
+    assert_eq!(offerer_stock.get(Good::Coal), 5);
+    assert_eq!(offerer_stock.get(Good::Grain), 10);
+    let partner_stock = world.get::<Stockpile>(partner).unwrap();
+    assert_eq!(partner_stock.get(Good::Coal), 15);
+    assert_eq!(partner_stock.get(Good::Grain), 20);
+}
+
+#[test]
+fn research_gift_moves_points_between_allies_only() {
+    let mut world = setup_world();
+    world.add_observer(apply_research_gift);
+
+    let donor = world
+        .spawn((
+            Nation,
+            Name::new("Donor"),
+            Treasury::new(1_000),
+            ResearchProgress { points: 50 },
+        ))
+        .id();
+    let ally = world
+        .spawn((
+            Nation,
+            Name::new("Ally"),
+            Treasury::new(1_000),
+            ResearchProgress::default(),
+        ))
+        .id();
+    let donor_inst = nation_instance(&world, donor);
+    let ally_inst = nation_instance(&world, ally);
+    let _ = world.run_system_once(sync_diplomatic_pairs);
+
+    let share = DiplomaticOrder {
+        actor: donor_inst,
+        target: ally_inst,
+        kind: DiplomaticOrderKind::ShareResearch { points: 30 },
+    };
+
+    // Without an alliance the offer is never made
+    world.trigger(share.clone());
+    assert_eq!(
+        world
+            .resource::<DiplomaticOffers>()
+            .iter_for(ally_inst)
+            .count(),
+        0
+    );
+
+    world
+        .resource_mut::<DiplomacyState>()
+        .set_treaty(donor_inst, ally_inst, |t| {
+            t.embassy = true;
+            t.alliance = true;
+        });
+    world.trigger(share);
+    let offer = world
+        .resource::<DiplomaticOffers>()
+        .iter_for(ally_inst)
+        .next()
+        .cloned()
+        .expect("research offer present");
+    assert!(matches!(
+        offer.kind,
+        DiplomaticOfferKind::ShareResearch { points: 30 }
+    ));
+
+    let score = |world: &World| {
+        world
+            .resource::<DiplomacyState>()
+            .relation(donor_inst, ally_inst)
+            .unwrap()
+            .score
+    };
+    let before = score(&world);
+    accept_offer(&mut world, offer.clone());
+
+    assert_eq!(world.get::<ResearchProgress>(donor).unwrap().points, 20);
+    assert_eq!(world.get::<ResearchProgress>(ally).unwrap().points, 30);
+    assert_eq!(score(&world), before + 3);
+
+    // The donor can no longer cover the same gift, so no goodwill is earned
+    accept_offer(&mut world, offer);
+    assert_eq!(world.get::<ResearchProgress>(ally).unwrap().points, 30);
+    assert_eq!(score(&world), before + 3);
+}
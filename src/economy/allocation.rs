@@ -1,31 +1,120 @@
 use bevy::prelude::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 
-use crate::economy::{goods::Good, reservation::ReservationId, workforce::WorkerSkill};
+use crate::economy::{
+    goods::Good,
+    nation::NationInstance,
+    reservation::{ReservationError, ReservationId},
+    workforce::WorkerSkill,
+};
+use crate::messages::{
+    AdjustMarketOrder, AdjustProduction, AdjustRecruitment, AdjustTraining, MarketInterest,
+};
+use crate::orders::OrdersQueue;
 
 /// Per-nation component tracking all resource allocations via reservation IDs
-/// Each reservation represents ONE unit of output/worker/etc.
+/// Each reservation represents ONE unit of output/worker/etc., except market sells,
+/// which hold every unit of a good in one bulk reservation.
+/// Keyed maps are `BTreeMap`s so finalization consumes reservations in a stable order.
 #[derive(Component, Debug, Clone, Default)]
 pub struct Allocations {
     /// Production allocations: (building, output_good) -> list of reservations
     /// Each ReservationId represents 1 unit of output
-    pub production: HashMap<(Entity, Good), Vec<ReservationId>>,
+    pub production: BTreeMap<(Entity, Good), Vec<ReservationId>>,
 
     /// Recruitment allocations: list of reservations
     /// Each ReservationId represents 1 worker recruitment
     pub recruitment: Vec<ReservationId>,
 
+    /// Inputs of civilian jobs started this turn: civilian -> reservation
+    /// Committed with everything else at turn end, released if the job is rescinded first
+    pub jobs: BTreeMap<Entity, ReservationId>,
+
+    /// Fuel set aside for the power plant, whose labor bonus is already in the labor pool
+    pub power_plant: Option<ReservationId>,
+
     /// Training allocations: skill level -> list of reservations
     /// Each ReservationId represents 1 worker training
-    pub training: HashMap<WorkerSkill, Vec<ReservationId>>,
+    pub training: BTreeMap<WorkerSkill, Vec<ReservationId>>,
 
     /// Market buy allocations: goods the nation has expressed interest in buying
     /// Buy interest is boolean - the amount purchased depends on available supply
     pub market_buys: HashSet<Good>,
 
     /// Market sell allocations: goods the nation wants to sell with quantities
-    /// Each ReservationId represents 1 unit reserved for selling
-    pub market_sells: HashMap<Good, Vec<ReservationId>>,
+    /// A single bulk reservation per good holds every unit offered for sale
+    pub market_sells: BTreeMap<Good, MarketSellOrder>,
+
+    /// Why the last attempt to raise a production allocation stopped short, if it did
+    pub production_blocked: BTreeMap<(Entity, Good), ReservationError>,
+}
+
+/// A nation's pending allocations as plain counts, saved in place of the live
+/// [`Allocations`] and their reservations. Loading replays it as orders so every
+/// reservation is made again exactly once.
+/// Only production allocated to the nation's own [`Buildings`](crate::economy::production::Buildings)
+/// is kept, since that is how allocations are keyed in play.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct AllocationSnapshot {
+    /// Output units allocated per good
+    pub production: Vec<(Good, u32)>,
+    pub recruitment: u32,
+    /// Workers in training per starting skill level
+    pub training: Vec<(WorkerSkill, u32)>,
+    pub market_buys: Vec<Good>,
+    /// Units offered for sale per good
+    pub market_sells: Vec<(Good, u32)>,
+}
+
+impl AllocationSnapshot {
+    /// Queue the orders that rebuild these allocations for `nation`
+    pub fn queue_orders(&self, nation: NationInstance, orders: &mut OrdersQueue) {
+        if self.recruitment > 0 {
+            orders.queue_recruitment(AdjustRecruitment {
+                nation,
+                requested: self.recruitment,
+            });
+        }
+        for &(from_skill, requested) in &self.training {
+            orders.queue_training(AdjustTraining {
+                nation,
+                from_skill,
+                requested,
+            });
+        }
+        for &(output_good, target_output) in &self.production {
+            orders.queue_production(AdjustProduction {
+                nation,
+                building: nation.entity(),
+                output_good,
+                target_output,
+            });
+        }
+        for &good in &self.market_buys {
+            orders.queue_market(AdjustMarketOrder {
+                nation,
+                good,
+                kind: MarketInterest::Buy,
+                requested: 1,
+            });
+        }
+        for &(good, requested) in &self.market_sells {
+            orders.queue_market(AdjustMarketOrder {
+                nation,
+                good,
+                kind: MarketInterest::Sell,
+                requested,
+            });
+        }
+    }
+}
+
+/// Units of one good offered on the market, held by a single reservation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarketSellOrder {
+    pub reservation: ReservationId,
+    pub quantity: u32,
 }
 
 impl Allocations {
@@ -47,6 +136,15 @@ impl Allocations {
         self.training.get(&skill).map(|v| v.len()).unwrap_or(0)
     }
 
+    /// Trainees at every skill level except `skill`, which share the Trade School with it
+    pub fn other_training_count(&self, skill: WorkerSkill) -> usize {
+        self.training
+            .iter()
+            .filter(|(other, _)| **other != skill)
+            .map(|(_, res_ids)| res_ids.len())
+            .sum()
+    }
+
     /// Check if nation has buy interest for a good
     pub fn has_buy_interest(&self, good: Good) -> bool {
         self.market_buys.contains(&good)
@@ -54,6 +152,37 @@ impl Allocations {
 
     /// Get market sell allocation count for a good
     pub fn market_sell_count(&self, good: Good) -> usize {
-        self.market_sells.get(&good).map(|v| v.len()).unwrap_or(0)
+        self.market_sells
+            .get(&good)
+            .map(|order| order.quantity as usize)
+            .unwrap_or(0)
+    }
+
+    /// Capture the pending allocations of `nation` for saving
+    pub fn snapshot(&self, nation: Entity) -> AllocationSnapshot {
+        let mut market_buys: Vec<Good> = self.market_buys.iter().copied().collect();
+        market_buys.sort();
+        AllocationSnapshot {
+            production: self
+                .production
+                .iter()
+                .filter(|((building, _), units)| *building == nation && !units.is_empty())
+                .map(|((_, good), units)| (*good, units.len() as u32))
+                .collect(),
+            recruitment: self.recruitment_count() as u32,
+            training: self
+                .training
+                .iter()
+                .filter(|(_, units)| !units.is_empty())
+                .map(|(skill, units)| (*skill, units.len() as u32))
+                .collect(),
+            market_buys,
+            market_sells: self
+                .market_sells
+                .iter()
+                .filter(|(_, order)| order.quantity > 0)
+                .map(|(good, order)| (*good, order.quantity))
+                .collect(),
+        }
     }
 }
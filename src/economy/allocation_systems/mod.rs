@@ -1,22 +1,35 @@
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
 use bevy::prelude::*;
 
 use crate::economy::{
-    allocation::Allocations,
+    allocation::{Allocations, MarketSellOrder},
     goods::Good,
-    production::{BuildingKind, Buildings, building_for_output},
+    nation::NationInstance,
+    production::{
+        Building, BuildingKind, Buildings, POWER_PLANT_FUEL_PER_TURN, ProductionResult,
+        building_for_output, preview_production, production_recipe,
+    },
     reservation::ReservationSystem,
-    stockpile::Stockpile,
-    treasury::Treasury,
-    workforce::{RecruitmentCapacity, types::*},
+    stockpile::{Stockpile, StockpileReserves},
+    treasury::{FinancialAction, FinancialRejection, Treasury},
+    workforce::{RecruitmentCapacity, calculate_recruitment_cap, types::*},
 };
 use crate::{
     map::province::Province,
     messages::{
-        AdjustMarketOrder, AdjustProduction, AdjustRecruitment, AdjustTraining, MarketInterest,
+        AdjustMarketOrder, AdjustProduction, AdjustRecruitment, AdjustTraining, CancelMarketOrder,
+        MarketInterest,
     },
     orders::OrdersQueue,
 };
 
+/// Cash reserved per worker allocated to training
+pub(crate) const TRAINING_COST: u32 = 100;
+/// Labor points of Trade School staff each worker in training occupies
+pub(crate) const TRAINING_LABOR: u32 = 1;
+
 // ============================================================================
 // Production Adjustment System (Unit-by-Unit Reservations)
 // ============================================================================
@@ -104,6 +117,131 @@ pub(crate) fn calculate_inputs_for_one_unit(
     }
 }
 
+// ============================================================================
+// Idle Labor
+// ============================================================================
+
+/// Sent when a nation leaves labor unallocated while buildings could still put it to work
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct IdleLabor {
+    pub nation: Entity,
+    /// Labor not allocated to anything
+    pub idle: u32,
+    /// Buildings with spare capacity and inputs on hand
+    pub buildings: Vec<BuildingKind>,
+}
+
+/// Requests putting a nation's idle labor to work in buildings with spare capacity.
+/// Buildings are filled one unit at a time, highest [`Buildings::priority`] first, for as
+/// long as labor and inputs last. A building keeps the output it already makes, or the
+/// one its inputs in the stockpile best suit.
+pub fn auto_assign_idle_labor(
+    nation: NationInstance,
+    workforce: &Workforce,
+    allocations: &Allocations,
+    buildings: &Buildings,
+    stockpile: &Stockpile,
+) -> Vec<AdjustProduction> {
+    let entity = nation.entity();
+    let mut idle = workforce.labor_pool.available();
+    let mut scratch = stockpile.clone();
+
+    let mut candidates: Vec<&Building> = buildings.buildings.values().collect();
+    candidates.sort_by_key(|b| (Reverse(buildings.priority(b.kind)), b.kind as u8));
+
+    let mut requests = Vec::new();
+    for building in candidates {
+        if idle == 0 {
+            break;
+        }
+        let Some(recipe) = production_recipe(building.kind) else {
+            continue;
+        };
+
+        let allocated: Vec<(Good, u32)> = allocations
+            .production
+            .iter()
+            .filter(|((owner, output), units)| {
+                *owner == entity && !units.is_empty() && recipe.produces(*output)
+            })
+            .map(|((_, output), units)| (*output, units.len() as u32))
+            .collect();
+        let Some(output) = allocated.first().map(|(good, _)| *good).or_else(|| {
+            recipe
+                .best_variant_for_stockpile(&scratch)
+                .and_then(|variant| variant.primary_output_good())
+        }) else {
+            continue;
+        };
+        let in_use: u32 = allocated.iter().map(|(_, units)| units).sum();
+        let current = allocations.production_count(entity, output) as u32;
+
+        let mut added = 0;
+        while idle > 0 && in_use + added < building.capacity {
+            let inputs = calculate_inputs_for_one_unit(building.kind, output, &scratch);
+            if inputs.is_empty()
+                || !inputs
+                    .iter()
+                    .all(|&(good, qty)| scratch.has_available(good, qty))
+            {
+                break;
+            }
+            for &(good, qty) in &inputs {
+                scratch.reserve(good, qty);
+            }
+            idle -= 1;
+            added += 1;
+        }
+
+        if added > 0 {
+            requests.push(AdjustProduction {
+                nation,
+                building: entity,
+                output_good: output,
+                target_output: current + added,
+            });
+        }
+    }
+    requests
+}
+
+/// Warn nations whose labor sits idle while buildings could use it.
+/// Runs after production orders are applied; a nation is warned once, and again only
+/// after its idle labor has been put to work in between.
+pub fn warn_idle_labor(
+    nations: Query<
+        (
+            NationInstance,
+            &Workforce,
+            &Allocations,
+            &Buildings,
+            &Stockpile,
+        ),
+        Or<(Changed<Allocations>, Changed<Workforce>, Changed<Stockpile>)>,
+    >,
+    mut warned: Local<HashSet<Entity>>,
+    mut idle_writer: MessageWriter<IdleLabor>,
+) {
+    for (nation, workforce, allocations, buildings, stockpile) in nations.iter() {
+        let idle = workforce.labor_pool.available();
+        let requests = auto_assign_idle_labor(nation, workforce, allocations, buildings, stockpile);
+        if idle == 0 || requests.is_empty() {
+            warned.remove(&nation.entity());
+            continue;
+        }
+        if warned.insert(nation.entity()) {
+            idle_writer.write(IdleLabor {
+                nation: nation.entity(),
+                idle,
+                buildings: requests
+                    .iter()
+                    .filter_map(|request| building_for_output(request.output_good))
+                    .collect(),
+            });
+        }
+    }
+}
+
 // ============================================================================
 // Recruitment Adjustment System
 // ============================================================================
@@ -137,6 +275,46 @@ pub fn apply_market_order_adjustments(
     orders.queue_market(*trigger.event());
 }
 
+/// Cancel a nation's market orders for a good immediately, rather than
+/// queueing a zero target: reserved goods return to the stockpile now
+pub fn cancel_market_order(
+    trigger: On<CancelMarketOrder>,
+    mut orders: ResMut<OrdersQueue>,
+    mut nations: Query<(
+        &mut Allocations,
+        &mut ReservationSystem,
+        &mut Stockpile,
+        &mut Workforce,
+        &mut Treasury,
+    )>,
+) {
+    let cancel = trigger.event();
+    let nation = cancel.nation.entity();
+    orders.discard_market(nation, cancel.good);
+
+    let Ok((mut allocations, mut reservations, mut stockpile, mut workforce, mut treasury)) =
+        nations.get_mut(nation)
+    else {
+        return;
+    };
+
+    if let Some(order) = allocations.market_sells.remove(&cancel.good) {
+        reservations.release(
+            order.reservation,
+            &mut stockpile,
+            &mut workforce,
+            &mut treasury,
+        );
+        debug!(
+            "Cancelled {} sell orders for {:?}",
+            order.quantity, cancel.good
+        );
+    }
+    if allocations.market_buys.remove(&cancel.good) {
+        debug!("Cancelled buy interest for {:?}", cancel.good);
+    }
+}
+
 pub fn execute_queued_production_orders(
     mut orders: ResMut<OrdersQueue>,
     mut nations: Query<(
@@ -146,14 +324,29 @@ pub fn execute_queued_production_orders(
         &mut Workforce,
     )>,
     buildings_query: Query<&Buildings>,
+    reserves: Query<&StockpileReserves>,
 ) {
-    let queued = orders.take_production();
+    let mut queued = orders.take_production();
     if queued.is_empty() {
         return;
     }
 
+    // Higher-priority buildings reserve scarce inputs first; ties keep queue order
+    queued.sort_by_key(|order| {
+        let priority = buildings_query
+            .get(order.building)
+            .ok()
+            .zip(building_for_output(order.output_good))
+            .map_or(0, |(buildings, kind)| buildings.priority(kind));
+        Reverse(priority)
+    });
+
     for order in queued {
-        process_production_adjustment(order, &mut nations, &buildings_query);
+        let Ok(nation_reserves) = reserves.get(order.nation.entity()) else {
+            warn!("Cannot adjust production: nation has no stockpile reserves");
+            continue;
+        };
+        process_production_adjustment(order, &mut nations, &buildings_query, nation_reserves);
     }
 }
 
@@ -166,6 +359,7 @@ fn process_production_adjustment(
         &mut Workforce,
     )>,
     buildings_query: &Query<&Buildings>,
+    reserves: &StockpileReserves,
 ) {
     let Ok((mut allocations, mut reservations, mut stockpile, mut workforce)) =
         nations.get_mut(msg.nation.entity())
@@ -232,6 +426,7 @@ fn process_production_adjustment(
 
     if target < current_count {
         let to_remove = current_count - target;
+        allocations.production_blocked.remove(&key);
         let vec = allocations.production.entry(key).or_default();
 
         for _ in 0..to_remove {
@@ -253,23 +448,40 @@ fn process_production_adjustment(
         let to_add = target - current_count;
         let vec = allocations.production.entry(key).or_default();
         let mut added = 0;
+        let mut blocked = None;
 
         for _ in 0..to_add {
             let inputs_per_unit =
                 calculate_inputs_for_one_unit(building.kind, msg.output_good, &stockpile);
+            if !reserves.allows(&stockpile, &inputs_per_unit) {
+                break;
+            }
 
-            if let Some(res_id) = reservations.try_reserve(
-                inputs_per_unit.clone(),
+            match reservations.reserve(
+                &inputs_per_unit,
                 1,
                 0,
                 &mut stockpile,
                 &mut workforce,
                 &mut Treasury::new(0),
             ) {
-                vec.push(res_id);
-                added += 1;
-            } else {
-                break;
+                Ok(res_id) => {
+                    vec.push(res_id);
+                    added += 1;
+                }
+                Err(error) => {
+                    blocked = Some(error);
+                    break;
+                }
+            }
+        }
+
+        match blocked {
+            Some(error) => {
+                allocations.production_blocked.insert(key, error);
+            }
+            None => {
+                allocations.production_blocked.remove(&key);
             }
         }
 
@@ -296,6 +508,8 @@ pub fn execute_queued_recruitment_orders(
     mut nations: Query<(&mut Allocations, &mut ReservationSystem, &mut Stockpile)>,
     provinces: Query<&Province>,
     recruitment_capacity: Query<&RecruitmentCapacity>,
+    buildings: Query<&Buildings>,
+    reserves: Query<&StockpileReserves>,
 ) {
     let queued = orders.take_recruitment();
     if queued.is_empty() {
@@ -303,7 +517,22 @@ pub fn execute_queued_recruitment_orders(
     }
 
     for order in queued {
-        process_recruitment_adjustment(order, &mut nations, &provinces, &recruitment_capacity);
+        let Ok(nation_reserves) = reserves.get(order.nation.entity()) else {
+            warn!("Cannot adjust recruitment: nation has no stockpile reserves");
+            continue;
+        };
+        let capitol_capacity = buildings
+            .get(order.nation.entity())
+            .ok()
+            .and_then(Buildings::capitol_capacity);
+        process_recruitment_adjustment(
+            order,
+            &mut nations,
+            &provinces,
+            &recruitment_capacity,
+            capitol_capacity,
+            nation_reserves,
+        );
     }
 }
 
@@ -312,6 +541,8 @@ fn process_recruitment_adjustment(
     nations: &mut Query<(&mut Allocations, &mut ReservationSystem, &mut Stockpile)>,
     provinces: &Query<&Province>,
     recruitment_capacity: &Query<&RecruitmentCapacity>,
+    capitol_capacity: Option<u32>,
+    reserves: &StockpileReserves,
 ) {
     let Ok((mut allocations, mut reservations, mut stockpile)) =
         nations.get_mut(msg.nation.entity())
@@ -330,11 +561,8 @@ fn process_recruitment_adjustment(
         .map(|c| c.upgraded)
         .unwrap_or(false);
 
-    let capacity_cap = if capacity_upgraded {
-        province_count / 3
-    } else {
-        province_count / 4
-    };
+    let capacity_cap =
+        calculate_recruitment_cap(province_count, capacity_upgraded, capitol_capacity);
 
     let current_count = allocations.recruitment_count();
     let target = msg.requested.min(capacity_cap) as usize;
@@ -366,8 +594,11 @@ fn process_recruitment_adjustment(
         let mut added = 0;
 
         for _ in 0..to_add {
+            if !reserves.allows(&stockpile, &inputs) {
+                break;
+            }
             if let Some(res_id) = reservations.try_reserve(
-                inputs.clone(),
+                &inputs,
                 0,
                 0,
                 &mut stockpile,
@@ -398,9 +629,12 @@ pub fn execute_queued_training_orders(
         &mut Allocations,
         &mut ReservationSystem,
         &mut Stockpile,
-        &Workforce,
+        &mut Workforce,
         &mut Treasury,
     )>,
+    buildings: Query<&Buildings>,
+    reserves: Query<&StockpileReserves>,
+    mut rejections: MessageWriter<FinancialRejection>,
 ) {
     let queued = orders.take_training();
     if queued.is_empty() {
@@ -408,21 +642,42 @@ pub fn execute_queued_training_orders(
     }
 
     for order in queued {
-        process_training_adjustment(order, &mut nations);
+        // Without a Trade School nobody can be trained
+        let school_capacity = buildings
+            .get(order.nation.entity())
+            .ok()
+            .and_then(Buildings::training_capacity)
+            .unwrap_or(0);
+        let Ok(nation_reserves) = reserves.get(order.nation.entity()) else {
+            warn!("Cannot adjust training: nation has no stockpile reserves");
+            continue;
+        };
+        process_training_adjustment(
+            order,
+            &mut nations,
+            school_capacity,
+            nation_reserves,
+            &mut rejections,
+        );
     }
 }
 
+/// Trainees are capped by the workers at the skill level and by the Trade School's
+/// capacity left over from other skill levels; each one also needs staff labor
 fn process_training_adjustment(
     msg: AdjustTraining,
     nations: &mut Query<(
         &mut Allocations,
         &mut ReservationSystem,
         &mut Stockpile,
-        &Workforce,
+        &mut Workforce,
         &mut Treasury,
     )>,
+    school_capacity: u32,
+    reserves: &StockpileReserves,
+    rejections: &mut MessageWriter<FinancialRejection>,
 ) {
-    let Ok((mut allocations, mut reservations, mut stockpile, workforce, mut treasury)) =
+    let Ok((mut allocations, mut reservations, mut stockpile, mut workforce, mut treasury)) =
         nations.get_mut(msg.nation.entity())
     else {
         warn!("Cannot adjust training: nation not found");
@@ -431,7 +686,9 @@ fn process_training_adjustment(
 
     let worker_cap = workforce.count_by_skill(msg.from_skill);
     let current_count = allocations.training_count(msg.from_skill);
-    let target = msg.requested.min(worker_cap) as usize;
+    let other_skills = allocations.other_training_count(msg.from_skill);
+    let school_cap = school_capacity.saturating_sub(other_skills as u32);
+    let target = msg.requested.min(worker_cap).min(school_cap) as usize;
 
     if target < current_count {
         let to_remove = current_count - target;
@@ -439,7 +696,7 @@ fn process_training_adjustment(
 
         for _ in 0..to_remove {
             if let Some(res_id) = vec.pop() {
-                reservations.release(res_id, &mut stockpile, &mut Workforce::new(), &mut treasury);
+                reservations.release(res_id, &mut stockpile, &mut workforce, &mut treasury);
             }
         }
 
@@ -450,23 +707,33 @@ fn process_training_adjustment(
     } else if target > current_count {
         let to_add = target - current_count;
         let inputs = vec![(Good::Paper, 1)];
-        const TRAINING_COST: u32 = 100;
 
         let vec = allocations.training.entry(msg.from_skill).or_default();
         let mut added = 0;
 
         for _ in 0..to_add {
+            if !reserves.allows(&stockpile, &inputs) {
+                break;
+            }
             if let Some(res_id) = reservations.try_reserve(
-                inputs.clone(),
-                0,
+                &inputs,
+                TRAINING_LABOR,
                 TRAINING_COST,
                 &mut stockpile,
-                &mut Workforce::new(),
+                &mut workforce,
                 &mut treasury,
             ) {
                 vec.push(res_id);
                 added += 1;
             } else {
+                if treasury.available() < TRAINING_COST as i64 {
+                    rejections.write(FinancialRejection::new(
+                        msg.nation.entity(),
+                        FinancialAction::Training,
+                        TRAINING_COST as i64,
+                        treasury.available(),
+                    ));
+                }
                 break;
             }
         }
@@ -492,6 +759,7 @@ pub fn execute_queued_market_orders(
         &mut Workforce,
         &mut Treasury,
     )>,
+    reserves: Query<&StockpileReserves>,
 ) {
     let queued = orders.take_market();
     if queued.is_empty() {
@@ -499,7 +767,11 @@ pub fn execute_queued_market_orders(
     }
 
     for order in queued {
-        process_market_adjustment(order, &mut nations);
+        let Ok(nation_reserves) = reserves.get(order.nation.entity()) else {
+            // Silently skip orders for nations that are gone
+            continue;
+        };
+        process_market_adjustment(order, &mut nations, nation_reserves);
     }
 }
 
@@ -523,6 +795,7 @@ fn process_market_adjustment(
         &mut Workforce,
         &mut Treasury,
     )>,
+    reserves: &StockpileReserves,
 ) {
     let Ok((mut allocations, mut reservations, mut stockpile, mut workforce, mut treasury)) =
         nations.get_mut(msg.nation.entity())
@@ -538,17 +811,17 @@ fn process_market_adjustment(
 
             if wants_buy {
                 // Clear any conflicting sell orders first
-                if let Some(sell_orders) = allocations.market_sells.get_mut(&msg.good) {
-                    let cleared_count = sell_orders.len();
-                    while let Some(res_id) = sell_orders.pop() {
-                        reservations.release(res_id, &mut stockpile, &mut workforce, &mut treasury);
-                    }
-                    if cleared_count > 0 {
-                        debug!(
-                            "Cleared {} sell orders for {:?} (switching to buy interest)",
-                            cleared_count, msg.good
-                        );
-                    }
+                if let Some(order) = allocations.market_sells.remove(&msg.good) {
+                    reservations.release(
+                        order.reservation,
+                        &mut stockpile,
+                        &mut workforce,
+                        &mut treasury,
+                    );
+                    debug!(
+                        "Cleared {} sell orders for {:?} (switching to buy interest)",
+                        order.quantity, msg.good
+                    );
                 }
 
                 // Express buy interest (boolean)
@@ -561,7 +834,7 @@ fn process_market_adjustment(
         }
 
         MarketInterest::Sell => {
-            let target = msg.requested as usize;
+            let target = msg.requested;
 
             if target > 0 && allocations.market_buys.remove(&msg.good) {
                 debug!(
@@ -570,47 +843,89 @@ fn process_market_adjustment(
                 );
             }
 
-            let vec = allocations.market_sells.entry(msg.good).or_default();
-            let current_count = vec.len();
-
-            if target < current_count {
-                let to_remove = current_count - target;
+            let current = allocations.market_sell_count(msg.good) as u32;
 
-                for _ in 0..to_remove {
-                    if let Some(res_id) = vec.pop() {
-                        reservations.release(res_id, &mut stockpile, &mut workforce, &mut treasury);
-                    }
-                }
-            } else if target > current_count {
-                let to_add = target - current_count;
-                let mut added = 0;
-
-                for _ in 0..to_add {
-                    if let Some(res_id) = reservations.try_reserve(
-                        vec![(msg.good, 1)],
-                        0,
-                        0,
+            if target < current {
+                let order = allocations
+                    .market_sells
+                    .get_mut(&msg.good)
+                    .expect("non-zero sell count implies an order");
+                if target == 0 {
+                    reservations.release(
+                        order.reservation,
                         &mut stockpile,
                         &mut workforce,
                         &mut treasury,
-                    ) {
-                        vec.push(res_id);
-                        added += 1;
-                    } else {
-                        break;
-                    }
+                    );
+                    allocations.market_sells.remove(&msg.good);
+                } else {
+                    reservations.release_units(
+                        order.reservation,
+                        msg.good,
+                        current - target,
+                        &mut stockpile,
+                    );
+                    order.quantity = target;
                 }
+            } else if target > current {
+                // Reserve as many of the requested units as are available above the
+                // reserve floor, in one go
+                let to_add = target - current;
+                let spendable = reserves.spendable(&stockpile, msg.good);
+                let added = to_add.min(spendable);
 
                 if added > 0 {
+                    let reserved = match allocations.market_sells.get_mut(&msg.good) {
+                        Some(order) => {
+                            let extended = reservations.try_extend(
+                                order.reservation,
+                                msg.good,
+                                added,
+                                &mut stockpile,
+                            );
+                            if extended {
+                                order.quantity += added;
+                            }
+                            extended
+                        }
+                        None => reservations
+                            .try_reserve(
+                                [(msg.good, added)],
+                                0,
+                                0,
+                                &mut stockpile,
+                                &mut workforce,
+                                &mut treasury,
+                            )
+                            .map(|reservation| {
+                                allocations.market_sells.insert(
+                                    msg.good,
+                                    MarketSellOrder {
+                                        reservation,
+                                        quantity: added,
+                                    },
+                                );
+                            })
+                            .is_some(),
+                    };
+                    if !reserved {
+                        warn!(
+                            "Failed to reserve {} × {:?} for sale despite available stock",
+                            added, msg.good
+                        );
+                        return;
+                    }
+
                     info!(
                         "Sell orders increased: {:?} {} → {} ({} added, nation: {:?})",
                         msg.good,
-                        current_count,
-                        current_count + added,
+                        current,
+                        current + added,
                         added,
                         msg.nation.entity()
                     );
-                } else if to_add > 0 {
+                }
+                if added < to_add {
                     info!(
                         "Failed to create sell orders for {:?}: wanted {} but could only add {} (insufficient available stock)",
                         msg.good, to_add, added
@@ -630,6 +945,7 @@ fn process_market_adjustment(
 /// NOTE: Registered via OnEnter(TurnPhase::Processing), so no phase check needed.
 pub fn finalize_allocations(
     mut nations: Query<(
+        Entity,
         &Allocations,
         &mut ReservationSystem,
         &mut Stockpile,
@@ -638,9 +954,10 @@ pub fn finalize_allocations(
         &mut crate::economy::workforce::RecruitmentQueue,
         &mut crate::economy::workforce::TrainingQueue,
     )>,
-    mut buildings: Query<&mut crate::economy::production::ProductionSettings>,
+    mut production: Query<(&Buildings, &mut ProductionResult)>,
 ) {
     for (
+        nation,
         allocations,
         mut reservations,
         mut stockpile,
@@ -682,39 +999,52 @@ pub fn finalize_allocations(
             }
         }
 
-        // 3. Finalize production allocations
-        for ((building_entity, output_good), res_ids) in &allocations.production {
-            let production_count = res_ids.len();
-            if production_count > 0 {
-                // Consume all production reservations
+        // 3. Run production: the preview decides what each building makes from the
+        //    reserved inputs, then the reservations are swapped for the real goods
+        if let Ok((buildings, mut result)) = production.get_mut(nation) {
+            let runs = preview_production(allocations, buildings, &stockpile, &workforce);
+            for res_ids in allocations.production.values() {
                 for res_id in res_ids {
-                    reservations.consume(*res_id, &mut stockpile, &mut workforce, &mut treasury);
+                    reservations.release(*res_id, &mut stockpile, &mut workforce, &mut treasury);
                 }
+            }
 
-                // Update production settings
-                if let Ok(mut settings) = buildings.get_mut(*building_entity) {
-                    settings.target_output = production_count as u32;
-                    info!(
-                        "Finalized production: building {:?}, output {:?}, target {}",
-                        building_entity, output_good, production_count
-                    );
+            result.reports.clear();
+            for run in runs {
+                for &(good, amount) in &run.inputs {
+                    stockpile.take_up_to(good, amount);
+                }
+                for &(good, amount) in &run.outputs {
+                    stockpile.add(good, amount);
                 }
+                info!(
+                    "Finalized production: {:?} made {} of {} ({:?})",
+                    run.kind, run.report.produced, run.report.target, run.outputs
+                );
+                result.record(run.kind, run.report);
             }
         }
 
+        // 4. Commit the inputs of civilian jobs started this turn
+        for res_id in allocations.jobs.values() {
+            reservations.consume(*res_id, &mut stockpile, &mut workforce, &mut treasury);
+        }
+
+        // 5. Burn the fuel that ran the power plant this turn
+        if let Some(fuel) = allocations.power_plant {
+            reservations.consume_units(fuel, Good::Fuel, POWER_PLANT_FUEL_PER_TURN, &mut stockpile);
+        }
+
         // Log market buy interest - execution happens in dedicated market systems
         for good in &allocations.market_buys {
             info!("Buy interest queued: {:?} (awaiting clearing)", good);
         }
 
-        for (good, res_ids) in &allocations.market_sells {
-            if !res_ids.is_empty() {
-                info!(
-                    "Queued market sell offers: {} × {:?} (awaiting clearing)",
-                    res_ids.len(),
-                    good
-                );
-            }
+        for (good, order) in &allocations.market_sells {
+            info!(
+                "Queued market sell offers: {} × {:?} (awaiting clearing)",
+                order.quantity, good
+            );
         }
     }
 }
@@ -753,13 +1083,26 @@ pub fn reset_allocations(
             }
         }
 
+        // Release inputs of civilian jobs that were never committed
+        for res_id in allocations.jobs.values() {
+            reservations.release(*res_id, &mut stockpile, &mut workforce, &mut treasury);
+        }
+
+        // Return fuel the power plant did not get to burn
+        if let Some(fuel) = allocations.power_plant {
+            reservations.release(fuel, &mut stockpile, &mut workforce, &mut treasury);
+        }
+
         // Buy interest has no reservations to release (it's just a flag)
 
         // Release market sell reservations (return goods)
-        for (_good, res_ids) in allocations.market_sells.iter() {
-            for res_id in res_ids {
-                reservations.release(*res_id, &mut stockpile, &mut workforce, &mut treasury);
-            }
+        for order in allocations.market_sells.values() {
+            reservations.release(
+                order.reservation,
+                &mut stockpile,
+                &mut workforce,
+                &mut treasury,
+            );
         }
 
         // Clear allocations
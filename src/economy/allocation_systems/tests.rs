@@ -1,18 +1,30 @@
-use bevy::ecs::system::SystemState;
-use bevy::prelude::{Query, ResMut, World};
+use bevy::ecs::system::{RunSystemOnce, SystemState};
+use bevy::prelude::{Messages, Query, ResMut, World};
+use bevy_ecs_tilemap::prelude::TilePos;
 
 use crate::economy::{
-    allocation::Allocations,
-    allocation_systems::{calculate_inputs_for_one_unit, execute_queued_production_orders},
+    allocation::{Allocations, MarketSellOrder},
+    allocation_systems::{
+        auto_assign_idle_labor, calculate_inputs_for_one_unit, cancel_market_order,
+        execute_queued_market_orders, execute_queued_production_orders,
+        execute_queued_recruitment_orders, execute_queued_training_orders, finalize_allocations,
+    },
     goods::Good,
     nation::{Nation, NationInstance},
-    production::{Building, BuildingKind, Buildings},
+    production::{Building, BuildingKind, Buildings, ProductionResult},
     reservation::ReservationSystem,
-    stockpile::Stockpile,
-    treasury::Treasury,
-    workforce::Workforce,
+    stockpile::{Stockpile, StockpileReserves},
+    treasury::{FinancialAction, FinancialRejection, Treasury},
+    workforce::{
+        CAPITOL_UPGRADE_COST, RecruitmentCapacity, RecruitmentQueue, TrainingQueue, WorkerSkill,
+        Workforce, recruitment::UPGRADED_CAPITOL_CAPACITY, upgrade_capitol,
+    },
+};
+use crate::map::province::{Province, ProvinceId};
+use crate::messages::{
+    AdjustMarketOrder, AdjustProduction, AdjustRecruitment, AdjustTraining, CancelMarketOrder,
+    MarketInterest, UpgradeCapitol,
 };
-use crate::messages::AdjustProduction;
 use crate::orders::OrdersQueue;
 
 /// Test the intelligent input selection logic for Textile Mill
@@ -506,24 +518,24 @@ fn test_market_orders_mutually_exclusive() {
     workforce.add_untrained(10);
     workforce.update_labor_pool();
 
-    // Place 3 sell orders for Cotton (uses reservation system)
-    for _ in 0..3 {
-        let res_id = reservations
-            .try_reserve(
-                vec![(Good::Cotton, 1)],
-                0,
-                0,
-                &mut stockpile,
-                &mut workforce,
-                &mut treasury,
-            )
-            .unwrap();
-        allocations
-            .market_sells
-            .entry(Good::Cotton)
-            .or_default()
-            .push(res_id);
-    }
+    // Place 3 sell orders for Cotton (one bulk reservation)
+    let reservation = reservations
+        .try_reserve(
+            vec![(Good::Cotton, 3)],
+            0,
+            0,
+            &mut stockpile,
+            &mut workforce,
+            &mut treasury,
+        )
+        .unwrap();
+    allocations.market_sells.insert(
+        Good::Cotton,
+        MarketSellOrder {
+            reservation,
+            quantity: 3,
+        },
+    );
 
     assert_eq!(allocations.market_sell_count(Good::Cotton), 3);
     assert!(!allocations.has_buy_interest(Good::Cotton));
@@ -534,10 +546,13 @@ fn test_market_orders_mutually_exclusive() {
 
     // Simulate what apply_market_order_adjustments would do:
     // When setting buy interest, it should clear sell orders
-    if let Some(sell_orders) = allocations.market_sells.get_mut(&Good::Cotton) {
-        while let Some(res_id) = sell_orders.pop() {
-            reservations.release(res_id, &mut stockpile, &mut workforce, &mut treasury);
-        }
+    if let Some(order) = allocations.market_sells.remove(&Good::Cotton) {
+        reservations.release(
+            order.reservation,
+            &mut stockpile,
+            &mut workforce,
+            &mut treasury,
+        );
     }
 
     // Verify sell orders are now cleared and buy interest is set
@@ -546,23 +561,23 @@ fn test_market_orders_mutually_exclusive() {
     assert_eq!(stockpile.get_available(Good::Cotton), 100); // All Cotton now available
 
     // Now reverse: place sell orders again, which should clear buy interest
-    for _ in 0..2 {
-        let res_id = reservations
-            .try_reserve(
-                vec![(Good::Cotton, 1)],
-                0,
-                0,
-                &mut stockpile,
-                &mut workforce,
-                &mut treasury,
-            )
-            .unwrap();
-        allocations
-            .market_sells
-            .entry(Good::Cotton)
-            .or_default()
-            .push(res_id);
-    }
+    let reservation = reservations
+        .try_reserve(
+            vec![(Good::Cotton, 2)],
+            0,
+            0,
+            &mut stockpile,
+            &mut workforce,
+            &mut treasury,
+        )
+        .unwrap();
+    allocations.market_sells.insert(
+        Good::Cotton,
+        MarketSellOrder {
+            reservation,
+            quantity: 2,
+        },
+    );
 
     // Simulate what apply_market_order_adjustments would do:
     // When setting sell orders, it should clear buy interest
@@ -574,6 +589,184 @@ fn test_market_orders_mutually_exclusive() {
     assert_eq!(stockpile.get_available(Good::Cotton), 98); // 2 reserved for selling
 }
 
+#[test]
+fn cancelling_sell_order_returns_reserved_goods_immediately() {
+    let mut world = World::new();
+    world.insert_resource(OrdersQueue::default());
+    world.add_observer(cancel_market_order);
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Cotton, 40);
+    let nation_entity = world
+        .spawn((
+            Nation,
+            Allocations::default(),
+            ReservationSystem::default(),
+            StockpileReserves::default(),
+            stockpile,
+            Workforce::new(),
+            Treasury::new(0),
+        ))
+        .id();
+    let nation = NationInstance::from_entity(world.entity(nation_entity))
+        .expect("failed to build nation instance");
+
+    let sell = |requested| AdjustMarketOrder {
+        nation,
+        good: Good::Cotton,
+        kind: MarketInterest::Sell,
+        requested,
+    };
+    world.resource_mut::<OrdersQueue>().queue_market(sell(25));
+    world
+        .run_system_once(execute_queued_market_orders)
+        .expect("market orders should run");
+    assert_eq!(
+        world
+            .get::<Stockpile>(nation_entity)
+            .unwrap()
+            .get_available(Good::Cotton),
+        15
+    );
+
+    // A pending adjustment is dropped along with the live order
+    world.resource_mut::<OrdersQueue>().queue_market(sell(30));
+    world.trigger(CancelMarketOrder {
+        nation,
+        good: Good::Cotton,
+    });
+
+    let stockpile = world.get::<Stockpile>(nation_entity).unwrap();
+    assert_eq!(stockpile.get_available(Good::Cotton), 40);
+    assert_eq!(stockpile.get_reserved(Good::Cotton), 0);
+    let allocations = world.get::<Allocations>(nation_entity).unwrap();
+    assert_eq!(allocations.market_sell_count(Good::Cotton), 0);
+    assert!(!allocations.has_buy_interest(Good::Cotton));
+    assert!(world.resource::<OrdersQueue>().peek_market().is_empty());
+}
+
+#[test]
+fn bulk_sell_order_reserves_and_clears_like_per_unit_orders() {
+    let mut world = World::new();
+    world.insert_resource(OrdersQueue::default());
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Cotton, 80);
+    let nation_entity = world
+        .spawn((
+            Nation,
+            Allocations::default(),
+            ReservationSystem::default(),
+            StockpileReserves::default(),
+            stockpile,
+            Workforce::new(),
+            Treasury::new(0),
+        ))
+        .id();
+    let nation = NationInstance::from_entity(world.entity(nation_entity))
+        .expect("failed to build nation instance");
+
+    let sell = |world: &mut World, requested: u32| {
+        world
+            .resource_mut::<OrdersQueue>()
+            .queue_market(AdjustMarketOrder {
+                nation,
+                good: Good::Cotton,
+                kind: MarketInterest::Sell,
+                requested,
+            });
+        world
+            .run_system_once(execute_queued_market_orders)
+            .expect("market orders should run");
+    };
+
+    // Per-unit reference: 50 single-unit reservations on an identical stockpile
+    let mut reference_stockpile = Stockpile::default();
+    reference_stockpile.add(Good::Cotton, 80);
+    let mut reference_reservations = ReservationSystem::default();
+    let mut workforce = Workforce::new();
+    let mut treasury = Treasury::new(0);
+    let per_unit: Vec<_> = (0..50)
+        .map(|_| {
+            reference_reservations
+                .try_reserve(
+                    [(Good::Cotton, 1)],
+                    0,
+                    0,
+                    &mut reference_stockpile,
+                    &mut workforce,
+                    &mut treasury,
+                )
+                .expect("reference reservation")
+        })
+        .collect();
+
+    sell(&mut world, 50);
+
+    let allocations = world.get::<Allocations>(nation_entity).unwrap();
+    let stockpile = world.get::<Stockpile>(nation_entity).unwrap();
+    assert_eq!(allocations.market_sell_count(Good::Cotton), 50);
+    assert_eq!(
+        world
+            .get::<ReservationSystem>(nation_entity)
+            .unwrap()
+            .count(),
+        1
+    );
+    assert_eq!(
+        stockpile.get_reserved(Good::Cotton),
+        reference_stockpile.get_reserved(Good::Cotton)
+    );
+    assert_eq!(
+        stockpile.get_available(Good::Cotton),
+        reference_stockpile.get_available(Good::Cotton)
+    );
+
+    // Shrinking keeps a single reservation holding the remaining units
+    sell(&mut world, 20);
+    for &id in &per_unit[20..] {
+        reference_reservations.release(id, &mut reference_stockpile, &mut workforce, &mut treasury);
+    }
+    let stockpile = world.get::<Stockpile>(nation_entity).unwrap();
+    assert_eq!(
+        world
+            .get::<Allocations>(nation_entity)
+            .unwrap()
+            .market_sell_count(Good::Cotton),
+        20
+    );
+    assert_eq!(
+        stockpile.get_reserved(Good::Cotton),
+        reference_stockpile.get_reserved(Good::Cotton)
+    );
+
+    // Clearing releases everything
+    sell(&mut world, 0);
+    for &id in &per_unit[..20] {
+        reference_reservations.release(id, &mut reference_stockpile, &mut workforce, &mut treasury);
+    }
+    let stockpile = world.get::<Stockpile>(nation_entity).unwrap();
+    assert!(
+        world
+            .get::<Allocations>(nation_entity)
+            .unwrap()
+            .market_sells
+            .is_empty()
+    );
+    assert_eq!(
+        world
+            .get::<ReservationSystem>(nation_entity)
+            .unwrap()
+            .count(),
+        0
+    );
+    assert_eq!(stockpile.get_reserved(Good::Cotton), 0);
+    assert_eq!(
+        stockpile.get_available(Good::Cotton),
+        reference_stockpile.get_available(Good::Cotton)
+    );
+}
+
 #[test]
 fn execute_queued_production_orders_apply_and_clear() {
     let mut world = World::new();
@@ -586,6 +779,7 @@ fn execute_queued_production_orders_apply_and_clear() {
             Nation,
             Allocations::default(),
             ReservationSystem::default(),
+            StockpileReserves::default(),
             Stockpile::default(),
             Workforce::new(),
             Treasury::new(0),
@@ -629,11 +823,12 @@ fn execute_queued_production_orders_apply_and_clear() {
             &mut Workforce,
         )>,
         Query<&Buildings>,
+        Query<&StockpileReserves>,
     )>::new(&mut world);
 
     {
-        let (orders, nations, buildings) = system_state.get_mut(&mut world);
-        execute_queued_production_orders(orders, nations, buildings);
+        let (orders, nations, buildings, reserves) = system_state.get_mut(&mut world);
+        execute_queued_production_orders(orders, nations, buildings, reserves);
     }
     system_state.apply(&mut world);
 
@@ -657,6 +852,7 @@ fn execute_queued_production_orders_respect_building_kind_capacity() {
             Nation,
             Allocations::default(),
             ReservationSystem::default(),
+            StockpileReserves::default(),
             Stockpile::default(),
             Workforce::new(),
             Treasury::new(0),
@@ -712,11 +908,12 @@ fn execute_queued_production_orders_respect_building_kind_capacity() {
             &mut Workforce,
         )>,
         Query<&Buildings>,
+        Query<&StockpileReserves>,
     )>::new(&mut world);
 
     {
-        let (orders, nations, buildings) = system_state.get_mut(&mut world);
-        execute_queued_production_orders(orders, nations, buildings);
+        let (orders, nations, buildings, reserves) = system_state.get_mut(&mut world);
+        execute_queued_production_orders(orders, nations, buildings, reserves);
     }
     system_state.apply(&mut world);
 
@@ -732,3 +929,441 @@ fn execute_queued_production_orders_respect_building_kind_capacity() {
         2
     );
 }
+
+#[test]
+fn recruitment_respects_reserve_floor() {
+    let mut world = World::new();
+    world.insert_resource(OrdersQueue::default());
+
+    let mut reserves = StockpileReserves::default();
+    reserves.set_floor(Good::CannedFood, 5);
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::CannedFood, 7);
+    stockpile.add(Good::Clothing, 10);
+    stockpile.add(Good::Furniture, 10);
+
+    let nation_entity = world
+        .spawn((
+            Nation,
+            Allocations::default(),
+            ReservationSystem::default(),
+            stockpile,
+            reserves,
+        ))
+        .id();
+
+    // 20 provinces allow up to 5 recruits
+    for id in 0..20 {
+        let mut province = Province::new(ProvinceId(id), vec![], TilePos::new(0, 0));
+        province.owner = Some(nation_entity);
+        world.spawn(province);
+    }
+
+    let nation = NationInstance::from_entity(world.entity(nation_entity))
+        .expect("failed to build nation instance");
+    world
+        .resource_mut::<OrdersQueue>()
+        .queue_recruitment(AdjustRecruitment {
+            nation,
+            requested: 5,
+        });
+    world
+        .run_system_once(execute_queued_recruitment_orders)
+        .expect("recruitment orders should run");
+
+    // Only the 2 units above the floor may be committed; the last 5 stay untouched
+    let allocations = world.get::<Allocations>(nation_entity).unwrap();
+    assert_eq!(allocations.recruitment_count(), 2);
+    let stockpile = world.get::<Stockpile>(nation_entity).unwrap();
+    assert_eq!(stockpile.get_available(Good::CannedFood), 5);
+    assert_eq!(stockpile.get_available(Good::Clothing), 8);
+}
+
+#[test]
+fn auto_assign_moves_idle_labor_into_under_allocated_building() {
+    let mut world = World::new();
+    world.insert_resource(OrdersQueue::default());
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Iron, 10);
+    stockpile.add(Good::Coal, 10);
+    let mut workforce = Workforce::new();
+    workforce.add_untrained(10);
+    workforce.update_labor_pool();
+    let nation_entity = world
+        .spawn((
+            Nation,
+            Allocations::default(),
+            ReservationSystem::default(),
+            StockpileReserves::default(),
+            stockpile,
+            workforce,
+            Buildings::with_all_initial(),
+        ))
+        .id();
+    let nation = NationInstance::from_entity(world.entity(nation_entity))
+        .expect("failed to build nation instance");
+
+    let run_orders = |world: &mut World, orders: Vec<AdjustProduction>| {
+        for order in orders {
+            world.resource_mut::<OrdersQueue>().queue_production(order);
+        }
+        world
+            .run_system_once(execute_queued_production_orders)
+            .expect("production orders should run");
+    };
+
+    // The steel mill (capacity 4) is allocated a single unit and the rest of the labor idles
+    run_orders(
+        &mut world,
+        vec![AdjustProduction {
+            nation,
+            building: nation_entity,
+            output_good: Good::Steel,
+            target_output: 1,
+        }],
+    );
+
+    let entity = world.entity(nation_entity);
+    let requests = auto_assign_idle_labor(
+        nation,
+        entity.get::<Workforce>().unwrap(),
+        entity.get::<Allocations>().unwrap(),
+        entity.get::<Buildings>().unwrap(),
+        entity.get::<Stockpile>().unwrap(),
+    );
+    // Only the steel mill has inputs on hand
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].output_good, Good::Steel);
+    assert_eq!(requests[0].target_output, 4);
+
+    run_orders(&mut world, requests);
+
+    let allocations = world.get::<Allocations>(nation_entity).unwrap();
+    assert_eq!(allocations.production_count(nation_entity, Good::Steel), 4);
+    let workforce = world.get::<Workforce>(nation_entity).unwrap();
+    assert_eq!(workforce.labor_pool.available(), 6);
+}
+
+#[test]
+fn training_is_capped_by_trade_school_capacity() {
+    let mut world = World::new();
+    world.insert_resource(OrdersQueue::default());
+    world.init_resource::<Messages<FinancialRejection>>();
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Paper, 10);
+    let mut workforce = Workforce::new();
+    workforce.add_untrained(6);
+    workforce.update_labor_pool();
+    let mut buildings = Buildings::new();
+    buildings.insert(Building::trade_school(2));
+    let nation_entity = world
+        .spawn((
+            Nation,
+            Allocations::default(),
+            ReservationSystem::default(),
+            StockpileReserves::default(),
+            stockpile,
+            workforce,
+            Treasury::new(10_000),
+            buildings,
+        ))
+        .id();
+    let nation = NationInstance::from_entity(world.entity(nation_entity))
+        .expect("failed to build nation instance");
+
+    // Six untrained workers, paper and cash for all of them, but room for two
+    world
+        .resource_mut::<OrdersQueue>()
+        .queue_training(AdjustTraining {
+            nation,
+            from_skill: WorkerSkill::Untrained,
+            requested: 5,
+        });
+    world
+        .run_system_once(execute_queued_training_orders)
+        .expect("training orders should run");
+
+    let allocations = world.get::<Allocations>(nation_entity).unwrap();
+    assert_eq!(allocations.training_count(WorkerSkill::Untrained), 2);
+    let workforce = world.get::<Workforce>(nation_entity).unwrap();
+    assert_eq!(
+        workforce.labor_pool.available(),
+        4,
+        "each trainee needs staff"
+    );
+    assert_eq!(
+        world
+            .get::<Stockpile>(nation_entity)
+            .unwrap()
+            .get_available(Good::Paper),
+        8
+    );
+}
+
+#[test]
+fn training_without_cash_reports_a_financial_rejection() {
+    let mut world = World::new();
+    world.insert_resource(OrdersQueue::default());
+    world.init_resource::<Messages<FinancialRejection>>();
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Paper, 10);
+    let mut workforce = Workforce::new();
+    workforce.add_untrained(4);
+    workforce.update_labor_pool();
+    let mut buildings = Buildings::new();
+    buildings.insert(Building::trade_school(4));
+    let nation_entity = world
+        .spawn((
+            Nation,
+            Allocations::default(),
+            ReservationSystem::default(),
+            StockpileReserves::default(),
+            stockpile,
+            workforce,
+            Treasury::new(150),
+            buildings,
+        ))
+        .id();
+    let nation = NationInstance::from_entity(world.entity(nation_entity))
+        .expect("failed to build nation instance");
+
+    // Cash for one trainee only
+    world
+        .resource_mut::<OrdersQueue>()
+        .queue_training(AdjustTraining {
+            nation,
+            from_skill: WorkerSkill::Untrained,
+            requested: 3,
+        });
+    world
+        .run_system_once(execute_queued_training_orders)
+        .expect("training orders should run");
+
+    let allocations = world.get::<Allocations>(nation_entity).unwrap();
+    assert_eq!(allocations.training_count(WorkerSkill::Untrained), 1);
+    let rejections: Vec<FinancialRejection> = world
+        .resource_mut::<Messages<FinancialRejection>>()
+        .drain()
+        .collect();
+    assert_eq!(
+        rejections,
+        vec![FinancialRejection {
+            nation: nation_entity,
+            action: FinancialAction::Training,
+            cost: 100,
+            shortfall: 50,
+        }]
+    );
+}
+
+#[test]
+fn recruitment_is_capped_by_capitol_capacity() {
+    let mut world = World::new();
+    world.insert_resource(OrdersQueue::default());
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::CannedFood, 10);
+    stockpile.add(Good::Clothing, 10);
+    stockpile.add(Good::Furniture, 10);
+    let mut buildings = Buildings::new();
+    buildings.insert(Building::capitol(2));
+    let nation_entity = world
+        .spawn((
+            Nation,
+            Allocations::default(),
+            ReservationSystem::default(),
+            StockpileReserves::default(),
+            stockpile,
+            buildings,
+            RecruitmentCapacity::default(),
+            Treasury::new(1_000),
+        ))
+        .id();
+
+    // 20 provinces would allow 5 recruits
+    for id in 0..20 {
+        let mut province = Province::new(ProvinceId(id), vec![], TilePos::new(0, 0));
+        province.owner = Some(nation_entity);
+        world.spawn(province);
+    }
+
+    let nation = NationInstance::from_entity(world.entity(nation_entity))
+        .expect("failed to build nation instance");
+    let recruit = |world: &mut World| {
+        world
+            .resource_mut::<OrdersQueue>()
+            .queue_recruitment(AdjustRecruitment {
+                nation,
+                requested: 5,
+            });
+        world
+            .run_system_once(execute_queued_recruitment_orders)
+            .expect("recruitment orders should run");
+        world
+            .get::<Allocations>(nation_entity)
+            .unwrap()
+            .recruitment_count()
+    };
+
+    assert_eq!(recruit(&mut world), 2);
+
+    // An upgrade the treasury cannot cover is refused
+    world.init_resource::<Messages<FinancialRejection>>();
+    world.add_observer(upgrade_capitol);
+    world.trigger(UpgradeCapitol { nation });
+    assert_eq!(recruit(&mut world), 2);
+    assert_eq!(world.resource::<Messages<FinancialRejection>>().len(), 1);
+
+    // Upgrading the Capitol raises the cap to its new capacity (20/3 provinces allow 6)
+    world
+        .get_mut::<Treasury>(nation_entity)
+        .unwrap()
+        .add(CAPITOL_UPGRADE_COST);
+    world.trigger(UpgradeCapitol { nation });
+    assert!(
+        world
+            .get::<RecruitmentCapacity>(nation_entity)
+            .unwrap()
+            .upgraded
+    );
+    assert_eq!(world.get::<Treasury>(nation_entity).unwrap().total(), 1_000);
+    assert_eq!(recruit(&mut world), UPGRADED_CAPITOL_CAPACITY);
+}
+
+#[test]
+fn finalized_production_turns_reserved_inputs_into_outputs() {
+    let mut world = World::new();
+    world.insert_resource(OrdersQueue::default());
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Iron, 3);
+    stockpile.add(Good::Coal, 3);
+    let mut workforce = Workforce::new();
+    workforce.add_untrained(5);
+    workforce.update_labor_pool();
+    let nation_entity = world
+        .spawn((
+            Nation,
+            Allocations::default(),
+            ReservationSystem::default(),
+            StockpileReserves::default(),
+            stockpile,
+            workforce,
+            Treasury::new(0),
+            RecruitmentQueue::default(),
+            TrainingQueue::default(),
+            Buildings::with_all_initial(),
+            ProductionResult::default(),
+        ))
+        .id();
+    let nation = NationInstance::from_entity(world.entity(nation_entity))
+        .expect("failed to build nation instance");
+
+    world
+        .resource_mut::<OrdersQueue>()
+        .queue_production(AdjustProduction {
+            nation,
+            building: nation_entity,
+            output_good: Good::Steel,
+            target_output: 2,
+        });
+    world
+        .run_system_once(execute_queued_production_orders)
+        .expect("production orders should run");
+    assert_eq!(
+        world
+            .get::<Stockpile>(nation_entity)
+            .unwrap()
+            .get(Good::Steel),
+        0,
+        "allocating commits nothing"
+    );
+
+    world
+        .run_system_once(finalize_allocations)
+        .expect("finalize should run");
+
+    let stockpile = world.get::<Stockpile>(nation_entity).unwrap();
+    assert_eq!(stockpile.get(Good::Steel), 2);
+    assert_eq!(stockpile.get(Good::Iron), 1);
+    assert_eq!(stockpile.get(Good::Coal), 1);
+    assert_eq!(
+        stockpile.get_available(Good::Iron),
+        1,
+        "nothing left reserved"
+    );
+    assert_eq!(
+        world
+            .get::<ReservationSystem>(nation_entity)
+            .unwrap()
+            .count(),
+        0
+    );
+
+    let report = world
+        .get::<ProductionResult>(nation_entity)
+        .unwrap()
+        .get(BuildingKind::SteelMill)
+        .copied()
+        .expect("steel mill result recorded");
+    assert_eq!(report.target, 2);
+    assert_eq!(report.produced, 2);
+}
+
+#[test]
+fn higher_priority_building_reserves_scarce_inputs_first() {
+    let mut world = World::new();
+    world.insert_resource(OrdersQueue::default());
+
+    // Two steel feed either one MetalWorks unit or two Railyard units
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Steel, 2);
+    stockpile.add(Good::Lumber, 2);
+    let mut workforce = Workforce::new();
+    workforce.add_untrained(5);
+    workforce.update_labor_pool();
+    let mut buildings = Buildings::with_all_initial();
+    buildings.set_priority(BuildingKind::MetalWorks, 1);
+    let nation_entity = world
+        .spawn((
+            Nation,
+            Allocations::default(),
+            ReservationSystem::default(),
+            StockpileReserves::default(),
+            stockpile,
+            workforce,
+            buildings,
+        ))
+        .id();
+    let nation = NationInstance::from_entity(world.entity(nation_entity))
+        .expect("failed to build nation instance");
+
+    // The Railyard is queued first, but the MetalWorks outranks it
+    for (output_good, target_output) in [(Good::Transport, 2), (Good::Hardware, 1)] {
+        world
+            .resource_mut::<OrdersQueue>()
+            .queue_production(AdjustProduction {
+                nation,
+                building: nation_entity,
+                output_good,
+                target_output,
+            });
+    }
+    world
+        .run_system_once(execute_queued_production_orders)
+        .expect("production orders should run");
+
+    let allocations = world.get::<Allocations>(nation_entity).unwrap();
+    assert_eq!(
+        allocations.production_count(nation_entity, Good::Hardware),
+        1
+    );
+    assert_eq!(
+        allocations.production_count(nation_entity, Good::Transport),
+        0
+    );
+}
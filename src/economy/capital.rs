@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::TilePos;
+
+use crate::economy::nation::{Capital, CapitalLost};
+use crate::economy::transport::{RailConnectivityCache, RecomputeConnectivity};
+use crate::economy::treasury::{FinancialAction, FinancialRejection, Treasury};
+use crate::map::province::{City, Province};
+use crate::messages::RelocateCapital;
+
+/// Money charged for moving the capital
+pub const CAPITAL_RELOCATION_COST: i64 = 5_000;
+
+/// Observer: move a nation's capital to another city it owns.
+/// The capital roots the rail network, so connectivity is recomputed from the new site.
+pub fn relocate_capital(
+    trigger: On<RelocateCapital>,
+    mut commands: Commands,
+    mut nations: Query<(&mut Capital, &mut Treasury)>,
+    mut cities: Query<(&mut City, &TilePos)>,
+    provinces: Query<&Province>,
+    mut cache: Option<ResMut<RailConnectivityCache>>,
+    mut rejections: MessageWriter<FinancialRejection>,
+) {
+    let RelocateCapital { nation, to } = *trigger.event();
+    let nation_entity = nation.entity();
+
+    let Ok((mut capital, mut treasury)) = nations.get_mut(nation_entity) else {
+        return;
+    };
+    if capital.0 == to {
+        return;
+    }
+
+    let owns_city_at_target = cities.iter().any(|(city, pos)| {
+        *pos == to
+            && provinces
+                .get(city.province_entity)
+                .is_ok_and(|province| province.owner == Some(nation_entity))
+    });
+    if !owns_city_at_target {
+        info!(
+            "Capital relocation rejected: no city owned by {:?} at ({}, {})",
+            nation_entity, to.x, to.y
+        );
+        return;
+    }
+    if treasury.available() < CAPITAL_RELOCATION_COST {
+        info!(
+            "Capital relocation rejected: {:?} cannot afford ${}",
+            nation_entity, CAPITAL_RELOCATION_COST
+        );
+        rejections.write(FinancialRejection::new(
+            nation_entity,
+            FinancialAction::CapitalRelocation,
+            CAPITAL_RELOCATION_COST,
+            treasury.available(),
+        ));
+        return;
+    }
+
+    treasury.subtract(CAPITAL_RELOCATION_COST);
+    let old = capital.0;
+    capital.0 = to;
+
+    for (mut city, pos) in cities.iter_mut() {
+        if *pos == old && city.is_capital {
+            city.is_capital = false;
+        } else if *pos == to {
+            city.is_capital = true;
+        }
+    }
+
+    if let Some(cache) = cache.as_mut() {
+        cache.invalidate();
+    }
+    commands.trigger(RecomputeConnectivity);
+    info!(
+        "{:?} moved its capital from ({}, {}) to ({}, {})",
+        nation_entity, old.x, old.y, to.x, to.y
+    );
+}
+
+/// Take the capital away from any nation whose capital tile lies in a province
+/// another nation now owns. The nation is marked [`CapitalLost`] and connectivity
+/// is recomputed, so its rail network stops feeding it production.
+pub fn revoke_captured_capitals(
+    mut commands: Commands,
+    nations: Query<(Entity, &Capital)>,
+    provinces: Query<&Province>,
+    mut cities: Query<(&mut City, &TilePos)>,
+    mut cache: Option<ResMut<RailConnectivityCache>>,
+) {
+    let mut revoked = false;
+    for (nation, capital) in nations.iter() {
+        let captured = provinces
+            .iter()
+            .find(|province| province.tiles.contains(&capital.0))
+            .is_some_and(|province| province.owner != Some(nation));
+        if !captured {
+            continue;
+        }
+
+        for (mut city, pos) in cities.iter_mut() {
+            if *pos == capital.0 {
+                city.is_capital = false;
+            }
+        }
+        commands
+            .entity(nation)
+            .remove::<Capital>()
+            .insert(CapitalLost);
+        revoked = true;
+        info!(
+            "{:?} lost its capital at ({}, {})",
+            nation, capital.0.x, capital.0.y
+        );
+    }
+
+    if revoked {
+        if let Some(cache) = cache.as_mut() {
+            cache.invalidate();
+        }
+        commands.trigger(RecomputeConnectivity);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,178 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::TilePos;
+
+use crate::civilians::types::ProspectingKnowledge;
+use crate::economy::capital::{
+    CAPITAL_RELOCATION_COST, relocate_capital, revoke_captured_capitals,
+};
+use crate::economy::goods::Good;
+use crate::economy::nation::{Capital, CapitalLost, Nation, NationInstance};
+use crate::economy::production::{
+    ConnectedProduction, calculate_connected_production, collect_connected_production,
+};
+use crate::economy::stockpile::Stockpile;
+use crate::economy::transport::{
+    Depot, RailConnectivityCache, Rails, RecomputeConnectivity, TransportAllocations,
+    TransportCommodity, compute_rail_connectivity, ordered_edge,
+};
+use crate::economy::treasury::{FinancialRejection, Treasury};
+use crate::map::province::{City, Province, ProvinceId};
+use crate::map::tiles::TerrainType;
+use crate::messages::RelocateCapital;
+use crate::resources::{ResourceType, TileResource};
+use crate::test_utils::{create_test_tile, create_test_tilemap};
+
+fn spawn_city(world: &mut World, id: u32, pos: TilePos, owner: Entity, is_capital: bool) {
+    let mut province = Province::new(ProvinceId(id), vec![pos], pos);
+    province.owner = Some(owner);
+    let province_entity = world.spawn(province).id();
+    world.spawn((
+        City {
+            province: ProvinceId(id),
+            province_entity,
+            is_capital,
+        },
+        pos,
+    ));
+}
+
+fn connected(world: &World, depot: Entity) -> bool {
+    world.get::<Depot>(depot).unwrap().connected
+}
+
+#[test]
+fn relocating_capital_reroots_depot_connectivity() {
+    let mut world = World::new();
+    world.init_resource::<RailConnectivityCache>();
+    world.init_resource::<Rails>();
+    world.init_resource::<Messages<FinancialRejection>>();
+    world.add_observer(compute_rail_connectivity);
+    world.add_observer(relocate_capital);
+
+    let old_capital = TilePos { x: 0, y: 0 };
+    let new_capital = TilePos { x: 10, y: 0 };
+    let nation = world
+        .spawn((Nation, Capital(old_capital), Treasury::new(10_000)))
+        .id();
+    spawn_city(&mut world, 0, old_capital, nation, true);
+    spawn_city(&mut world, 1, new_capital, nation, false);
+
+    // Two separate rail lines, one from each city
+    {
+        let mut rails = world.resource_mut::<Rails>();
+        rails
+            .0
+            .insert(ordered_edge(old_capital, TilePos { x: 1, y: 0 }));
+        rails
+            .0
+            .insert(ordered_edge(new_capital, TilePos { x: 9, y: 0 }));
+    }
+    let near_old = world
+        .spawn(Depot {
+            position: TilePos { x: 1, y: 0 },
+            owner: nation,
+            connected: false,
+        })
+        .id();
+    let near_new = world
+        .spawn(Depot {
+            position: TilePos { x: 9, y: 0 },
+            owner: nation,
+            connected: false,
+        })
+        .id();
+    world.trigger(RecomputeConnectivity);
+    assert!(connected(&world, near_old));
+    assert!(!connected(&world, near_new));
+
+    let instance = NationInstance::from_entity(world.entity(nation)).unwrap();
+    world.trigger(RelocateCapital {
+        nation: instance,
+        to: new_capital,
+    });
+    world.flush();
+
+    assert_eq!(world.get::<Capital>(nation).unwrap().0, new_capital);
+    assert_eq!(
+        world.get::<Treasury>(nation).unwrap().total(),
+        10_000 - CAPITAL_RELOCATION_COST
+    );
+    assert!(!connected(&world, near_old));
+    assert!(connected(&world, near_new));
+
+    // Relocating to a tile without an owned city is refused
+    world.trigger(RelocateCapital {
+        nation: instance,
+        to: TilePos { x: 5, y: 5 },
+    });
+    world.flush();
+    assert_eq!(world.get::<Capital>(nation).unwrap().0, new_capital);
+}
+
+#[test]
+fn captured_capital_stops_connected_production() {
+    let mut world = World::new();
+    world.init_resource::<RailConnectivityCache>();
+    world.init_resource::<Rails>();
+    world.init_resource::<ConnectedProduction>();
+    world.init_resource::<ProspectingKnowledge>();
+    world.init_resource::<TransportAllocations>();
+    world.add_observer(compute_rail_connectivity);
+    world.add_observer(calculate_connected_production);
+
+    let capital = TilePos { x: 1, y: 1 };
+    let (tilemap, mut storage) = create_test_tilemap(&mut world, 3, 3);
+    let field = create_test_tile(
+        &mut world,
+        TilePos { x: 1, y: 2 },
+        TerrainType::Farmland,
+        tilemap,
+        &mut storage,
+    );
+    world
+        .entity_mut(field)
+        .insert(TileResource::visible(ResourceType::Grain));
+    world.entity_mut(tilemap).insert(storage);
+
+    let nation = world
+        .spawn((Nation, Capital(capital), Stockpile::default()))
+        .id();
+    let conqueror = world.spawn(Nation).id();
+    spawn_city(&mut world, 0, capital, nation, true);
+    {
+        let mut allocations = world.resource_mut::<TransportAllocations>();
+        let slot = allocations
+            .ensure_nation(nation)
+            .slot_mut(TransportCommodity::Grain);
+        slot.requested = 5;
+        slot.granted = 5;
+    }
+    world.trigger(RecomputeConnectivity);
+
+    let _ = world.run_system_once(collect_connected_production);
+    let grain = |world: &World| world.get::<Stockpile>(nation).unwrap().get(Good::Grain);
+    assert_eq!(grain(&world), 1, "capital-adjacent field yields grain");
+
+    // Keeping the capital changes nothing
+    let _ = world.run_system_once(revoke_captured_capitals);
+    assert!(world.get::<Capital>(nation).is_some());
+
+    let mut provinces = world.query::<&mut Province>();
+    for mut province in provinces.iter_mut(&mut world) {
+        province.owner = Some(conqueror);
+    }
+    let _ = world.run_system_once(revoke_captured_capitals);
+
+    assert!(world.get::<Capital>(nation).is_none());
+    assert!(world.get::<CapitalLost>(nation).is_some());
+    assert!(
+        world
+            .resource::<ConnectedProduction>()
+            .totals
+            .get(&nation)
+            .is_none()
+    );
+    let _ = world.run_system_once(collect_connected_production);
+    assert_eq!(grain(&world), 1, "no more production once the capital fell");
+}
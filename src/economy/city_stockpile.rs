@@ -0,0 +1,275 @@
+use bevy::prelude::*;
+
+use crate::economy::nation::Nation;
+use crate::economy::stockpile::Stockpile;
+use crate::economy::transport::{TransportAllocations, TransportCapacity};
+use crate::map::province::{City, Province};
+use crate::messages::TransferGoods;
+
+/// Transport capacity one unit of goods takes up when moved between cities
+pub const TRANSFER_CAPACITY_PER_UNIT: u32 = 1;
+
+/// Goods stored in a single city, separate from the nation's own stockpile.
+/// The capital keeps no stock of its own: its goods are the nation's [`Stockpile`].
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct CityStockpile(pub Stockpile);
+
+/// Observer: move goods between two cities of the same nation.
+/// The shipment is booked against the nation's transport allocations for the turn;
+/// transfers that would exceed its idle capacity, or that touch a city the nation
+/// does not own, are refused. Shipping from or to the capital draws on or fills the
+/// nation's stockpile.
+pub fn transfer_between_cities(
+    trigger: On<TransferGoods>,
+    mut cities: Query<(&City, &mut CityStockpile)>,
+    provinces: Query<&Province>,
+    mut nations: Query<&mut Stockpile, With<Nation>>,
+    mut capacity: ResMut<TransportCapacity>,
+    mut allocations: ResMut<TransportAllocations>,
+) {
+    let TransferGoods {
+        nation,
+        from,
+        to,
+        good,
+        amount,
+    } = *trigger.event();
+    let nation = nation.entity();
+    if from == to || amount == 0 {
+        return;
+    }
+
+    let owned = |city: Entity| {
+        cities.get(city).is_ok_and(|(city, _)| {
+            provinces
+                .get(city.province_entity)
+                .is_ok_and(|province| province.owner == Some(nation))
+        })
+    };
+    if !owned(from) || !owned(to) {
+        info!("Transfer rejected: {:?} does not own both cities", nation);
+        return;
+    }
+
+    let cost = amount * TRANSFER_CAPACITY_PER_UNIT;
+    let total = capacity.snapshot(nation).total;
+    let nation_alloc = allocations.ensure_nation(nation);
+    let idle = total.saturating_sub(nation_alloc.regrant(total));
+    if cost > idle {
+        info!(
+            "Transfer rejected: moving {} {:?} needs {} transport capacity, {} idle",
+            amount, good, cost, idle
+        );
+        return;
+    }
+
+    let Ok([(from_city, mut from_store), (to_city, mut to_store)]) =
+        cities.get_many_mut([from, to])
+    else {
+        return;
+    };
+    let Ok(mut national) = nations.get_mut(nation) else {
+        return;
+    };
+
+    let source = if from_city.is_capital {
+        &mut *national
+    } else {
+        &mut from_store.0
+    };
+    if !source.has_available(good, amount) {
+        info!(
+            "Transfer rejected: only {} {:?} available to move",
+            source.get_available(good),
+            good
+        );
+        return;
+    }
+    source.take_up_to(good, amount);
+
+    let destination = if to_city.is_capital {
+        &mut *national
+    } else {
+        &mut to_store.0
+    };
+    destination.add(good, amount);
+
+    nation_alloc.transfers += cost;
+    capacity.snapshot_mut(nation).used = nation_alloc.regrant(total);
+    debug!("{:?} moved {} {:?} between cities", nation, amount, good);
+}
+
+/// Free the capacity spent on city transfers when a new turn starts
+pub fn reset_city_transfers(
+    mut capacity: ResMut<TransportCapacity>,
+    mut allocations: ResMut<TransportAllocations>,
+) {
+    for (nation, nation_alloc) in allocations.nations.iter_mut() {
+        if nation_alloc.transfers == 0 {
+            continue;
+        }
+        nation_alloc.transfers = 0;
+        let total = capacity.snapshot(*nation).total;
+        capacity.snapshot_mut(*nation).used = nation_alloc.regrant(total);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use bevy_ecs_tilemap::prelude::TilePos;
+
+    use crate::economy::city_stockpile::{
+        CityStockpile, reset_city_transfers, transfer_between_cities,
+    };
+    use crate::economy::goods::Good;
+    use crate::economy::nation::{Nation, NationInstance};
+    use crate::economy::stockpile::Stockpile;
+    use crate::economy::transport::{TransportAllocations, TransportCapacity, TransportCommodity};
+    use crate::map::province::{City, Province, ProvinceId};
+    use crate::messages::TransferGoods;
+
+    fn spawn_city(world: &mut World, id: u32, owner: Entity, is_capital: bool) -> Entity {
+        let pos = TilePos { x: id, y: 0 };
+        let mut province = Province::new(ProvinceId(id), vec![pos], pos);
+        province.owner = Some(owner);
+        let province_entity = world.spawn(province).id();
+        world
+            .spawn((
+                City {
+                    province: ProvinceId(id),
+                    province_entity,
+                    is_capital,
+                },
+                pos,
+            ))
+            .id()
+    }
+
+    fn coal(world: &World, city: Entity) -> u32 {
+        world.get::<CityStockpile>(city).unwrap().0.get(Good::Coal)
+    }
+
+    fn used(world: &World, nation: Entity) -> u32 {
+        world.resource::<TransportCapacity>().snapshot(nation).used
+    }
+
+    fn setup() -> (World, Entity, NationInstance) {
+        let mut world = World::new();
+        world.init_resource::<TransportCapacity>();
+        world.init_resource::<TransportAllocations>();
+        world.add_observer(transfer_between_cities);
+
+        let nation = world.spawn((Nation, Stockpile::default())).id();
+        // Five capacity, two of it hauling coal
+        world
+            .resource_mut::<TransportCapacity>()
+            .snapshot_mut(nation)
+            .total = 5;
+        world
+            .resource_mut::<TransportAllocations>()
+            .ensure_nation(nation)
+            .slot_mut(TransportCommodity::Coal)
+            .requested = 2;
+        let instance = NationInstance::from_entity(world.entity(nation)).unwrap();
+        (world, nation, instance)
+    }
+
+    #[test]
+    fn transfer_between_owned_cities_spends_transport_capacity() {
+        let (mut world, nation, instance) = setup();
+        let rival = world.spawn(Nation).id();
+        let mine = spawn_city(&mut world, 0, nation, false);
+        let port = spawn_city(&mut world, 1, nation, false);
+        let foreign = spawn_city(&mut world, 2, rival, false);
+        world
+            .get_mut::<CityStockpile>(mine)
+            .unwrap()
+            .0
+            .add(Good::Coal, 5);
+        let transfer = |from, to, amount| TransferGoods {
+            nation: instance,
+            from,
+            to,
+            good: Good::Coal,
+            amount,
+        };
+
+        world.trigger(transfer(mine, port, 2));
+        assert_eq!(coal(&world, mine), 3);
+        assert_eq!(coal(&world, port), 2);
+        assert_eq!(used(&world, nation), 4);
+        assert_eq!(
+            world.resource::<TransportAllocations>().nations[&nation].transfers,
+            2
+        );
+
+        // Only one unit of capacity is left idle
+        world.trigger(transfer(mine, port, 2));
+        assert_eq!(coal(&world, mine), 3);
+        assert_eq!(coal(&world, port), 2);
+
+        // Goods cannot be shipped into another nation's city
+        world.trigger(transfer(mine, foreign, 1));
+        assert_eq!(coal(&world, foreign), 0);
+
+        world.trigger(transfer(mine, port, 1));
+        assert_eq!(coal(&world, mine), 2);
+        assert_eq!(coal(&world, port), 3);
+        assert_eq!(used(&world, nation), 5);
+
+        // Re-granting the coal allocation keeps the capacity spent on transfers
+        let total = world.resource::<TransportCapacity>().snapshot(nation).total;
+        let mut allocations = world.resource_mut::<TransportAllocations>();
+        let nation_alloc = allocations.ensure_nation(nation);
+        nation_alloc.slot_mut(TransportCommodity::Coal).requested = 5;
+        assert_eq!(nation_alloc.regrant(total), 5);
+        assert_eq!(nation_alloc.slot(TransportCommodity::Coal).granted, 2);
+
+        // A new turn frees the transfer capacity for the coal allocation again
+        world.run_system_once(reset_city_transfers).unwrap();
+        assert_eq!(used(&world, nation), 5);
+        assert_eq!(
+            world
+                .resource::<TransportAllocations>()
+                .slot(nation, TransportCommodity::Coal)
+                .granted,
+            5
+        );
+    }
+
+    #[test]
+    fn capital_ships_from_and_to_the_nation_stockpile() {
+        let (mut world, nation, instance) = setup();
+        let capital = spawn_city(&mut world, 0, nation, true);
+        let port = spawn_city(&mut world, 1, nation, false);
+        world
+            .get_mut::<Stockpile>(nation)
+            .unwrap()
+            .add(Good::Coal, 3);
+
+        world.trigger(TransferGoods {
+            nation: instance,
+            from: capital,
+            to: port,
+            good: Good::Coal,
+            amount: 2,
+        });
+        assert_eq!(world.get::<Stockpile>(nation).unwrap().get(Good::Coal), 1);
+        assert_eq!(coal(&world, port), 2);
+        assert_eq!(coal(&world, capital), 0);
+
+        world.trigger(TransferGoods {
+            nation: instance,
+            from: port,
+            to: capital,
+            good: Good::Coal,
+            amount: 1,
+        });
+        assert_eq!(world.get::<Stockpile>(nation).unwrap().get(Good::Coal), 2);
+        assert_eq!(coal(&world, port), 1);
+        assert_eq!(used(&world, nation), 5);
+    }
+}
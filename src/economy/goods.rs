@@ -40,7 +40,43 @@ pub enum Good {
     Transport, // Freight cars for moving goods
 }
 
+/// Number of `Good` variants
+pub const GOOD_COUNT: usize = Good::ALL.len();
+
 impl Good {
+    /// Every good in declaration order
+    pub const ALL: [Good; 24] = [
+        Good::Grain,
+        Good::Fruit,
+        Good::Livestock,
+        Good::Fish,
+        Good::Cotton,
+        Good::Wool,
+        Good::Timber,
+        Good::Coal,
+        Good::Iron,
+        Good::Gold,
+        Good::Gems,
+        Good::Oil,
+        Good::Fabric,
+        Good::Paper,
+        Good::Lumber,
+        Good::Steel,
+        Good::Fuel,
+        Good::Clothing,
+        Good::Furniture,
+        Good::Hardware,
+        Good::Arms,
+        Good::CannedFood,
+        Good::Horses,
+        Good::Transport,
+    ];
+
+    /// Position of this good in [`Good::ALL`], used to index dense per-good arrays
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
     /// Returns true if this is a raw food resource (Grain, Fruit, Livestock, Fish)
     pub fn is_raw_food(self) -> bool {
         matches!(
@@ -2,6 +2,7 @@ use bevy::prelude::Resource;
 use std::collections::HashMap;
 
 use crate::economy::Good;
+use crate::economy::trade_capacity::TradeCapacitySnapshot;
 
 /// List of tradable resources currently exposed in the market UI.
 pub const MARKET_RESOURCES: &[Good] = &[
@@ -11,6 +12,7 @@ pub const MARKET_RESOURCES: &[Good] = &[
     Good::Fish,
     Good::Cotton,
     Good::Wool,
+    Good::Fabric,
     Good::Timber,
     Good::Coal,
     Good::Iron,
@@ -19,6 +21,48 @@ pub const MARKET_RESOURCES: &[Good] = &[
     Good::Oil,
 ];
 
+/// How strongly the clearing price responds to the demand/supply ratio
+const PRICE_ELASTICITY: f32 = 0.5;
+
+/// Fraction of the gap to the equilibrium price closed each turn
+const PRICE_DAMPING: f32 = 0.5;
+
+/// Extra share of the market price paid by a nation whose cargo holds are all in use
+pub const MAX_TRANSPORT_SURCHARGE: f32 = 0.5;
+
+/// Price a nation effectively pays for goods delivered at `market_price`.
+///
+/// Shipping competes for the same cargo holds as every other trade, so the
+/// surcharge grows with trade capacity utilization: a nation with spare holds
+/// pays the market price, a fully booked one pays up to
+/// `MAX_TRANSPORT_SURCHARGE` more.
+pub fn delivered_price(market_price: u32, capacity: TradeCapacitySnapshot) -> u32 {
+    surcharged_price(market_price, capacity, MAX_TRANSPORT_SURCHARGE)
+}
+
+/// Share of the transport surcharge still paid on goods from a trade agreement partner
+pub const TRADE_AGREEMENT_SURCHARGE_SHARE: f32 = 0.5;
+
+/// Like [`delivered_price`], but for goods bought from a specific partner.
+/// A trade agreement lets both sides share shipping, cutting the surcharge.
+pub fn partner_delivered_price(
+    market_price: u32,
+    capacity: TradeCapacitySnapshot,
+    trade_agreement: bool,
+) -> u32 {
+    let max_surcharge = if trade_agreement {
+        MAX_TRANSPORT_SURCHARGE * TRADE_AGREEMENT_SURCHARGE_SHARE
+    } else {
+        MAX_TRANSPORT_SURCHARGE
+    };
+    surcharged_price(market_price, capacity, max_surcharge)
+}
+
+fn surcharged_price(market_price: u32, capacity: TradeCapacitySnapshot, max_surcharge: f32) -> u32 {
+    let surcharge = market_price as f32 * max_surcharge * capacity.utilization();
+    market_price + surcharge.round() as u32
+}
+
 /// Aggregated supply and demand information for a single good during a market
 /// clearing pass.
 #[derive(Debug, Clone, Copy, Default)]
@@ -47,6 +91,8 @@ pub struct MarketPriceModel {
     base_prices: HashMap<Good, u32>,
     /// Track last turn's supply/demand for each good (for logging/debugging)
     last_volumes: HashMap<Good, MarketVolume>,
+    /// Clearing price computed from last turn's supply/demand
+    last_equilibria: HashMap<Good, u32>,
 }
 
 impl Default for MarketPriceModel {
@@ -54,6 +100,7 @@ impl Default for MarketPriceModel {
         Self {
             base_prices: default_price_table(),
             last_volumes: HashMap::new(),
+            last_equilibria: HashMap::new(),
         }
     }
 }
@@ -86,46 +133,60 @@ impl MarketPriceModel {
         self.base_prices.insert(good, price.max(1));
     }
 
-    /// Updates the base price for a good based on observed supply and demand.
+    /// Price at which this turn's aggregate buy and sell interest would clear.
     ///
-    /// Per the Imperialism manual: "If demand is stronger than supply, price rises.
-    /// If supply exceeds demand, price falls. If balanced, price stays similar."
-    ///
-    /// The adjustment uses a gradual formula to prevent wild price swings:
-    /// - Maximum adjustment per turn is ±12.5% of the current price
-    /// - Price floors at 20% of original and caps at 300% of original
-    pub fn update_price_from_volume(&mut self, good: Good, volume: MarketVolume) {
-        self.last_volumes.insert(good, volume);
-
+    /// Demand is assumed to respond to price with constant elasticity, so the
+    /// clearing price scales the good's reference price by
+    /// `(demand / supply) ^ PRICE_ELASTICITY`, clamped to 20%-300% of it.
+    /// Returns `None` when there was no market activity.
+    pub fn equilibrium_price(&self, good: Good, volume: MarketVolume) -> Option<u32> {
         let MarketVolume {
             supply_units,
             demand_units,
         } = volume;
 
-        // Only adjust if there was actual market activity
         if supply_units == 0 && demand_units == 0 {
-            return;
+            return None;
         }
 
-        let current_price = self.base_price(good);
-        let original_price = default_price_table().get(&good).copied().unwrap_or(100);
-
-        // Calculate imbalance: positive = demand > supply (price up), negative = supply > demand (price down)
-        let supply = supply_units.max(1) as f32;
-        let demand = demand_units.max(1) as f32;
-        let imbalance = (demand - supply) / (supply + demand);
-
-        // Apply gradual adjustment: max ±12.5% per turn
-        let adjustment_factor = 1.0 + imbalance.clamp(-0.5, 0.5) * 0.25;
-        let new_price = (current_price as f32 * adjustment_factor).round() as u32;
+        let original_price = reference_price(good);
+        let ratio = demand_units.max(1) as f32 / supply_units.max(1) as f32;
+        let equilibrium = (original_price as f32 * ratio.powf(PRICE_ELASTICITY)).round() as u32;
 
         // Clamp to 20%-300% of original price
         let min_price = (original_price as f32 * 0.2).max(1.0) as u32;
         let max_price = (original_price as f32 * 3.0) as u32;
-        let clamped_price = new_price.clamp(min_price, max_price);
+        Some(equilibrium.clamp(min_price, max_price))
+    }
 
-        if clamped_price != current_price {
-            self.base_prices.insert(good, clamped_price);
+    /// Updates the base price for a good based on observed supply and demand.
+    ///
+    /// Per the Imperialism manual: "If demand is stronger than supply, price rises.
+    /// If supply exceeds demand, price falls. If balanced, price stays similar."
+    ///
+    /// The price moves toward the [`equilibrium_price`](Self::equilibrium_price)
+    /// for the observed volume, closing `PRICE_DAMPING` of the gap each turn so a
+    /// persistent imbalance converges instead of oscillating.
+    pub fn update_price_from_volume(&mut self, good: Good, volume: MarketVolume) {
+        self.last_volumes.insert(good, volume);
+
+        // Only adjust if there was actual market activity
+        let Some(equilibrium) = self.equilibrium_price(good, volume) else {
+            return;
+        };
+        self.last_equilibria.insert(good, equilibrium);
+
+        let current_price = self.base_price(good);
+        let gap = equilibrium as f32 - current_price as f32;
+        let mut step = gap * PRICE_DAMPING;
+        // Always make progress on small gaps so prices actually reach equilibrium
+        if step.abs() < 1.0 {
+            step = gap.signum() * gap.abs().min(1.0);
+        }
+        let new_price = (current_price as f32 + step).round() as u32;
+
+        if new_price != current_price {
+            self.base_prices.insert(good, new_price.max(1));
         }
     }
 
@@ -134,16 +195,32 @@ impl MarketPriceModel {
         self.base_price(good)
     }
 
+    /// Current price of a good as seen by a nation with the given trade capacity,
+    /// including the transport surcharge (see [`delivered_price`]).
+    pub fn delivered_price(&self, good: Good, capacity: TradeCapacitySnapshot) -> u32 {
+        delivered_price(self.current_price(good), capacity)
+    }
+
     /// Returns the last recorded market volume for a good.
     pub fn last_volume(&self, good: Good) -> Option<MarketVolume> {
         self.last_volumes.get(&good).copied()
     }
 
+    /// Returns the equilibrium price computed at the last market clearing for a good.
+    pub fn last_equilibrium(&self, good: Good) -> Option<u32> {
+        self.last_equilibria.get(&good).copied()
+    }
+
     fn base_price(&self, good: Good) -> u32 {
         *self.base_prices.get(&good).unwrap_or(&100)
     }
 }
 
+/// Starting price of a good, used as the anchor for equilibrium pricing
+fn reference_price(good: Good) -> u32 {
+    default_price_table().get(&good).copied().unwrap_or(100)
+}
+
 fn default_price_table() -> HashMap<Good, u32> {
     let mut map = HashMap::new();
     map.insert(Good::Grain, 60);
@@ -152,6 +229,7 @@ fn default_price_table() -> HashMap<Good, u32> {
     map.insert(Good::Fish, 80);
     map.insert(Good::Cotton, 90);
     map.insert(Good::Wool, 90);
+    map.insert(Good::Fabric, 200);
     map.insert(Good::Timber, 70);
     map.insert(Good::Coal, 100);
     map.insert(Good::Iron, 100);
@@ -160,3 +238,84 @@ fn default_price_table() -> HashMap<Good, u32> {
     map.insert(Good::Oil, 110);
     map
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::economy::Good;
+    use crate::economy::TradeCapacitySnapshot;
+    use crate::economy::market::{MarketPriceModel, MarketVolume};
+
+    fn run_turns(model: &mut MarketPriceModel, good: Good, volume: MarketVolume) -> Vec<u32> {
+        (0..12)
+            .map(|_| {
+                model.update_price_from_volume(good, volume);
+                model.current_price(good)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn net_buying_and_selling_converge_to_equilibrium() {
+        let mut model = MarketPriceModel::default();
+        let start = model.current_price(Good::Coal);
+
+        // Heavy net buying: price climbs every turn and settles at the clearing price
+        let buying = MarketVolume::new(2, 10);
+        let equilibrium = model.equilibrium_price(Good::Coal, buying).unwrap();
+        assert!(equilibrium > start);
+        let prices = run_turns(&mut model, Good::Coal, buying);
+        assert!(prices[0] > start, "next turn's price should rise");
+        assert!(prices.windows(2).all(|w| w[1] >= w[0]), "{:?}", prices);
+        assert_eq!(*prices.last().unwrap(), equilibrium);
+        assert_eq!(model.last_equilibrium(Good::Coal), Some(equilibrium));
+
+        // Heavy net selling: price falls back down to the lower clearing price
+        let selling = MarketVolume::new(10, 2);
+        let equilibrium = model.equilibrium_price(Good::Coal, selling).unwrap();
+        assert!(equilibrium < start);
+        let high = model.current_price(Good::Coal);
+        let prices = run_turns(&mut model, Good::Coal, selling);
+        assert!(prices[0] < high, "next turn's price should fall");
+        assert!(prices.windows(2).all(|w| w[1] <= w[0]), "{:?}", prices);
+        assert_eq!(*prices.last().unwrap(), equilibrium);
+    }
+
+    #[test]
+    fn balanced_or_idle_markets_keep_their_price() {
+        let mut model = MarketPriceModel::default();
+        let start = model.current_price(Good::Iron);
+
+        model.update_price_from_volume(Good::Iron, MarketVolume::new(4, 4));
+        assert_eq!(model.current_price(Good::Iron), start);
+
+        assert_eq!(
+            model.equilibrium_price(Good::Iron, MarketVolume::default()),
+            None
+        );
+        model.update_price_from_volume(Good::Iron, MarketVolume::default());
+        assert_eq!(model.current_price(Good::Iron), start);
+    }
+
+    #[test]
+    fn constrained_nation_pays_more_for_delivery() {
+        let model = MarketPriceModel::default();
+        let spare = TradeCapacitySnapshot { total: 10, used: 1 };
+        let constrained = TradeCapacitySnapshot { total: 10, used: 9 };
+
+        let market_price = model.current_price(Good::Steel);
+        let spare_price = model.delivered_price(Good::Steel, spare);
+        let constrained_price = model.delivered_price(Good::Steel, constrained);
+
+        assert!(spare_price >= market_price);
+        assert!(
+            constrained_price > spare_price,
+            "near capacity {} should exceed spare capacity {}",
+            constrained_price,
+            spare_price
+        );
+        assert_eq!(
+            model.delivered_price(Good::Steel, TradeCapacitySnapshot { total: 10, used: 0 }),
+            market_price
+        );
+    }
+}
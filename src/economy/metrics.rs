@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+
+use crate::economy::production::ProductionResult;
+use crate::economy::trade::TradeLedger;
+use crate::economy::{Treasury, Workforce};
+
+/// Read-only summary of how a nation's economy moved this turn, computed on demand
+/// from the components and resources that own the figures
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NationMetrics {
+    /// Units made by all buildings in the last production run, minus the input units
+    /// they used up
+    pub net_output: i64,
+    /// Units bought minus units sold on the market
+    pub net_trade_units: i64,
+    /// Money earned from sales minus money spent on purchases
+    pub net_trade_income: i64,
+    pub population: u32,
+    /// Treasury change since the start of the turn
+    pub treasury_delta: i64,
+}
+
+impl NationMetrics {
+    pub fn collect(
+        nation: Entity,
+        treasury: &Treasury,
+        treasury_at_turn_start: i64,
+        production: Option<&ProductionResult>,
+        workforce: Option<&Workforce>,
+        ledger: &TradeLedger,
+    ) -> Self {
+        let flow = ledger.flow(nation);
+        NationMetrics {
+            net_output: production.map_or(0, |result| {
+                result
+                    .reports
+                    .values()
+                    .map(|report| report.produced as i64 - report.consumed as i64)
+                    .sum()
+            }),
+            net_trade_units: flow.units_bought as i64 - flow.units_sold as i64,
+            net_trade_income: flow.earned - flow.spent,
+            population: workforce.map_or(0, |w| w.workers.len() as u32),
+            treasury_delta: treasury.total() - treasury_at_turn_start,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::economy::metrics::NationMetrics;
+    use crate::economy::production::{BuildingKind, ProductionReport, ProductionResult};
+    use crate::economy::trade::TradeLedger;
+    use crate::economy::{Treasury, Workforce};
+
+    #[test]
+    fn metrics_net_out_production_trade_and_treasury() {
+        let mut world = World::new();
+        let exporter = world.spawn_empty().id();
+        let importer = world.spawn_empty().id();
+
+        let mut production = ProductionResult::default();
+        for (kind, produced) in [(BuildingKind::TextileMill, 4), (BuildingKind::SteelMill, 3)] {
+            production.reports.insert(
+                kind,
+                ProductionReport {
+                    target: 4,
+                    produced,
+                    consumed: produced * 2,
+                    limiting_input: None,
+                },
+            );
+        }
+
+        // Exporter sells 5 units at $100, buys 2 at $150 from the importer
+        let mut ledger = TradeLedger::default();
+        ledger.record_sale(exporter, importer, 5, 100);
+        ledger.record_sale(importer, exporter, 2, 150);
+
+        let mut workforce = Workforce::new();
+        workforce.add_untrained(7);
+        let mut treasury = Treasury::new(1_000);
+        treasury.add(500 - 300);
+        treasury.subtract(40);
+
+        let metrics = NationMetrics::collect(
+            exporter,
+            &treasury,
+            1_000,
+            Some(&production),
+            Some(&workforce),
+            &ledger,
+        );
+        assert_eq!(
+            metrics,
+            NationMetrics {
+                net_output: -7,
+                net_trade_units: -3,
+                net_trade_income: 200,
+                population: 7,
+                treasury_delta: 160,
+            }
+        );
+
+        let importer_metrics =
+            NationMetrics::collect(importer, &Treasury::new(0), 0, None, None, &ledger);
+        assert_eq!(importer_metrics.net_trade_units, 3);
+        assert_eq!(importer_metrics.net_trade_income, -200);
+        assert_eq!(importer_metrics.net_output, 0);
+    }
+}
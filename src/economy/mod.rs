@@ -7,8 +7,11 @@ use crate::ui::menu::AppState;
 pub mod allocation;
 pub mod allocation_systems;
 pub mod calendar;
+pub mod capital;
+pub mod city_stockpile;
 pub mod goods;
 pub mod market;
+pub mod metrics;
 pub mod nation;
 pub mod production;
 pub mod reservation;
@@ -18,23 +21,31 @@ pub mod trade;
 pub mod trade_capacity;
 pub mod transport;
 pub mod treasury;
+pub mod upkeep;
 pub mod workforce;
 
 pub use crate::messages::{
-    AdjustMarketOrder, AdjustProduction, AdjustRecruitment, AdjustTraining, MarketInterest,
+    AdjustMarketOrder, AdjustProduction, AdjustRecruitment, AdjustTraining, CancelMarketOrder,
+    MarketInterest, RelocateCapital, SetBuildingPriority, TransferGoods,
 };
-pub use allocation::Allocations;
+pub use allocation::{Allocations, MarketSellOrder};
 pub use calendar::{Calendar, Season};
+pub use city_stockpile::CityStockpile;
 pub use goods::Good;
 pub use market::{MARKET_RESOURCES, MarketPriceModel, MarketVolume};
-pub use nation::{Capital, Nation, NationColor, NationInstance, OwnedBy, PlayerNation};
+pub use metrics::NationMetrics;
+pub use nation::{
+    Capital, CapitalLost, Nation, NationColor, NationColorSlot, NationId, NationInstance, OwnedBy,
+    PlayerNation,
+};
 pub use production::{Building, BuildingKind, ConnectedProduction};
-pub use reservation::{ReservationId, ReservationSystem, ResourcePool};
-pub use stockpile::Stockpile;
-pub use technology::{Technologies, Technology};
+pub use reservation::{ReservationError, ReservationId, ReservationSystem, ResourcePool};
+pub use stockpile::{Stockpile, StockpileReserves};
+pub use technology::{ResearchProgress, Technologies, Technology};
 pub use trade_capacity::{TradeCapacity, TradeCapacitySnapshot};
-pub use transport::{Depot, ImprovementKind, PlaceImprovement, Port, Rails};
-pub use treasury::Treasury;
+pub use transport::{Bridges, Depot, ImprovementKind, PlaceImprovement, Port, Rails};
+pub use treasury::{FinancialAction, FinancialRejection, Treasury};
+pub use upkeep::{Bankruptcy, UpkeepArrears, UpkeepPolicy};
 pub use workforce::{
     RecruitWorkers, RecruitmentCapacity, RecruitmentQueue, TrainWorker, TrainingQueue, Worker,
     WorkerHealth, WorkerSkill, Workforce,
@@ -53,9 +64,13 @@ impl Plugin for EconomyPlugin {
         app.insert_resource(Calendar::default())
             .insert_resource(market::MarketPriceModel::default())
             .insert_resource(transport::Rails::default())
+            .insert_resource(transport::Bridges::default())
+            .init_resource::<upkeep::UpkeepPolicy>()
+            .init_resource::<transport::RailConnectivityCache>()
             .insert_resource(production::ConnectedProduction::default())
             .insert_resource(transport::TransportCapacity::default())
             .insert_resource(trade_capacity::TradeCapacity::default())
+            .init_resource::<trade::TradeLedger>()
             .insert_resource(transport::TransportAllocations::default())
             .insert_resource(transport::TransportDemandSnapshot::default())
             .insert_resource(OrdersQueue::default());
@@ -65,14 +80,30 @@ impl Plugin for EconomyPlugin {
         // must run before calculate_connected_production
         app.add_observer(transport::apply_improvements)
             .add_observer(transport::compute_rail_connectivity)
+            .add_observer(transport::on_depot_added)
+            .add_observer(transport::on_depot_removed)
+            .add_observer(transport::on_port_added)
+            .add_observer(transport::on_port_removed)
             .add_observer(production::calculate_connected_production)
             .add_observer(transport::apply_transport_allocations)
+            .add_observer(transport::apply_transport_priority)
+            .add_observer(transport::cancel_rail_construction)
             .add_observer(allocation_systems::apply_recruitment_adjustments)
             .add_observer(allocation_systems::apply_training_adjustments)
             .add_observer(allocation_systems::apply_production_adjustments)
             .add_observer(allocation_systems::apply_market_order_adjustments)
+            .add_observer(allocation_systems::cancel_market_order)
             .add_observer(workforce::handle_recruitment)
-            .add_observer(workforce::handle_training);
+            .add_observer(workforce::upgrade_capitol)
+            .add_observer(workforce::handle_training)
+            .add_observer(capital::relocate_capital)
+            .add_observer(production::apply_building_priority)
+            .add_observer(city_stockpile::transfer_between_cities)
+            .add_observer(stockpile::apply_reserve_floor)
+            .add_message::<allocation_systems::IdleLabor>()
+            .add_message::<transport::IdleTransportCapacity>()
+            .add_message::<treasury::FinancialRejection>()
+            .add_message::<upkeep::Bankruptcy>();
 
         // Configure the economy system set to run only in-game
         app.configure_sets(Update, EconomySet.run_if(in_state(AppState::InGame)));
@@ -87,9 +118,18 @@ impl Plugin for EconomyPlugin {
         app.add_systems(
             Update,
             (
-                transport::initialize_transport_capacity,
+                (
+                    transport::initialize_transport_capacity,
+                    transport::apply_technology_transport_capacity,
+                )
+                    .chain(),
                 trade_capacity::initialize_trade_capacity,
                 transport::update_transport_demand_snapshot,
+                transport::warn_idle_transport_capacity
+                    .after(transport::update_transport_demand_snapshot)
+                    .after(allocation_systems::execute_queued_transport_orders),
+                allocation_systems::warn_idle_labor
+                    .after(allocation_systems::execute_queued_production_orders),
             )
                 .in_set(EconomySet),
         );
@@ -117,8 +157,11 @@ impl Plugin for EconomyPlugin {
         app.add_systems(
             OnEnter(TurnPhase::PlayerTurn),
             (
+                capital::revoke_captured_capitals.before(production::collect_connected_production),
                 transport::advance_rail_construction,
                 production::collect_connected_production,
+                production::deplete_collected_reserves
+                    .after(production::collect_connected_production),
             )
                 .in_set(PlayerTurnSet::Collection),
         );
@@ -126,7 +169,23 @@ impl Plugin for EconomyPlugin {
         // Maintenance: Feed workers, apply recurring effects
         app.add_systems(
             OnEnter(TurnPhase::PlayerTurn),
-            (workforce::feed_workers, workforce::update_labor_pools)
+            (
+                workforce::feed_workers,
+                workforce::grow_population,
+                workforce::emigrate_hungry_workers,
+                workforce::update_labor_pools,
+                upkeep::charge_upkeep,
+            )
+                .chain()
+                .in_set(PlayerTurnSet::Maintenance),
+        );
+        app.add_systems(
+            OnEnter(TurnPhase::PlayerTurn),
+            (
+                technology::accrue_research,
+                technology::unlock_researched_technologies,
+            )
+                .chain()
                 .in_set(PlayerTurnSet::Maintenance),
         );
 
@@ -139,7 +198,13 @@ impl Plugin for EconomyPlugin {
         // Reset: Clear allocations for new turn
         app.add_systems(
             OnEnter(TurnPhase::PlayerTurn),
-            allocation_systems::reset_allocations.in_set(PlayerTurnSet::Reset),
+            (
+                allocation_systems::reset_allocations,
+                production::fire_power_plants,
+                city_stockpile::reset_city_transfers,
+            )
+                .chain()
+                .in_set(PlayerTurnSet::Reset),
         );
 
         // ====================================================================
@@ -152,10 +217,12 @@ impl Plugin for EconomyPlugin {
             allocation_systems::finalize_allocations.in_set(ProcessingSet::Finalize),
         );
 
-        // Production: Execute production
+        // Production: Apply pollution from the production finalize_allocations just ran
         app.add_systems(
             OnEnter(TurnPhase::Processing),
-            production::run_production.in_set(ProcessingSet::Production),
+            production::update_industrial_pollution
+                .after(allocation_systems::finalize_allocations)
+                .in_set(ProcessingSet::Production),
         );
 
         // Conversion: Convert goods to capacity
@@ -31,6 +31,12 @@ impl NationMember {
     }
 }
 
+/// Stable nation identifier. Unlike entity ids it survives save/load, so persisted
+/// cross-nation data (treaties, aid) is keyed by it.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct NationId(pub u32);
+
 /// Type-safe handle to a nation entity.
 /// Can be used directly in queries: `Query<(NationInstance, &Name)>`
 pub type NationInstance = Instance<Nation>;
@@ -40,6 +46,12 @@ pub type NationInstance = Instance<Nation>;
 #[reflect(Component)]
 pub struct Capital(pub TilePos);
 
+/// Marks a nation whose capital tile is held by another nation. It collects no
+/// connected production, cannot lay rail, and is a candidate for defeat checks.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct CapitalLost;
+
 /// Resource pointing to the player's active nation entity
 #[derive(Resource, Clone, Copy, Debug)]
 pub struct PlayerNation(pub NationInstance);
@@ -77,6 +89,11 @@ impl PlayerNation {
 #[reflect(Component)]
 pub struct NationColor(pub Color);
 
+/// Index into the active `ColorPalette`, so nation colors can be swapped when the palette changes
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct NationColorSlot(pub usize);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,7 +5,7 @@ use std::iter;
 use crate::{
     civilians::types::ProspectingKnowledge,
     economy::{
-        nation::Capital,
+        nation::{Capital, CapitalLost},
         transport::{Depot, Port},
     },
     map::tile_pos::{HexExt, TilePosExt},
@@ -13,6 +13,9 @@ use crate::{
 };
 use bevy_ecs_tilemap::prelude::{TilePos, TileStorage};
 
+use crate::economy::allocation::Allocations;
+use crate::economy::reservation::ReservationSystem;
+use crate::economy::treasury::Treasury;
 use crate::economy::workforce::Workforce;
 use crate::economy::{goods::Good, stockpile::Stockpile};
 
@@ -26,6 +29,19 @@ pub struct ConnectedProduction {
     pub tiles: Vec<ConnectedTileOutput>,
 }
 
+impl ConnectedProduction {
+    /// Units of `good` reaching `nation` from connected tiles this turn
+    pub fn available(&self, nation: Entity, good: Good) -> u32 {
+        self.totals.get(&nation).map_or(0, |totals| {
+            totals
+                .iter()
+                .filter(|(resource, _)| resource.to_good() == good)
+                .map(|(_, (_, output))| *output)
+                .sum()
+        })
+    }
+}
+
 /// Details about one tile contributing to connected production.
 #[derive(Debug, Clone)]
 pub struct ConnectedTileOutput {
@@ -143,13 +159,19 @@ pub fn calculate_connected_production(
                     {
                         continue;
                     }
+                    let neighbors = hex
+                        .all_neighbors()
+                        .into_iter()
+                        .filter_map(|neighbor| neighbor.to_tile_pos())
+                        .filter_map(|pos| tile_storage.get(&pos))
+                        .filter_map(|entity| tile_resources.get(entity).ok());
                     record_output(
                         production,
                         processed_tiles,
                         owner,
                         resource.resource_type,
                         Some(tile_pos),
-                        resource.get_output(),
+                        resource.get_output() + resource.adjacency_bonus(neighbors),
                         true,
                         ConnectedTileSource::Improvement,
                     );
@@ -239,7 +261,7 @@ pub fn calculate_connected_production(
 pub fn collect_connected_production(
     connected: Res<ConnectedProduction>,
     transport_allocations: Res<crate::economy::transport::TransportAllocations>,
-    mut nations: Query<(Entity, &mut Stockpile)>,
+    mut nations: Query<(Entity, &mut Stockpile), Without<CapitalLost>>,
 ) {
     use crate::economy::transport::TransportCommodity;
 
@@ -289,118 +311,138 @@ pub fn collect_connected_production(
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::economy::production::{ConnectedTileSource, calculate_connected_production};
-    use crate::economy::transport::RecomputeConnectivity;
-    use crate::economy::*;
-    use crate::{
-        civilians::types::ProspectingKnowledge,
-        economy::{nation::Capital, transport::Port},
-        map::tiles::TerrainType,
-        resources::{ResourceType, TileResource},
-        test_utils::{create_test_tile, create_test_tilemap},
+/// Draws down non-renewable deposits by what was collected this turn.
+/// Runs right after [`collect_connected_production`]; when a deposit's output
+/// shrinks, connectivity is recomputed so the smaller yield is used next turn.
+pub fn deplete_collected_reserves(
+    mut commands: Commands,
+    connected: Res<ConnectedProduction>,
+    transport_allocations: Res<crate::economy::transport::TransportAllocations>,
+    tile_storage: Query<&TileStorage>,
+    mut tile_resources: Query<&mut TileResource>,
+) {
+    use crate::economy::transport::TransportCommodity;
+
+    let Ok(tile_storage) = tile_storage.single() else {
+        return;
     };
-    use bevy_ecs_tilemap::prelude::TilePos;
-
-    #[test]
-    fn capital_adjacent_tiles_provide_baseline_yield() {
-        let mut app = App::new();
-        app.insert_resource(ConnectedProduction::default());
-        app.insert_resource(ProspectingKnowledge::default());
-        app.add_observer(calculate_connected_production);
-
-        let (tilemap_entity, mut tile_storage) = create_test_tilemap(app.world_mut(), 3, 3);
-        let capital_pos = TilePos { x: 1, y: 1 };
-        let field_pos = TilePos { x: 1, y: 2 };
-
-        let field_entity = create_test_tile(
-            app.world_mut(),
-            field_pos,
-            TerrainType::Farmland,
-            tilemap_entity,
-            &mut tile_storage,
-        );
-        app.world_mut()
-            .entity_mut(field_entity)
-            .insert(TileResource::visible(ResourceType::Grain));
-
-        app.world_mut()
-            .entity_mut(tilemap_entity)
-            .insert(tile_storage);
-
-        let nation = app.world_mut().spawn(Capital(capital_pos)).id();
-
-        // Trigger the observer
-        app.world_mut().trigger(RecomputeConnectivity);
-
-        let production = app.world().resource::<ConnectedProduction>();
-        let nation_output = production
-            .totals
-            .get(&nation)
-            .expect("capital-adjacent production recorded");
-        let grain_entry = nation_output
-            .get(&ResourceType::Grain)
-            .expect("grain entry exists");
-        assert_eq!(grain_entry.0, 0, "no improvements should be counted");
-        assert_eq!(grain_entry.1, 1, "capital adjacency yields one grain");
-
-        assert!(
-            production.tiles.iter().any(|tile| {
-                tile.owner == nation
-                    && tile.resource_type == ResourceType::Grain
-                    && tile.tile_pos == field_pos
-                    && tile.output == 1
-                    && tile.source == ConnectedTileSource::Baseline
-            }),
-            "baseline tile contribution recorded"
-        );
+
+    let mut output_changed = false;
+    for (&nation, totals) in &connected.totals {
+        for (&resource_type, &(_, total_output)) in totals {
+            if resource_type.initial_reserves().is_none() {
+                continue;
+            }
+            let Some(commodity) = TransportCommodity::from_good(resource_type.to_good()) else {
+                continue;
+            };
+            // Mirrors the amount collect_connected_production moved into the stockpile
+            let mut remaining = transport_allocations
+                .slot(nation, commodity)
+                .granted
+                .min(total_output);
+
+            for tile in connected
+                .tiles
+                .iter()
+                .filter(|t| t.owner == nation && t.resource_type == resource_type)
+            {
+                if remaining == 0 {
+                    break;
+                }
+                let Some(tile_entity) = tile_storage.get(&tile.tile_pos) else {
+                    continue;
+                };
+                let Ok(mut resource) = tile_resources.get_mut(tile_entity) else {
+                    continue;
+                };
+
+                let taken = remaining.min(tile.output);
+                let before = resource.get_output();
+                resource.deplete(taken);
+                remaining -= taken;
+
+                if resource.get_output() != before {
+                    output_changed = true;
+                }
+                if resource.is_exhausted() {
+                    info!(
+                        "{:?} deposit at ({}, {}) is exhausted",
+                        resource_type, tile.tile_pos.x, tile.tile_pos.y
+                    );
+                }
+            }
+        }
     }
 
-    #[test]
-    fn connected_port_produces_two_fish() {
-        let mut app = App::new();
-        app.insert_resource(ConnectedProduction::default());
-        app.insert_resource(ProspectingKnowledge::default());
-        app.add_observer(calculate_connected_production);
-
-        let (tilemap_entity, tile_storage) = create_test_tilemap(app.world_mut(), 3, 3);
-        app.world_mut()
-            .entity_mut(tilemap_entity)
-            .insert(tile_storage.clone());
-
-        let nation = app.world_mut().spawn_empty().id();
-        app.world_mut().spawn(Port {
-            position: TilePos { x: 1, y: 1 },
-            owner: nation,
-            connected: true,
-            is_river: false,
-        });
+    if output_changed {
+        commands.trigger(crate::economy::transport::RecomputeConnectivity);
+    }
+}
 
-        // Trigger the observer
-        app.world_mut().trigger(RecomputeConnectivity);
-
-        let production = app.world().resource::<ConnectedProduction>();
-        let nation_output = production
-            .totals
-            .get(&nation)
-            .expect("port production recorded");
-        let fish_entry = nation_output
-            .get(&ResourceType::Fish)
-            .expect("fish entry exists");
-        assert_eq!(fish_entry.0, 1, "port counts as improvement for fish");
-        assert_eq!(fish_entry.1, 2, "ports yield two fish");
-
-        assert!(
-            production.tiles.iter().any(|tile| {
-                tile.owner == nation
-                    && tile.resource_type == ResourceType::Fish
-                    && tile.tile_pos == TilePos { x: 1, y: 1 }
-                    && tile.output == 2
-                    && tile.source == ConnectedTileSource::Port
-            }),
-            "port fish debug tile recorded"
-        );
+/// Combined steel and fuel output per turn above which a capital's
+/// surroundings start to collect pollution
+pub const POLLUTION_OUTPUT_THRESHOLD: u32 = 3;
+/// Pollution each tile sheds at the end of every production run
+pub const POLLUTION_DECAY_PER_TURN: u32 = 2;
+
+/// Spread pollution from steel mills and refineries onto the tiles around the
+/// producing nation's capital, and let older pollution fade.
+/// Reads the [`ProductionResult`] that `finalize_allocations` recorded for this turn.
+/// A power plant halves the emissions.
+pub fn update_industrial_pollution(
+    mut commands: Commands,
+    industry: Query<(&Capital, &ProductionResult, &Buildings)>,
+    tile_storage: Query<&TileStorage>,
+    mut tile_resources: Query<&mut TileResource>,
+) {
+    let mut output_changed = false;
+
+    for mut resource in tile_resources.iter_mut() {
+        if resource.pollution == 0 {
+            continue;
+        }
+        let before = resource.get_output();
+        resource.pollution = resource.pollution.saturating_sub(POLLUTION_DECAY_PER_TURN);
+        output_changed |= resource.get_output() != before;
+    }
+
+    let Ok(tile_storage) = tile_storage.single() else {
+        return;
+    };
+
+    for (capital, result, buildings) in industry.iter() {
+        let heavy_output: u32 = [BuildingKind::SteelMill, BuildingKind::Refinery]
+            .into_iter()
+            .filter_map(|kind| result.get(kind))
+            .map(|report| report.produced)
+            .sum();
+        if heavy_output < POLLUTION_OUTPUT_THRESHOLD {
+            continue;
+        }
+
+        let has_power_plant = buildings.buildings.contains_key(&BuildingKind::PowerPlant);
+        let emitted = if has_power_plant {
+            heavy_output / 2
+        } else {
+            heavy_output
+        };
+
+        for hex in capital.0.to_hex().all_neighbors() {
+            let Some(tile_entity) = hex.to_tile_pos().and_then(|pos| tile_storage.get(&pos)) else {
+                continue;
+            };
+            let Ok(mut resource) = tile_resources.get_mut(tile_entity) else {
+                continue;
+            };
+            let before = resource.get_output();
+            resource.pollution += emitted;
+            output_changed |= resource.get_output() != before;
+        }
+    }
+
+    if output_changed {
+        commands.trigger(crate::economy::transport::RecomputeConnectivity);
     }
 }
 
@@ -433,8 +475,55 @@ pub struct ProductionSettings {
     pub target_output: u32,
 }
 
+/// Outcome of the last production run for one building
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub struct ProductionReport {
+    /// Output that was requested for the turn
+    pub target: u32,
+    /// Output that was actually produced
+    pub produced: u32,
+    /// Input units used up to make it
+    pub consumed: u32,
+    /// Input whose shortage capped production, if inputs were the bottleneck
+    pub limiting_input: Option<Good>,
+}
+
+impl ProductionReport {
+    /// Fraction of the target that was produced (1.0 when nothing was requested)
+    pub fn efficiency(&self) -> f32 {
+        if self.target == 0 {
+            1.0
+        } else {
+            self.produced as f32 / self.target as f32
+        }
+    }
+
+    pub fn is_below_target(&self) -> bool {
+        self.produced < self.target
+    }
+}
+
+/// Per-building results of last turn's production (actual vs target output), recorded on
+/// the nation when its allocations are finalized
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct ProductionResult {
+    pub reports: HashMap<BuildingKind, ProductionReport>,
+}
+
+impl ProductionResult {
+    pub fn get(&self, kind: BuildingKind) -> Option<&ProductionReport> {
+        self.reports.get(&kind)
+    }
+
+    pub fn record(&mut self, kind: BuildingKind, report: ProductionReport) {
+        self.reports.insert(kind, report);
+    }
+}
+
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 #[reflect(Component)]
+#[require(ProductionResult)]
 pub struct Building {
     pub kind: BuildingKind,
     pub capacity: u32, // Maximum output per turn
@@ -511,17 +600,17 @@ impl Building {
         }
     }
 
-    pub fn capitol() -> Self {
+    pub fn capitol(capacity: u32) -> Self {
         Self {
             kind: BuildingKind::Capitol,
-            capacity: 0, // Not a production building
+            capacity, // Workers recruited per turn
         }
     }
 
-    pub fn trade_school() -> Self {
+    pub fn trade_school(capacity: u32) -> Self {
         Self {
             kind: BuildingKind::TradeSchool,
-            capacity: 0, // Not a production building
+            capacity, // Workers trained per turn
         }
     }
 
@@ -954,11 +1043,36 @@ pub fn input_requirement_per_unit(
     production_recipe(kind)?.input_amount_for(output_good, input_good)
 }
 
+/// Every good needed to make `good`, walking the recipe graph back to raw inputs.
+/// Starts with `good` itself and lists each upstream good once, nearest first;
+/// alternative inputs (e.g. Cotton or Wool for Fabric) are all included.
+/// A good no building produces returns just itself.
+pub fn production_chain(good: Good) -> Vec<Good> {
+    let mut chain = vec![good];
+    let mut next = 0;
+    while let Some(&current) = chain.get(next) {
+        next += 1;
+        let Some(recipe) = building_for_output(current).and_then(production_recipe) else {
+            continue;
+        };
+        for info in recipe.variants_for_output(current) {
+            for ingredient in info.variant.inputs() {
+                if !chain.contains(&ingredient.good) {
+                    chain.push(ingredient.good);
+                }
+            }
+        }
+    }
+    chain
+}
+
 /// Collection of all buildings for a nation
 #[derive(Component, Debug, Clone, Default, Reflect)]
 #[reflect(Component)]
 pub struct Buildings {
     pub buildings: HashMap<BuildingKind, Building>,
+    /// Rank for scarce inputs; higher goes first, unlisted buildings are 0
+    pub priorities: HashMap<BuildingKind, u8>,
 }
 
 impl Buildings {
@@ -984,7 +1098,12 @@ impl Buildings {
         buildings.insert(BuildingKind::Refinery, Building::refinery(2));
         buildings.insert(BuildingKind::Railyard, Building::railyard());
         buildings.insert(BuildingKind::Shipyard, Building::shipyard());
-        Self { buildings }
+        buildings.insert(BuildingKind::TradeSchool, Building::trade_school(2));
+        buildings.insert(BuildingKind::Capitol, Building::capitol(2));
+        Self {
+            buildings,
+            priorities: HashMap::new(),
+        }
     }
 
     pub fn get(&self, kind: BuildingKind) -> Option<Building> {
@@ -994,79 +1113,215 @@ impl Buildings {
     pub fn insert(&mut self, building: Building) {
         self.buildings.insert(building.kind, building);
     }
+
+    pub fn priority(&self, kind: BuildingKind) -> u8 {
+        self.priorities.get(&kind).copied().unwrap_or(0)
+    }
+
+    pub fn set_priority(&mut self, kind: BuildingKind, priority: u8) {
+        self.priorities.insert(kind, priority);
+    }
+
+    /// Workers the Capitol can recruit per turn, if the nation has one
+    pub fn capitol_capacity(&self) -> Option<u32> {
+        self.get(BuildingKind::Capitol)
+            .map(|capitol| capitol.capacity)
+    }
+
+    /// Workers the Trade School can train per turn across all skill levels, if the
+    /// nation has one
+    pub fn training_capacity(&self) -> Option<u32> {
+        self.get(BuildingKind::TradeSchool)
+            .map(|school| school.capacity)
+    }
+}
+
+/// Observer: record a building's input priority for its nation.
+/// Triggered via `commands.trigger(SetBuildingPriority { ... })`.
+pub fn apply_building_priority(
+    trigger: On<crate::messages::SetBuildingPriority>,
+    mut nations: Query<&mut Buildings>,
+) {
+    let request = trigger.event();
+    if let Ok(mut buildings) = nations.get_mut(request.nation.entity()) {
+        buildings.set_priority(request.building, request.priority);
+    }
 }
 
-/// Runs production across all entities that have both a Stockpile and a Building.
-/// Consumes reserved resources and produces outputs.
-/// Production rules follow 2:1 ratios (2 inputs → 1 output).
-/// Production now requires labor points from workers.
-///
-/// Note: This system runs via OnEnter(TurnPhase::Processing) in ProcessingSet::Production,
-/// so no phase check is needed.
-pub fn run_production(
-    mut q: Query<(
-        Option<&Workforce>,
+/// Fuel a power plant burns each turn to run
+pub const POWER_PLANT_FUEL_PER_TURN: u32 = 1;
+/// Extra labor a running power plant gives every building, in percent of the nation's labor
+pub const POWER_PLANT_LABOR_BONUS_PERCENT: u32 = 50;
+
+/// Start each nation's power plant for the turn: reserve its fuel and add the labor bonus
+/// to the labor pool, where allocations can spend it. The fuel is burned when the turn's
+/// allocations are finalized; without a power plant or enough fuel, labor is unchanged.
+/// Runs after [`reset_allocations`](crate::economy::allocation_systems::reset_allocations).
+pub fn fire_power_plants(
+    mut nations: Query<(
+        &Buildings,
+        &mut Allocations,
+        &mut ReservationSystem,
         &mut Stockpile,
-        &Building,
-        &mut ProductionSettings,
+        &mut Workforce,
+        &mut Treasury,
     )>,
 ) {
-    for (workforce_opt, mut stock, building, mut settings) in q.iter_mut() {
-        // Calculate available labor (0 if no workforce)
-        let available_labor = workforce_opt.map(|w| w.available_labor()).unwrap_or(0);
-
-        // Each unit of production requires 1 labor point
-        // This acts as another constraint on production alongside capacity and inputs
-        let max_from_labor = available_labor;
-        let Some(recipe) = production_recipe(building.kind) else {
-            continue;
-        };
-
-        let desired_output = settings
-            .target_output
-            .min(max_from_labor)
-            .min(building.capacity);
-        if desired_output == 0 {
-            settings.target_output = 0;
+    for (buildings, mut allocations, mut reservations, mut stock, mut workforce, mut treasury) in
+        nations.iter_mut()
+    {
+        if allocations.power_plant.is_some()
+            || !buildings.buildings.contains_key(&BuildingKind::PowerPlant)
+        {
             continue;
         }
-
-        // Select variant based on stockpile availability instead of stored choice
-        let Some(variant) = recipe.best_variant_for_stockpile(&stock) else {
-            settings.target_output = 0;
-            debug!(
-                "Skipping production for {:?}: no suitable variant found",
-                building.kind
-            );
+        let Ok(fuel) = reservations.reserve(
+            [(Good::Fuel, POWER_PLANT_FUEL_PER_TURN)],
+            0,
+            0,
+            &mut stock,
+            &mut workforce,
+            &mut treasury,
+        ) else {
             continue;
         };
+        allocations.power_plant = Some(fuel);
+        let labor = workforce.available_labor();
+        workforce.labor_pool.total = labor + labor * POWER_PLANT_LABOR_BONUS_PERCENT / 100;
+    }
+}
 
-        let output_per_batch = variant.primary_output_amount();
-        if output_per_batch == 0 {
-            settings.target_output = 0;
-            continue;
-        }
+/// Outcome of running one building for a turn
+struct ProductionRun {
+    report: ProductionReport,
+    consumption: Vec<ConsumptionRecord>,
+    outputs: Vec<(Good, u32)>,
+    /// Labor points spent, one per unit of desired output
+    labor: u32,
+}
 
-        let target_batches = desired_output.div_ceil(output_per_batch);
-        if target_batches == 0 {
-            settings.target_output = 0;
-            continue;
-        }
+/// Run `building` toward `target` units, consuming reserved inputs from `stock`
+fn produce(
+    stock: &mut Stockpile,
+    building: &Building,
+    target: u32,
+    available_labor: u32,
+) -> ProductionRun {
+    let mut run = ProductionRun {
+        report: ProductionReport {
+            target,
+            ..default()
+        },
+        consumption: Vec::new(),
+        outputs: Vec::new(),
+        labor: 0,
+    };
 
-        let (produced_output, consumption) = execute_variant(&mut stock, variant, target_batches);
+    // Each unit of production requires 1 labor point
+    // This acts as another constraint on production alongside capacity and inputs
+    let Some(recipe) = production_recipe(building.kind) else {
+        return run;
+    };
 
-        if produced_output < desired_output {
-            log_production_shortfall(
-                building.kind,
-                variant,
-                desired_output,
-                produced_output,
-                &consumption,
-            );
-        }
+    let desired_output = target.min(available_labor).min(building.capacity);
+    if desired_output == 0 {
+        return run;
+    }
 
-        settings.target_output = produced_output;
+    // Select variant based on stockpile availability instead of stored choice
+    let Some(variant) = recipe.best_variant_for_stockpile(stock) else {
+        debug!(
+            "Skipping production for {:?}: no suitable variant found",
+            building.kind
+        );
+        return run;
+    };
+
+    let output_per_batch = variant.primary_output_amount();
+    if output_per_batch == 0 {
+        return run;
+    }
+
+    let target_batches = desired_output.div_ceil(output_per_batch);
+    let (produced_output, outputs, consumption) = execute_variant(stock, variant, target_batches);
+
+    run.report.produced = produced_output;
+    run.report.consumed = consumption.iter().map(|record| record.consumed).sum();
+    run.report.limiting_input = limiting_input(&consumption);
+    run.consumption = consumption;
+    run.outputs = outputs;
+    run.labor = desired_output;
+    run
+}
+
+/// Projected result of a building's pending allocation for next turn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProductionPreview {
+    pub kind: BuildingKind,
+    /// Projected target, output and bottleneck, as `finalize_allocations` will record them
+    pub report: ProductionReport,
+    /// Goods produced, including by-products
+    pub outputs: Vec<(Good, u32)>,
+    /// Goods consumed
+    pub inputs: Vec<(Good, u32)>,
+    /// Labor points the allocation would use
+    pub labor: u32,
+}
+
+/// Project next turn's production from the pending `allocations` without committing anything.
+/// Buildings run in priority order against a scratch copy of the stockpile, so outputs and
+/// inputs match what `finalize_allocations` will commit at the end of the turn.
+pub fn preview_production(
+    allocations: &Allocations,
+    buildings: &Buildings,
+    stockpile: &Stockpile,
+    workforce: &Workforce,
+) -> Vec<ProductionPreview> {
+    let mut targets: Vec<(BuildingKind, u32)> = Vec::new();
+    for ((_, output), reservations) in &allocations.production {
+        let Some(kind) = building_for_output(*output) else {
+            continue;
+        };
+        match targets.iter_mut().find(|(existing, _)| *existing == kind) {
+            Some((_, target)) => *target += reservations.len() as u32,
+            None => targets.push((kind, reservations.len() as u32)),
+        }
     }
+
+    let mut scratch = stockpile.clone();
+    produce_by_priority(&mut scratch, buildings, targets, workforce.labor_pool.total)
+        .into_iter()
+        .map(|(kind, run)| ProductionPreview {
+            kind,
+            report: run.report,
+            outputs: run.outputs,
+            inputs: run
+                .consumption
+                .iter()
+                .filter(|record| record.consumed > 0)
+                .map(|record| (record.ingredient.good, record.consumed))
+                .collect(),
+            labor: run.labor,
+        })
+        .collect()
+}
+
+/// Run each building toward its target against one shared stockpile. Buildings with a
+/// higher [`Buildings::priority`] draw scarce inputs first; ties keep the order of `targets`.
+fn produce_by_priority(
+    stock: &mut Stockpile,
+    buildings: &Buildings,
+    mut targets: Vec<(BuildingKind, u32)>,
+    available_labor: u32,
+) -> Vec<(BuildingKind, ProductionRun)> {
+    targets.sort_by_key(|(kind, _)| std::cmp::Reverse(buildings.priority(*kind)));
+    targets
+        .into_iter()
+        .filter_map(|(kind, target)| {
+            let building = buildings.get(kind)?;
+            Some((kind, produce(stock, &building, target, available_labor)))
+        })
+        .collect()
 }
 
 #[derive(Clone, Debug)]
@@ -1080,9 +1335,9 @@ fn execute_variant(
     stock: &mut Stockpile,
     variant: RecipeVariant,
     target_batches: u32,
-) -> (u32, Vec<ConsumptionRecord>) {
+) -> (u32, Vec<(Good, u32)>, Vec<ConsumptionRecord>) {
     if target_batches == 0 {
-        return (0, Vec::new());
+        return (0, Vec::new(), Vec::new());
     }
 
     let mut actual_batches = target_batches;
@@ -1107,56 +1362,30 @@ fn execute_variant(
 
     let primary_output = variant.primary_output();
     let mut produced_primary = 0;
+    let mut outputs = Vec::with_capacity(variant.outputs().len());
 
     for output in variant.outputs() {
         let produced_amount = actual_batches.saturating_mul(output.amount);
         if produced_amount > 0 {
             stock.add(output.good, produced_amount);
+            outputs.push((output.good, produced_amount));
         }
         if primary_output.is_some_and(|primary| primary.good == output.good) {
             produced_primary = produced_amount;
         }
     }
 
-    (produced_primary, consumption)
+    (produced_primary, outputs, consumption)
 }
 
-fn log_production_shortfall(
-    building_kind: BuildingKind,
-    variant: RecipeVariant,
-    requested_output: u32,
-    produced_output: u32,
-    consumption: &[ConsumptionRecord],
-) {
-    if produced_output >= requested_output {
-        return;
-    }
-
-    let Some(output_good) = variant.primary_output_good() else {
-        warn!(
-            "{:?}: requested {} output but recipe variant has no primary output good (produced {}). Consumption: {:?}",
-            building_kind, requested_output, produced_output, consumption
-        );
-        return;
-    };
-
-    let details = if consumption.is_empty() {
-        "no inputs consumed".to_string()
-    } else {
-        consumption
-            .iter()
-            .map(|record| {
-                format!(
-                    "{:?} {}/{}",
-                    record.ingredient.good, record.consumed, record.required
-                )
-            })
-            .collect::<Vec<_>>()
-            .join(", ")
-    };
-
-    info!(
-        "{:?}: requested {} {:?} but produced {} ({})",
-        building_kind, requested_output, output_good, produced_output, details
-    );
+/// The input that supported the fewest batches, if any input fell short of its requirement
+fn limiting_input(consumption: &[ConsumptionRecord]) -> Option<Good> {
+    consumption
+        .iter()
+        .filter(|record| record.consumed < record.required && record.ingredient.amount > 0)
+        .min_by_key(|record| record.consumed / record.ingredient.amount)
+        .map(|record| record.ingredient.good)
 }
+
+#[cfg(test)]
+mod tests;
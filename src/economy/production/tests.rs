@@ -0,0 +1,635 @@
+use crate::economy::production::{ConnectedTileSource, calculate_connected_production};
+use crate::economy::transport::RecomputeConnectivity;
+use crate::economy::*;
+use crate::{
+    civilians::types::ProspectingKnowledge,
+    economy::{nation::Capital, transport::Port},
+    map::tiles::TerrainType,
+    resources::{ResourceType, TileResource},
+    test_utils::{create_test_tile, create_test_tilemap},
+};
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ecs_tilemap::prelude::TilePos;
+
+#[test]
+fn capital_adjacent_tiles_provide_baseline_yield() {
+    let mut app = App::new();
+    app.insert_resource(ConnectedProduction::default());
+    app.insert_resource(ProspectingKnowledge::default());
+    app.add_observer(calculate_connected_production);
+
+    let (tilemap_entity, mut tile_storage) = create_test_tilemap(app.world_mut(), 3, 3);
+    let capital_pos = TilePos { x: 1, y: 1 };
+    let field_pos = TilePos { x: 1, y: 2 };
+
+    let field_entity = create_test_tile(
+        app.world_mut(),
+        field_pos,
+        TerrainType::Farmland,
+        tilemap_entity,
+        &mut tile_storage,
+    );
+    app.world_mut()
+        .entity_mut(field_entity)
+        .insert(TileResource::visible(ResourceType::Grain));
+
+    app.world_mut()
+        .entity_mut(tilemap_entity)
+        .insert(tile_storage);
+
+    let nation = app.world_mut().spawn(Capital(capital_pos)).id();
+
+    // Trigger the observer
+    app.world_mut().trigger(RecomputeConnectivity);
+
+    let production = app.world().resource::<ConnectedProduction>();
+    let nation_output = production
+        .totals
+        .get(&nation)
+        .expect("capital-adjacent production recorded");
+    let grain_entry = nation_output
+        .get(&ResourceType::Grain)
+        .expect("grain entry exists");
+    assert_eq!(grain_entry.0, 0, "no improvements should be counted");
+    assert_eq!(grain_entry.1, 1, "capital adjacency yields one grain");
+
+    assert!(
+        production.tiles.iter().any(|tile| {
+            tile.owner == nation
+                && tile.resource_type == ResourceType::Grain
+                && tile.tile_pos == field_pos
+                && tile.output == 1
+                && tile.source == ConnectedTileSource::Baseline
+        }),
+        "baseline tile contribution recorded"
+    );
+}
+
+#[test]
+fn connecting_grain_depot_raises_available_grain_by_its_yield() {
+    let mut app = App::new();
+    app.insert_resource(ConnectedProduction::default());
+    app.insert_resource(ProspectingKnowledge::default());
+    app.add_observer(calculate_connected_production);
+
+    let (tilemap_entity, mut tile_storage) = create_test_tilemap(app.world_mut(), 5, 5);
+    let field_pos = TilePos { x: 4, y: 4 };
+    let field_entity = create_test_tile(
+        app.world_mut(),
+        field_pos,
+        TerrainType::Farmland,
+        tilemap_entity,
+        &mut tile_storage,
+    );
+    let grain = TileResource::visible(ResourceType::Grain);
+    let grain_yield = grain.get_output();
+    app.world_mut().entity_mut(field_entity).insert(grain);
+    app.world_mut()
+        .entity_mut(tilemap_entity)
+        .insert(tile_storage);
+
+    let nation = app.world_mut().spawn(Capital(TilePos { x: 0, y: 0 })).id();
+    let depot = app
+        .world_mut()
+        .spawn(Depot {
+            position: field_pos,
+            owner: nation,
+            connected: false,
+        })
+        .id();
+
+    app.world_mut().trigger(RecomputeConnectivity);
+    let before = app
+        .world()
+        .resource::<ConnectedProduction>()
+        .available(nation, Good::Grain);
+
+    app.world_mut().get_mut::<Depot>(depot).unwrap().connected = true;
+    app.world_mut().trigger(RecomputeConnectivity);
+    let after = app
+        .world()
+        .resource::<ConnectedProduction>()
+        .available(nation, Good::Grain);
+
+    assert!(grain_yield > 0);
+    assert_eq!(after, before + grain_yield);
+}
+
+#[test]
+fn adjacent_irrigated_farms_out_yield_an_isolated_one() {
+    use crate::map::tile_pos::{HexExt, TilePosExt};
+    use crate::resources::DevelopmentLevel;
+
+    let mut app = App::new();
+    app.insert_resource(ConnectedProduction::default());
+    app.insert_resource(ProspectingKnowledge::default());
+    app.add_observer(calculate_connected_production);
+
+    let (tilemap_entity, mut tile_storage) = create_test_tilemap(app.world_mut(), 10, 10);
+    let farm_a = TilePos { x: 2, y: 2 };
+    let farm_b = farm_a.to_hex().all_neighbors()[0].to_tile_pos().unwrap();
+    let isolated = TilePos { x: 7, y: 7 };
+    for pos in [farm_a, farm_b, isolated] {
+        let tile = create_test_tile(
+            app.world_mut(),
+            pos,
+            TerrainType::Farmland,
+            tilemap_entity,
+            &mut tile_storage,
+        );
+        let mut grain = TileResource::visible(ResourceType::Grain);
+        grain.development = DevelopmentLevel::Lv2;
+        app.world_mut().entity_mut(tile).insert(grain);
+    }
+    app.world_mut()
+        .entity_mut(tilemap_entity)
+        .insert(tile_storage);
+
+    let nation = app.world_mut().spawn(Capital(TilePos { x: 0, y: 9 })).id();
+    for position in [farm_a, isolated] {
+        app.world_mut().spawn(Depot {
+            position,
+            owner: nation,
+            connected: true,
+        });
+    }
+    app.world_mut().trigger(RecomputeConnectivity);
+
+    let production = app.world().resource::<ConnectedProduction>();
+    let output_at = |pos: TilePos| {
+        production
+            .tiles
+            .iter()
+            .find(|tile| tile.tile_pos == pos)
+            .map(|tile| tile.output)
+            .unwrap()
+    };
+    assert_eq!(output_at(isolated), 3);
+    assert!(output_at(farm_a) > output_at(isolated));
+    assert!(output_at(farm_b) > output_at(isolated));
+}
+
+#[test]
+fn connected_port_produces_two_fish() {
+    let mut app = App::new();
+    app.insert_resource(ConnectedProduction::default());
+    app.insert_resource(ProspectingKnowledge::default());
+    app.add_observer(calculate_connected_production);
+
+    let (tilemap_entity, tile_storage) = create_test_tilemap(app.world_mut(), 3, 3);
+    app.world_mut()
+        .entity_mut(tilemap_entity)
+        .insert(tile_storage.clone());
+
+    let nation = app.world_mut().spawn_empty().id();
+    app.world_mut().spawn(Port {
+        position: TilePos { x: 1, y: 1 },
+        owner: nation,
+        connected: true,
+        is_river: false,
+    });
+
+    // Trigger the observer
+    app.world_mut().trigger(RecomputeConnectivity);
+
+    let production = app.world().resource::<ConnectedProduction>();
+    let nation_output = production
+        .totals
+        .get(&nation)
+        .expect("port production recorded");
+    let fish_entry = nation_output
+        .get(&ResourceType::Fish)
+        .expect("fish entry exists");
+    assert_eq!(fish_entry.0, 1, "port counts as improvement for fish");
+    assert_eq!(fish_entry.1, 2, "ports yield two fish");
+
+    assert!(
+        production.tiles.iter().any(|tile| {
+            tile.owner == nation
+                && tile.resource_type == ResourceType::Fish
+                && tile.tile_pos == TilePos { x: 1, y: 1 }
+                && tile.output == 2
+                && tile.source == ConnectedTileSource::Port
+        }),
+        "port fish debug tile recorded"
+    );
+}
+
+#[test]
+fn input_starved_building_reports_limiting_input() {
+    use crate::economy::allocation::Allocations;
+    use crate::economy::allocation_systems::finalize_allocations;
+    use crate::economy::production::{Building, BuildingKind, Buildings, ProductionResult};
+    use crate::economy::reservation::ReservationSystem;
+    use crate::economy::treasury::Treasury;
+    use crate::economy::workforce::{RecruitmentQueue, TrainingQueue};
+
+    let mut world = World::new();
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Iron, 4);
+    stockpile.add(Good::Coal, 1);
+    let mut workforce = Workforce::new();
+    workforce.add_untrained(4);
+    workforce.update_labor_pool();
+    let mut treasury = Treasury::new(0);
+
+    // Four units allocated, but only one unit's worth of coal is on hand
+    let nation = world.spawn_empty().id();
+    let mut allocations = Allocations::default();
+    let mut reservations = ReservationSystem::default();
+    let goods = [
+        vec![(Good::Iron, 4), (Good::Coal, 1)],
+        vec![],
+        vec![],
+        vec![],
+    ];
+    for goods in goods {
+        let id = reservations
+            .try_reserve(goods, 1, 0, &mut stockpile, &mut workforce, &mut treasury)
+            .unwrap();
+        allocations
+            .production
+            .entry((nation, Good::Steel))
+            .or_default()
+            .push(id);
+    }
+    let mut buildings = Buildings::new();
+    buildings.insert(Building::steel_mill(4));
+
+    world.entity_mut(nation).insert((
+        allocations,
+        reservations,
+        stockpile,
+        workforce,
+        treasury,
+        buildings,
+        ProductionResult::default(),
+        RecruitmentQueue::default(),
+        TrainingQueue::default(),
+    ));
+    let _ = world.run_system_once(finalize_allocations);
+
+    let result = world.get::<ProductionResult>(nation).unwrap();
+    let report = result.get(BuildingKind::SteelMill).unwrap();
+    assert_eq!(report.target, 4);
+    assert_eq!(report.produced, 1);
+    assert!(report.is_below_target());
+    assert_eq!(report.efficiency(), 0.25);
+    assert_eq!(report.limiting_input, Some(Good::Coal));
+}
+
+#[test]
+fn production_preview_matches_what_finalize_allocations_commits() {
+    use crate::economy::allocation::Allocations;
+    use crate::economy::allocation_systems::{calculate_inputs_for_one_unit, finalize_allocations};
+    use crate::economy::production::{
+        Building, BuildingKind, Buildings, ProductionResult, preview_production,
+    };
+    use crate::economy::reservation::ReservationSystem;
+    use crate::economy::treasury::Treasury;
+    use crate::economy::workforce::{RecruitmentQueue, TrainingQueue};
+
+    let mut world = World::new();
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Iron, 5);
+    stockpile.add(Good::Coal, 5);
+    let mut workforce = Workforce::new();
+    workforce.add_untrained(4);
+    workforce.update_labor_pool();
+    let mut treasury = Treasury::new(0);
+
+    // Three units allocated to a mill that can only make two
+    let nation = world.spawn_empty().id();
+    let mut allocations = Allocations::default();
+    let mut reservations = ReservationSystem::default();
+    for _ in 0..3 {
+        let inputs =
+            calculate_inputs_for_one_unit(BuildingKind::SteelMill, Good::Steel, &stockpile);
+        let id = reservations
+            .try_reserve(&inputs, 1, 0, &mut stockpile, &mut workforce, &mut treasury)
+            .unwrap();
+        allocations
+            .production
+            .entry((nation, Good::Steel))
+            .or_default()
+            .push(id);
+    }
+
+    let mut buildings = Buildings::new();
+    buildings.insert(Building::steel_mill(2));
+    let preview = preview_production(&allocations, &buildings, &stockpile, &workforce);
+    assert_eq!(preview.len(), 1);
+    let preview = preview[0].clone();
+    assert_eq!(preview.outputs, vec![(Good::Steel, 2)]);
+    assert_eq!(preview.inputs, vec![(Good::Iron, 2), (Good::Coal, 2)]);
+    assert_eq!(preview.labor, 2);
+    assert_eq!(stockpile.get(Good::Steel), 0, "preview commits nothing");
+
+    world.entity_mut(nation).insert((
+        allocations,
+        reservations,
+        stockpile,
+        workforce,
+        treasury,
+        buildings,
+        ProductionResult::default(),
+        RecruitmentQueue::default(),
+        TrainingQueue::default(),
+    ));
+    let _ = world.run_system_once(finalize_allocations);
+
+    let report = world
+        .get::<ProductionResult>(nation)
+        .unwrap()
+        .get(BuildingKind::SteelMill)
+        .copied()
+        .unwrap();
+    assert_eq!(report, preview.report);
+    let stockpile = world.get::<Stockpile>(nation).unwrap();
+    for (good, amount) in preview.outputs {
+        assert_eq!(stockpile.get(good), amount);
+    }
+    for (good, amount) in preview.inputs {
+        assert_eq!(stockpile.get(good), 5 - amount);
+        assert_eq!(stockpile.get_reserved(good), 0);
+    }
+}
+
+#[test]
+fn steel_mill_pollution_lowers_adjacent_farm_yield_until_it_decays() {
+    use crate::economy::production::{
+        BuildingKind, Buildings, ProductionReport, ProductionResult, update_industrial_pollution,
+    };
+    use crate::resources::DevelopmentLevel;
+
+    let mut world = World::new();
+    let (tilemap_entity, mut tile_storage) = create_test_tilemap(&mut world, 3, 3);
+    let farm_entity = create_test_tile(
+        &mut world,
+        TilePos { x: 1, y: 2 },
+        TerrainType::Farmland,
+        tilemap_entity,
+        &mut tile_storage,
+    );
+    let mut grain = TileResource::visible(ResourceType::Grain);
+    grain.development = DevelopmentLevel::Lv1;
+    world.entity_mut(farm_entity).insert(grain);
+    world.entity_mut(tilemap_entity).insert(tile_storage);
+
+    let nation = world
+        .spawn((
+            Capital(TilePos { x: 1, y: 1 }),
+            ProductionResult::default(),
+            Buildings::new(),
+        ))
+        .id();
+    let set_steel_output = |world: &mut World, produced: u32| {
+        world.get_mut::<ProductionResult>(nation).unwrap().record(
+            BuildingKind::SteelMill,
+            ProductionReport {
+                target: produced,
+                produced,
+                consumed: produced * 2,
+                limiting_input: None,
+            },
+        );
+    };
+    let farm_state = |world: &World| {
+        let resource = world.get::<TileResource>(farm_entity).unwrap();
+        (resource.pollution, resource.get_output())
+    };
+
+    let clean_output = farm_state(&world).1;
+    assert_eq!(clean_output, 2);
+
+    set_steel_output(&mut world, 4);
+    let mut polluted = Vec::new();
+    for _ in 0..3 {
+        let _ = world.run_system_once(update_industrial_pollution);
+        polluted.push(farm_state(&world));
+    }
+    assert_eq!(polluted, vec![(4, 1), (6, 0), (8, 0)]);
+
+    set_steel_output(&mut world, 0);
+    let mut recovering = Vec::new();
+    for _ in 0..4 {
+        let _ = world.run_system_once(update_industrial_pollution);
+        recovering.push(farm_state(&world));
+    }
+    assert_eq!(recovering, vec![(6, 0), (4, 1), (2, 2), (0, 2)]);
+}
+
+#[test]
+fn higher_priority_building_gets_scarce_inputs_first() {
+    use crate::economy::production::{Building, BuildingKind, Buildings, produce_by_priority};
+
+    // Two steel feed either one MetalWorks batch or two Railyard batches
+    let mut stockpile = Stockpile::default();
+    for (good, amount) in [(Good::Steel, 2), (Good::Lumber, 2)] {
+        stockpile.add(good, amount);
+        stockpile.reserve(good, amount);
+    }
+    let mut buildings = Buildings::new();
+    buildings.insert(Building::railyard());
+    buildings.insert(Building::metal_works(2));
+    buildings.set_priority(BuildingKind::MetalWorks, 1);
+
+    let runs = produce_by_priority(
+        &mut stockpile.clone(),
+        &buildings,
+        vec![(BuildingKind::Railyard, 2), (BuildingKind::MetalWorks, 1)],
+        10,
+    );
+    let produced = |kind| {
+        runs.iter()
+            .find(|(run_kind, _)| *run_kind == kind)
+            .map(|(_, run)| run.report.produced)
+            .unwrap()
+    };
+    assert_eq!(runs[0].0, BuildingKind::MetalWorks);
+    assert_eq!(produced(BuildingKind::MetalWorks), 1, "full rate");
+    assert_eq!(produced(BuildingKind::Railyard), 0, "starved of steel");
+
+    // Raising the Railyard above the MetalWorks flips who is served
+    buildings.set_priority(BuildingKind::Railyard, 2);
+    let runs = produce_by_priority(
+        &mut stockpile,
+        &buildings,
+        vec![(BuildingKind::Railyard, 2), (BuildingKind::MetalWorks, 1)],
+        10,
+    );
+    assert_eq!(runs[0].0, BuildingKind::Railyard);
+    assert_eq!(runs[0].1.report.produced, 2);
+    assert_eq!(runs[1].1.report.produced, 0);
+}
+
+#[test]
+fn fueled_power_plant_raises_labor_pool_and_burns_fuel_at_turn_end() {
+    use crate::economy::allocation::Allocations;
+    use crate::economy::allocation_systems::{finalize_allocations, reset_allocations};
+    use crate::economy::production::{Building, Buildings, ProductionResult, fire_power_plants};
+    use crate::economy::reservation::ReservationSystem;
+    use crate::economy::treasury::Treasury;
+    use crate::economy::workforce::{RecruitmentQueue, TrainingQueue};
+
+    let mut world = World::new();
+    let mut buildings = Buildings::new();
+    buildings.insert(Building::textile_mill(8));
+    buildings.insert(Building::power_plant(1));
+    let mut workforce = Workforce::new();
+    workforce.add_untrained(2);
+    workforce.update_labor_pool();
+    let nation = world
+        .spawn((
+            buildings,
+            Allocations::default(),
+            ReservationSystem::default(),
+            Stockpile::default(),
+            workforce,
+            Treasury::new(0),
+            RecruitmentQueue::default(),
+            TrainingQueue::default(),
+            ProductionResult::default(),
+        ))
+        .id();
+    let labor_pool = |world: &World| world.get::<Workforce>(nation).unwrap().labor_pool.total;
+
+    // Without fuel the plant adds nothing
+    let _ = world.run_system_once(fire_power_plants);
+    assert_eq!(labor_pool(&world), 2);
+
+    world
+        .get_mut::<Stockpile>(nation)
+        .unwrap()
+        .add(Good::Fuel, 2);
+    let _ = world.run_system_once(fire_power_plants);
+    assert_eq!(labor_pool(&world), 3);
+    let stockpile = world.get::<Stockpile>(nation).unwrap();
+    assert_eq!(stockpile.get_reserved(Good::Fuel), 1, "fuel is set aside");
+
+    // Firing again the same turn neither stacks the bonus nor reserves more fuel
+    let _ = world.run_system_once(fire_power_plants);
+    assert_eq!(labor_pool(&world), 3);
+    assert_eq!(
+        world
+            .get::<Stockpile>(nation)
+            .unwrap()
+            .get_reserved(Good::Fuel),
+        1
+    );
+
+    let _ = world.run_system_once(finalize_allocations);
+    let _ = world.run_system_once(reset_allocations);
+    let stockpile = world.get::<Stockpile>(nation).unwrap();
+    assert_eq!(
+        stockpile.get(Good::Fuel),
+        1,
+        "the plant burns one fuel a turn"
+    );
+    assert_eq!(stockpile.get_reserved(Good::Fuel), 0);
+}
+
+#[test]
+fn production_chain_walks_back_to_raw_inputs() {
+    use crate::economy::production::production_chain;
+
+    let chain = production_chain(Good::Clothing);
+    assert_eq!(chain[0], Good::Clothing);
+    assert!(chain.contains(&Good::Fabric));
+    assert!(chain.contains(&Good::Cotton) || chain.contains(&Good::Wool));
+    let fabric = chain.iter().position(|g| *g == Good::Fabric).unwrap();
+    let cotton = chain.iter().position(|g| *g == Good::Cotton).unwrap();
+    assert!(fabric < cotton, "nearer inputs come first");
+
+    assert_eq!(production_chain(Good::Cotton), vec![Good::Cotton]);
+}
+
+#[test]
+fn mined_out_deposit_stops_producing() {
+    use crate::economy::production::{collect_connected_production, deplete_collected_reserves};
+    use crate::economy::transport::{
+        Depot, TransportAllocations, TransportCapacity, TransportCommodity,
+    };
+    use crate::resources::DevelopmentLevel;
+
+    let mut app = App::new();
+    app.insert_resource(ConnectedProduction::default());
+    app.insert_resource(ProspectingKnowledge::default());
+    app.insert_resource(TransportAllocations::default());
+    app.insert_resource(TransportCapacity::default());
+    app.add_observer(calculate_connected_production);
+
+    let (tilemap_entity, mut tile_storage) = create_test_tilemap(app.world_mut(), 3, 3);
+    let mine_pos = TilePos { x: 1, y: 1 };
+    let mine_entity = create_test_tile(
+        app.world_mut(),
+        mine_pos,
+        TerrainType::Hills,
+        tilemap_entity,
+        &mut tile_storage,
+    );
+    let mut coal = TileResource::visible(ResourceType::Coal);
+    coal.development = DevelopmentLevel::Lv1; // 2 coal per turn
+    coal.reserves = Some(5);
+    app.world_mut().entity_mut(mine_entity).insert(coal);
+    app.world_mut()
+        .entity_mut(tilemap_entity)
+        .insert(tile_storage);
+
+    let nation = app.world_mut().spawn(Stockpile::default()).id();
+    app.world_mut()
+        .resource_mut::<ProspectingKnowledge>()
+        .mark_discovered(mine_entity, nation);
+    app.world_mut().spawn(Depot {
+        position: mine_pos,
+        owner: nation,
+        connected: true,
+    });
+    {
+        let mut allocations = app.world_mut().resource_mut::<TransportAllocations>();
+        let slot = allocations
+            .ensure_nation(nation)
+            .slot_mut(TransportCommodity::Coal);
+        slot.requested = 10;
+        slot.granted = 10;
+    }
+
+    app.world_mut().trigger(RecomputeConnectivity);
+
+    let mut outputs = Vec::new();
+    for _ in 0..4 {
+        let _ = app
+            .world_mut()
+            .run_system_once(collect_connected_production);
+        let _ = app.world_mut().run_system_once(deplete_collected_reserves);
+        outputs.push(
+            app.world()
+                .get::<TileResource>(mine_entity)
+                .unwrap()
+                .get_output(),
+        );
+    }
+
+    assert_eq!(outputs, vec![2, 1, 0, 0]);
+    let resource = app.world().get::<TileResource>(mine_entity).unwrap();
+    assert!(resource.is_exhausted());
+    assert_eq!(
+        app.world()
+            .get::<Stockpile>(nation)
+            .unwrap()
+            .get(Good::Coal),
+        5,
+        "only the deposit's reserves can ever be collected"
+    );
+    assert!(
+        app.world()
+            .resource::<ConnectedProduction>()
+            .totals
+            .get(&nation)
+            .and_then(|totals| totals.get(&ResourceType::Coal))
+            .is_none(),
+        "exhausted deposit no longer contributes"
+    );
+}
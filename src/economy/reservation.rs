@@ -1,8 +1,23 @@
 use bevy::prelude::*;
-use std::collections::HashMap;
+use thiserror::Error;
 
 use crate::economy::goods::Good;
 
+/// The resource that was short when a reservation could not be made
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReservationError {
+    #[error("not enough {good} (need {needed}, have {available})")]
+    InsufficientGood {
+        good: Good,
+        needed: u32,
+        available: u32,
+    },
+    #[error("not enough labor (need {needed}, have {available})")]
+    InsufficientLabor { needed: u32, available: u32 },
+    #[error("not enough money (need ${needed}, have ${available})")]
+    InsufficientFunds { needed: u32, available: i64 },
+}
+
 /// A pool of resources with reservations
 #[derive(Debug, Clone, Default, Reflect)]
 #[reflect(Debug)]
@@ -37,6 +52,13 @@ impl ResourcePool {
         self.reserved = self.reserved.saturating_sub(amount);
     }
 
+    /// Consume part of a reservation (subtract from both total and reserved)
+    pub fn consume(&mut self, amount: u32) {
+        let amount = amount.min(self.reserved);
+        self.total = self.total.saturating_sub(amount);
+        self.reserved -= amount;
+    }
+
     /// Consume all reservations (turn resources into actual usage)
     pub fn consume_reserved(&mut self) {
         self.total = self.total.saturating_sub(self.reserved);
@@ -44,9 +66,14 @@ impl ResourcePool {
     }
 }
 
-/// Opaque identifier for a reservation
+/// Opaque identifier for a reservation.
+/// Slots are recycled once a reservation is released or consumed; the generation
+/// makes stale ids for a recycled slot harmless no-ops.
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug, Reflect)]
-pub struct ReservationId(u32);
+pub struct ReservationId {
+    slot: u32,
+    generation: u32,
+}
 
 /// Internal data for a reservation
 #[derive(Debug, Clone, Reflect)]
@@ -56,12 +83,24 @@ struct ReservationData {
     money: u32,
 }
 
+#[derive(Debug, Default)]
+struct ReservationSlot {
+    generation: u32,
+    data: Option<ReservationData>,
+}
+
+/// Upper bound on recycled goods vectors kept around between reservations
+const MAX_SPARE_GOODS_BUFFERS: usize = 256;
+
 /// Per-nation reservation tracking system
 /// Each nation has its own instance as a Component
 #[derive(Component, Debug, Default)]
 pub struct ReservationSystem {
-    next_id: u32,
-    reservations: HashMap<ReservationId, ReservationData>,
+    slots: Vec<ReservationSlot>,
+    free_slots: Vec<u32>,
+    /// Goods vectors from finished reservations, reused to avoid per-unit allocations
+    spare_goods: Vec<Vec<(Good, u32)>>,
+    active: usize,
 }
 
 impl ReservationSystem {
@@ -69,91 +108,125 @@ impl ReservationSystem {
     /// Returns ReservationId on success, None if any resource unavailable
     pub fn try_reserve(
         &mut self,
-        goods: Vec<(Good, u32)>,
+        goods: impl AsRef<[(Good, u32)]>,
         labor: u32,
         money: u32,
         stockpile: &mut crate::economy::stockpile::Stockpile,
         workforce: &mut crate::economy::workforce::Workforce,
         treasury: &mut crate::economy::treasury::Treasury,
     ) -> Option<ReservationId> {
-        let mut reserved_goods = Vec::new();
-
-        // Try to reserve all goods
-        for (good, amount) in &goods {
-            if let Some(pool) = stockpile.get_pool_mut(*good) {
-                let available = pool.available();
-                if pool.try_reserve(*amount) {
-                    reserved_goods.push((*good, *amount));
-                } else {
-                    // ROLLBACK: release everything we reserved so far
-                    for (g, amt) in reserved_goods {
-                        if let Some(pool) = stockpile.get_pool_mut(g) {
-                            pool.release(amt);
-                        }
-                    }
-                    info!(
-                        "Reservation failed: insufficient {:?} (need {}, have {})",
-                        good, amount, available
-                    );
-                    return None;
-                }
-            } else {
-                // Good doesn't exist in stockpile - fail and rollback
-                for (g, amt) in reserved_goods {
-                    if let Some(pool) = stockpile.get_pool_mut(g) {
-                        pool.release(amt);
-                    }
-                }
-                info!("Reservation failed: {:?} not in stockpile", good);
-                return None;
+        self.reserve(goods, labor, money, stockpile, workforce, treasury)
+            .inspect_err(|error| info!("Reservation failed: {error}"))
+            .ok()
+    }
+
+    /// Reserve multiple resources atomically (all-or-nothing), naming the first
+    /// resource that ran short when the reservation cannot be made
+    pub fn reserve(
+        &mut self,
+        goods: impl AsRef<[(Good, u32)]>,
+        labor: u32,
+        money: u32,
+        stockpile: &mut crate::economy::stockpile::Stockpile,
+        workforce: &mut crate::economy::workforce::Workforce,
+        treasury: &mut crate::economy::treasury::Treasury,
+    ) -> Result<ReservationId, ReservationError> {
+        let goods = goods.as_ref();
+
+        // Check everything before reserving anything, so a failure leaves no trace.
+        // A good listed more than once must cover the sum of its entries.
+        for (index, &(good, _)) in goods.iter().enumerate() {
+            if goods[..index].iter().any(|&(earlier, _)| earlier == good) {
+                continue;
+            }
+            let needed: u32 = goods
+                .iter()
+                .filter(|&&(other, _)| other == good)
+                .map(|&(_, amount)| amount)
+                .sum();
+            let available = stockpile.get_available(good);
+            if needed > available {
+                return Err(ReservationError::InsufficientGood {
+                    good,
+                    needed,
+                    available,
+                });
             }
         }
 
-        // Try to reserve labor
         let labor_available = workforce.labor_pool.available();
-        if !workforce.try_reserve_labor(labor) {
-            // ROLLBACK: release goods
-            for (good, amt) in reserved_goods {
-                if let Some(pool) = stockpile.get_pool_mut(good) {
-                    pool.release(amt);
-                }
-            }
-            info!(
-                "Reservation failed: insufficient labor (need {}, have {})",
-                labor, labor_available
-            );
-            return None;
+        if labor > labor_available {
+            return Err(ReservationError::InsufficientLabor {
+                needed: labor,
+                available: labor_available,
+            });
         }
 
-        // Try to reserve money
         let money_available = treasury.available();
-        if !treasury.try_reserve(money) {
-            // ROLLBACK: release goods and labor
-            for (good, amt) in reserved_goods {
-                if let Some(pool) = stockpile.get_pool_mut(good) {
-                    pool.release(amt);
-                }
+        if money as i64 > money_available {
+            return Err(ReservationError::InsufficientFunds {
+                needed: money,
+                available: money_available,
+            });
+        }
+
+        // Everything is available, so none of these can fail
+        for &(good, amount) in goods {
+            let reserved = stockpile.reserve(good, amount);
+            debug_assert!(reserved, "goods checked above");
+        }
+        let labor_reserved = workforce.try_reserve_labor(labor);
+        let money_reserved = treasury.try_reserve(money);
+        debug_assert!(
+            labor_reserved && money_reserved,
+            "labor and money checked above"
+        );
+
+        // SUCCESS - store in a recycled slot when one is free
+        let mut stored_goods = self.spare_goods.pop().unwrap_or_default();
+        stored_goods.extend_from_slice(goods);
+        let data = ReservationData {
+            goods: stored_goods,
+            labor,
+            money,
+        };
+
+        let slot = match self.free_slots.pop() {
+            Some(slot) => slot,
+            None => {
+                self.slots.push(ReservationSlot::default());
+                (self.slots.len() - 1) as u32
             }
-            workforce.release_labor(labor);
-            info!(
-                "Reservation failed: insufficient money (need {}, have {})",
-                money, money_available
-            );
+        };
+        let entry = &mut self.slots[slot as usize];
+        entry.data = Some(data);
+        self.active += 1;
+
+        Ok(ReservationId {
+            slot,
+            generation: entry.generation,
+        })
+    }
+
+    /// Remove a live reservation, freeing its slot for reuse
+    fn take(&mut self, id: ReservationId) -> Option<ReservationData> {
+        let entry = self.slots.get_mut(id.slot as usize)?;
+        if entry.generation != id.generation {
             return None;
         }
+        let data = entry.data.take()?;
+        entry.generation = entry.generation.wrapping_add(1);
+        self.free_slots.push(id.slot);
+        self.active -= 1;
+        Some(data)
+    }
 
-        // SUCCESS - store in database
-        let id = ReservationId(self.next_id);
-        self.next_id += 1;
-        self.reservations.insert(
-            id,
-            ReservationData {
-                goods,
-                labor,
-                money,
-            },
-        );
-        Some(id)
+    /// Return a goods vector to the spare pool
+    fn recycle(&mut self, mut goods: Vec<(Good, u32)>) {
+        if self.spare_goods.len() < MAX_SPARE_GOODS_BUFFERS {
+            goods.clear();
+            self.spare_goods.push(goods);
+        }
     }
 
     /// Release a reservation (puts resources back, consumes the reservation)
@@ -164,14 +237,15 @@ impl ReservationSystem {
         workforce: &mut crate::economy::workforce::Workforce,
         treasury: &mut crate::economy::treasury::Treasury,
     ) {
-        if let Some(data) = self.reservations.remove(&id) {
-            for (good, amt) in data.goods {
+        if let Some(data) = self.take(id) {
+            for &(good, amt) in &data.goods {
                 if let Some(pool) = stockpile.get_pool_mut(good) {
                     pool.release(amt);
                 }
             }
             workforce.release_labor(data.labor);
             treasury.release(data.money);
+            self.recycle(data.goods);
         }
     }
 
@@ -184,27 +258,144 @@ impl ReservationSystem {
         workforce: &mut crate::economy::workforce::Workforce,
         treasury: &mut crate::economy::treasury::Treasury,
     ) {
-        if let Some(data) = self.reservations.remove(&id) {
+        if let Some(data) = self.take(id) {
             // For each reserved resource, consume it (subtract from total, clear reservation)
-            for (good, _amt) in data.goods {
+            for &(good, _amt) in &data.goods {
                 if let Some(pool) = stockpile.get_pool_mut(good) {
                     pool.consume_reserved();
                 }
             }
             workforce.labor_pool.consume_reserved();
             treasury.consume_reserved();
+            self.recycle(data.goods);
         }
     }
 
+    /// Live reservation data for `id`, if it hasn't been released or consumed
+    fn live_mut(&mut self, id: ReservationId) -> Option<&mut ReservationData> {
+        self.slots
+            .get_mut(id.slot as usize)
+            .filter(|entry| entry.generation == id.generation)
+            .and_then(|entry| entry.data.as_mut())
+    }
+
+    /// Units of `good` currently held by a reservation
+    pub fn reserved_units(&self, id: ReservationId, good: Good) -> u32 {
+        self.slots
+            .get(id.slot as usize)
+            .filter(|entry| entry.generation == id.generation)
+            .and_then(|entry| entry.data.as_ref())
+            .map(|data| {
+                data.goods
+                    .iter()
+                    .filter(|(g, _)| *g == good)
+                    .map(|(_, amount)| amount)
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+
+    /// Reserve `amount` more of `good` under an existing reservation
+    /// Returns false (and changes nothing) if the reservation is gone or the goods are unavailable
+    pub fn try_extend(
+        &mut self,
+        id: ReservationId,
+        good: Good,
+        amount: u32,
+        stockpile: &mut crate::economy::stockpile::Stockpile,
+    ) -> bool {
+        let Some(data) = self.live_mut(id) else {
+            return false;
+        };
+        let Some(pool) = stockpile.get_pool_mut(good) else {
+            return false;
+        };
+        if !pool.try_reserve(amount) {
+            return false;
+        }
+        match data.goods.iter_mut().find(|(g, _)| *g == good) {
+            Some((_, reserved)) => *reserved += amount,
+            None => data.goods.push((good, amount)),
+        }
+        true
+    }
+
+    /// Remove up to `units` of `good` from a reservation, freeing it once nothing is left.
+    /// Returns how many units were actually removed.
+    fn shrink(&mut self, id: ReservationId, good: Good, units: u32) -> u32 {
+        let Some(data) = self.live_mut(id) else {
+            return 0;
+        };
+        let Some(index) = data.goods.iter().position(|(g, _)| *g == good) else {
+            return 0;
+        };
+        let removed = data.goods[index].1.min(units);
+        data.goods[index].1 -= removed;
+        if data.goods[index].1 == 0 {
+            data.goods.swap_remove(index);
+        }
+        if data.goods.is_empty()
+            && data.labor == 0
+            && data.money == 0
+            && let Some(data) = self.take(id)
+        {
+            self.recycle(data.goods);
+        }
+        removed
+    }
+
+    /// Put `units` of `good` back into the stockpile, keeping the rest reserved
+    pub fn release_units(
+        &mut self,
+        id: ReservationId,
+        good: Good,
+        units: u32,
+        stockpile: &mut crate::economy::stockpile::Stockpile,
+    ) -> u32 {
+        let released = self.shrink(id, good, units);
+        if released > 0
+            && let Some(pool) = stockpile.get_pool_mut(good)
+        {
+            pool.release(released);
+        }
+        released
+    }
+
+    /// Commit `units` of `good` from a reservation (e.g. a partial market sale)
+    /// Unlike [`Self::consume`], only the given units leave the stockpile.
+    pub fn consume_units(
+        &mut self,
+        id: ReservationId,
+        good: Good,
+        units: u32,
+        stockpile: &mut crate::economy::stockpile::Stockpile,
+    ) -> u32 {
+        let consumed = self.shrink(id, good, units);
+        if consumed > 0
+            && let Some(pool) = stockpile.get_pool_mut(good)
+        {
+            pool.consume(consumed);
+        }
+        consumed
+    }
+
     /// Consume all reservations (at turn end - commits them)
     /// The actual resource consumption happens in the pools themselves
     pub fn consume_all(&mut self) {
-        self.reservations.clear();
+        for slot in 0..self.slots.len() {
+            let entry = &mut self.slots[slot];
+            if let Some(data) = entry.data.take() {
+                entry.generation = entry.generation.wrapping_add(1);
+                self.free_slots.push(slot as u32);
+                self.recycle(data.goods);
+            }
+        }
+        self.active = 0;
     }
 
     /// Get count of active reservations (for debugging/UI)
     pub fn count(&self) -> usize {
-        self.reservations.len()
+        self.active
     }
 }
 
@@ -249,4 +440,132 @@ mod tests {
         assert_eq!(pool.reserved, 0);
         assert_eq!(pool.available(), 6);
     }
+
+    #[test]
+    fn reserving_and_releasing_many_units_recycles_ids() {
+        let mut reservations = ReservationSystem::default();
+        let mut stockpile = Stockpile::default();
+        let mut workforce = Workforce::new();
+        let mut treasury = Treasury::new(0);
+        stockpile.add(Good::Cotton, 1000);
+
+        let mut first_round = Vec::new();
+        for round in 0..3 {
+            let ids: Vec<ReservationId> = (0..1000)
+                .map(|_| {
+                    reservations
+                        .try_reserve(
+                            [(Good::Cotton, 1)],
+                            0,
+                            0,
+                            &mut stockpile,
+                            &mut workforce,
+                            &mut treasury,
+                        )
+                        .expect("enough cotton")
+                })
+                .collect();
+            assert_eq!(reservations.count(), 1000);
+            assert_eq!(stockpile.get_available(Good::Cotton), 0);
+
+            for &id in &ids {
+                reservations.release(id, &mut stockpile, &mut workforce, &mut treasury);
+            }
+            assert_eq!(reservations.count(), 0);
+            assert_eq!(stockpile.get_reserved(Good::Cotton), 0);
+            assert_eq!(stockpile.get(Good::Cotton), 1000);
+
+            if round == 0 {
+                first_round = ids;
+            } else {
+                // Slots are reused instead of growing the system
+                assert!(reservations.slots.len() <= 1000);
+            }
+        }
+
+        // Ids from the first round are stale now: releasing them must not touch anything
+        let live = reservations
+            .try_reserve(
+                [(Good::Cotton, 5)],
+                0,
+                0,
+                &mut stockpile,
+                &mut workforce,
+                &mut treasury,
+            )
+            .unwrap();
+        for &stale in &first_round {
+            reservations.release(stale, &mut stockpile, &mut workforce, &mut treasury);
+        }
+        assert_eq!(reservations.count(), 1);
+        assert_eq!(stockpile.get_reserved(Good::Cotton), 5);
+
+        reservations.release(live, &mut stockpile, &mut workforce, &mut treasury);
+        assert_eq!(stockpile.get_available(Good::Cotton), 1000);
+    }
+
+    #[test]
+    fn reserving_without_enough_cotton_names_cotton() {
+        let mut reservations = ReservationSystem::default();
+        let mut stockpile = Stockpile::default();
+        let mut workforce = Workforce::new();
+        workforce.add_untrained(1);
+        workforce.update_labor_pool();
+        let mut treasury = Treasury::new(0);
+        stockpile.add(Good::Cotton, 1);
+        stockpile.add(Good::Wool, 5);
+
+        let result = reservations.reserve(
+            [(Good::Wool, 1), (Good::Cotton, 2)],
+            1,
+            0,
+            &mut stockpile,
+            &mut workforce,
+            &mut treasury,
+        );
+
+        assert_eq!(
+            result,
+            Err(ReservationError::InsufficientGood {
+                good: Good::Cotton,
+                needed: 2,
+                available: 1,
+            })
+        );
+        assert!(result.unwrap_err().to_string().contains("Cotton"));
+        // Nothing stays reserved after the failure
+        assert_eq!(stockpile.get_reserved(Good::Wool), 0);
+        assert_eq!(workforce.labor_pool.available(), 1);
+    }
+
+    #[test]
+    fn reservation_failing_on_labor_leaves_stockpile_and_treasury_untouched() {
+        let mut reservations = ReservationSystem::default();
+        let mut stockpile = Stockpile::default();
+        let mut workforce = Workforce::new();
+        let mut treasury = Treasury::new(500);
+        stockpile.add(Good::Cotton, 4);
+
+        let result = reservations.reserve(
+            [(Good::Cotton, 2)],
+            1,
+            100,
+            &mut stockpile,
+            &mut workforce,
+            &mut treasury,
+        );
+
+        assert_eq!(
+            result,
+            Err(ReservationError::InsufficientLabor {
+                needed: 1,
+                available: 0,
+            })
+        );
+        assert_eq!(stockpile.get(Good::Cotton), 4);
+        assert_eq!(stockpile.get_reserved(Good::Cotton), 0);
+        assert_eq!(treasury.total(), 500);
+        assert_eq!(treasury.reserved(), 0);
+        assert_eq!(reservations.count(), 0);
+    }
 }
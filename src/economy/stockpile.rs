@@ -1,7 +1,6 @@
 use bevy::prelude::*;
-use std::collections::HashMap;
 
-use crate::economy::goods::Good;
+use crate::economy::goods::{GOOD_COUNT, Good};
 use crate::economy::reservation::ResourcePool;
 
 /// Immutable view into a single stockpile entry.
@@ -13,42 +12,53 @@ pub struct StockpileEntry {
     pub available: u32,
 }
 
+/// Per-nation goods storage.
+/// Backed by a dense array indexed by [`Good::index`], so the hot production and market
+/// paths avoid hashing. `None` marks goods the stockpile has never tracked.
 #[derive(Component, Debug, Clone, Default, Reflect)]
 #[reflect(Component)]
 pub struct Stockpile {
-    pools: HashMap<Good, ResourcePool>,
+    pools: [Option<ResourcePool>; GOOD_COUNT],
 }
 
 impl Stockpile {
+    fn pool(&self, good: Good) -> Option<&ResourcePool> {
+        self.pools[good.index()].as_ref()
+    }
+
+    fn pool_entry(&mut self, good: Good) -> &mut ResourcePool {
+        self.pools[good.index()].get_or_insert_default()
+    }
+
     /// Get total amount of a good (including reserved)
     pub fn get(&self, good: Good) -> u32 {
-        self.pools.get(&good).map(|p| p.total).unwrap_or(0)
+        self.pool(good).map(|p| p.total).unwrap_or(0)
     }
 
     /// Get reserved amount of a good
     pub fn get_reserved(&self, good: Good) -> u32 {
-        self.pools.get(&good).map(|p| p.reserved).unwrap_or(0)
+        self.pool(good).map(|p| p.reserved).unwrap_or(0)
     }
 
     /// Get available amount of a good (total - reserved)
     pub fn get_available(&self, good: Good) -> u32 {
-        self.pools.get(&good).map(|p| p.available()).unwrap_or(0)
+        self.pool(good).map(|p| p.available()).unwrap_or(0)
     }
 
     /// Add resources to the stockpile
     pub fn add(&mut self, good: Good, qty: u32) {
-        self.pools.entry(good).or_default().total += qty;
+        self.pool_entry(good).total += qty;
     }
 
     /// Reserve resources for a specific purpose (production, orders, etc.)
     /// Returns true if successful, false if not enough available
     pub fn reserve(&mut self, good: Good, qty: u32) -> bool {
-        self.pools.entry(good).or_default().try_reserve(qty)
+        self.pool_entry(good).try_reserve(qty)
     }
 
     /// Unreserve resources (e.g., cancel an order)
     pub fn unreserve(&mut self, good: Good, qty: u32) {
-        if let Some(pool) = self.pools.get_mut(&good) {
+        if let Some(pool) = self.pools[good.index()].as_mut() {
             pool.release(qty);
         }
     }
@@ -71,7 +81,7 @@ impl Stockpile {
         let available = self.get(good);
         let take = available.min(qty);
         if take > 0
-            && let Some(pool) = self.pools.get_mut(&good)
+            && let Some(pool) = self.pools[good.index()].as_mut()
         {
             pool.total = pool.total.saturating_sub(take);
         }
@@ -83,6 +93,13 @@ impl Stockpile {
         self.get_available(good) >= qty
     }
 
+    /// Drop every reservation, making all goods available again
+    pub fn clear_reserved(&mut self) {
+        for pool in self.pools.iter_mut().flatten() {
+            pool.reserved = 0;
+        }
+    }
+
     /// Returns true if the stockpile has at least `qty` units total (including reserved)
     pub fn has_at_least(&self, good: Good, qty: u32) -> bool {
         self.get(good) >= qty
@@ -91,20 +108,67 @@ impl Stockpile {
     /// Iterate over all goods tracked by the stockpile.
     ///
     /// The iterator yields immutable snapshots containing total, reserved,
-    /// and available quantities for each [`Good`]. Consumers should sort the
-    /// returned collection if they require deterministic ordering.
+    /// and available quantities for each tracked [`Good`], in [`Good::ALL`] order.
     pub fn entries(&self) -> impl Iterator<Item = StockpileEntry> + '_ {
-        self.pools.iter().map(|(good, pool)| StockpileEntry {
-            good: *good,
-            total: pool.total,
-            reserved: pool.reserved,
-            available: pool.available(),
+        Good::ALL.into_iter().filter_map(|good| {
+            self.pool(good).map(|pool| StockpileEntry {
+                good,
+                total: pool.total,
+                reserved: pool.reserved,
+                available: pool.available(),
+            })
         })
     }
 
     /// Internal: Get mutable access to a pool (for ReservationSystem)
     pub(super) fn get_pool_mut(&mut self, good: Good) -> Option<&mut ResourcePool> {
-        Some(self.pools.entry(good).or_default())
+        Some(self.pool_entry(good))
+    }
+}
+
+/// Per-good reserve floors a nation keeps out of reach of allocations and AI trading.
+/// Recruitment, production, training and market sell orders never commit a good
+/// below its floor.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct StockpileReserves {
+    floors: [u32; GOOD_COUNT],
+}
+
+impl StockpileReserves {
+    /// Minimum uncommitted amount kept for `good`
+    pub fn floor(&self, good: Good) -> u32 {
+        self.floors[good.index()]
+    }
+
+    pub fn set_floor(&mut self, good: Good, floor: u32) {
+        self.floors[good.index()] = floor;
+    }
+
+    /// Units of `good` that can still be reserved without dipping below its floor
+    pub fn spendable(&self, stockpile: &Stockpile, good: Good) -> u32 {
+        stockpile
+            .get_available(good)
+            .saturating_sub(self.floor(good))
+    }
+
+    /// Returns true if every input can be reserved while respecting the floors
+    pub fn allows(&self, stockpile: &Stockpile, inputs: &[(Good, u32)]) -> bool {
+        inputs
+            .iter()
+            .all(|&(good, qty)| self.spendable(stockpile, good) >= qty)
+    }
+}
+
+/// Observer: change one of a nation's reserve floors.
+/// Triggered via `commands.trigger(SetReserveFloor { ... })`.
+pub fn apply_reserve_floor(
+    trigger: On<crate::messages::SetReserveFloor>,
+    mut nations: Query<&mut StockpileReserves>,
+) {
+    let request = trigger.event();
+    if let Ok(mut reserves) = nations.get_mut(request.nation.entity()) {
+        reserves.set_floor(request.good, request.floor);
     }
 }
 
@@ -197,4 +261,107 @@ mod tests {
         assert_eq!(steel.reserved, 0);
         assert_eq!(steel.available, 2);
     }
+
+    /// The previous `HashMap`-backed implementation, kept as a reference model
+    #[derive(Default)]
+    struct MapStockpile {
+        pools: std::collections::HashMap<Good, (u32, u32)>,
+    }
+
+    impl MapStockpile {
+        fn get(&self, good: Good) -> u32 {
+            self.pools.get(&good).map(|p| p.0).unwrap_or(0)
+        }
+
+        fn get_available(&self, good: Good) -> u32 {
+            self.pools
+                .get(&good)
+                .map(|p| p.0.saturating_sub(p.1))
+                .unwrap_or(0)
+        }
+
+        fn add(&mut self, good: Good, qty: u32) {
+            self.pools.entry(good).or_default().0 += qty;
+        }
+
+        fn reserve(&mut self, good: Good, qty: u32) -> bool {
+            let pool = self.pools.entry(good).or_default();
+            if qty <= pool.0.saturating_sub(pool.1) {
+                pool.1 += qty;
+                true
+            } else {
+                false
+            }
+        }
+
+        fn take_up_to(&mut self, good: Good, qty: u32) -> u32 {
+            let take = self.get(good).min(qty);
+            if take > 0
+                && let Some(pool) = self.pools.get_mut(&good)
+            {
+                pool.0 -= take;
+            }
+            take
+        }
+    }
+
+    #[test]
+    fn array_stockpile_matches_map_stockpile_for_all_goods() {
+        let mut dense = Stockpile::default();
+        let mut map = MapStockpile::default();
+
+        for (i, good) in Good::ALL.into_iter().enumerate() {
+            let i = i as u32;
+            // Leave every third good untouched so untracked goods are covered too
+            if i % 3 == 2 {
+                continue;
+            }
+            dense.add(good, 10 + i);
+            map.add(good, 10 + i);
+            assert_eq!(dense.reserve(good, 4 + i), map.reserve(good, 4 + i));
+            assert_eq!(dense.reserve(good, 20), map.reserve(good, 20));
+            assert_eq!(
+                dense.take_up_to(good, 3 + i / 2),
+                map.take_up_to(good, 3 + i / 2)
+            );
+        }
+
+        for good in Good::ALL {
+            assert_eq!(dense.get(good), map.get(good), "{:?} total", good);
+            assert_eq!(
+                dense.get_available(good),
+                map.get_available(good),
+                "{:?} available",
+                good
+            );
+        }
+
+        let tracked: Vec<Good> = dense.entries().map(|entry| entry.good).collect();
+        let mut expected: Vec<Good> = map.pools.keys().copied().collect();
+        expected.sort();
+        assert_eq!(tracked, expected);
+    }
+
+    #[test]
+    fn reserve_floor_is_set_through_its_event() {
+        use bevy::prelude::World;
+
+        use crate::economy::stockpile::apply_reserve_floor;
+        use crate::messages::SetReserveFloor;
+
+        let mut world = World::new();
+        world.add_observer(apply_reserve_floor);
+        let nation = world.spawn((Nation, StockpileReserves::default())).id();
+        let instance = NationInstance::from_entity(world.entity(nation)).unwrap();
+
+        world.trigger(SetReserveFloor {
+            nation: instance,
+            good: Good::Grain,
+            floor: 6,
+        });
+
+        let reserves = world.get::<StockpileReserves>(nation).unwrap();
+        assert_eq!(reserves.floor(Good::Grain), 6);
+        assert_eq!(reserves.floor(Good::Fruit), 0);
+    }
 }
@@ -1,12 +1,137 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+use crate::economy::workforce::Workforce;
+use crate::map::tiles::TerrainType;
+use crate::resources::DevelopmentLevel;
+
+/// Highest tile development reachable without any technology
+pub const BASE_DEVELOPMENT_CAP: DevelopmentLevel = DevelopmentLevel::Lv2;
+/// Research points needed for Improved Tools, for nations that start without it
+pub const IMPROVED_TOOLS_RESEARCH_COST: u32 = 50;
+/// Research points needed for Mechanization
+pub const MECHANIZATION_RESEARCH_COST: u32 = 150;
+/// Research points every nation banks each turn
+pub const BASE_RESEARCH_PER_TURN: u32 = 2;
+/// Extra research points each expert worker adds per turn
+pub const RESEARCH_PER_EXPERT: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 pub enum Technology {
     // Rail construction technologies
     MountainEngineering, // Allows building rails in mountains
     SwampDrainage,       // Allows building rails in swamps
     HillGrading,         // Allows building rails in hills
+    Bridging,            // Allows engineers to bridge river crossings
+    // Logistics technologies
+    StandardGauge, // Extra transport capacity
+    // Resource development technologies
+    ImprovedTools, // Tiles may be developed to Lv3
+    Mechanization, // Tiles may be developed to Lv4
+}
+
+/// A single effect granted by owning a technology
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TechEffect {
+    /// Rails may be laid on this terrain
+    UnlockRailTerrain(TerrainType),
+    /// Flat bonus to the nation's transport capacity
+    TransportCapacity(u32),
+    /// Engineers may build bridges across rivers
+    BuildBridges,
+    /// Tiles may be developed up to this level
+    DevelopmentCap(DevelopmentLevel),
+}
+
+/// Registry entry describing a technology and everything it grants
+#[derive(Debug, Clone, Copy)]
+pub struct TechDefinition {
+    pub technology: Technology,
+    pub name: &'static str,
+    /// Shown when an action is blocked for lack of this technology
+    pub missing_message: &'static str,
+    pub effects: &'static [TechEffect],
+    /// Research points that unlock it, if nations can research it at all
+    pub research_cost: Option<u32>,
+}
+
+/// Every technology and its effects; systems consult this instead of matching on `Technology`
+pub const TECH_DEFINITIONS: &[TechDefinition] = &[
+    TechDefinition {
+        technology: Technology::MountainEngineering,
+        name: "Mountain Engineering",
+        missing_message: "Mountain Engineering technology required",
+        effects: &[TechEffect::UnlockRailTerrain(TerrainType::Mountain)],
+        research_cost: None,
+    },
+    TechDefinition {
+        technology: Technology::SwampDrainage,
+        name: "Swamp Drainage",
+        missing_message: "Swamp Drainage technology required",
+        effects: &[TechEffect::UnlockRailTerrain(TerrainType::Swamp)],
+        research_cost: None,
+    },
+    TechDefinition {
+        technology: Technology::HillGrading,
+        name: "Hill Grading",
+        missing_message: "Hill Grading technology required",
+        effects: &[TechEffect::UnlockRailTerrain(TerrainType::Hills)],
+        research_cost: None,
+    },
+    TechDefinition {
+        technology: Technology::Bridging,
+        name: "Bridging",
+        missing_message: "Bridging technology required",
+        effects: &[TechEffect::BuildBridges],
+        research_cost: None,
+    },
+    TechDefinition {
+        technology: Technology::StandardGauge,
+        name: "Standard Gauge",
+        missing_message: "Standard Gauge technology required",
+        effects: &[TechEffect::TransportCapacity(3)],
+        research_cost: None,
+    },
+    TechDefinition {
+        technology: Technology::ImprovedTools,
+        name: "Improved Tools",
+        missing_message: "Improved Tools technology required",
+        effects: &[TechEffect::DevelopmentCap(DevelopmentLevel::Lv3)],
+        research_cost: Some(IMPROVED_TOOLS_RESEARCH_COST),
+    },
+    TechDefinition {
+        technology: Technology::Mechanization,
+        name: "Mechanization",
+        missing_message: "Mechanization technology required",
+        effects: &[TechEffect::DevelopmentCap(DevelopmentLevel::Lv4)],
+        research_cost: Some(MECHANIZATION_RESEARCH_COST),
+    },
+];
+
+impl Technology {
+    pub fn definition(self) -> &'static TechDefinition {
+        TECH_DEFINITIONS
+            .iter()
+            .find(|definition| definition.technology == self)
+            .expect("every technology has a registry entry")
+    }
+
+    pub fn effects(self) -> &'static [TechEffect] {
+        self.definition().effects
+    }
+
+    /// Technology that unlocks rails on `terrain`, if the terrain is gated at all
+    pub fn required_for_rail(terrain: TerrainType) -> Option<Technology> {
+        TECH_DEFINITIONS
+            .iter()
+            .find(|definition| {
+                definition
+                    .effects
+                    .contains(&TechEffect::UnlockRailTerrain(terrain))
+            })
+            .map(|definition| definition.technology)
+    }
 }
 
 /// Set of technologies owned by a nation
@@ -26,4 +151,185 @@ impl Technologies {
     pub fn unlock(&mut self, tech: Technology) {
         self.0.insert(tech);
     }
+
+    /// All effects granted by the owned technologies
+    pub fn effects(&self) -> impl Iterator<Item = &'static TechEffect> + '_ {
+        self.0.iter().flat_map(|tech| tech.effects().iter())
+    }
+
+    /// Whether the owned technologies let engineers build bridges
+    pub fn can_bridge(&self) -> bool {
+        self.effects()
+            .any(|effect| *effect == TechEffect::BuildBridges)
+    }
+
+    /// Total transport capacity bonus granted by the owned technologies
+    pub fn transport_capacity_bonus(&self) -> u32 {
+        self.effects()
+            .map(|effect| match effect {
+                TechEffect::TransportCapacity(bonus) => *bonus,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Highest development level the nation's civilians may bring a tile to
+    pub fn development_cap(&self) -> DevelopmentLevel {
+        self.effects()
+            .filter_map(|effect| match effect {
+                TechEffect::DevelopmentCap(level) => Some(*level),
+                _ => None,
+            })
+            .fold(BASE_DEVELOPMENT_CAP, DevelopmentLevel::max)
+    }
+}
+
+/// Research points a nation has accumulated toward new technologies
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct ResearchProgress {
+    pub points: u32,
+}
+
+impl ResearchProgress {
+    pub fn add(&mut self, points: u32) {
+        self.points = self.points.saturating_add(points);
+    }
+
+    /// Spend `points` if that many are banked; returns whether they were spent
+    pub fn spend(&mut self, points: u32) -> bool {
+        if self.points < points {
+            return false;
+        }
+        self.points -= points;
+        true
+    }
+}
+
+/// Bank this turn's research: a base amount plus a share from every expert worker
+pub fn accrue_research(mut nations: Query<(&Workforce, &mut ResearchProgress)>) {
+    for (workforce, mut progress) in nations.iter_mut() {
+        progress.add(BASE_RESEARCH_PER_TURN + workforce.expert_count() * RESEARCH_PER_EXPERT);
+    }
+}
+
+/// Unlock every researchable technology a nation has banked enough points for, in
+/// registry order
+pub fn unlock_researched_technologies(
+    mut nations: Query<(&mut Technologies, &mut ResearchProgress)>,
+) {
+    for (mut technologies, mut progress) in nations.iter_mut() {
+        for definition in TECH_DEFINITIONS {
+            let Some(cost) = definition.research_cost else {
+                continue;
+            };
+            if technologies.has(definition.technology) || !progress.spend(cost) {
+                continue;
+            }
+            technologies.unlock(definition.technology);
+            info!("Researched {}", definition.name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+
+    use crate::economy::nation::Nation;
+    use crate::economy::technology::{
+        BASE_RESEARCH_PER_TURN, MECHANIZATION_RESEARCH_COST, RESEARCH_PER_EXPERT, ResearchProgress,
+        Technologies, Technology, accrue_research, unlock_researched_technologies,
+    };
+    use crate::economy::transport::{
+        BASE_TRANSPORT_CAPACITY, TransportCapacity, apply_technology_transport_capacity,
+        initialize_transport_capacity,
+    };
+    use crate::economy::workforce::{WorkerSkill, Workforce};
+
+    #[test]
+    fn capacity_technology_raises_effective_transport_capacity() {
+        let mut world = World::new();
+        world.init_resource::<TransportCapacity>();
+
+        let nation = world.spawn((Nation, Technologies::new())).id();
+        let _ = world.run_system_once(initialize_transport_capacity);
+        let _ = world.run_system_once(apply_technology_transport_capacity);
+        assert_eq!(
+            world.resource::<TransportCapacity>().snapshot(nation).total,
+            BASE_TRANSPORT_CAPACITY
+        );
+
+        world
+            .get_mut::<Technologies>(nation)
+            .unwrap()
+            .unlock(Technology::StandardGauge);
+        let _ = world.run_system_once(apply_technology_transport_capacity);
+        // Re-applying must not stack the bonus
+        let _ = world.run_system_once(apply_technology_transport_capacity);
+
+        assert_eq!(
+            world.resource::<TransportCapacity>().snapshot(nation).total,
+            BASE_TRANSPORT_CAPACITY + 3
+        );
+    }
+
+    #[test]
+    fn banked_research_unlocks_mechanization() {
+        let mut world = World::new();
+        let nation = world
+            .spawn((
+                Nation,
+                Technologies([Technology::ImprovedTools].into()),
+                ResearchProgress {
+                    points: MECHANIZATION_RESEARCH_COST - 1,
+                },
+            ))
+            .id();
+
+        let _ = world.run_system_once(unlock_researched_technologies);
+        assert!(
+            !world
+                .get::<Technologies>(nation)
+                .unwrap()
+                .has(Technology::Mechanization)
+        );
+
+        world.get_mut::<ResearchProgress>(nation).unwrap().add(11);
+        let _ = world.run_system_once(unlock_researched_technologies);
+        assert!(
+            world
+                .get::<Technologies>(nation)
+                .unwrap()
+                .has(Technology::Mechanization)
+        );
+        assert_eq!(world.get::<ResearchProgress>(nation).unwrap().points, 10);
+    }
+
+    #[test]
+    fn experts_speed_up_research_accrual() {
+        let mut world = World::new();
+        let mut workforce = Workforce::new();
+        workforce.add_untrained(3);
+        let laborers = world
+            .spawn((Nation, workforce.clone(), ResearchProgress::default()))
+            .id();
+        workforce.train_worker(WorkerSkill::Untrained);
+        workforce.train_worker(WorkerSkill::Trained);
+        let scholars = world
+            .spawn((Nation, workforce, ResearchProgress::default()))
+            .id();
+
+        let _ = world.run_system_once(accrue_research);
+
+        assert_eq!(
+            world.get::<ResearchProgress>(laborers).unwrap().points,
+            BASE_RESEARCH_PER_TURN
+        );
+        assert_eq!(
+            world.get::<ResearchProgress>(scholars).unwrap().points,
+            BASE_RESEARCH_PER_TURN + RESEARCH_PER_EXPERT
+        );
+    }
 }
@@ -1,20 +1,81 @@
 use bevy::prelude::*;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 
 use crate::economy::market::{MARKET_RESOURCES, MarketPriceModel, MarketVolume};
-use crate::economy::nation::Nation;
+use crate::economy::nation::{Nation, NationId};
 use crate::economy::trade_capacity::TradeCapacity;
 use crate::economy::{
-    Allocations, Good, ReservationId, ReservationSystem, Stockpile, Treasury, Workforce,
+    Allocations, Good, MarketSellOrder, ReservationId, ReservationSystem, Stockpile, Treasury,
+    Workforce,
 };
 
+/// Units and money one nation moved on the market during the last resolution
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TradeFlow {
+    pub units_bought: u32,
+    pub units_sold: u32,
+    pub spent: i64,
+    pub earned: i64,
+}
+
+/// Units each pair of nations traded with each other during the last market resolution
+#[derive(Resource, Default, Debug)]
+pub struct TradeLedger {
+    units: HashMap<(Entity, Entity), u32>,
+    flows: HashMap<Entity, TradeFlow>,
+}
+
+impl TradeLedger {
+    fn key(a: Entity, b: Entity) -> (Entity, Entity) {
+        if a <= b { (a, b) } else { (b, a) }
+    }
+
+    pub fn record(&mut self, a: Entity, b: Entity, units: u32) {
+        *self.units.entry(Self::key(a, b)).or_insert(0) += units;
+    }
+
+    /// Record `units` sold by `seller` to `buyer` at `price` each
+    pub fn record_sale(&mut self, seller: Entity, buyer: Entity, units: u32, price: i64) {
+        self.record(seller, buyer, units);
+        let value = price * units as i64;
+        let seller_flow = self.flows.entry(seller).or_default();
+        seller_flow.units_sold += units;
+        seller_flow.earned += value;
+        let buyer_flow = self.flows.entry(buyer).or_default();
+        buyer_flow.units_bought += units;
+        buyer_flow.spent += value;
+    }
+
+    /// What `nation` bought and sold in total
+    pub fn flow(&self, nation: Entity) -> TradeFlow {
+        self.flows.get(&nation).copied().unwrap_or_default()
+    }
+
+    /// Units exchanged between `a` and `b`, in either direction
+    pub fn units_between(&self, a: Entity, b: Entity) -> u32 {
+        self.units.get(&Self::key(a, b)).copied().unwrap_or(0)
+    }
+
+    /// Every trading pair with the units they exchanged
+    pub fn pairs(&self) -> impl Iterator<Item = ((Entity, Entity), u32)> + '_ {
+        self.units.iter().map(|(&pair, &units)| (pair, units))
+    }
+
+    pub fn clear(&mut self) {
+        self.units.clear();
+        self.flows.clear();
+    }
+}
+
 #[derive(Debug, Clone)]
 struct NationMarketSnapshot {
     entity: Entity,
+    /// Stable ordering key, so ties break the same way after a save and reload
+    order: (u32, Entity),
     name: Option<String>,
     available_cash: i64,
     buy_interest: HashSet<Good>,
-    sell_orders: HashMap<Good, Vec<ReservationId>>,
+    sell_orders: HashMap<Good, MarketSellOrder>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -29,6 +90,8 @@ struct PlannedTrade {
 /// Matches sell reservations against nations with buy interest and transfers goods
 /// and cash between their stockpiles and treasuries. Unsold reservations remain
 /// in place so they can be released when allocations reset at the start of the next turn.
+/// Scarce supply is shared out one unit per buyer per round, so the split does not
+/// depend on the order nations are queried in.
 ///
 /// After resolution, base prices are updated based on observed supply/demand.
 pub fn resolve_market_orders(
@@ -40,30 +103,34 @@ pub fn resolve_market_orders(
             &mut Workforce,
             &mut Treasury,
             Option<&Name>,
+            Option<&NationId>,
         ),
         With<Nation>,
     >,
     nation_entities: Query<Entity, With<Nation>>,
     mut pricing: ResMut<MarketPriceModel>,
     mut trade_capacity: ResMut<TradeCapacity>,
+    mut ledger: ResMut<TradeLedger>,
 ) {
+    ledger.clear();
     let mut snapshots = Vec::new();
 
     for entity in nation_entities.iter() {
-        if let Ok((allocations, _reservations, _stockpile, _workforce, treasury, name)) =
+        if let Ok((allocations, _reservations, _stockpile, _workforce, treasury, name, id)) =
             nations.get_mut(entity)
         {
             let buy_interest: HashSet<Good> = allocations.market_buys.clone();
 
-            let mut sell_orders: HashMap<Good, Vec<ReservationId>> = HashMap::new();
-            for (good, reservations) in allocations.market_sells.iter() {
-                if !reservations.is_empty() {
-                    sell_orders.insert(*good, reservations.clone());
-                }
-            }
+            let sell_orders: HashMap<Good, MarketSellOrder> = allocations
+                .market_sells
+                .iter()
+                .filter(|(_, order)| order.quantity > 0)
+                .map(|(good, order)| (*good, *order))
+                .collect();
 
             snapshots.push(NationMarketSnapshot {
                 entity,
+                order: (id.map_or(u32::MAX, |id| id.0), entity),
                 name: name.map(|n| n.to_string()),
                 available_cash: treasury.available(),
                 buy_interest,
@@ -75,6 +142,8 @@ pub fn resolve_market_orders(
     if snapshots.is_empty() {
         return;
     }
+    // Sellers and buyers are visited in nation id order from here on
+    snapshots.sort_by_key(|snapshot| snapshot.order);
 
     trade_capacity.reset_usage();
 
@@ -94,13 +163,13 @@ pub fn resolve_market_orders(
     let mut observed_volumes: HashMap<Good, MarketVolume> = HashMap::new();
 
     for &good in MARKET_RESOURCES {
-        let mut sellers: Vec<(Entity, Vec<ReservationId>)> = snapshots
+        let sellers: Vec<(Entity, MarketSellOrder)> = snapshots
             .iter()
             .filter_map(|snapshot| {
                 snapshot
                     .sell_orders
                     .get(&good)
-                    .map(|reservations| (snapshot.entity, reservations.clone()))
+                    .map(|order| (snapshot.entity, *order))
             })
             .collect();
 
@@ -120,10 +189,7 @@ pub fn resolve_market_orders(
             })
             .collect();
 
-        let total_supply: u32 = sellers
-            .iter()
-            .map(|(_, reservations)| reservations.len() as u32)
-            .sum();
+        let total_supply: u32 = sellers.iter().map(|(_, order)| order.quantity).sum();
 
         // If no buyers, record supply and zero demand
         if interested_buyers.is_empty() {
@@ -141,116 +207,89 @@ pub fn resolve_market_orders(
         // The price updates for the *next* turn based on the activity we record now
         let price = pricing.current_price(good) as i64;
 
-        // Track demand: sum of everything bought + everything buyers WANTED to buy but couldn't (stockout)
-        let mut total_demand_accumulated: u32 = 0;
-
-        sellers.sort_by_key(|(entity, _)| entity.index());
-
-        let mut seller_queue: VecDeque<(Entity, Vec<ReservationId>)> =
-            sellers.into_iter().collect();
-
-        // We iterate buyers in order (First-Come-First-Served for now)
-        // TODO: In the future, this order might be randomized or based on prestige/diplomacy
-        for buyer in interested_buyers {
-            // Check if there are any goods left to offer
-            if seller_queue.is_empty() {
-                // Determine how much they WOULD have bought (Unsatisfied Demand)
-                let unfulfilled_demand = estimate_potential_demand(
+        // Every buyer states up front how much it wants of the supply offered by others,
+        // so no buyer's demand depends on what was bought before it
+        let mut wants: Vec<(Entity, u32)> = interested_buyers
+            .iter()
+            .map(|&buyer| {
+                let from_others: u32 = sellers
+                    .iter()
+                    .filter(|(seller, _)| *seller != buyer)
+                    .map(|(_, order)| order.quantity)
+                    .sum();
+                let wanted = decide_buyer_quantity(
                     buyer,
                     good,
                     price,
+                    from_others,
                     cash_map.get(&buyer).copied().unwrap_or(0),
                     capacity_available.get(&buyer).copied().unwrap_or(0),
                 );
-                total_demand_accumulated += unfulfilled_demand;
-                continue;
-            }
-
-            let Some(mut cash_available) = cash_map.get(&buyer).copied() else {
-                continue;
-            };
-
-            // --- DECISION POINT ---
-            // Determine how much the buyer wants to take from the available market supply.
-            // Currently, this is a greedy algorithm (take max possible).
-            // Future UI hook will go here.
-            let available_in_market: u32 = seller_queue.iter().map(|(_, r)| r.len() as u32).sum();
-            let buyer_capacity = capacity_available.get(&buyer).copied().unwrap_or(0);
-
-            let quantity_wanted = decide_buyer_quantity(
-                buyer,
-                good,
-                price,
-                available_in_market,
-                cash_available,
-                buyer_capacity,
-            );
+                (buyer, wanted)
+            })
+            .collect();
+        // Demand for pricing is what buyers would take if supply were unlimited, so a
+        // stockout still pushes the price up
+        let total_demand_accumulated: u32 = interested_buyers
+            .iter()
+            .map(|buyer| {
+                estimate_potential_demand(
+                    *buyer,
+                    good,
+                    price,
+                    cash_map.get(buyer).copied().unwrap_or(0),
+                    capacity_available.get(buyer).copied().unwrap_or(0),
+                )
+            })
+            .sum();
 
-            // Record demand
-            total_demand_accumulated += quantity_wanted;
-
-            // Execute the purchase loop
-            let mut quantity_to_buy = quantity_wanted;
-
-            while quantity_to_buy > 0 {
-                // Get next seller
-                let mut seller_entry: Option<(Entity, Vec<ReservationId>)> = None;
-
-                // Find a valid seller (skip self-trading)
-                let queue_len = seller_queue.len();
-                for _ in 0..queue_len {
-                    if let Some((seller_candidate, reservations)) = seller_queue.pop_front() {
-                        if seller_candidate == buyer {
-                            // Can't buy from self, put back at end
-                            seller_queue.push_back((seller_candidate, reservations));
-                            continue;
-                        }
-                        seller_entry = Some((seller_candidate, reservations));
-                        break;
-                    }
+        // Buyers take one unit each per round until supply or demand runs out, so scarce
+        // supply is split evenly whatever order the nations were queried in. A unit left
+        // over from an uneven split goes to the buyer with the lowest nation id.
+        let mut seller_queue: Vec<(Entity, MarketSellOrder)> = sellers;
+        let mut bought_this_round = true;
+        while bought_this_round {
+            bought_this_round = false;
+            for (buyer, wanted) in wants.iter_mut() {
+                let buyer = *buyer;
+                if *wanted == 0 {
+                    continue;
                 }
-
-                let Some((seller, mut reservations)) = seller_entry else {
-                    // No valid sellers (or only self remaining)
-                    break;
-                };
-
-                let seller_capacity = capacity_available.get(&seller).copied().unwrap_or(0);
-
-                // Validate seller capacity
-                if seller_capacity == 0 {
-                    debug!(
-                        "Skipping seller {:?} for {:?}: no trade capacity available",
-                        seller, good
-                    );
-                    // Put back (maybe they can sell to someone else? actually no, if no cap, they can't sell)
-                    // But maybe we should handle this better. For now, skip.
-                    // If we discard them, the goods are effectively removed from market for this buyer.
-                    // But we push them back for next buyer? No, if no cap, they can't sell to ANYONE.
-                    // Ideally we should filter them out earlier, but capacity is dynamic.
+                if capacity_available.get(&buyer).copied().unwrap_or(0) == 0 {
+                    *wanted = 0;
                     continue;
                 }
 
-                // Take one unit
-                let Some(reservation) = reservations.pop() else {
+                // Drain sellers one at a time; sellers without trade capacity cannot sell
+                let Some(index) = seller_queue.iter().position(|(seller, order)| {
+                    *seller != buyer
+                        && order.quantity > 0
+                        && capacity_available.get(seller).copied().unwrap_or(0) > 0
+                }) else {
+                    *wanted = 0;
                     continue;
                 };
+                let (seller, order) = &mut seller_queue[index];
+                let seller = *seller;
+                order.quantity -= 1;
 
                 planned_trades.push(PlannedTrade {
                     good,
                     price: price as u32,
                     seller,
                     buyer,
-                    reservation,
+                    reservation: order.reservation,
                 });
+                if order.quantity == 0 {
+                    seller_queue.remove(index);
+                }
 
                 info!(
                     "Market trade: {:?} sold for ${} (seller: {:?}, buyer: {:?})",
                     good, price, seller, buyer
                 );
 
-                // Update State
-                cash_available -= price;
+                *cash_map.entry(buyer).or_insert(0) -= price;
                 *cash_map.entry(seller).or_insert(0) += price;
 
                 if let Some(entry) = capacity_available.get_mut(&seller) {
@@ -264,19 +303,9 @@ pub fn resolve_market_orders(
                 let buyer_consumed = trade_capacity.consume(buyer, 1);
                 debug_assert!(seller_consumed && buyer_consumed, "trade capacity mismatch");
 
-                quantity_to_buy -= 1;
-
-                // Return seller to queue if they still have stock
-                if !reservations.is_empty() {
-                    seller_queue.push_front((seller, reservations)); // Push front to keep buying from same seller?
-                    // Or push back to spread load?
-                    // Imperialism usually drains one seller then next.
-                    // But `seller_queue.push_back` was original.
-                    // Let's use push_front to minimize transaction fragmentation (buy all from A, then B).
-                }
+                *wanted -= 1;
+                bought_this_round = true;
             }
-
-            cash_map.insert(buyer, cash_available);
         }
 
         let volume = MarketVolume::new(total_supply, total_demand_accumulated);
@@ -311,27 +340,30 @@ pub fn resolve_market_orders(
             mut seller_alloc,
             mut seller_reservations,
             mut seller_stockpile,
-            mut seller_workforce,
+            _,
             mut seller_treasury,
             _,
+            _,
         )) = nations.get_mut(trade.seller)
         {
-            if let Some(vec) = seller_alloc.market_sells.get_mut(&trade.good) {
-                if let Some(pos) = vec.iter().position(|id| *id == trade.reservation) {
-                    vec.swap_remove(pos);
-                } else {
-                    warn!(
-                        "Market trade missing reservation for seller {:?} {:?}",
-                        trade.seller, trade.good
-                    );
+            match seller_alloc.market_sells.get_mut(&trade.good) {
+                Some(order) if order.reservation == trade.reservation => {
+                    order.quantity = order.quantity.saturating_sub(1);
+                    if order.quantity == 0 {
+                        seller_alloc.market_sells.remove(&trade.good);
+                    }
                 }
+                _ => warn!(
+                    "Market trade missing reservation for seller {:?} {:?}",
+                    trade.seller, trade.good
+                ),
             }
 
-            seller_reservations.consume(
+            seller_reservations.consume_units(
                 trade.reservation,
+                trade.good,
+                1,
                 &mut seller_stockpile,
-                &mut seller_workforce,
-                &mut seller_treasury,
             );
             seller_treasury.add(price);
         } else {
@@ -339,7 +371,7 @@ pub fn resolve_market_orders(
             continue;
         }
 
-        if let Ok((_, _, mut buyer_stockpile, _, mut buyer_treasury, _)) =
+        if let Ok((_, _, mut buyer_stockpile, _, mut buyer_treasury, _, _)) =
             nations.get_mut(trade.buyer)
         {
             buyer_stockpile.add(trade.good, 1);
@@ -348,6 +380,7 @@ pub fn resolve_market_orders(
             warn!("Market trade failed: buyer {:?} not found", trade.buyer);
             continue;
         }
+        ledger.record_sale(trade.seller, trade.buyer, 1, price);
 
         let seller_name = name_lookup
             .get(&trade.seller)
@@ -427,9 +460,6 @@ fn estimate_potential_demand(
     cash_available: i64,
     capacity_available: u32,
 ) -> u32 {
-    // Similar to decide_buyer_quantity but ignores available_quantity.
-    // Represents "How much do I want?"
-
     if price <= 0 {
         return capacity_available;
     }
@@ -440,11 +470,12 @@ fn estimate_potential_demand(
 
 #[cfg(test)]
 mod tests {
-    use bevy::ecs::system::SystemState;
+    use bevy::ecs::system::{RunSystemOnce, SystemState};
     use bevy::prelude::{App, Entity, Query, ResMut, With};
 
+    use crate::economy::NationMetrics;
     use crate::economy::market::MarketPriceModel;
-    use crate::economy::trade::resolve_market_orders;
+    use crate::economy::trade::{TradeLedger, resolve_market_orders};
     use crate::economy::trade_capacity::TradeCapacity;
 
     fn set_trade_capacity(app: &mut App, nation: Entity, total: u32) {
@@ -455,8 +486,13 @@ mod tests {
         snapshot.used = 0;
     }
     use crate::economy::{
-        Good, allocation::Allocations, nation::Nation, reservation::ReservationSystem,
-        stockpile::Stockpile, treasury::Treasury, workforce::Workforce,
+        Good, MarketSellOrder,
+        allocation::Allocations,
+        nation::{Nation, NationId},
+        reservation::ReservationSystem,
+        stockpile::Stockpile,
+        treasury::Treasury,
+        workforce::Workforce,
     };
     use bevy::prelude::Name;
 
@@ -465,6 +501,7 @@ mod tests {
         let mut app = App::new();
         app.insert_resource(MarketPriceModel::default());
         app.insert_resource(TradeCapacity::default());
+        app.init_resource::<TradeLedger>();
 
         let seller = app
             .world_mut()
@@ -516,22 +553,23 @@ mod tests {
             let (mut stockpile, mut reservations, mut allocations, mut workforce, mut treasury) =
                 seller_query.get_mut(world, seller).expect("seller data");
 
-            if let Some(res_id) = reservations.try_reserve(
-                vec![(Good::Grain, 1u32)],
-                0,
-                0,
-                &mut stockpile,
-                &mut workforce,
-                &mut treasury,
-            ) {
-                allocations
-                    .market_sells
-                    .entry(Good::Grain)
-                    .or_default()
-                    .push(res_id);
-            } else {
-                panic!("Failed to reserve grain for sale");
-            }
+            let reservation = reservations
+                .try_reserve(
+                    vec![(Good::Grain, 1u32)],
+                    0,
+                    0,
+                    &mut stockpile,
+                    &mut workforce,
+                    &mut treasury,
+                )
+                .expect("Failed to reserve grain for sale");
+            allocations.market_sells.insert(
+                Good::Grain,
+                MarketSellOrder {
+                    reservation,
+                    quantity: 1,
+                },
+            );
 
             world
                 .get_mut::<Allocations>(buyer)
@@ -549,18 +587,20 @@ mod tests {
                     &mut Workforce,
                     &mut Treasury,
                     Option<&Name>,
+                    Option<&NationId>,
                 ),
                 With<Nation>,
             >,
             Query<Entity, With<Nation>>,
             ResMut<MarketPriceModel>,
             ResMut<TradeCapacity>,
+            ResMut<TradeLedger>,
         )> = SystemState::new(app.world_mut());
 
         {
-            let (nations, nation_entities, pricing, trade_capacity) =
+            let (nations, nation_entities, pricing, trade_capacity, ledger) =
                 system_state.get_mut(app.world_mut());
-            resolve_market_orders(nations, nation_entities, pricing, trade_capacity);
+            resolve_market_orders(nations, nation_entities, pricing, trade_capacity, ledger);
             system_state.apply(app.world_mut());
         }
 
@@ -573,6 +613,10 @@ mod tests {
         // Verify that 1 unit was traded
         assert_eq!(seller_stockpile.get(Good::Grain), 4);
         assert_eq!(buyer_stockpile.get(Good::Grain), 1);
+        assert_eq!(
+            world.resource::<TradeLedger>().units_between(buyer, seller),
+            1
+        );
 
         // With boolean buy interest, pricing is based on estimated demand
         // Just verify that money was transferred correctly
@@ -580,6 +624,18 @@ mod tests {
         let buyer_cost = 1_000 - buyer_treasury.total();
         assert_eq!(seller_gain, buyer_cost, "Money transfer mismatch");
         assert!(seller_gain > 0, "Seller should have earned money");
+
+        // Turn metrics net out the sale for both sides
+        let ledger = world.resource::<TradeLedger>();
+        let seller_metrics =
+            NationMetrics::collect(seller, seller_treasury, 1_000, None, None, ledger);
+        assert_eq!(seller_metrics.net_trade_units, -1);
+        assert_eq!(seller_metrics.net_trade_income, seller_gain);
+        assert_eq!(seller_metrics.treasury_delta, seller_gain);
+        let buyer_metrics =
+            NationMetrics::collect(buyer, buyer_treasury, 1_000, None, None, ledger);
+        assert_eq!(buyer_metrics.net_trade_units, 1);
+        assert_eq!(buyer_metrics.net_trade_income, -buyer_cost);
     }
 
     #[test]
@@ -587,6 +643,7 @@ mod tests {
         let mut app = App::new();
         app.insert_resource(MarketPriceModel::default());
         app.insert_resource(TradeCapacity::default());
+        app.init_resource::<TradeLedger>();
 
         let seller = app
             .world_mut()
@@ -638,23 +695,23 @@ mod tests {
             let (mut stockpile, mut reservations, mut allocations, mut workforce, mut treasury) =
                 seller_query.get_mut(world, seller).expect("seller data");
 
-            for _ in 0..2 {
-                let res_id = reservations
-                    .try_reserve(
-                        vec![(Good::Grain, 1u32)],
-                        0,
-                        0,
-                        &mut stockpile,
-                        &mut workforce,
-                        &mut treasury,
-                    )
-                    .expect("reserve grain for sale");
-                allocations
-                    .market_sells
-                    .entry(Good::Grain)
-                    .or_default()
-                    .push(res_id);
-            }
+            let reservation = reservations
+                .try_reserve(
+                    vec![(Good::Grain, 2u32)],
+                    0,
+                    0,
+                    &mut stockpile,
+                    &mut workforce,
+                    &mut treasury,
+                )
+                .expect("reserve grain for sale");
+            allocations.market_sells.insert(
+                Good::Grain,
+                MarketSellOrder {
+                    reservation,
+                    quantity: 2,
+                },
+            );
 
             world
                 .get_mut::<Allocations>(buyer)
@@ -672,18 +729,20 @@ mod tests {
                     &mut Workforce,
                     &mut Treasury,
                     Option<&Name>,
+                    Option<&NationId>,
                 ),
                 With<Nation>,
             >,
             Query<Entity, With<Nation>>,
             ResMut<MarketPriceModel>,
             ResMut<TradeCapacity>,
+            ResMut<TradeLedger>,
         )> = SystemState::new(app.world_mut());
 
         {
-            let (nations, nation_entities, pricing, trade_capacity) =
+            let (nations, nation_entities, pricing, trade_capacity, ledger) =
                 system_state.get_mut(app.world_mut());
-            resolve_market_orders(nations, nation_entities, pricing, trade_capacity);
+            resolve_market_orders(nations, nation_entities, pricing, trade_capacity, ledger);
             system_state.apply(app.world_mut());
         }
 
@@ -710,6 +769,7 @@ mod tests {
         let mut app = App::new();
         app.insert_resource(MarketPriceModel::default());
         app.insert_resource(TradeCapacity::default());
+        app.init_resource::<TradeLedger>();
 
         let seller = app
             .world_mut()
@@ -754,23 +814,23 @@ mod tests {
                 seller_query.get_mut(world, seller).expect("seller data");
 
             stockpile.add(Good::Grain, 4);
-            for _ in 0..2 {
-                let res_id = reservations
-                    .try_reserve(
-                        vec![(Good::Grain, 1u32)],
-                        0,
-                        0,
-                        &mut stockpile,
-                        &mut workforce,
-                        &mut treasury,
-                    )
-                    .expect("reserve grain for sale");
-                allocations
-                    .market_sells
-                    .entry(Good::Grain)
-                    .or_default()
-                    .push(res_id);
-            }
+            let reservation = reservations
+                .try_reserve(
+                    vec![(Good::Grain, 2u32)],
+                    0,
+                    0,
+                    &mut stockpile,
+                    &mut workforce,
+                    &mut treasury,
+                )
+                .expect("reserve grain for sale");
+            allocations.market_sells.insert(
+                Good::Grain,
+                MarketSellOrder {
+                    reservation,
+                    quantity: 2,
+                },
+            );
         }
 
         {
@@ -790,18 +850,20 @@ mod tests {
                     &mut Workforce,
                     &mut Treasury,
                     Option<&Name>,
+                    Option<&NationId>,
                 ),
                 With<Nation>,
             >,
             Query<Entity, With<Nation>>,
             ResMut<MarketPriceModel>,
             ResMut<TradeCapacity>,
+            ResMut<TradeLedger>,
         )> = SystemState::new(app.world_mut());
 
         {
-            let (nations, nation_entities, pricing, trade_capacity) =
+            let (nations, nation_entities, pricing, trade_capacity, ledger) =
                 system_state.get_mut(app.world_mut());
-            resolve_market_orders(nations, nation_entities, pricing, trade_capacity);
+            resolve_market_orders(nations, nation_entities, pricing, trade_capacity, ledger);
             system_state.apply(app.world_mut());
         }
 
@@ -833,6 +895,7 @@ mod tests {
         let mut app = App::new();
         app.insert_resource(MarketPriceModel::default());
         app.insert_resource(TradeCapacity::default());
+        app.init_resource::<TradeLedger>();
 
         let seller = app
             .world_mut()
@@ -883,22 +946,23 @@ mod tests {
                 seller_query.get_mut(world, seller).expect("seller data");
 
             // Seller reserves 3 Coal for sale
-            for _ in 0..3 {
-                if let Some(res_id) = reservations.try_reserve(
-                    vec![(Good::Coal, 1u32)],
+            let reservation = reservations
+                .try_reserve(
+                    vec![(Good::Coal, 3u32)],
                     0,
                     0,
                     &mut stockpile,
                     &mut workforce,
                     &mut treasury,
-                ) {
-                    allocations
-                        .market_sells
-                        .entry(Good::Coal)
-                        .or_default()
-                        .push(res_id);
-                }
-            }
+                )
+                .expect("reserve coal for sale");
+            allocations.market_sells.insert(
+                Good::Coal,
+                MarketSellOrder {
+                    reservation,
+                    quantity: 3,
+                },
+            );
         }
 
         // Buyer expresses interest (simulating EnemyTurn - happens AFTER seller's sell orders)
@@ -921,18 +985,20 @@ mod tests {
                     &mut Workforce,
                     &mut Treasury,
                     Option<&Name>,
+                    Option<&NationId>,
                 ),
                 With<Nation>,
             >,
             Query<Entity, With<Nation>>,
             ResMut<MarketPriceModel>,
             ResMut<TradeCapacity>,
+            ResMut<TradeLedger>,
         )> = SystemState::new(app.world_mut());
 
         {
-            let (nations, nation_entities, pricing, trade_capacity) =
+            let (nations, nation_entities, pricing, trade_capacity, ledger) =
                 system_state.get_mut(app.world_mut());
-            resolve_market_orders(nations, nation_entities, pricing, trade_capacity);
+            resolve_market_orders(nations, nation_entities, pricing, trade_capacity, ledger);
             system_state.apply(app.world_mut());
         }
 
@@ -973,6 +1039,7 @@ mod tests {
         let mut app = App::new();
         app.insert_resource(MarketPriceModel::default());
         app.insert_resource(TradeCapacity::default());
+        app.init_resource::<TradeLedger>();
 
         let seller = app
             .world_mut()
@@ -1021,7 +1088,7 @@ mod tests {
             stockpile.add(Good::Cotton, 1);
 
             for good in [Good::Grain, Good::Cotton] {
-                let res_id = reservations
+                let reservation = reservations
                     .try_reserve(
                         vec![(good, 1u32)],
                         0,
@@ -1031,11 +1098,13 @@ mod tests {
                         &mut treasury,
                     )
                     .expect("reserve good for sale");
-                allocations
-                    .market_sells
-                    .entry(good)
-                    .or_default()
-                    .push(res_id);
+                allocations.market_sells.insert(
+                    good,
+                    MarketSellOrder {
+                        reservation,
+                        quantity: 1,
+                    },
+                );
             }
         }
 
@@ -1063,18 +1132,20 @@ mod tests {
                     &mut Workforce,
                     &mut Treasury,
                     Option<&Name>,
+                    Option<&NationId>,
                 ),
                 With<Nation>,
             >,
             Query<Entity, With<Nation>>,
             ResMut<MarketPriceModel>,
             ResMut<TradeCapacity>,
+            ResMut<TradeLedger>,
         )> = SystemState::new(app.world_mut());
 
         {
-            let (nations, nation_entities, pricing, trade_capacity) =
+            let (nations, nation_entities, pricing, trade_capacity, ledger) =
                 system_state.get_mut(app.world_mut());
-            resolve_market_orders(nations, nation_entities, pricing, trade_capacity);
+            resolve_market_orders(nations, nation_entities, pricing, trade_capacity, ledger);
             system_state.apply(app.world_mut());
         }
 
@@ -1092,6 +1163,7 @@ mod tests {
         let mut app = App::new();
         app.insert_resource(MarketPriceModel::default());
         app.insert_resource(TradeCapacity::default());
+        app.init_resource::<TradeLedger>();
 
         let seller = app
             .world_mut()
@@ -1151,7 +1223,7 @@ mod tests {
                 seller_query.get_mut(world, seller).expect("seller data");
 
             stockpile.add(Good::Coal, 1);
-            let res_id = reservations
+            let reservation = reservations
                 .try_reserve(
                     vec![(Good::Coal, 1u32)],
                     0,
@@ -1161,11 +1233,13 @@ mod tests {
                     &mut treasury,
                 )
                 .expect("reserve coal for sale");
-            allocations
-                .market_sells
-                .entry(Good::Coal)
-                .or_default()
-                .push(res_id);
+            allocations.market_sells.insert(
+                Good::Coal,
+                MarketSellOrder {
+                    reservation,
+                    quantity: 1,
+                },
+            );
         }
 
         // Both buyers express interest in Coal, pushing demand above supply.
@@ -1192,18 +1266,20 @@ mod tests {
                     &mut Workforce,
                     &mut Treasury,
                     Option<&Name>,
+                    Option<&NationId>,
                 ),
                 With<Nation>,
             >,
             Query<Entity, With<Nation>>,
             ResMut<MarketPriceModel>,
             ResMut<TradeCapacity>,
+            ResMut<TradeLedger>,
         )> = SystemState::new(app.world_mut());
 
         {
-            let (nations, nation_entities, pricing, trade_capacity) =
+            let (nations, nation_entities, pricing, trade_capacity, ledger) =
                 system_state.get_mut(app.world_mut());
-            resolve_market_orders(nations, nation_entities, pricing, trade_capacity);
+            resolve_market_orders(nations, nation_entities, pricing, trade_capacity, ledger);
             system_state.apply(app.world_mut());
         }
 
@@ -1232,6 +1308,7 @@ mod tests {
         let mut app = App::new();
         app.insert_resource(MarketPriceModel::default());
         app.insert_resource(TradeCapacity::default());
+        app.init_resource::<TradeLedger>();
 
         let seller = app
             .world_mut()
@@ -1301,7 +1378,7 @@ mod tests {
             let (mut stockpile, mut reservations, mut allocations, mut workforce, mut treasury) =
                 seller_query.get_mut(world, seller).expect("seller data");
 
-            let res_id = reservations
+            let reservation = reservations
                 .try_reserve(
                     vec![(Good::Iron, 1u32)],
                     0,
@@ -1311,11 +1388,13 @@ mod tests {
                     &mut treasury,
                 )
                 .expect("reserve iron for sale");
-            allocations
-                .market_sells
-                .entry(Good::Iron)
-                .or_default()
-                .push(res_id);
+            allocations.market_sells.insert(
+                Good::Iron,
+                MarketSellOrder {
+                    reservation,
+                    quantity: 1,
+                },
+            );
 
             // Both buyers express interest
             world
@@ -1340,18 +1419,20 @@ mod tests {
                     &mut Workforce,
                     &mut Treasury,
                     Option<&Name>,
+                    Option<&NationId>,
                 ),
                 With<Nation>,
             >,
             Query<Entity, With<Nation>>,
             ResMut<MarketPriceModel>,
             ResMut<TradeCapacity>,
+            ResMut<TradeLedger>,
         )> = SystemState::new(app.world_mut());
 
         {
-            let (nations, nation_entities, pricing, trade_capacity) =
+            let (nations, nation_entities, pricing, trade_capacity, ledger) =
                 system_state.get_mut(app.world_mut());
-            resolve_market_orders(nations, nation_entities, pricing, trade_capacity);
+            resolve_market_orders(nations, nation_entities, pricing, trade_capacity, ledger);
             system_state.apply(app.world_mut());
         }
 
@@ -1373,6 +1454,7 @@ mod tests {
         let mut app = App::new();
         app.insert_resource(MarketPriceModel::default());
         app.insert_resource(TradeCapacity::default());
+        app.init_resource::<TradeLedger>();
 
         let seller = app
             .world_mut()
@@ -1430,23 +1512,23 @@ mod tests {
                 seller_query.get_mut(world, seller).expect("seller data");
 
             // Sell 5 units - high supply
-            for _ in 0..5 {
-                let res_id = reservations
-                    .try_reserve(
-                        vec![(Good::Grain, 1u32)],
-                        0,
-                        0,
-                        &mut stockpile,
-                        &mut workforce,
-                        &mut treasury,
-                    )
-                    .expect("reserve grain for sale");
-                allocations
-                    .market_sells
-                    .entry(Good::Grain)
-                    .or_default()
-                    .push(res_id);
-            }
+            let reservation = reservations
+                .try_reserve(
+                    vec![(Good::Grain, 5u32)],
+                    0,
+                    0,
+                    &mut stockpile,
+                    &mut workforce,
+                    &mut treasury,
+                )
+                .expect("reserve grain for sale");
+            allocations.market_sells.insert(
+                Good::Grain,
+                MarketSellOrder {
+                    reservation,
+                    quantity: 5,
+                },
+            );
 
             // Just one buyer with limited buying power
             world
@@ -1466,18 +1548,20 @@ mod tests {
                     &mut Workforce,
                     &mut Treasury,
                     Option<&Name>,
+                    Option<&NationId>,
                 ),
                 With<Nation>,
             >,
             Query<Entity, With<Nation>>,
             ResMut<MarketPriceModel>,
             ResMut<TradeCapacity>,
+            ResMut<TradeLedger>,
         )> = SystemState::new(app.world_mut());
 
         {
-            let (nations, nation_entities, pricing, trade_capacity) =
+            let (nations, nation_entities, pricing, trade_capacity, ledger) =
                 system_state.get_mut(app.world_mut());
-            resolve_market_orders(nations, nation_entities, pricing, trade_capacity);
+            resolve_market_orders(nations, nation_entities, pricing, trade_capacity, ledger);
             system_state.apply(app.world_mut());
         }
 
@@ -1493,4 +1577,100 @@ mod tests {
             initial_price
         );
     }
+
+    /// Spawn a seller offering `supply` Coal and two buyers who each want more than that,
+    /// spawning the buyers in the given order, and return what each buyer received.
+    /// Buyer A has the lower nation id.
+    fn split_between_competing_buyers(buyer_a_first: bool, supply: u32) -> (u32, u32) {
+        let mut app = App::new();
+        app.insert_resource(MarketPriceModel::default());
+        app.insert_resource(TradeCapacity::default());
+        app.init_resource::<TradeLedger>();
+
+        let spawn_nation = |app: &mut App, name: &str, id: u32, cash: i64| {
+            app.world_mut()
+                .spawn((
+                    Nation,
+                    NationId(id),
+                    Name::new(name.to_string()),
+                    Allocations::default(),
+                    ReservationSystem::default(),
+                    Stockpile::default(),
+                    Workforce::new(),
+                    Treasury::new(cash),
+                ))
+                .id()
+        };
+        let seller = spawn_nation(&mut app, "Seller", 0, 0);
+        let (buyer_a, buyer_b) = if buyer_a_first {
+            let a = spawn_nation(&mut app, "Buyer A", 1, 1_000);
+            (a, spawn_nation(&mut app, "Buyer B", 2, 1_000))
+        } else {
+            let b = spawn_nation(&mut app, "Buyer B", 2, 1_000);
+            (spawn_nation(&mut app, "Buyer A", 1, 1_000), b)
+        };
+        for nation in [seller, buyer_a, buyer_b] {
+            set_trade_capacity(&mut app, nation, 10);
+        }
+
+        {
+            let world = app.world_mut();
+            let mut seller_query = world.query::<(
+                &mut Stockpile,
+                &mut ReservationSystem,
+                &mut Allocations,
+                &mut Workforce,
+                &mut Treasury,
+            )>();
+            let (mut stockpile, mut reservations, mut allocations, mut workforce, mut treasury) =
+                seller_query.get_mut(world, seller).expect("seller data");
+            stockpile.add(Good::Coal, supply);
+            let reservation = reservations
+                .try_reserve(
+                    vec![(Good::Coal, supply)],
+                    0,
+                    0,
+                    &mut stockpile,
+                    &mut workforce,
+                    &mut treasury,
+                )
+                .expect("reserve coal for sale");
+            allocations.market_sells.insert(
+                Good::Coal,
+                MarketSellOrder {
+                    reservation,
+                    quantity: supply,
+                },
+            );
+        }
+        for buyer in [buyer_a, buyer_b] {
+            app.world_mut()
+                .get_mut::<Allocations>(buyer)
+                .unwrap()
+                .market_buys
+                .insert(Good::Coal);
+        }
+
+        app.world_mut()
+            .run_system_once(resolve_market_orders)
+            .expect("market resolution should run");
+
+        let world = app.world();
+        (
+            world.get::<Stockpile>(buyer_a).unwrap().get(Good::Coal),
+            world.get::<Stockpile>(buyer_b).unwrap().get(Good::Coal),
+        )
+    }
+
+    #[test]
+    fn competing_buyers_split_scarce_supply_regardless_of_order() {
+        assert_eq!(split_between_competing_buyers(true, 4), (2, 2));
+        assert_eq!(split_between_competing_buyers(false, 4), (2, 2));
+    }
+
+    #[test]
+    fn odd_unit_goes_to_the_lowest_nation_id_whatever_the_spawn_order() {
+        assert_eq!(split_between_competing_buyers(true, 5), (3, 2));
+        assert_eq!(split_between_competing_buyers(false, 5), (3, 2));
+    }
 }
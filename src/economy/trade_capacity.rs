@@ -32,6 +32,15 @@ impl TradeCapacitySnapshot {
     pub fn available(&self) -> u32 {
         self.total.saturating_sub(self.used)
     }
+
+    /// Fraction of cargo holds in use (0.0 to 1.0). A nation without any holds
+    /// counts as fully utilized since it cannot move goods at all.
+    pub fn utilization(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.used as f32 / self.total as f32).min(1.0)
+    }
 }
 
 impl TradeCapacity {
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, TaskPool};
 use bevy_ecs_tilemap::prelude::TilePos;
 use std::collections::{HashMap, HashSet, VecDeque};
 
@@ -8,13 +9,182 @@ use crate::economy::transport::types::{Depot, Port, Rails};
 /// Build adjacency list for BFS from rail edges
 pub fn build_rail_graph(rails: &Rails) -> HashMap<TilePos, Vec<TilePos>> {
     let mut graph: HashMap<TilePos, Vec<TilePos>> = HashMap::new();
-    for &(a, b) in rails.0.iter() {
-        graph.entry(a).or_default().push(b);
-        graph.entry(b).or_default().push(a);
+    for &edge in rails.0.iter() {
+        add_rail_edge(&mut graph, edge);
     }
     graph
 }
 
+/// Insert a single undirected edge into an existing adjacency list
+pub fn add_rail_edge(graph: &mut HashMap<TilePos, Vec<TilePos>>, (a, b): (TilePos, TilePos)) {
+    graph.entry(a).or_default().push(b);
+    graph.entry(b).or_default().push(a);
+}
+
+/// Grow a reachable set after `edge` was added to `graph`.
+/// Only explores from the newly reached endpoint, so the cost is proportional to the
+/// newly connected part of the network rather than the whole graph.
+pub fn extend_reachability(
+    reachable: &mut HashSet<TilePos>,
+    graph: &HashMap<TilePos, Vec<TilePos>>,
+    (a, b): (TilePos, TilePos),
+) {
+    let start = match (reachable.contains(&a), reachable.contains(&b)) {
+        (true, false) => b,
+        (false, true) => a,
+        // Either both sides were already reachable or neither is: nothing new
+        _ => return,
+    };
+
+    let mut queue: VecDeque<TilePos> = VecDeque::new();
+    reachable.insert(start);
+    queue.push_back(start);
+
+    while let Some(current) = queue.pop_front() {
+        if let Some(neighbors) = graph.get(&current) {
+            for &neighbor in neighbors {
+                if reachable.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+}
+
+/// BFS over the rail graph from a single capital
+fn reachable_from(
+    capital_pos: TilePos,
+    graph: &HashMap<TilePos, Vec<TilePos>>,
+) -> HashSet<TilePos> {
+    let mut reachable: HashSet<TilePos> = HashSet::new();
+    let mut queue: VecDeque<TilePos> = VecDeque::new();
+
+    queue.push_back(capital_pos);
+    reachable.insert(capital_pos);
+
+    while let Some(current) = queue.pop_front() {
+        if let Some(neighbors) = graph.get(&current) {
+            for &neighbor in neighbors {
+                if reachable.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+
+    reachable
+}
+
+/// Reachable tiles for every nation, one BFS after another
+pub fn nation_reachability_serial(
+    graph: &HashMap<TilePos, Vec<TilePos>>,
+    capitals: &[(Entity, TilePos)],
+) -> HashMap<Entity, HashSet<TilePos>> {
+    capitals
+        .iter()
+        .map(|&(nation, capital_pos)| (nation, reachable_from(capital_pos, graph)))
+        .collect()
+}
+
+/// Reachable tiles for every nation, running one BFS per nation on the compute task pool.
+/// The graph is only read, so nations have no data dependencies on each other; results are
+/// gathered in `capitals` order, so the outcome matches the serial path exactly.
+pub fn nation_reachability_parallel(
+    graph: &HashMap<TilePos, Vec<TilePos>>,
+    capitals: &[(Entity, TilePos)],
+) -> HashMap<Entity, HashSet<TilePos>> {
+    if capitals.len() < 2 {
+        return nation_reachability_serial(graph, capitals);
+    }
+
+    let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+    let results = pool.scope(|scope| {
+        for &(nation, capital_pos) in capitals {
+            scope.spawn(async move { (nation, reachable_from(capital_pos, graph)) });
+        }
+    });
+
+    results.into_iter().collect()
+}
+
+/// Per-nation rail reachability from the last BFS run.
+/// `compute_rail_connectivity` reuses it until the network actually changes: rails or
+/// capitals are modified, or a depot/port is added or removed.
+#[derive(Resource, Debug)]
+pub struct RailConnectivityCache {
+    pub reachable: HashMap<Entity, HashSet<TilePos>>,
+    /// Rail graph the reachable sets were computed from
+    graph: HashMap<TilePos, Vec<TilePos>>,
+    /// Number of rail edges in `graph`
+    edge_count: usize,
+    /// Edges added to `Rails` since the last pass, applied incrementally
+    pending_edges: Vec<(TilePos, TilePos)>,
+    dirty: bool,
+    /// Number of full reachability recomputations (for diagnostics)
+    pub recomputations: u32,
+    /// Number of passes that only extended reachability from new edges (for diagnostics)
+    pub incremental_updates: u32,
+}
+
+impl Default for RailConnectivityCache {
+    fn default() -> Self {
+        Self {
+            reachable: HashMap::new(),
+            graph: HashMap::new(),
+            edge_count: 0,
+            pending_edges: Vec::new(),
+            dirty: true,
+            recomputations: 0,
+            incremental_updates: 0,
+        }
+    }
+}
+
+impl RailConnectivityCache {
+    /// Force the next connectivity pass to rebuild reachability
+    pub fn invalidate(&mut self) {
+        self.dirty = true;
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Record a rail edge that was just inserted into `Rails`, so the next pass can
+    /// extend reachability from it instead of rebuilding everything
+    pub fn record_rail_added(&mut self, edge: (TilePos, TilePos)) {
+        self.pending_edges.push(edge);
+    }
+
+    /// Pending edges fully explain the change to `Rails` (nothing removed or replaced)
+    fn can_apply_incrementally(&self, rails: &Rails) -> bool {
+        !self.pending_edges.is_empty()
+            && rails.0.len() == self.edge_count + self.pending_edges.len()
+            && self.pending_edges.iter().all(|edge| rails.0.contains(edge))
+    }
+
+    fn rebuild(&mut self, rails: &Rails, capitals: &[(Entity, TilePos)]) {
+        self.graph = build_rail_graph(rails);
+        self.edge_count = rails.0.len();
+        self.pending_edges.clear();
+        // Per-nation BFS runs in parallel; each nation only reads the shared graph
+        self.reachable = nation_reachability_parallel(&self.graph, capitals);
+        self.dirty = false;
+        self.recomputations += 1;
+    }
+
+    fn apply_pending_edges(&mut self) {
+        for edge in std::mem::take(&mut self.pending_edges) {
+            add_rail_edge(&mut self.graph, edge);
+            self.edge_count += 1;
+            for reachable in self.reachable.values_mut() {
+                extend_reachability(reachable, &self.graph, edge);
+            }
+        }
+        self.incremental_updates += 1;
+    }
+}
+
 /// Compute rail network connectivity for all nations (Logic Layer)
 /// Uses BFS from each nation's capital to mark depots/ports as connected
 /// Observer triggered by RecomputeConnectivity events (topology changes)
@@ -22,40 +192,30 @@ pub fn build_rail_graph(rails: &Rails) -> HashMap<TilePos, Vec<TilePos>> {
 pub fn compute_rail_connectivity(
     _trigger: On<RecomputeConnectivity>,
     rails: Res<Rails>,
-    nations: Query<(Entity, &crate::economy::nation::Capital)>,
+    mut cache: ResMut<RailConnectivityCache>,
+    nations: Query<(Entity, Ref<crate::economy::nation::Capital>)>,
     mut depots: Query<&mut Depot>,
     mut ports: Query<&mut Port>,
 ) {
-    // Build the rail graph once
-    let graph = build_rail_graph(&rails);
-
-    // Build a HashMap of nation reachability sets to avoid nested iteration
-    let mut nation_reachable: HashMap<Entity, HashSet<TilePos>> = HashMap::new();
-
-    // For each nation, run BFS from their capital
-    for (nation_entity, capital) in nations.iter() {
-        let capital_pos = capital.0;
-
-        // BFS to find all reachable tiles from this capital
-        let mut reachable: HashSet<TilePos> = HashSet::new();
-        let mut queue: VecDeque<TilePos> = VecDeque::new();
-
-        queue.push_back(capital_pos);
-        reachable.insert(capital_pos);
-
-        while let Some(current) = queue.pop_front() {
-            if let Some(neighbors) = graph.get(&current) {
-                for &neighbor in neighbors {
-                    if !reachable.contains(&neighbor) {
-                        reachable.insert(neighbor);
-                        queue.push_back(neighbor);
-                    }
-                }
-            }
+    let capitals_changed = nations.iter().any(|(_, capital)| capital.is_changed());
+    if cache.dirty || capitals_changed {
+        let capitals: Vec<(Entity, TilePos)> = nations
+            .iter()
+            .map(|(nation_entity, capital)| (nation_entity, capital.0))
+            .collect();
+        cache.rebuild(&rails, &capitals);
+    } else if rails.is_changed() {
+        if cache.can_apply_incrementally(&rails) {
+            cache.apply_pending_edges();
+        } else {
+            let capitals: Vec<(Entity, TilePos)> = nations
+                .iter()
+                .map(|(nation_entity, capital)| (nation_entity, capital.0))
+                .collect();
+            cache.rebuild(&rails, &capitals);
         }
-
-        nation_reachable.insert(nation_entity, reachable);
     }
+    let nation_reachable = &cache.reachable;
 
     // Update all depots in a single pass using cached reachability sets
     // This eliminates O(n*m) nested iteration
@@ -73,22 +233,250 @@ pub fn compute_rail_connectivity(
     }
 }
 
-/// Observer: trigger connectivity recomputation when Depot is added
-pub fn on_depot_added(_trigger: On<Add, Depot>, mut commands: Commands) {
+/// Observer: invalidate the cache and recompute connectivity when Depot is added
+pub fn on_depot_added(
+    _trigger: On<Add, Depot>,
+    mut cache: ResMut<RailConnectivityCache>,
+    mut commands: Commands,
+) {
+    cache.invalidate();
     commands.trigger(RecomputeConnectivity);
 }
 
-/// Observer: trigger connectivity recomputation when Depot is removed
-pub fn on_depot_removed(_trigger: On<Remove, Depot>, mut commands: Commands) {
+/// Observer: invalidate the cache and recompute connectivity when Depot is removed
+pub fn on_depot_removed(
+    _trigger: On<Remove, Depot>,
+    mut cache: ResMut<RailConnectivityCache>,
+    mut commands: Commands,
+) {
+    cache.invalidate();
     commands.trigger(RecomputeConnectivity);
 }
 
-/// Observer: trigger connectivity recomputation when Port is added
-pub fn on_port_added(_trigger: On<Add, Port>, mut commands: Commands) {
+/// Observer: invalidate the cache and recompute connectivity when Port is added
+pub fn on_port_added(
+    _trigger: On<Add, Port>,
+    mut cache: ResMut<RailConnectivityCache>,
+    mut commands: Commands,
+) {
+    cache.invalidate();
     commands.trigger(RecomputeConnectivity);
 }
 
-/// Observer: trigger connectivity recomputation when Port is removed
-pub fn on_port_removed(_trigger: On<Remove, Port>, mut commands: Commands) {
+/// Observer: invalidate the cache and recompute connectivity when Port is removed
+pub fn on_port_removed(
+    _trigger: On<Remove, Port>,
+    mut cache: ResMut<RailConnectivityCache>,
+    mut commands: Commands,
+) {
+    cache.invalidate();
     commands.trigger(RecomputeConnectivity);
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy_ecs_tilemap::prelude::TilePos;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::economy::nation::Capital;
+    use crate::economy::transport::connectivity::{
+        RailConnectivityCache, add_rail_edge, build_rail_graph, compute_rail_connectivity,
+        extend_reachability, nation_reachability_parallel, nation_reachability_serial,
+        on_depot_added, on_depot_removed,
+    };
+    use crate::economy::transport::{Depot, Rails, RecomputeConnectivity, ordered_edge};
+
+    /// Random rail network on a large map, split into several disconnected clusters
+    fn generated_rails(size: u32, edges: usize, seed: u64) -> Rails {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut rails = Rails::default();
+        while rails.0.len() < edges {
+            let x = rng.random_range(0..size - 1);
+            let y = rng.random_range(0..size - 1);
+            let a = TilePos::new(x, y);
+            let b = if rng.random_bool(0.5) {
+                TilePos::new(x + 1, y)
+            } else {
+                TilePos::new(x, y + 1)
+            };
+            rails.0.insert(ordered_edge(a, b));
+        }
+        rails
+    }
+
+    #[test]
+    fn parallel_connectivity_matches_serial_on_generated_map() {
+        let rails = generated_rails(200, 30_000, 7);
+        let graph = build_rail_graph(&rails);
+
+        // Capitals sit on rail tiles spread over the map, so every BFS has work to do
+        let mut rail_tiles: Vec<TilePos> = graph.keys().copied().collect();
+        rail_tiles.sort_by_key(|pos| (pos.y, pos.x));
+        let capitals: Vec<(Entity, TilePos)> = rail_tiles
+            .iter()
+            .step_by(rail_tiles.len() / 8)
+            .take(8)
+            .enumerate()
+            .map(|(i, &pos)| (Entity::from_bits(i as u64 + 1), pos))
+            .collect();
+        assert_eq!(capitals.len(), 8);
+
+        let serial = nation_reachability_serial(&graph, &capitals);
+        let parallel = nation_reachability_parallel(&graph, &capitals);
+
+        assert_eq!(serial.len(), capitals.len());
+        assert!(serial.values().all(|tiles| tiles.len() > 1));
+        assert_eq!(serial, parallel);
+    }
+
+    fn connectivity_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Rails>()
+            .init_resource::<RailConnectivityCache>()
+            .add_observer(compute_rail_connectivity)
+            .add_observer(on_depot_added)
+            .add_observer(on_depot_removed);
+        app
+    }
+
+    #[test]
+    fn connectivity_is_cached_until_network_changes() {
+        let mut app = connectivity_app();
+
+        let capital = TilePos::new(0, 0);
+        let depot_pos = TilePos::new(1, 0);
+        let nation = app.world_mut().spawn(Capital(capital)).id();
+        app.world_mut()
+            .resource_mut::<Rails>()
+            .0
+            .insert(ordered_edge(capital, depot_pos));
+
+        app.world_mut().trigger(RecomputeConnectivity);
+        app.update();
+        assert_eq!(
+            app.world()
+                .resource::<RailConnectivityCache>()
+                .recomputations,
+            1
+        );
+
+        // Nothing changed: the cached reachability is reused
+        app.update();
+        app.world_mut().trigger(RecomputeConnectivity);
+        app.update();
+        assert_eq!(
+            app.world()
+                .resource::<RailConnectivityCache>()
+                .recomputations,
+            1
+        );
+
+        // Adding a depot invalidates the cache and marks the depot connected
+        let depot = app
+            .world_mut()
+            .spawn(Depot {
+                position: depot_pos,
+                owner: nation,
+                connected: false,
+            })
+            .id();
+        app.update();
+        assert_eq!(
+            app.world()
+                .resource::<RailConnectivityCache>()
+                .recomputations,
+            2
+        );
+        assert!(app.world().get::<Depot>(depot).unwrap().connected);
+    }
+
+    #[test]
+    fn incremental_edge_matches_full_rebuild() {
+        let mut rails = generated_rails(60, 1_500, 11);
+        let capital = TilePos::new(30, 30);
+        rails.0.insert(ordered_edge(capital, TilePos::new(31, 30)));
+
+        let mut graph = build_rail_graph(&rails);
+        let capitals = [(Entity::from_bits(1), capital)];
+        let mut reachable = nation_reachability_serial(&graph, &capitals)
+            .remove(&capitals[0].0)
+            .unwrap();
+
+        // Bridge from the capital's cluster to a tile it cannot reach yet
+        let outside = rails
+            .0
+            .iter()
+            .flat_map(|&(a, b)| [a, b])
+            .filter(|tile| !reachable.contains(tile))
+            .min_by_key(|tile| {
+                (
+                    tile.x.abs_diff(capital.x) + tile.y.abs_diff(capital.y),
+                    tile.x,
+                    tile.y,
+                )
+            })
+            .expect("generated map has a disconnected cluster");
+        let inside = reachable
+            .iter()
+            .copied()
+            .min_by_key(|tile| {
+                (
+                    tile.x.abs_diff(outside.x) + tile.y.abs_diff(outside.y),
+                    tile.x,
+                    tile.y,
+                )
+            })
+            .unwrap();
+        let edge = ordered_edge(inside, outside);
+
+        rails.0.insert(edge);
+        add_rail_edge(&mut graph, edge);
+        extend_reachability(&mut reachable, &graph, edge);
+
+        let rebuilt = nation_reachability_serial(&build_rail_graph(&rails), &capitals);
+        assert!(reachable.contains(&outside));
+        assert_eq!(reachable, rebuilt[&capitals[0].0]);
+    }
+
+    #[test]
+    fn recorded_rail_is_applied_without_full_rebuild() {
+        let mut app = connectivity_app();
+
+        let capital = TilePos::new(0, 0);
+        let depot_pos = TilePos::new(2, 0);
+        let nation = app.world_mut().spawn(Capital(capital)).id();
+        let depot = app
+            .world_mut()
+            .spawn(Depot {
+                position: depot_pos,
+                owner: nation,
+                connected: false,
+            })
+            .id();
+        app.update();
+        let full = app
+            .world()
+            .resource::<RailConnectivityCache>()
+            .recomputations;
+        assert!(!app.world().get::<Depot>(depot).unwrap().connected);
+
+        for edge in [
+            ordered_edge(capital, TilePos::new(1, 0)),
+            ordered_edge(TilePos::new(1, 0), depot_pos),
+        ] {
+            app.world_mut().resource_mut::<Rails>().0.insert(edge);
+            app.world_mut()
+                .resource_mut::<RailConnectivityCache>()
+                .record_rail_added(edge);
+            app.world_mut().trigger(RecomputeConnectivity);
+            app.update();
+        }
+
+        let cache = app.world().resource::<RailConnectivityCache>();
+        assert_eq!(cache.recomputations, full);
+        assert_eq!(cache.incremental_updates, 2);
+        assert!(app.world().get::<Depot>(depot).unwrap().connected);
+    }
+}
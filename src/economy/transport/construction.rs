@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 
-use crate::economy::transport::messages::RecomputeConnectivity;
+use crate::civilians::{ActionTurn, CivilianJob, CivilianOrder, JobType, PreviousPosition};
+use crate::economy::transport::connectivity::RailConnectivityCache;
+use crate::economy::transport::messages::{CancelRailConstruction, RecomputeConnectivity};
 use crate::economy::transport::types::{RailConstruction, Rails, ordered_edge};
 
 /// Advance rail construction progress each turn (Logic Layer)
@@ -9,6 +11,7 @@ pub fn advance_rail_construction(
     mut commands: Commands,
     mut constructions: Query<(Entity, &mut RailConstruction)>,
     mut rails: ResMut<Rails>,
+    mut connectivity: ResMut<RailConnectivityCache>,
 ) {
     for (entity, mut construction) in constructions.iter_mut() {
         construction.turns_remaining -= 1;
@@ -16,7 +19,9 @@ pub fn advance_rail_construction(
         if construction.turns_remaining == 0 {
             // Construction complete!
             let edge = ordered_edge(construction.from, construction.to);
-            rails.0.insert(edge);
+            if rails.0.insert(edge) {
+                connectivity.record_rail_added(edge);
+            }
 
             // Trigger connectivity recomputation since topology changed
             commands.trigger(RecomputeConnectivity);
@@ -40,3 +45,41 @@ pub fn advance_rail_construction(
         }
     }
 }
+
+/// Stop a rail segment before it completes. The edge never reaches `Rails`, so the
+/// connectivity graph is unaffected; the engineer drops the job, along with the undo
+/// state kept for rescinding it, and is free for new orders. The $50 paid to start the segment is not returned.
+pub fn cancel_rail_construction(
+    trigger: On<CancelRailConstruction>,
+    mut commands: Commands,
+    constructions: Query<&RailConstruction>,
+    jobs: Query<&CivilianJob>,
+) {
+    let entity = trigger.event().construction;
+    let Ok(construction) = constructions.get(entity) else {
+        return;
+    };
+
+    let engineer = construction.engineer;
+    if jobs
+        .get(engineer)
+        .is_ok_and(|job| job.job_type == JobType::BuildingRail)
+    {
+        commands
+            .entity(engineer)
+            .remove::<CivilianJob>()
+            .remove::<CivilianOrder>()
+            .remove::<PreviousPosition>()
+            .remove::<ActionTurn>();
+    }
+    commands.entity(entity).despawn();
+
+    info!(
+        "Rail construction canceled: ({}, {}) to ({}, {}) with {} turns remaining",
+        construction.from.x,
+        construction.from.y,
+        construction.to.x,
+        construction.to.y,
+        construction.turns_remaining
+    );
+}
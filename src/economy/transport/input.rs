@@ -3,16 +3,17 @@ use bevy_ecs_tilemap::prelude::{TilePos, TileStorage};
 
 use crate::economy::transport::messages::PlaceImprovement;
 use crate::economy::transport::types::{
-    Depot, ImprovementKind, Port, RailConstruction, Rails, ordered_edge,
+    Bridges, Depot, ImprovementKind, Port, RailConstruction, Rails, ordered_edge,
 };
 use crate::economy::transport::validation::{are_adjacent, can_build_rail_on_terrain};
 use crate::map::tile_pos::{HexExt, TilePosExt};
 use crate::map::tiles::TerrainType;
 use hexx::Hex;
 
+use crate::civilians::Civilian;
 use crate::economy::{
-    nation::{OwnedBy, PlayerNation},
-    technology::Technologies,
+    nation::{CapitalLost, OwnedBy, PlayerNation},
+    technology::{Technologies, Technology},
     treasury::Treasury,
 };
 
@@ -22,11 +23,14 @@ pub fn apply_improvements(
     trigger: On<PlaceImprovement>,
     mut commands: Commands,
     rails: ResMut<Rails>,
+    mut bridges: ResMut<Bridges>,
     player: Option<Res<PlayerNation>>,
     mut treasuries: Query<&mut Treasury>,
     nations: Query<&Technologies>,
+    lost_capitals: Query<(), With<CapitalLost>>,
     tile_storage_query: Query<&TileStorage>,
     tile_types: Query<&TerrainType>,
+    civilians: Query<&Civilian>,
 ) {
     let e = trigger.event();
     match e.kind {
@@ -35,11 +39,14 @@ pub fn apply_improvements(
                 &mut commands,
                 e,
                 &rails,
+                &bridges,
                 &player,
                 &mut treasuries,
                 &nations,
+                &lost_capitals,
                 &tile_storage_query,
                 &tile_types,
+                &civilians,
             );
         }
         ImprovementKind::Depot => {
@@ -56,6 +63,17 @@ pub fn apply_improvements(
                 &tile_types,
             );
         }
+        ImprovementKind::Bridge => {
+            handle_bridge_placement(
+                e,
+                &mut bridges,
+                &player,
+                &mut treasuries,
+                &nations,
+                &tile_storage_query,
+                &tile_types,
+            );
+        }
     }
 }
 
@@ -63,11 +81,14 @@ fn handle_rail_construction(
     commands: &mut Commands,
     e: &PlaceImprovement,
     rails: &ResMut<Rails>,
+    bridges: &ResMut<Bridges>,
     player: &Option<Res<PlayerNation>>,
     treasuries: &mut Query<&mut Treasury>,
     nations: &Query<&Technologies>,
+    lost_capitals: &Query<(), With<CapitalLost>>,
     tile_storage_query: &Query<&TileStorage>,
     tile_types: &Query<&TerrainType>,
+    civilians: &Query<&Civilian>,
 ) {
     if !are_adjacent(e.a, e.b) {
         return;
@@ -83,9 +104,26 @@ fn handle_rail_construction(
         return;
     }
 
+    // A bridge on this exact edge carries the rail over its water endpoint
+    let bridged = bridges.0.contains(&edge);
+    let check_terrain = |terrain: &TerrainType, techs: &Technologies| {
+        if bridged && *terrain == TerrainType::Water {
+            (true, None)
+        } else {
+            can_build_rail_on_terrain(terrain, techs)
+        }
+    };
+
     // Check terrain buildability for both endpoints
     // Determine builder nation (AI or Player)
     let builder_nation = e.nation.or_else(|| player.as_ref().map(|p| p.entity()));
+    // Rail networks are rooted at the capital; without one there is nothing to extend
+    if builder_nation.is_some_and(|nation| lost_capitals.contains(nation)) {
+        info!("Cannot build rail: the nation has lost its capital");
+        return;
+    }
+    // Rough ground along the edge lengthens construction
+    let mut extra_turns = 0;
 
     if let Some(nation_entity) = builder_nation {
         // Get builder nation's technologies
@@ -102,7 +140,8 @@ fn handle_rail_construction(
                     if let Ok(terrain_a) = tile_types.get(tile_entity_a)
                         && let Some(techs) = builder_techs
                     {
-                        let (buildable, reason) = can_build_rail_on_terrain(terrain_a, techs);
+                        extra_turns = extra_turns.max(terrain_a.build_difficulty());
+                        let (buildable, reason) = check_terrain(terrain_a, techs);
                         if !buildable {
                             can_build = false;
                             failure_reason = Some(format!(
@@ -129,7 +168,8 @@ fn handle_rail_construction(
                     if let Ok(terrain_b) = tile_types.get(tile_entity_b)
                         && let Some(techs) = builder_techs
                     {
-                        let (buildable, reason) = can_build_rail_on_terrain(terrain_b, techs);
+                        extra_turns = extra_turns.max(terrain_b.build_difficulty());
+                        let (buildable, reason) = check_terrain(terrain_b, techs);
                         if !buildable {
                             can_build = false;
                             failure_reason = Some(format!(
@@ -160,8 +200,14 @@ fn handle_rail_construction(
         }
     }
 
-    // Start rail construction (takes 2 turns)
+    // Start rail construction (2 turns, plus any terrain difficulty,
+    // less whatever a veteran engineer saves)
     let cost: i64 = 50;
+    let speedup = e
+        .engineer
+        .and_then(|engineer| civilians.get(engineer).ok())
+        .map_or(0, Civilian::job_speedup);
+    let turns = (2 + extra_turns).saturating_sub(speedup).max(1);
     if let Some(nation_entity) = builder_nation
         && let Ok(mut treasury) = treasuries.get_mut(nation_entity)
     {
@@ -171,7 +217,7 @@ fn handle_rail_construction(
                 RailConstruction {
                     from: edge.0,
                     to: edge.1,
-                    turns_remaining: 2,
+                    turns_remaining: turns,
                     owner: nation_entity,
                     engineer: e.engineer.unwrap_or(nation_entity),
                 },
@@ -179,8 +225,8 @@ fn handle_rail_construction(
             ));
 
             info!(
-                "Started rail construction from ({}, {}) to ({}, {}) for ${} (2 turns)",
-                edge.0.x, edge.0.y, edge.1.x, edge.1.y, cost
+                "Started rail construction from ({}, {}) to ({}, {}) for ${} ({} turns)",
+                edge.0.x, edge.0.y, edge.1.x, edge.1.y, cost, turns
             );
         } else {
             info!(
@@ -286,6 +332,92 @@ fn handle_port_placement(
     }
 }
 
+fn handle_bridge_placement(
+    e: &PlaceImprovement,
+    bridges: &mut ResMut<Bridges>,
+    player: &Option<Res<PlayerNation>>,
+    treasuries: &mut Query<&mut Treasury>,
+    nations: &Query<&Technologies>,
+    tile_storage_query: &Query<&TileStorage>,
+    tile_types: &Query<&TerrainType>,
+) {
+    if !are_adjacent(e.a, e.b) {
+        return;
+    }
+    let edge = ordered_edge(e.a, e.b);
+
+    if bridges.0.contains(&edge) {
+        info!(
+            "Bridge already exists between ({}, {}) and ({}, {})",
+            edge.0.x, edge.0.y, edge.1.x, edge.1.y
+        );
+        return;
+    }
+
+    let Some(nation_entity) = e.nation.or_else(|| player.as_ref().map(|p| p.entity())) else {
+        return;
+    };
+
+    if !nations
+        .get(nation_entity)
+        .is_ok_and(|techs| techs.can_bridge())
+    {
+        info!(
+            "Cannot bridge ({}, {}) to ({}, {}): {}",
+            edge.0.x,
+            edge.0.y,
+            edge.1.x,
+            edge.1.y,
+            Technology::Bridging.definition().missing_message
+        );
+        return;
+    }
+
+    // The edge must cross a river: at least one water endpoint, and no open sea
+    let Some(storage) = tile_storage_query.iter().next() else {
+        return;
+    };
+    let is_water = |pos: TilePos| {
+        storage
+            .get(&pos)
+            .and_then(|tile| tile_types.get(tile).ok())
+            .is_some_and(|terrain| *terrain == TerrainType::Water)
+    };
+    let water_ends: Vec<TilePos> = [e.a, e.b]
+        .into_iter()
+        .filter(|&pos| is_water(pos))
+        .collect();
+    if water_ends.is_empty()
+        || water_ends
+            .iter()
+            .any(|&pos| is_ocean_tile(pos, storage, tile_types))
+    {
+        info!(
+            "Cannot bridge ({}, {}) to ({}, {}): bridges must span a river",
+            edge.0.x, edge.0.y, edge.1.x, edge.1.y
+        );
+        return;
+    }
+
+    let cost: i64 = 200;
+    if let Ok(mut treasury) = treasuries.get_mut(nation_entity) {
+        if treasury.total() >= cost {
+            treasury.subtract(cost);
+            bridges.0.insert(edge);
+            info!(
+                "Built bridge from ({}, {}) to ({}, {}) for ${}",
+                edge.0.x, edge.0.y, edge.1.x, edge.1.y, cost
+            );
+        } else {
+            info!(
+                "Not enough money to build bridge (need ${}, have ${})",
+                cost,
+                treasury.total()
+            );
+        }
+    }
+}
+
 fn find_adjacent_water_tiles<'a>(
     center: TilePos,
     tile_storage_query: &'a Query<&TileStorage>,
@@ -1 +1,3 @@
-pub use crate::messages::transport::{PlaceImprovement, RecomputeConnectivity};
+pub use crate::messages::transport::{
+    CancelRailConstruction, PlaceImprovement, RecomputeConnectivity,
+};
@@ -1,10 +1,12 @@
 use bevy::prelude::*;
+use std::collections::HashSet;
 
 use crate::economy::{
     allocation::Allocations,
     goods::Good,
     nation::Nation,
     production::{BuildingKind, Buildings, production_recipe},
+    technology::Technologies,
     transport::{
         AllocationSlot, BASE_TRANSPORT_CAPACITY, CapacitySnapshot, DemandEntry,
         TransportAllocations, TransportCapacity, TransportCommodity, TransportDemandSnapshot,
@@ -21,6 +23,24 @@ pub fn initialize_transport_capacity(
         let snapshot = capacity.snapshot_mut(nation);
         snapshot.total = BASE_TRANSPORT_CAPACITY;
         snapshot.used = 0;
+        snapshot.technology_bonus = 0;
+    }
+}
+
+/// Keep each nation's transport capacity in line with its technology bonuses.
+/// Only the difference from the previously applied bonus is added, so re-running is harmless.
+pub fn apply_technology_transport_capacity(
+    mut capacity: ResMut<TransportCapacity>,
+    nations: Query<(Entity, &Technologies), Changed<Technologies>>,
+) {
+    for (nation, technologies) in nations.iter() {
+        let bonus = technologies.transport_capacity_bonus();
+        let snapshot = capacity.snapshot_mut(nation);
+        if snapshot.technology_bonus == bonus {
+            continue;
+        }
+        snapshot.total = (snapshot.total + bonus).saturating_sub(snapshot.technology_bonus);
+        snapshot.technology_bonus = bonus;
     }
 }
 
@@ -78,16 +98,141 @@ pub fn apply_transport_allocations(
     // Recompute granted totals for this nation
     let nation = request.nation;
     if let Some(nation_alloc) = allocations.nations.get_mut(&nation) {
-        let mut remaining = capacity.snapshot(nation).total;
-        for commodity in TransportCommodity::ORDERED.iter() {
-            if let Some(slot) = nation_alloc.commodities.get_mut(commodity) {
-                let granted = slot.requested.min(remaining);
-                slot.granted = granted;
-                remaining = remaining.saturating_sub(granted);
+        capacity.snapshot_mut(nation).used = nation_alloc.regrant(capacity.snapshot(nation).total);
+    }
+}
+
+/// Event pinning a commodity's transport priority; pinned goods get capacity before others.
+/// Triggered via `commands.trigger(TransportSetPriority { ... })`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TransportSetPriority {
+    pub nation: Entity,
+    pub commodity: TransportCommodity,
+    pub priority: u8,
+}
+
+/// Apply a new priority rank and re-grant the nation's capacity accordingly.
+/// Observer triggered by TransportSetPriority events.
+pub fn apply_transport_priority(
+    trigger: On<TransportSetPriority>,
+    mut capacity: ResMut<TransportCapacity>,
+    mut allocations: ResMut<TransportAllocations>,
+) {
+    let request = trigger.event();
+    let nation_alloc = allocations.ensure_nation(request.nation);
+    nation_alloc.slot_mut(request.commodity).priority = request.priority;
+    capacity.snapshot_mut(request.nation).used =
+        nation_alloc.regrant(capacity.snapshot(request.nation).total);
+}
+
+/// Sent when a nation leaves transport capacity idle while connected resources go uncollected
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct IdleTransportCapacity {
+    pub nation: Entity,
+    /// Capacity not allocated to any commodity
+    pub idle: u32,
+    /// Commodities with more supply than their granted allocation
+    pub stranded: Vec<TransportCommodity>,
+}
+
+/// Warn nations that could haul more than they allocated.
+/// Runs after allocation changes are applied; a nation is warned once, and again only
+/// after its idle capacity or stranded supply has been resolved in between.
+pub fn warn_idle_transport_capacity(
+    capacity: Res<TransportCapacity>,
+    allocations: Res<TransportAllocations>,
+    demand_snapshot: Res<TransportDemandSnapshot>,
+    mut warned: Local<HashSet<Entity>>,
+    mut idle_writer: MessageWriter<IdleTransportCapacity>,
+) {
+    if !capacity.is_changed() && !allocations.is_changed() && !demand_snapshot.is_changed() {
+        return;
+    }
+
+    for (&nation, entries) in demand_snapshot.nations.iter() {
+        let snapshot = capacity.snapshot(nation);
+        let idle = snapshot.total.saturating_sub(snapshot.used);
+        let stranded: Vec<TransportCommodity> = TransportCommodity::ORDERED
+            .iter()
+            .copied()
+            .filter(|commodity| {
+                entries.get(commodity).is_some_and(|entry| {
+                    entry.supply > allocations.slot(nation, *commodity).granted
+                })
+            })
+            .collect();
+
+        if idle == 0 || stranded.is_empty() {
+            warned.remove(&nation);
+            continue;
+        }
+        if warned.insert(nation) {
+            idle_writer.write(IdleTransportCapacity {
+                nation,
+                idle,
+                stranded,
+            });
+        }
+    }
+}
+
+/// Requests splitting a nation's transport capacity across commodities in proportion to
+/// demand. Nothing goes to commodities without supply, no commodity gets more than it
+/// demands or can supply, and slots lost to rounding go to the largest remainders.
+/// Every commodity gets a request, so earlier manual allocations are replaced.
+pub fn auto_balance_transport(
+    capacity: &TransportCapacity,
+    demand_snapshot: &TransportDemandSnapshot,
+    nation: Entity,
+) -> Vec<TransportAdjustAllocation> {
+    let total = capacity.snapshot(nation).total;
+    let entries: Vec<(TransportCommodity, u32)> = TransportCommodity::ORDERED
+        .iter()
+        .map(|&commodity| {
+            let entry = transport_demand(demand_snapshot, nation, commodity);
+            (commodity, entry.demand.min(entry.supply))
+        })
+        .collect();
+
+    let total_demand: u64 = entries.iter().map(|(_, cap)| *cap as u64).sum();
+    let mut shares: Vec<(TransportCommodity, u32, u32, u64)> = entries
+        .iter()
+        .map(|&(commodity, cap)| {
+            if total_demand == 0 {
+                return (commodity, 0, cap, 0);
+            }
+            let exact = total as u64 * cap as u64;
+            let share = ((exact / total_demand) as u32).min(cap);
+            (commodity, share, cap, exact % total_demand)
+        })
+        .collect();
+
+    let assigned: u32 = shares.iter().map(|(_, share, _, _)| *share).sum();
+    let mut leftover = total.saturating_sub(assigned);
+    let mut order: Vec<usize> = (0..shares.len()).collect();
+    order.sort_by_key(|&index| std::cmp::Reverse(shares[index].3));
+    while leftover > 0 {
+        let mut handed_out = false;
+        for &index in &order {
+            if leftover > 0 && shares[index].1 < shares[index].2 {
+                shares[index].1 += 1;
+                leftover -= 1;
+                handed_out = true;
             }
         }
-        capacity.snapshot_mut(nation).used = capacity.snapshot(nation).total - remaining;
+        if !handed_out {
+            break;
+        }
     }
+
+    shares
+        .into_iter()
+        .map(|(commodity, requested, _, _)| TransportAdjustAllocation {
+            nation,
+            commodity,
+            requested,
+        })
+        .collect()
 }
 
 /// Helper to estimate input requirements for production demand calculation.
@@ -230,3 +375,183 @@ pub fn transport_demand(
 pub fn transport_capacity(capacity: &TransportCapacity, nation: Entity) -> CapacitySnapshot {
     capacity.snapshot(nation)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+
+    use crate::economy::transport::{
+        DemandEntry, IdleTransportCapacity, TransportAdjustAllocation, TransportAllocations,
+        TransportCapacity, TransportCommodity, TransportDemandSnapshot, TransportSetPriority,
+        apply_transport_allocations, apply_transport_priority, auto_balance_transport,
+        warn_idle_transport_capacity,
+    };
+
+    #[test]
+    fn pinned_commodity_is_served_before_unpinned_ones() {
+        let mut world = World::new();
+        world.init_resource::<TransportAllocations>();
+        world.add_observer(apply_transport_allocations);
+        world.add_observer(apply_transport_priority);
+
+        let nation = world.spawn_empty().id();
+        let mut capacity = TransportCapacity::default();
+        capacity.snapshot_mut(nation).total = 6;
+        world.insert_resource(capacity);
+        let entry = DemandEntry {
+            supply: 10,
+            demand: 0,
+        };
+        let mut snapshot = TransportDemandSnapshot::default();
+        snapshot.nations.insert(
+            nation,
+            HashMap::from([
+                (TransportCommodity::Grain, entry),
+                (TransportCommodity::Steel, entry),
+            ]),
+        );
+        world.insert_resource(snapshot);
+
+        for commodity in [TransportCommodity::Grain, TransportCommodity::Steel] {
+            world.trigger(TransportAdjustAllocation {
+                nation,
+                commodity,
+                requested: 4,
+            });
+        }
+        let granted = |world: &World, commodity| {
+            world
+                .resource::<TransportAllocations>()
+                .slot(nation, commodity)
+                .granted
+        };
+        // Unpinned, grain comes first in UI order
+        assert_eq!(granted(&world, TransportCommodity::Grain), 4);
+        assert_eq!(granted(&world, TransportCommodity::Steel), 2);
+
+        world.trigger(TransportSetPriority {
+            nation,
+            commodity: TransportCommodity::Steel,
+            priority: 1,
+        });
+        assert_eq!(granted(&world, TransportCommodity::Steel), 4);
+        assert_eq!(granted(&world, TransportCommodity::Grain), 2);
+        assert_eq!(
+            world.resource::<TransportCapacity>().snapshot(nation).used,
+            6
+        );
+    }
+
+    #[test]
+    fn auto_balance_gives_high_demand_commodities_more_capacity() {
+        let mut world = World::new();
+        world.init_resource::<TransportAllocations>();
+        world.add_observer(apply_transport_allocations);
+
+        let nation = world.spawn_empty().id();
+        let mut capacity = TransportCapacity::default();
+        capacity.snapshot_mut(nation).total = 10;
+
+        let entry = |demand| DemandEntry { supply: 20, demand };
+        let mut snapshot = TransportDemandSnapshot::default();
+        snapshot.nations.insert(
+            nation,
+            HashMap::from([
+                (TransportCommodity::Grain, entry(6)),
+                (TransportCommodity::Iron, entry(4)),
+                (TransportCommodity::Coal, entry(2)),
+                // Demanded but nothing to haul
+                (
+                    TransportCommodity::Timber,
+                    DemandEntry {
+                        supply: 0,
+                        demand: 8,
+                    },
+                ),
+            ]),
+        );
+
+        let requests = auto_balance_transport(&capacity, &snapshot, nation);
+        world.insert_resource(capacity);
+        world.insert_resource(snapshot);
+        for request in requests {
+            world.trigger(request);
+        }
+
+        let allocations = world.resource::<TransportAllocations>();
+        let granted = |commodity| allocations.slot(nation, commodity).granted;
+        assert_eq!(granted(TransportCommodity::Grain), 5);
+        assert_eq!(granted(TransportCommodity::Iron), 3);
+        assert_eq!(granted(TransportCommodity::Coal), 2);
+        assert_eq!(granted(TransportCommodity::Timber), 0);
+        assert_eq!(
+            world.resource::<TransportCapacity>().snapshot(nation).used,
+            10
+        );
+    }
+
+    #[test]
+    fn spare_capacity_with_stranded_resources_warns() {
+        let mut world = World::new();
+        world.init_resource::<TransportAllocations>();
+        world.init_resource::<Messages<IdleTransportCapacity>>();
+        world.add_observer(apply_transport_allocations);
+
+        let nation = world.spawn_empty().id();
+        let mut capacity = TransportCapacity::default();
+        capacity.snapshot_mut(nation).total = 10;
+        world.insert_resource(capacity);
+        let mut snapshot = TransportDemandSnapshot::default();
+        snapshot.nations.insert(
+            nation,
+            HashMap::from([(
+                TransportCommodity::Coal,
+                DemandEntry {
+                    supply: 6,
+                    demand: 0,
+                },
+            )]),
+        );
+        world.insert_resource(snapshot);
+
+        // Only 2 of 6 coal are hauled while 8 capacity sits idle
+        world.trigger(TransportAdjustAllocation {
+            nation,
+            commodity: TransportCommodity::Coal,
+            requested: 2,
+        });
+        world.run_system_once(warn_idle_transport_capacity).unwrap();
+
+        let warnings: Vec<IdleTransportCapacity> = world
+            .resource_mut::<Messages<IdleTransportCapacity>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![IdleTransportCapacity {
+                nation,
+                idle: 8,
+                stranded: vec![TransportCommodity::Coal],
+            }]
+        );
+
+        // Hauling everything clears the warning
+        world.trigger(TransportAdjustAllocation {
+            nation,
+            commodity: TransportCommodity::Coal,
+            requested: 6,
+        });
+        world.run_system_once(warn_idle_transport_capacity).unwrap();
+        assert!(
+            world
+                .resource_mut::<Messages<IdleTransportCapacity>>()
+                .drain()
+                .next()
+                .is_none()
+        );
+    }
+}
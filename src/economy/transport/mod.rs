@@ -1,6 +1,6 @@
 // Core types and structs
 pub mod types;
-pub use types::{Depot, ImprovementKind, Port, RailConstruction, Rails, ordered_edge};
+pub use types::{Bridges, Depot, ImprovementKind, Port, RailConstruction, Rails, ordered_edge};
 
 // Transport state (capacity, allocations, demand)
 pub mod state;
@@ -12,14 +12,16 @@ pub use state::{
 // Derived metrics and logic
 pub mod metrics;
 pub use metrics::{
-    TransportAdjustAllocation, apply_transport_allocations, convert_transport_goods_to_capacity,
-    initialize_transport_capacity, transport_capacity, transport_demand, transport_slot,
-    update_transport_demand_snapshot,
+    IdleTransportCapacity, TransportAdjustAllocation, TransportSetPriority,
+    apply_technology_transport_capacity, apply_transport_allocations, apply_transport_priority,
+    auto_balance_transport, convert_transport_goods_to_capacity, initialize_transport_capacity,
+    transport_capacity, transport_demand, transport_slot, update_transport_demand_snapshot,
+    warn_idle_transport_capacity,
 };
 
 // Messages
 pub mod messages;
-pub use messages::{PlaceImprovement, RecomputeConnectivity};
+pub use messages::{CancelRailConstruction, PlaceImprovement, RecomputeConnectivity};
 
 // Validation logic
 pub mod validation;
@@ -27,13 +29,13 @@ pub use validation::{are_adjacent, can_build_depot_on_terrain, can_build_rail_on
 
 // Construction systems (Logic Layer)
 pub mod construction;
-pub use construction::advance_rail_construction;
+pub use construction::{advance_rail_construction, cancel_rail_construction};
 
 // Connectivity systems (Logic Layer)
 pub mod connectivity;
 pub use connectivity::{
-    build_rail_graph, compute_rail_connectivity, on_depot_added, on_depot_removed, on_port_added,
-    on_port_removed,
+    RailConnectivityCache, build_rail_graph, compute_rail_connectivity, on_depot_added,
+    on_depot_removed, on_port_added, on_port_removed,
 };
 
 // Input handlers (Input Layer)
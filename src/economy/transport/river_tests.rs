@@ -1,4 +1,9 @@
-use crate::economy::transport::input::is_ocean_tile;
+use crate::economy::technology::{Technologies, Technology};
+use crate::economy::transport::input::{apply_improvements, is_ocean_tile};
+use crate::economy::transport::{
+    Bridges, ImprovementKind, PlaceImprovement, RailConstruction, Rails, ordered_edge,
+};
+use crate::economy::treasury::Treasury;
 use crate::map::tiles::TerrainType;
 use bevy::ecs::system::SystemState;
 use bevy::prelude::*;
@@ -221,3 +226,61 @@ fn test_is_ocean_tile_confluence() {
         "River confluence should NOT be ocean"
     );
 }
+
+#[test]
+fn bridge_opens_only_its_own_river_edge_to_rail() {
+    let (mut app, storage_entity) = setup_app();
+    app.init_resource::<Rails>()
+        .init_resource::<Bridges>()
+        .add_observer(apply_improvements);
+
+    // A river runs east-west along row 5; everything else is grassland
+    for x in 0..10 {
+        for y in 0..10 {
+            let terrain = if y == 5 {
+                TerrainType::Water
+            } else {
+                TerrainType::Grass
+            };
+            set_tile(app.world_mut(), storage_entity, TilePos { x, y }, terrain);
+        }
+    }
+
+    let mut technologies = Technologies::new();
+    technologies.unlock(Technology::Bridging);
+    let nation = app
+        .world_mut()
+        .spawn((Treasury::new(1_000), technologies))
+        .id();
+
+    let river = TilePos { x: 5, y: 5 };
+    let north_bank = TilePos { x: 5, y: 4 };
+    let south_bank = TilePos { x: 5, y: 6 };
+    let place = |kind, a, b| PlaceImprovement {
+        a,
+        b,
+        kind,
+        nation: Some(nation),
+        engineer: None,
+    };
+
+    app.world_mut()
+        .trigger(place(ImprovementKind::Bridge, north_bank, river));
+    app.world_mut()
+        .trigger(place(ImprovementKind::Rail, north_bank, river));
+    app.world_mut()
+        .trigger(place(ImprovementKind::Rail, river, south_bank));
+    app.world_mut().flush();
+
+    let mut constructions = app.world_mut().query::<&RailConstruction>();
+    let started: Vec<_> = constructions
+        .iter(app.world())
+        .map(|construction| (construction.from, construction.to))
+        .collect();
+    assert_eq!(started, vec![ordered_edge(north_bank, river)]);
+    assert_eq!(
+        app.world().get::<Treasury>(nation).unwrap().total(),
+        1_000 - 200 - 50,
+        "only the bridge and the bridged rail are paid for"
+    );
+}
@@ -167,6 +167,8 @@ pub const BASE_TRANSPORT_CAPACITY: u32 = 5;
 pub struct CapacitySnapshot {
     pub total: u32,
     pub used: u32,
+    /// Portion of `total` granted by technology effects
+    pub technology_bonus: u32,
 }
 
 impl Default for CapacitySnapshot {
@@ -174,6 +176,7 @@ impl Default for CapacitySnapshot {
         Self {
             total: BASE_TRANSPORT_CAPACITY,
             used: 0,
+            technology_bonus: 0,
         }
     }
 }
@@ -187,12 +190,16 @@ pub struct TransportAllocations {
 #[derive(Default, Debug, Clone)]
 pub struct NationAllocations {
     pub commodities: HashMap<TransportCommodity, AllocationSlot>,
+    /// Capacity spent this turn moving goods between the nation's cities
+    pub transfers: u32,
 }
 
 #[derive(Default, Debug, Clone, Copy)]
 pub struct AllocationSlot {
     pub requested: u32,
     pub granted: u32,
+    /// Slots with a higher rank are served first when capacity is scarce; 0 is unpinned
+    pub priority: u8,
 }
 
 /// Snapshot of supply/demand used for UI hints.
@@ -218,6 +225,23 @@ impl NationAllocations {
             .copied()
             .unwrap_or_default()
     }
+
+    /// Hand out `total` capacity to the requested slots, highest priority first and
+    /// in UI order among equals. Capacity already spent on city transfers is set aside
+    /// first. Returns the capacity used, transfers included.
+    pub fn regrant(&mut self, total: u32) -> u32 {
+        let mut order = TransportCommodity::ORDERED;
+        order.sort_by_key(|commodity| std::cmp::Reverse(self.slot(*commodity).priority));
+
+        let mut remaining = total.saturating_sub(self.transfers);
+        for commodity in order {
+            if let Some(slot) = self.commodities.get_mut(&commodity) {
+                slot.granted = slot.requested.min(remaining);
+                remaining -= slot.granted;
+            }
+        }
+        total.max(self.transfers) - remaining
+    }
 }
 
 impl TransportAllocations {
@@ -6,11 +6,12 @@ use moonshine_save::prelude::Save;
 use std::collections::HashSet;
 
 /// Type of transport improvement
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Reflect)]
 pub enum ImprovementKind {
-    Rail,  // High-capacity transport network
-    Depot, // Gathers resources from tile + 8 neighbors
-    Port,  // Coastal/river gathering point
+    Rail,   // High-capacity transport network
+    Depot,  // Gathers resources from tile + 8 neighbors
+    Port,   // Coastal/river gathering point
+    Bridge, // Carries rails across a single river edge
 }
 
 /// Marker component for depots that gather resources
@@ -39,6 +40,11 @@ pub struct Port {
 #[reflect(Resource)]
 pub struct Rails(pub HashSet<(TilePos, TilePos)>);
 
+/// Bridged edges, stored like [`Rails`]; rails may cross water only along these
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct Bridges(pub HashSet<(TilePos, TilePos)>);
+
 /// Component tracking rail construction in progress (takes 2 turns to complete)
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component, MapEntities)]
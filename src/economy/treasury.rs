@@ -1,9 +1,11 @@
 use bevy::prelude::*;
 
 use crate::economy::reservation::ResourcePool;
+use crate::economy::upkeep::UpkeepArrears;
 
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component)]
+#[require(UpkeepArrears)]
 pub struct Treasury {
     money_pool: ResourcePool,
 }
@@ -62,12 +64,67 @@ impl Treasury {
         self.money_pool.release(amount);
     }
 
+    /// Drop every money reservation
+    pub fn clear_reserved(&mut self) {
+        self.money_pool.reserved = 0;
+    }
+
     /// Consume reserved money (for ReservationSystem)
     pub fn consume_reserved(&mut self) {
         self.money_pool.consume_reserved();
     }
 }
 
+/// Spending that can be refused when a nation cannot pay for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinancialAction {
+    Consulate,
+    Embassy,
+    ForeignAid,
+    CapitalRelocation,
+    HireCivilian,
+    Upkeep,
+    CapitolUpgrade,
+    Training,
+}
+
+impl FinancialAction {
+    pub fn label(self) -> &'static str {
+        match self {
+            FinancialAction::Consulate => "open a consulate",
+            FinancialAction::Embassy => "open an embassy",
+            FinancialAction::ForeignAid => "send foreign aid",
+            FinancialAction::CapitalRelocation => "relocate the capital",
+            FinancialAction::HireCivilian => "hire a civilian",
+            FinancialAction::Upkeep => "pay upkeep",
+            FinancialAction::CapitolUpgrade => "upgrade the Capitol",
+            FinancialAction::Training => "train a worker",
+        }
+    }
+}
+
+/// Sent whenever an action is denied for lack of funds, so the refusal can be
+/// surfaced instead of silently leaving the treasury untouched
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FinancialRejection {
+    pub nation: Entity,
+    pub action: FinancialAction,
+    pub cost: i64,
+    /// How much more money the action needed
+    pub shortfall: i64,
+}
+
+impl FinancialRejection {
+    pub fn new(nation: Entity, action: FinancialAction, cost: i64, available: i64) -> Self {
+        FinancialRejection {
+            nation,
+            action,
+            cost,
+            shortfall: (cost - available).max(0),
+        }
+    }
+}
+
 // Compatibility: allow tuple-like access for existing code
 impl From<i64> for Treasury {
     fn from(amount: i64) -> Self {
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::civilians::hiring::dismiss_civilian;
+use crate::civilians::{Civilian, SelectedCivilian};
+use crate::economy::production::Buildings;
+use crate::economy::treasury::{FinancialAction, FinancialRejection, Treasury};
+use crate::turn_system::TurnCounter;
+
+/// Money each nation starts with and what it pays every turn to keep its
+/// buildings and civilians running
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpkeepPolicy {
+    pub starting_treasury: i64,
+    pub per_building: i64,
+    pub per_civilian: i64,
+    /// Turns in a row upkeep can go unpaid before the nation is declared bankrupt
+    pub bankruptcy_after: u32,
+}
+
+impl Default for UpkeepPolicy {
+    fn default() -> Self {
+        UpkeepPolicy {
+            starting_treasury: 10_000,
+            per_building: 10,
+            per_civilian: 25,
+            bankruptcy_after: 3,
+        }
+    }
+}
+
+/// Upkeep a nation could not pay, carried over to the next turn's bill
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct UpkeepArrears {
+    pub debt: i64,
+    /// Consecutive turns the bill went unpaid
+    pub turns_unpaid: u32,
+}
+
+/// Sent when a nation has left its upkeep unpaid for too long. Its debt is
+/// written off and every one of its civilians is dismissed without a refund.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bankruptcy {
+    pub nation: Entity,
+    pub debt: i64,
+}
+
+impl UpkeepPolicy {
+    /// Upkeep owed for `buildings` buildings and `civilians` civilians
+    pub fn due(&self, buildings: usize, civilians: usize) -> i64 {
+        self.per_building * buildings as i64 + self.per_civilian * civilians as i64
+    }
+}
+
+/// Charge every nation its per-turn upkeep plus any arrears, starting with the second turn.
+/// Whatever cannot be paid is reported as a [`FinancialRejection`] with the
+/// unpaid amount as the shortfall and carried over as [`UpkeepArrears`].
+/// A nation that stays in arrears for [`UpkeepPolicy::bankruptcy_after`] turns
+/// goes bankrupt: the debt is written off and its civilians are dismissed
+/// without the refund a voluntary disband would pay.
+pub fn charge_upkeep(
+    mut commands: Commands,
+    policy: Res<UpkeepPolicy>,
+    mut nations: Query<(
+        Entity,
+        &mut Treasury,
+        &mut UpkeepArrears,
+        Option<&Buildings>,
+    )>,
+    civilians: Query<(Entity, &Civilian)>,
+    selected: Option<Res<SelectedCivilian>>,
+    mut rejections: MessageWriter<FinancialRejection>,
+    mut bankruptcies: MessageWriter<Bankruptcy>,
+    turn: Res<TurnCounter>,
+) {
+    // Nothing has been built or hired yet when the game starts
+    if turn.current <= 1 {
+        return;
+    }
+
+    let mut civilians_per_nation: HashMap<Entity, Vec<Entity>> = HashMap::new();
+    for (entity, civilian) in civilians.iter() {
+        civilians_per_nation
+            .entry(civilian.owner)
+            .or_default()
+            .push(entity);
+    }
+
+    for (entity, mut treasury, mut arrears, buildings) in nations.iter_mut() {
+        let building_count = buildings.map_or(0, |b| b.buildings.len());
+        let nation_civilians = civilians_per_nation
+            .get(&entity)
+            .map_or(&[][..], Vec::as_slice);
+        let due = policy.due(building_count, nation_civilians.len()) + arrears.debt;
+        if due <= 0 {
+            continue;
+        }
+
+        let available = treasury.available();
+        if available >= due {
+            treasury.subtract(due);
+            *arrears = UpkeepArrears::default();
+            debug!("{:?} paid ${} upkeep", entity, due);
+            continue;
+        }
+
+        let paid = available.max(0);
+        treasury.subtract(paid);
+        rejections.write(FinancialRejection::new(
+            entity,
+            FinancialAction::Upkeep,
+            due,
+            available,
+        ));
+        arrears.debt = due - paid;
+        arrears.turns_unpaid += 1;
+        if arrears.turns_unpaid < policy.bankruptcy_after {
+            info!(
+                "{:?} could only pay ${} of its ${} upkeep",
+                entity, paid, due
+            );
+            continue;
+        }
+
+        warn!("{:?} went bankrupt owing ${}", entity, arrears.debt);
+        bankruptcies.write(Bankruptcy {
+            nation: entity,
+            debt: arrears.debt,
+        });
+        *arrears = UpkeepArrears::default();
+        for &civilian in nation_civilians {
+            dismiss_civilian(&mut commands, civilian, entity, selected.as_deref());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use bevy_ecs_tilemap::prelude::TilePos;
+
+    use crate::civilians::Civilian;
+    use crate::civilians::hiring::disband_civilian;
+    use crate::civilians::types::{CivilianId, CivilianKind};
+    use crate::economy::production::{Building, BuildingKind, Buildings};
+    use crate::economy::treasury::{FinancialAction, FinancialRejection, Treasury};
+    use crate::economy::upkeep::{Bankruptcy, UpkeepArrears, UpkeepPolicy, charge_upkeep};
+    use crate::turn_system::TurnCounter;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(UpkeepPolicy {
+            starting_treasury: 10_000,
+            per_building: 10,
+            per_civilian: 25,
+            bankruptcy_after: 3,
+        });
+        world.init_resource::<Messages<FinancialRejection>>();
+        world.init_resource::<Messages<Bankruptcy>>();
+        world.insert_resource(TurnCounter::new(2));
+        world
+    }
+
+    #[test]
+    fn upkeep_scales_with_buildings_and_drops_when_one_is_removed() {
+        let mut world = setup_world();
+        let nation = world
+            .spawn((Treasury::new(1_000), Buildings::with_all_initial()))
+            .id();
+        world.spawn(Civilian {
+            kind: CivilianKind::Engineer,
+            position: TilePos { x: 0, y: 0 },
+            owner: nation,
+            civilian_id: CivilianId(1),
+            has_moved: false,
+            experience: 0,
+        });
+
+        let building_count = world.get::<Buildings>(nation).unwrap().buildings.len() as i64;
+        let full_upkeep = building_count * 10 + 25;
+
+        let _ = world.run_system_once(charge_upkeep);
+        assert_eq!(
+            world.get::<Treasury>(nation).unwrap().total(),
+            1_000 - full_upkeep
+        );
+
+        let _ = world.run_system_once(charge_upkeep);
+        assert_eq!(
+            world.get::<Treasury>(nation).unwrap().total(),
+            1_000 - 2 * full_upkeep
+        );
+
+        world
+            .get_mut::<Buildings>(nation)
+            .unwrap()
+            .buildings
+            .remove(&BuildingKind::Refinery);
+        let _ = world.run_system_once(charge_upkeep);
+        assert_eq!(
+            world.get::<Treasury>(nation).unwrap().total(),
+            1_000 - 3 * full_upkeep + 10
+        );
+        assert!(
+            world
+                .resource_mut::<Messages<FinancialRejection>>()
+                .drain()
+                .next()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unpaid_upkeep_empties_treasury_and_reports_shortfall() {
+        let mut world = setup_world();
+        let mut buildings = Buildings::new();
+        buildings.insert(Building::textile_mill(8));
+        buildings.insert(Building::lumber_mill(4));
+        let nation = world.spawn((Treasury::new(15), buildings)).id();
+
+        let _ = world.run_system_once(charge_upkeep);
+
+        assert_eq!(world.get::<Treasury>(nation).unwrap().total(), 0);
+        let rejections: Vec<FinancialRejection> = world
+            .resource_mut::<Messages<FinancialRejection>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            rejections,
+            vec![FinancialRejection {
+                nation,
+                action: FinancialAction::Upkeep,
+                cost: 20,
+                shortfall: 5,
+            }]
+        );
+    }
+
+    #[test]
+    fn upkeep_left_unpaid_for_three_turns_bankrupts_the_nation() {
+        let mut world = setup_world();
+        // Registered as in the game; bankruptcy must not go through its refund
+        world.add_observer(disband_civilian);
+        let mut buildings = Buildings::new();
+        buildings.insert(Building::textile_mill(8));
+        let nation = world.spawn((Treasury::new(15), buildings)).id();
+        let engineer = world
+            .spawn(Civilian {
+                kind: CivilianKind::Engineer,
+                position: TilePos { x: 0, y: 0 },
+                owner: nation,
+                civilian_id: CivilianId(1),
+                has_moved: false,
+                experience: 0,
+            })
+            .id();
+
+        // 35 due, 15 paid: the remaining 20 is owed on top of the next bill
+        let _ = world.run_system_once(charge_upkeep);
+        assert_eq!(
+            *world.get::<UpkeepArrears>(nation).unwrap(),
+            UpkeepArrears {
+                debt: 20,
+                turns_unpaid: 1,
+            }
+        );
+
+        let _ = world.run_system_once(charge_upkeep);
+        assert_eq!(world.get::<UpkeepArrears>(nation).unwrap().debt, 55);
+        assert!(world.get_entity(engineer).is_ok());
+
+        let _ = world.run_system_once(charge_upkeep);
+        let bankruptcies: Vec<Bankruptcy> = world
+            .resource_mut::<Messages<Bankruptcy>>()
+            .drain()
+            .collect();
+        assert_eq!(bankruptcies, vec![Bankruptcy { nation, debt: 90 }]);
+        assert_eq!(
+            *world.get::<UpkeepArrears>(nation).unwrap(),
+            UpkeepArrears::default()
+        );
+        assert!(world.get_entity(engineer).is_err());
+        assert_eq!(world.get::<Treasury>(nation).unwrap().total(), 0);
+    }
+}
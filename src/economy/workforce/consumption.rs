@@ -5,14 +5,30 @@ use crate::economy::goods::Good;
 use crate::economy::stockpile::Stockpile;
 use crate::economy::workforce::types::{WorkerHealth, Workforce};
 
+/// Outcome of the last feeding, read by population growth
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct FoodSupply {
+    /// Workers who ate the wrong food or nothing at all last turn
+    pub hungry: u32,
+    /// Consecutive turns with hungry workers
+    pub shortage_turns: u32,
+}
+
+impl FoodSupply {
+    pub fn is_short(&self) -> bool {
+        self.hungry > 0
+    }
+}
+
 /// System that feeds workers at the start of each player turn
 /// Implements the feeding preference cycle: preferred raw → canned → wrong raw (sick) → none (dead)
 /// NOTE: Registered via OnEnter(TurnPhase::PlayerTurn), so no phase check needed.
 pub fn feed_workers(
-    mut nations: Query<(Entity, &mut Workforce, &mut Stockpile)>,
+    mut nations: Query<(Entity, &mut Workforce, &mut Stockpile, &mut FoodSupply)>,
     player_nation: Option<Res<PlayerNation>>,
 ) {
-    for (entity, mut workforce, mut stockpile) in nations.iter_mut() {
+    for (entity, mut workforce, mut stockpile, mut supply) in nations.iter_mut() {
         let is_player = player_nation
             .as_ref()
             .map(|p| p.0 == entity)
@@ -54,6 +70,13 @@ pub fn feed_workers(
             }
         }
 
+        supply.hungry = sick_count + dead_count;
+        if supply.is_short() {
+            supply.shortage_turns += 1;
+        } else {
+            supply.shortage_turns = 0;
+        }
+
         // Log warnings for player only
         if is_player {
             if sick_count > 0 {
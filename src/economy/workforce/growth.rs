@@ -0,0 +1,208 @@
+use bevy::prelude::*;
+
+use crate::economy::goods::Good;
+use crate::economy::stockpile::Stockpile;
+use crate::economy::workforce::consumption::FoodSupply;
+use crate::economy::workforce::types::Workforce;
+use crate::map::province::Province;
+
+/// Food goods that count toward the surplus driving growth
+const FOOD_GOODS: [Good; 5] = [
+    Good::Grain,
+    Good::Fruit,
+    Good::Livestock,
+    Good::Fish,
+    Good::CannedFood,
+];
+
+/// Most growth a single province can contribute per turn
+pub const GROWTH_PER_PROVINCE: u32 = 2;
+
+/// Growth points needed for one new untrained worker
+pub const GROWTH_PER_WORKER: u32 = 10;
+
+/// Turns of food shortage in a row before workers start leaving
+pub const EMIGRATION_SHORTAGE_TURNS: u32 = 3;
+
+/// Growth accumulated toward the nation's next worker
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct PopulationGrowth {
+    pub progress: u32,
+}
+
+/// System that slowly adds untrained workers to well-fed nations.
+/// Runs right after `feed_workers`; the food the current workforce will eat next turn
+/// is set aside first, and only what remains counts as surplus.
+/// Growth per turn is the surplus, capped by province count; hungry nations do not grow,
+/// and no more workers arrive than the surplus could feed.
+pub fn grow_population(
+    mut nations: Query<(
+        Entity,
+        &mut Workforce,
+        &Stockpile,
+        &FoodSupply,
+        &mut PopulationGrowth,
+    )>,
+    provinces: Query<&Province>,
+) {
+    for (entity, mut workforce, stockpile, supply, mut growth) in nations.iter_mut() {
+        if supply.is_short() {
+            growth.progress = 0;
+            continue;
+        }
+
+        let stored: u32 = FOOD_GOODS.iter().map(|good| stockpile.get(*good)).sum();
+        let consumption = workforce.workers.len() as u32;
+        let surplus = stored.saturating_sub(consumption);
+        let province_count = provinces
+            .iter()
+            .filter(|province| province.owner == Some(entity))
+            .count() as u32;
+
+        growth.progress += surplus.min(province_count * GROWTH_PER_PROVINCE);
+
+        let new_workers = (growth.progress / GROWTH_PER_WORKER).min(surplus);
+        if new_workers > 0 {
+            growth.progress -= new_workers * GROWTH_PER_WORKER;
+            workforce.add_untrained(new_workers);
+        }
+    }
+}
+
+/// System that makes workers emigrate from nations whose food has been short for
+/// several turns running. One in ten workers leaves per turn (at least one),
+/// least skilled first.
+pub fn emigrate_hungry_workers(mut nations: Query<(&mut Workforce, &FoodSupply)>) {
+    for (mut workforce, supply) in nations.iter_mut() {
+        if supply.shortage_turns < EMIGRATION_SHORTAGE_TURNS || workforce.workers.is_empty() {
+            continue;
+        }
+
+        let leaving = (workforce.workers.len() / 10).max(1);
+        for _ in 0..leaving {
+            let Some(index) = workforce
+                .workers
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, worker)| worker.skill)
+                .map(|(index, _)| index)
+            else {
+                break;
+            };
+            workforce.workers.remove(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use bevy_ecs_tilemap::prelude::TilePos;
+
+    use crate::economy::goods::Good;
+    use crate::economy::nation::Nation;
+    use crate::economy::stockpile::Stockpile;
+    use crate::economy::workforce::{
+        PopulationGrowth, Workforce, emigrate_hungry_workers, feed_workers, grow_population,
+    };
+    use crate::map::province::{Province, ProvinceId};
+
+    fn spawn_nation(world: &mut World, workers: u32, food_per_kind: u32) -> Entity {
+        let mut workforce = Workforce::new();
+        workforce.add_untrained(workers);
+        let mut stockpile = Stockpile::default();
+        for food in [Good::Grain, Good::Fruit, Good::Livestock] {
+            stockpile.add(food, food_per_kind);
+        }
+        let nation = world.spawn((Nation, workforce, stockpile)).id();
+
+        for id in 0..3 {
+            let pos = TilePos { x: id, y: 0 };
+            let mut province = Province::new(ProvinceId(id), vec![pos], pos);
+            province.owner = Some(nation);
+            world.spawn(province);
+        }
+        nation
+    }
+
+    fn run_turns(world: &mut World, turns: usize) {
+        for _ in 0..turns {
+            world.run_system_once(feed_workers).unwrap();
+            world.run_system_once(grow_population).unwrap();
+            world.run_system_once(emigrate_hungry_workers).unwrap();
+        }
+    }
+
+    fn worker_count(world: &World, nation: Entity) -> usize {
+        world.get::<Workforce>(nation).unwrap().workers.len()
+    }
+
+    #[test]
+    fn well_fed_nation_grows_and_starving_one_does_not() {
+        let mut fed_world = World::new();
+        let fed = spawn_nation(&mut fed_world, 3, 200);
+        run_turns(&mut fed_world, 6);
+        assert!(
+            worker_count(&fed_world, fed) > 3,
+            "surplus food should add workers"
+        );
+
+        // Three workers but food for only one of them per kind, for a single turn
+        let mut starving_world = World::new();
+        let starving = spawn_nation(&mut starving_world, 3, 1);
+        let mut workers = worker_count(&starving_world, starving);
+        for _ in 0..6 {
+            run_turns(&mut starving_world, 1);
+            assert_eq!(
+                starving_world
+                    .get::<PopulationGrowth>(starving)
+                    .unwrap()
+                    .progress,
+                0,
+                "food eaten this turn is not surplus"
+            );
+            let now = worker_count(&starving_world, starving);
+            assert!(now <= workers, "a hungry nation must not grow");
+            workers = now;
+        }
+    }
+
+    #[test]
+    fn surplus_is_what_remains_after_next_turns_meals() {
+        // Nine food for three workers leaves six after feeding; three more are set
+        // aside for next turn, so only three count toward growth
+        let mut world = World::new();
+        let nation = spawn_nation(&mut world, 3, 3);
+        run_turns(&mut world, 1);
+
+        assert_eq!(world.get::<PopulationGrowth>(nation).unwrap().progress, 3);
+        assert_eq!(worker_count(&world, nation), 3);
+    }
+
+    #[test]
+    fn chronically_hungry_nation_loses_workers_while_content_one_keeps_them() {
+        fn spawn_landless(world: &mut World, foods: &[Good]) -> Entity {
+            let mut workforce = Workforce::new();
+            workforce.add_untrained(6);
+            let mut stockpile = Stockpile::default();
+            for food in foods {
+                stockpile.add(*food, 100);
+            }
+            world.spawn((Nation, workforce, stockpile)).id()
+        }
+
+        // Grain alone keeps everyone alive, but most workers eat the wrong food
+        let mut world = World::new();
+        let hungry = spawn_landless(&mut world, &[Good::Grain]);
+        let content = spawn_landless(&mut world, &[Good::Grain, Good::Fruit, Good::Livestock]);
+
+        run_turns(&mut world, 2);
+        assert_eq!(worker_count(&world, hungry), 6, "no one leaves right away");
+
+        run_turns(&mut world, 3);
+        assert!(worker_count(&world, hungry) < 6);
+        assert_eq!(worker_count(&world, content), 6);
+    }
+}
@@ -9,7 +9,10 @@ pub use systems::{calculate_recruitment_cap, update_labor_pools};
 // Recruitment systems
 pub mod recruitment;
 pub use crate::messages::workforce::RecruitWorkers;
-pub use recruitment::{RecruitmentQueue, execute_recruitment_orders, handle_recruitment};
+pub use recruitment::{
+    CAPITOL_UPGRADE_COST, RecruitmentQueue, execute_recruitment_orders, handle_recruitment,
+    upgrade_capitol,
+};
 
 // Training systems
 pub mod training;
@@ -18,4 +21,8 @@ pub use training::{TrainingQueue, execute_training_orders, handle_training};
 
 // Food consumption systems
 pub mod consumption;
-pub use consumption::feed_workers;
+pub use consumption::{FoodSupply, feed_workers};
+
+// Natural population growth
+pub mod growth;
+pub use growth::{PopulationGrowth, emigrate_hungry_workers, grow_population};
@@ -1,11 +1,19 @@
 use bevy::prelude::*;
 
 use crate::economy::goods::Good;
+use crate::economy::production::{Building, Buildings};
 use crate::economy::stockpile::Stockpile;
+use crate::economy::treasury::{FinancialAction, FinancialRejection, Treasury};
 use crate::economy::workforce::systems::calculate_recruitment_cap;
 use crate::economy::workforce::types::{RecruitmentCapacity, Workforce};
 use crate::map::province::Province;
-use crate::messages::workforce::RecruitWorkers;
+use crate::messages::workforce::{RecruitWorkers, UpgradeCapitol};
+
+/// Money charged for upgrading the Capitol
+pub const CAPITOL_UPGRADE_COST: i64 = 2_000;
+
+/// Workers an upgraded Capitol can recruit per turn
+pub const UPGRADED_CAPITOL_CAPACITY: u32 = 4;
 
 /// Component tracking queued recruitment orders for a nation
 #[derive(Component, Debug, Clone, Default, Reflect)]
@@ -15,6 +23,41 @@ pub struct RecruitmentQueue {
     pub queued: u32,
 }
 
+/// Observer: upgrade a nation's Capitol. The upgrade lifts the province share of
+/// the recruitment cap (provinces/4 to provinces/3) and the Capitol's own capacity.
+pub fn upgrade_capitol(
+    trigger: On<UpgradeCapitol>,
+    mut nations: Query<(&mut RecruitmentCapacity, &mut Buildings, &mut Treasury)>,
+    mut rejections: MessageWriter<FinancialRejection>,
+) {
+    let nation = trigger.event().nation.entity();
+    let Ok((mut capacity, mut buildings, mut treasury)) = nations.get_mut(nation) else {
+        return;
+    };
+    if capacity.upgraded {
+        info!("Capitol upgrade skipped: {:?} already upgraded", nation);
+        return;
+    }
+    if treasury.available() < CAPITOL_UPGRADE_COST {
+        info!(
+            "Capitol upgrade rejected: {:?} cannot afford ${}",
+            nation, CAPITOL_UPGRADE_COST
+        );
+        rejections.write(FinancialRejection::new(
+            nation,
+            FinancialAction::CapitolUpgrade,
+            CAPITOL_UPGRADE_COST,
+            treasury.available(),
+        ));
+        return;
+    }
+
+    treasury.subtract(CAPITOL_UPGRADE_COST);
+    capacity.upgraded = true;
+    buildings.insert(Building::capitol(UPGRADED_CAPITOL_CAPACITY));
+    info!("{:?} upgraded its Capitol", nation);
+}
+
 /// System to queue worker recruitment orders at the Capitol (Input Layer)
 /// Validates resources exist and caps, reserves resources, queues the order
 pub fn handle_recruitment(
@@ -22,6 +65,7 @@ pub fn handle_recruitment(
     mut nations: Query<(&mut RecruitmentQueue, &mut Stockpile)>,
     recruitment_capacity: Query<&RecruitmentCapacity>,
     provinces: Query<&Province>,
+    buildings: Query<&Buildings>,
 ) {
     let event = trigger.event();
     if let Ok((mut queue, mut stockpile)) = nations.get_mut(event.nation.entity()) {
@@ -36,14 +80,18 @@ pub fn handle_recruitment(
             .map(|c| c.upgraded)
             .unwrap_or(false);
 
-        let cap = calculate_recruitment_cap(province_count, capacity);
+        let capitol_capacity = buildings
+            .get(event.nation.entity())
+            .ok()
+            .and_then(Buildings::capitol_capacity);
+        let cap = calculate_recruitment_cap(province_count, capacity, capitol_capacity);
 
         // Limit requested count to cap
         let actual_count = event.count.min(cap);
 
         if actual_count == 0 {
-            warn!("Cannot queue recruitment: cap is 0 (need more provinces)");
-            info!("Cannot recruit: need more provinces");
+            warn!("Cannot queue recruitment: cap is 0 (need more provinces or a larger Capitol)");
+            info!("Cannot recruit: need more provinces or a larger Capitol");
             return;
         }
 
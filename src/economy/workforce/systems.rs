@@ -1,13 +1,19 @@
 use crate::economy::workforce::Workforce;
 use bevy::prelude::*;
 
-/// Calculate recruitment cap based on province count
-pub fn calculate_recruitment_cap(province_count: u32, upgraded: bool) -> u32 {
-    if upgraded {
+/// Calculate recruitment cap based on province count, never more than the Capitol can
+/// take in (`None` when the nation has no Capitol building recorded)
+pub fn calculate_recruitment_cap(
+    province_count: u32,
+    upgraded: bool,
+    capitol_capacity: Option<u32>,
+) -> u32 {
+    let province_cap = if upgraded {
         province_count / 3
     } else {
         province_count / 4
-    }
+    };
+    capitol_capacity.map_or(province_cap, |capacity| province_cap.min(capacity))
 }
 
 /// Update labor pools to match current workforce state
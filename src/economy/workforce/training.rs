@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use crate::economy::goods::Good;
 use crate::economy::stockpile::Stockpile;
-use crate::economy::treasury::Treasury;
+use crate::economy::treasury::{FinancialAction, FinancialRejection, Treasury};
 use crate::economy::workforce::types::{WorkerSkill, Workforce};
 use crate::messages::workforce::TrainWorker;
 
@@ -38,6 +38,7 @@ impl TrainingQueue {
 pub fn handle_training(
     trigger: On<TrainWorker>,
     mut nations: Query<(&Workforce, &mut Stockpile, &Treasury, &mut TrainingQueue)>,
+    mut rejections: MessageWriter<FinancialRejection>,
 ) {
     const TRAINING_COST_PAPER: u32 = 1;
     const TRAINING_COST_CASH: i64 = 100;
@@ -88,12 +89,12 @@ pub fn handle_training(
         let total_queued = queue.total_queued();
         let total_cash_needed = (total_queued as i64 + 1) * TRAINING_COST_CASH;
         if treasury.total() < total_cash_needed {
-            warn!("Cannot queue training: not enough money");
-            info!(
-                "Cannot train: need ${} (have: ${})",
+            rejections.write(FinancialRejection::new(
+                event.nation.entity(),
+                FinancialAction::Training,
                 total_cash_needed,
-                treasury.total()
-            );
+                treasury.total(),
+            ));
             return;
         }
 
@@ -165,3 +166,60 @@ pub fn execute_training_orders(
         queue.orders.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::economy::goods::Good;
+    use crate::economy::nation::{Nation, NationInstance};
+    use crate::economy::stockpile::Stockpile;
+    use crate::economy::treasury::{FinancialAction, FinancialRejection, Treasury};
+    use crate::economy::workforce::{TrainingQueue, WorkerSkill, Workforce, handle_training};
+    use crate::messages::workforce::TrainWorker;
+
+    #[test]
+    fn queueing_training_without_cash_reports_a_financial_rejection() {
+        let mut world = World::new();
+        world.init_resource::<Messages<FinancialRejection>>();
+        world.add_observer(handle_training);
+
+        let mut workforce = Workforce::new();
+        workforce.add_untrained(1);
+        let mut stockpile = Stockpile::default();
+        stockpile.add(Good::Paper, 1);
+        let nation = world
+            .spawn((
+                Nation,
+                workforce,
+                stockpile,
+                Treasury::new(40),
+                TrainingQueue::default(),
+            ))
+            .id();
+        let instance = NationInstance::from_entity(world.entity(nation)).unwrap();
+
+        world.trigger(TrainWorker {
+            nation: instance,
+            from_skill: WorkerSkill::Untrained,
+        });
+
+        assert_eq!(
+            world.get::<TrainingQueue>(nation).unwrap().total_queued(),
+            0
+        );
+        let rejections: Vec<FinancialRejection> = world
+            .resource_mut::<Messages<FinancialRejection>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            rejections,
+            vec![FinancialRejection {
+                nation,
+                action: FinancialAction::Training,
+                cost: 100,
+                shortfall: 60,
+            }]
+        );
+    }
+}
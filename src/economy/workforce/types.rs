@@ -2,11 +2,14 @@ use bevy::prelude::*;
 
 use crate::economy::goods::Good;
 use crate::economy::reservation::ResourcePool;
+use crate::economy::workforce::consumption::FoodSupply;
+use crate::economy::workforce::growth::PopulationGrowth;
 
 /// Workforce component tracks workers by skill level for a nation
 /// Workers provide labor points: Untrained=1, Trained=2, Expert=4
 #[derive(Component, Debug, Clone, Default, Reflect)]
 #[reflect(Component)]
+#[require(FoodSupply, PopulationGrowth)]
 pub struct Workforce {
     /// Individual workers with their state
     pub workers: Vec<Worker>,
@@ -149,7 +152,7 @@ pub struct Worker {
 }
 
 /// Worker skill level determines labor points
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect)]
 pub enum WorkerSkill {
     Untrained, // 1 labor point
     Trained,   // 2 labor points
@@ -16,8 +16,8 @@ impl Plugin for CameraPlugin {
         app.add_systems(Startup, setup).add_systems(
             Update,
             (
-                center_on_player_capital.run_if(resource_added::<PlayerNation>),
-                movement.run_if(in_state(GameMode::Map)),
+                center_on_player_capital.run_if(resource_exists_and_changed::<PlayerNation>),
+                movement.run_if(in_state(GameMode::Map).or(in_state(GameMode::Editor))),
             ),
         );
     }
@@ -34,13 +34,13 @@ fn setup(mut commands: Commands) {
     ));
 }
 
-/// Center camera on player's capital when the game starts
+/// Center camera on player's capital when the game starts or another hotseat player takes over
 fn center_on_player_capital(
     mut camera: Query<&mut Transform, With<Camera2d>>,
     player_nation: Option<Res<PlayerNation>>,
     capitals: Query<(Entity, &Capital), With<Nation>>,
 ) {
-    // Only run once when player nation is available
+    // Runs whenever the active player nation is set
     let Some(player) = player_nation else {
         return;
     };
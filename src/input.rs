@@ -1,11 +1,14 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 
-use crate::civilians::commands::SelectedCivilian;
-use crate::civilians::{Civilian, CivilianCommand, CivilianKind, CivilianOrderKind};
+use crate::civilians::commands::{BoxSelection, MoveSelectedCivilians, SelectedCivilian};
+use crate::civilians::{Civilian, CivilianCommand, CivilianOrderKind};
+use crate::economy::transport::{Bridges, ordered_edge};
 use crate::map::tile_pos::TilePosExt;
+use crate::map::tiles::TerrainType;
 
 use crate::ui::menu::AppState;
+use crate::ui::mode::GameMode;
 
 pub struct InputPlugin;
 
@@ -18,6 +21,11 @@ impl Plugin for InputPlugin {
             Update,
             crate::civilians::systems::handle_deselect_key.run_if(in_state(AppState::InGame)),
         );
+        app.init_resource::<BoxSelection>().add_systems(
+            Update,
+            crate::civilians::systems::box_select_civilians
+                .run_if(in_state(AppState::InGame).and(in_state(GameMode::Map))),
+        );
 
         // Register UI observers
         app.add_observer(crate::civilians::ui_components::show_civilian_orders_ui)
@@ -34,16 +42,30 @@ fn keyboard_input(keys: Res<ButtonInput<KeyCode>>, mut commands: Commands) {
     }
 }
 
-/// Handle tile clicks when any civilian is selected
+/// Handle tile clicks when any civilian is selected; only active in map mode
 pub fn handle_tile_click(
     trigger: On<Pointer<Click>>,
     mut commands: Commands,
+    mode: Option<Res<State<GameMode>>>,
     selected_civilian: Option<Res<SelectedCivilian>>,
     tile_positions: Query<&TilePos>,
     civilians: Query<(Entity, &Civilian)>,
     potential_minerals: Query<&crate::map::PotentialMineral>,
     tile_storage_query: Query<&bevy_ecs_tilemap::prelude::TileStorage>,
+    terrain: Query<&TerrainType>,
+    bridges: Option<Res<Bridges>>,
+    box_selection: Option<Res<BoxSelection>>,
 ) {
+    // Editor clicks paint terrain; they must not also order the selected civilian around
+    if mode.is_none_or(|mode| *mode.get() != GameMode::Map) {
+        return;
+    }
+
+    // Releasing a box selection over a tile is not a click on it
+    if box_selection.is_some_and(|selection| selection.dragging) {
+        return;
+    }
+
     // Get the clicked tile position
     let Ok(clicked_pos) = tile_positions.get(trigger.entity) else {
         return;
@@ -53,6 +75,12 @@ pub fn handle_tile_click(
     let Some(selected_civilian) = selected_civilian else {
         return;
     };
+
+    // A box-selected group shares a single move order
+    if selected_civilian.is_group() {
+        commands.trigger(MoveSelectedCivilians { to: *clicked_pos });
+        return;
+    }
     let selected = selected_civilian.0;
     let Ok((civilian_entity, civilian)) = civilians.get(selected) else {
         return;
@@ -65,7 +93,7 @@ pub fn handle_tile_click(
     // If the unit is stationary and supports a tile action, check if action is valid
     if distance == 0 {
         // For prospectors, check if tile can be prospected
-        if civilian.kind == CivilianKind::Prospector {
+        if civilian.kind.capabilities().prospects {
             // Check if tile has PotentialMineral
             let tile_storage = tile_storage_query.iter().next();
             let can_prospect = tile_storage
@@ -93,20 +121,42 @@ pub fn handle_tile_click(
         return;
     }
 
-    // Special handling for Engineer: adjacent click = build rail
-    if civilian.kind == CivilianKind::Engineer && distance == 1 {
-        info!(
-            "Clicked adjacent tile ({}, {}) with Engineer, sending BuildRail order",
-            clicked_pos.x, clicked_pos.y
-        );
+    // Special handling for Engineer: adjacent click = build rail,
+    // or bridge the river first if the clicked edge is not yet bridged
+    if civilian.kind.capabilities().builds_rails && distance == 1 {
+        let clicked_water = tile_storage_query
+            .iter()
+            .next()
+            .and_then(|storage| storage.get(clicked_pos))
+            .and_then(|tile_entity| terrain.get(tile_entity).ok())
+            .is_some_and(|terrain| *terrain == TerrainType::Water);
+        let bridged = bridges.is_some_and(|bridges| {
+            bridges
+                .0
+                .contains(&ordered_edge(civilian.position, *clicked_pos))
+        });
+
+        let order = if clicked_water && !bridged {
+            info!(
+                "Clicked adjacent river ({}, {}) with Engineer, sending BuildBridge order",
+                clicked_pos.x, clicked_pos.y
+            );
+            CivilianOrderKind::BuildBridge { to: *clicked_pos }
+        } else {
+            info!(
+                "Clicked adjacent tile ({}, {}) with Engineer, sending BuildRail order",
+                clicked_pos.x, clicked_pos.y
+            );
+            CivilianOrderKind::BuildRail { to: *clicked_pos }
+        };
 
         commands.trigger(CivilianCommand {
             civilian: civilian_entity,
-            order: CivilianOrderKind::BuildRail { to: *clicked_pos },
+            order,
         });
     } else if distance >= 1 {
         // For prospectors, check if target tile can be prospected
-        if civilian.kind == CivilianKind::Prospector {
+        if civilian.kind.capabilities().prospects {
             let tile_storage = tile_storage_query.iter().next();
             let can_prospect = tile_storage
                 .and_then(|storage| storage.get(clicked_pos))
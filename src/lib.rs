@@ -9,6 +9,7 @@ pub use crate::economy::EconomyPlugin;
 pub use crate::helpers::camera::CameraPlugin;
 pub use crate::helpers::picking::TilemapBackend;
 pub use crate::input::InputPlugin;
+pub use crate::map::editor::MapEditorPlugin;
 pub use crate::map::rendering::MapRenderingPlugin;
 pub use crate::map::{MapGenerationPlugin, MapLogicPlugin};
 use crate::save::GameSavePlugin;
@@ -100,6 +101,7 @@ impl PluginGroup for InputPlugins {
         PluginGroupBuilder::start::<Self>()
             .add(BevyUiInputPlugin)
             .add(InputPlugin)
+            .add(MapEditorPlugin)
             .add(GameUIPlugin)
     }
 }
@@ -131,7 +133,11 @@ pub fn app() -> App {
         ));
 
     #[cfg(feature = "debug")]
-    app.add_plugins((EguiPlugin::default(), WorldInspectorPlugin::new()));
+    app.add_plugins((
+        EguiPlugin::default(),
+        WorldInspectorPlugin::new(),
+        ai::debug_overlay::AiDebugOverlayPlugin,
+    ));
 
     app
 }
@@ -0,0 +1,284 @@
+//! Lightweight map editor for building scenarios.
+//!
+//! In `GameMode::Editor` clicking a tile applies the current [`EditorBrush`]: either
+//! cycling its terrain or placing a resource. Painted tiles are marked with
+//! [`EditedTile`] so [`export_scenario`] only writes what was changed by hand.
+
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::{TilePos, TileStorage, TileTextureIndex};
+
+use crate::map::scenario::{Scenario, ScenarioTile, SelectedScenario};
+use crate::map::tiles::TerrainType;
+use crate::resources::{ALL_RESOURCES, ResourceType, TileResource};
+use crate::ui::menu::AppState;
+use crate::ui::mode::GameMode;
+
+/// Where the editor writes exported scenarios
+pub const EDITOR_EXPORT_PATH: &str = "scenarios/editor_export.ron";
+
+/// Plugin for the scenario map editor
+pub struct MapEditorPlugin;
+
+impl Plugin for MapEditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditorBrush>()
+            .add_observer(apply_paint)
+            .add_systems(
+                Update,
+                toggle_editor_mode.run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(
+                Update,
+                (cycle_editor_brush, export_on_key).run_if(in_state(GameMode::Editor)),
+            );
+    }
+}
+
+/// What a click paints in editor mode
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub enum EditorBrush {
+    /// Cycle the tile's terrain
+    #[default]
+    Terrain,
+    /// Place this resource on the tile
+    Resource(ResourceType),
+    /// Remove any resource from the tile
+    ClearResource,
+}
+
+impl EditorBrush {
+    /// Terrain, then every resource in turn, then clearing
+    fn next(self) -> Self {
+        match self {
+            EditorBrush::Terrain => EditorBrush::Resource(ALL_RESOURCES[0]),
+            EditorBrush::Resource(current) => ALL_RESOURCES
+                .iter()
+                .position(|resource| *resource == current)
+                .and_then(|index| ALL_RESOURCES.get(index + 1))
+                .map_or(EditorBrush::ClearResource, |next| {
+                    EditorBrush::Resource(*next)
+                }),
+            EditorBrush::ClearResource => EditorBrush::Terrain,
+        }
+    }
+}
+
+/// Marker for tiles changed in the editor
+#[derive(Component, Debug, Default)]
+pub struct EditedTile;
+
+/// Paint the tile at `pos` with the current brush.
+/// Triggered via `commands.trigger(PaintTile { pos })`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PaintTile {
+    pub pos: TilePos,
+}
+
+/// Tile click observer; only active in editor mode
+pub fn paint_on_click(
+    trigger: On<Pointer<Click>>,
+    mode: Option<Res<State<GameMode>>>,
+    tile_positions: Query<&TilePos>,
+    mut commands: Commands,
+) {
+    if mode.is_none_or(|mode| *mode.get() != GameMode::Editor) {
+        return;
+    }
+    if let Ok(pos) = tile_positions.get(trigger.entity) {
+        commands.trigger(PaintTile { pos: *pos });
+    }
+}
+
+fn apply_paint(trigger: On<PaintTile>, brush: Res<EditorBrush>, mut commands: Commands) {
+    let pos = trigger.event().pos;
+    let brush = *brush;
+    commands.queue(move |world: &mut World| {
+        let Some(tile) = tile_entity(world, pos) else {
+            return;
+        };
+        let Some(terrain) = world.get::<TerrainType>(tile).copied() else {
+            return;
+        };
+        let resource = world
+            .get::<TileResource>(tile)
+            .map(|resource| resource.resource_type);
+        let (terrain, resource) = match brush {
+            EditorBrush::Terrain => (terrain.next(), resource),
+            EditorBrush::Resource(placed) => (terrain, Some(placed)),
+            EditorBrush::ClearResource => (terrain, None),
+        };
+        set_tile(world, pos, terrain, resource);
+    });
+}
+
+fn tile_entity(world: &mut World, pos: TilePos) -> Option<Entity> {
+    world
+        .query::<&TileStorage>()
+        .iter(world)
+        .next()
+        .and_then(|storage| storage.get(&pos))
+}
+
+/// Overwrite a tile's terrain and resource, marking it as edited
+pub fn set_tile(
+    world: &mut World,
+    pos: TilePos,
+    terrain: TerrainType,
+    resource: Option<ResourceType>,
+) {
+    let Some(tile) = tile_entity(world, pos) else {
+        warn!("No tile at ({}, {}) to edit", pos.x, pos.y);
+        return;
+    };
+    let unchanged = world
+        .get::<TileResource>(tile)
+        .map(|existing| existing.resource_type)
+        == resource;
+
+    let mut entity = world.entity_mut(tile);
+    entity.insert((
+        terrain,
+        TileTextureIndex(terrain.get_texture_index()),
+        EditedTile,
+    ));
+    if unchanged {
+        return;
+    }
+    match resource {
+        Some(resource_type) => {
+            // Minerals stay hidden until prospected, as on generated maps
+            let mut placed = TileResource::visible(resource_type);
+            if placed.requires_prospecting() {
+                placed = TileResource::hidden_mineral(resource_type);
+            }
+            entity.insert(placed);
+        }
+        None => {
+            entity.remove::<TileResource>();
+        }
+    }
+}
+
+/// Scenario holding every edited tile, on top of the selected scenario if there is one
+pub fn export_scenario(world: &mut World) -> Scenario {
+    let mut scenario = world
+        .get_resource::<SelectedScenario>()
+        .map(|selected| selected.0.clone())
+        .unwrap_or_default();
+
+    let mut tiles: Vec<ScenarioTile> = world
+        .query_filtered::<(&TilePos, &TerrainType, Option<&TileResource>), With<EditedTile>>()
+        .iter(world)
+        .map(|(pos, terrain, resource)| ScenarioTile {
+            x: pos.x,
+            y: pos.y,
+            terrain: *terrain,
+            resource: resource.map(|resource| resource.resource_type),
+        })
+        .collect();
+    tiles.sort_by_key(|tile| (tile.x, tile.y));
+    scenario.tiles = tiles;
+    scenario
+}
+
+/// F2 switches between the map and the editor
+fn toggle_editor_mode(
+    keys: Res<ButtonInput<KeyCode>>,
+    mode: Res<State<GameMode>>,
+    mut next_mode: ResMut<NextState<GameMode>>,
+) {
+    if !keys.just_pressed(KeyCode::F2) {
+        return;
+    }
+    match mode.get() {
+        GameMode::Map => next_mode.set(GameMode::Editor),
+        GameMode::Editor => next_mode.set(GameMode::Map),
+        _ => {}
+    }
+}
+
+fn cycle_editor_brush(keys: Res<ButtonInput<KeyCode>>, mut brush: ResMut<EditorBrush>) {
+    if keys.just_pressed(KeyCode::KeyB) {
+        *brush = brush.next();
+        info!("Editor brush: {:?}", *brush);
+    }
+}
+
+fn export_on_key(keys: Res<ButtonInput<KeyCode>>, mut commands: Commands) {
+    if !keys.just_pressed(KeyCode::F6) {
+        return;
+    }
+    commands.queue(|world: &mut World| {
+        let scenario = export_scenario(world);
+        let result = scenario
+            .to_ron()
+            .map_err(|e| e.to_string())
+            .and_then(|ron| {
+                if let Some(parent) = std::path::Path::new(EDITOR_EXPORT_PATH).parent() {
+                    std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+                }
+                std::fs::write(EDITOR_EXPORT_PATH, ron).map_err(|e| e.to_string())
+            });
+        match result {
+            Ok(()) => info!("Exported scenario to {EDITOR_EXPORT_PATH}"),
+            Err(error) => error!("Failed to export scenario: {error}"),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy_ecs_tilemap::prelude::{TilePos, TileStorage, TileTextureIndex, TilemapSize};
+
+    use crate::map::editor::{EditorBrush, PaintTile, apply_paint, export_scenario};
+    use crate::map::scenario::Scenario;
+    use crate::map::tiles::TerrainType;
+    use crate::resources::ResourceType;
+
+    fn editor_world() -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<EditorBrush>();
+        world.add_observer(apply_paint);
+
+        let mut storage = TileStorage::empty(TilemapSize { x: 2, y: 2 });
+        let pos = TilePos { x: 1, y: 0 };
+        let tile = world
+            .spawn((
+                pos,
+                TerrainType::Grass,
+                TileTextureIndex(TerrainType::Grass.get_texture_index()),
+            ))
+            .id();
+        storage.set(&pos, tile);
+        world.spawn(storage);
+        (world, tile)
+    }
+
+    #[test]
+    fn painting_a_tile_changes_terrain_and_export_captures_it() {
+        let (mut world, tile) = editor_world();
+        let pos = TilePos { x: 1, y: 0 };
+
+        world.trigger(PaintTile { pos });
+        world.flush();
+        assert_eq!(world.get::<TerrainType>(tile), Some(&TerrainType::Water));
+        assert_eq!(
+            world.get::<TileTextureIndex>(tile).unwrap().0,
+            TerrainType::Water.get_texture_index()
+        );
+
+        *world.resource_mut::<EditorBrush>() = EditorBrush::Resource(ResourceType::Timber);
+        world.trigger(PaintTile { pos });
+        world.flush();
+
+        let scenario = export_scenario(&mut world);
+        assert_eq!(scenario.tiles.len(), 1);
+        assert_eq!(scenario.tiles[0].terrain, TerrainType::Water);
+        assert_eq!(scenario.tiles[0].resource, Some(ResourceType::Timber));
+
+        // The export round-trips through the scenario file format
+        let reparsed = Scenario::from_ron(&scenario.to_ron().unwrap()).unwrap();
+        assert_eq!(reparsed, scenario);
+    }
+}
@@ -1,36 +1,62 @@
+use bevy::ecs::system::ScheduleSystem;
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 
 use crate::constants::{MAP_SIZE, TERRAIN_SEED, TILE_SIZE};
 use crate::input::handle_tile_click;
-use crate::resources::{ResourceType, TileResource};
+use crate::map::rendering::ColorPalette;
 use crate::ui::components::MapTilemap;
 use crate::ui::menu::AppState;
 
 // Map-related modules
+pub mod editor;
+pub mod neighbor_cache;
 pub mod prospecting;
 pub mod province;
 pub mod province_gen;
 pub mod province_setup;
 pub mod rendering;
+pub mod scenario;
+pub mod spatial_index;
 pub mod terrain_gen;
 pub mod tile_pos;
+pub mod tile_spawner;
 pub mod tiles;
 
 // Re-exports for convenience
+pub use neighbor_cache::NeighborCache;
 pub use prospecting::*;
 pub use province::*;
 pub use province_gen::*;
 pub use province_setup::*;
+pub use scenario::{Scenario, SelectedScenario};
+pub use spatial_index::SpatialIndex;
 pub use terrain_gen::*;
 pub use tile_pos::*;
+pub use tile_spawner::{PendingTileSpawn, TileSpawnSettings, TileSpawner};
 pub use tiles::*;
 
 /// Plugin that handles core map logic and resources
 pub struct MapLogicPlugin;
 
 impl Plugin for MapLogicPlugin {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        // Indexing runs in PreUpdate so it is current before turn-phase transitions
+        // (e.g. the AI snapshot built on entering EnemyTurn)
+        app.init_resource::<SpatialIndex>()
+            .init_resource::<NeighborCache>()
+            // Nation setup picks colors from the palette, so headless games need it too
+            .init_resource::<ColorPalette>()
+            .add_systems(
+                PreUpdate,
+                (
+                    spatial_index::index_tiles,
+                    spatial_index::index_tile_ownership,
+                    spatial_index::index_units,
+                    neighbor_cache::resize_neighbor_cache,
+                ),
+            );
+    }
 }
 
 /// Plugin that handles random map and province generation
@@ -38,33 +64,69 @@ pub struct MapGenerationPlugin;
 
 impl Plugin for MapGenerationPlugin {
     fn build(&self, app: &mut App) {
-        // Run map generation strictly once when entering game state, if not already created
+        app.init_resource::<TileSpawnSettings>()
+            .init_resource::<TerrainParams>();
+
+        // Run map generation strictly once when entering game state, if not already created.
+        // With chunked tile spawning the remaining setup runs once the last chunk is in.
         app.add_systems(
             OnEnter(AppState::InGame),
             (
                 create_tilemap_logic,
                 ApplyDeferred,
-                province_setup::generate_provinces_system,
-                ApplyDeferred,
-                province_setup::assign_provinces_to_countries,
-                ApplyDeferred,
-                setup_tilemap_input,
-                ApplyDeferred,
-                province_setup::prune_to_test_map,
+                finish_map_setup().run_if(tile_spawner::map_tiles_spawned),
             )
                 .chain()
                 .run_if(map_not_created),
         );
+        app.add_systems(
+            Update,
+            (
+                tile_spawner::spawn_pending_tile_chunks,
+                ApplyDeferred,
+                finish_map_setup()
+                    .run_if(tile_spawner::map_tiles_spawned.and(provinces_not_generated)),
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame).and(any_with_component::<PendingTileSpawn>)),
+        );
     }
 }
 
+/// Province generation and input setup, which need every tile to exist
+fn finish_map_setup() -> impl IntoScheduleConfigs<ScheduleSystem, ()> {
+    (
+        province_setup::generate_provinces_system,
+        ApplyDeferred,
+        (
+            province_setup::assign_provinces_to_countries
+                .run_if(not(resource_exists::<SelectedScenario>)),
+            scenario::apply_scenario.run_if(resource_exists::<SelectedScenario>),
+        ),
+        ApplyDeferred,
+        setup_tilemap_input,
+        ApplyDeferred,
+        province_setup::prune_to_test_map,
+    )
+        .chain()
+}
+
+fn provinces_not_generated(provinces: Query<(), With<Province>>) -> bool {
+    provinces.is_empty()
+}
+
 /// Helper condition to check if map needs generation
 fn map_not_created(query: Query<(), With<MapTilemap>>) -> bool {
     query.is_empty()
 }
 
 /// Logic part of tilemap creation: spawns entities with terrain and resources
-fn create_tilemap_logic(mut commands: Commands) {
+fn create_tilemap_logic(
+    mut commands: Commands,
+    settings: Res<TileSpawnSettings>,
+    terrain_params: Res<TerrainParams>,
+    scenario: Option<Res<SelectedScenario>>,
+) {
     info!("Creating tilemap logic...");
 
     let map_size = TilemapSize {
@@ -76,110 +138,28 @@ fn create_tilemap_logic(mut commands: Commands) {
 
     let mut tile_storage = TileStorage::empty(map_size);
 
-    // Create terrain generator with a fixed seed for consistent worlds
-    let terrain_gen = TerrainGenerator::new(TERRAIN_SEED);
-
-    // Use deterministic RNG for resource placement (based on TERRAIN_SEED)
-    use rand::rngs::StdRng;
-    use rand::{Rng, SeedableRng};
-    let mut rng = StdRng::seed_from_u64(TERRAIN_SEED as u64);
-
-    for x in 0..map_size.x {
-        for y in 0..map_size.y {
-            let tile_pos = TilePos { x, y };
-
-            // Generate terrain using noise functions
-            let terrain_type = terrain_gen.generate_terrain(x, y, map_size.x, map_size.y);
-
-            let mut tile_entity_commands = commands.spawn((
-                tile_pos,
-                TilemapId(tilemap_entity),
-                terrain_type, // Add the terrain type component
-            ));
-
-            // Assign resources based on terrain type
-            match terrain_type {
-                tiles::TerrainType::Farmland => {
-                    // Farmland: Grain (70%), Cotton (20%), or Fruit (10%)
-                    let roll = rng.random::<f32>();
-                    let resource = if roll < 0.7 {
-                        ResourceType::Grain
-                    } else if roll < 0.9 {
-                        ResourceType::Cotton
-                    } else {
-                        ResourceType::Fruit
-                    };
-                    tile_entity_commands.insert(TileResource::visible(resource));
-                }
-                tiles::TerrainType::Grass => {
-                    // Grassland: 40% chance of Wool or Livestock
-                    if rng.random::<f32>() < 0.4 {
-                        let resource = if rng.random::<bool>() {
-                            ResourceType::Wool
-                        } else {
-                            ResourceType::Livestock
-                        };
-                        tile_entity_commands.insert(TileResource::visible(resource));
-                    }
-                }
-                tiles::TerrainType::Forest => {
-                    // Forest: Always has Timber
-                    tile_entity_commands.insert(TileResource::visible(ResourceType::Timber));
-                }
-                tiles::TerrainType::Mountain => {
-                    // Mountains: All can be prospected
-                    // 60% chance of actual mineral: Coal, Iron, Gold, or Gems
-                    let has_mineral = rng.random::<f32>() < 0.6;
-                    let mineral_type = if has_mineral {
-                        let roll = rng.random::<f32>();
-                        if roll < 0.4 {
-                            Some(ResourceType::Coal)
-                        } else if roll < 0.7 {
-                            Some(ResourceType::Iron)
-                        } else if roll < 0.9 {
-                            Some(ResourceType::Gold)
-                        } else {
-                            Some(ResourceType::Gems)
-                        }
-                    } else {
-                        None
-                    };
-                    tile_entity_commands.insert(PotentialMineral::new(mineral_type));
-                }
-                tiles::TerrainType::Hills => {
-                    // Hills: All can be prospected
-                    // 40% chance of actual mineral: Coal or Iron only (no gold/gems!)
-                    let has_mineral = rng.random::<f32>() < 0.4;
-                    let mineral_type = if has_mineral {
-                        let roll = rng.random::<f32>();
-                        if roll < 0.6 {
-                            Some(ResourceType::Coal)
-                        } else {
-                            Some(ResourceType::Iron)
-                        }
-                    } else {
-                        None
-                    };
-                    tile_entity_commands.insert(PotentialMineral::new(mineral_type));
-                }
-                tiles::TerrainType::Desert => {
-                    // Desert: All can be prospected for oil
-                    // 15% chance of Oil
-                    let has_oil = rng.random::<f32>() < 0.15;
-                    let mineral_type = if has_oil {
-                        Some(ResourceType::Oil)
-                    } else {
-                        None
-                    };
-                    tile_entity_commands.insert(PotentialMineral::new(mineral_type));
-                }
-                tiles::TerrainType::Water | tiles::TerrainType::Swamp => {
-                    // Water and Swamp: No resources
-                }
-            }
-
-            let tile_entity = tile_entity_commands.id();
-            tile_storage.set(&tile_pos, tile_entity);
+    // A scenario's own terrain parameters take precedence
+    let terrain_params = scenario
+        .and_then(|scenario| scenario.0.terrain_params)
+        .unwrap_or(*terrain_params);
+
+    // Fixed seed for consistent worlds
+    let mut spawner = TileSpawner::with_terrain_params(map_size, TERRAIN_SEED, terrain_params);
+
+    match settings.tiles_per_frame {
+        Some(tiles_per_frame) => {
+            // The rest of map setup waits until spawn_pending_tile_chunks is done
+            commands.entity(tilemap_entity).insert(PendingTileSpawn {
+                spawner,
+                tiles_per_frame,
+            });
+            info!(
+                "Spawning tiles over several frames ({} per frame)",
+                tiles_per_frame
+            );
+        }
+        None => {
+            spawner.spawn_chunk(&mut commands, tilemap_entity, &mut tile_storage, u32::MAX);
         }
     }
 
@@ -198,7 +178,11 @@ pub fn setup_tilemap_rendering(
     terrain_atlas: Option<Res<rendering::TerrainAtlas>>,
     tilemap_query: Query<
         (Entity, &TilemapSize, &TileStorage),
-        (With<MapTilemap>, Without<TilemapTexture>),
+        (
+            With<MapTilemap>,
+            Without<TilemapTexture>,
+            Without<PendingTileSpawn>,
+        ),
     >,
     tiles_query: Query<(Entity, &TerrainType), Without<TileTextureIndex>>,
 ) {
@@ -221,7 +205,7 @@ pub fn setup_tilemap_rendering(
         y: TILE_SIZE,
     };
     let grid_size = crate::constants::get_hex_grid_size();
-    let map_type = TilemapType::Hexagon(HexCoordSystem::Row);
+    let map_type = crate::constants::hex_map_type();
 
     commands.entity(tilemap_entity).insert((TilemapBundle {
         grid_size,
@@ -270,6 +254,7 @@ pub fn setup_tilemap_input(
             let tile_pos = TilePos { x, y };
             if let Some(tile_entity) = (*tile_storage).get(&tile_pos) {
                 commands.entity(tile_entity).observe(handle_tile_click);
+                commands.entity(tile_entity).observe(editor::paint_on_click);
                 commands.entity(tile_entity).observe(handle_tile_hover);
                 commands.entity(tile_entity).observe(handle_tile_out);
             }
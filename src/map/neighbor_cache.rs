@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::{TilePos, TilemapSize};
+
+use crate::constants::MAP_SIZE;
+use crate::map::tile_pos::{HexExt, TilePosExt};
+
+/// Precomputed in-bounds hex neighbors for every tile of the map, so BFS and
+/// AI scoring loops don't redo hex math and bounds checks on every visit.
+/// Neighbors are stored in `Hex::all_neighbors` order.
+#[derive(Resource, Debug, Clone)]
+pub struct NeighborCache {
+    size: TilemapSize,
+    /// Start of each tile's neighbors in `neighbors` (one extra entry at the end)
+    offsets: Vec<u32>,
+    neighbors: Vec<TilePos>,
+}
+
+impl Default for NeighborCache {
+    fn default() -> Self {
+        Self::new(TilemapSize {
+            x: MAP_SIZE,
+            y: MAP_SIZE,
+        })
+    }
+}
+
+impl NeighborCache {
+    pub fn new(size: TilemapSize) -> Self {
+        let tile_count = size.count();
+        let mut offsets = Vec::with_capacity(tile_count + 1);
+        let mut neighbors = Vec::with_capacity(tile_count * 6);
+
+        for y in 0..size.y {
+            for x in 0..size.x {
+                offsets.push(neighbors.len() as u32);
+                neighbors.extend(
+                    TilePos { x, y }
+                        .to_hex()
+                        .all_neighbors()
+                        .into_iter()
+                        .filter_map(|hex| hex.to_tile_pos())
+                        .filter(|pos| pos.within_map_bounds(&size)),
+                );
+            }
+        }
+        offsets.push(neighbors.len() as u32);
+
+        Self {
+            size,
+            offsets,
+            neighbors,
+        }
+    }
+
+    pub fn size(&self) -> TilemapSize {
+        self.size
+    }
+
+    /// In-bounds neighbors of `pos` (empty if `pos` itself is off the map)
+    pub fn neighbors(&self, pos: TilePos) -> &[TilePos] {
+        if !pos.within_map_bounds(&self.size) {
+            return &[];
+        }
+        let index = (pos.y * self.size.x + pos.x) as usize;
+        let start = self.offsets[index] as usize;
+        let end = self.offsets[index + 1] as usize;
+        &self.neighbors[start..end]
+    }
+}
+
+/// Rebuild the cache when a map of a different size is created or loaded
+pub fn resize_neighbor_cache(
+    mut cache: ResMut<NeighborCache>,
+    maps: Query<&TilemapSize, Added<TilemapSize>>,
+) {
+    for size in maps.iter() {
+        if cache.size() != *size {
+            *cache = NeighborCache::new(*size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs_tilemap::prelude::{TilePos, TilemapSize};
+
+    use crate::map::neighbor_cache::NeighborCache;
+    use crate::map::tile_pos::{HexExt, TilePosExt};
+
+    #[test]
+    fn cached_neighbors_match_on_the_fly_hex_neighbors() {
+        let size = TilemapSize { x: 20, y: 15 };
+        let cache = NeighborCache::new(size);
+
+        let samples = [
+            TilePos { x: 0, y: 0 },
+            TilePos { x: 19, y: 0 },
+            TilePos { x: 0, y: 14 },
+            TilePos { x: 19, y: 14 },
+            TilePos { x: 7, y: 3 },
+            TilePos { x: 10, y: 14 },
+            TilePos { x: 19, y: 8 },
+            TilePos { x: 12, y: 11 },
+        ];
+
+        for pos in samples {
+            let expected: Vec<TilePos> = pos
+                .to_hex()
+                .all_neighbors()
+                .into_iter()
+                .filter_map(|hex| hex.to_tile_pos())
+                .filter(|n| n.x < size.x && n.y < size.y)
+                .collect();
+            assert_eq!(cache.neighbors(pos), expected.as_slice(), "at {:?}", pos);
+        }
+
+        assert_eq!(cache.neighbors(TilePos { x: 7, y: 3 }).len(), 6);
+        assert!(cache.neighbors(TilePos { x: 20, y: 0 }).is_empty());
+    }
+}
@@ -4,6 +4,8 @@ use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::TilePos;
 use moonshine_save::prelude::Save;
 
+use crate::economy::CityStockpile;
+
 /// Unique identifier for a province
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 #[reflect(Component)]
@@ -23,7 +25,7 @@ pub struct Province {
 /// Marker component for the city within a province
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 #[reflect(Component, MapEntities)]
-#[require(Save)]
+#[require(Save, CityStockpile)]
 pub struct City {
     pub province: ProvinceId,
     pub province_entity: Entity,
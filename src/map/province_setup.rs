@@ -1,29 +1,69 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::{TilePos, TileStorage, TilemapSize};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::collections::{HashMap, HashSet};
 
 use crate::ai::{AiControlledCivilian, AiNation};
+use crate::civilians::types::NextCivilianId;
 use crate::civilians::{Civilian, CivilianKind};
-use crate::constants::MAP_SIZE;
+use crate::constants::{MAP_SIZE, MAX_NATIONS};
 use crate::economy::Rails;
 use crate::economy::{
-    Allocations, Capital, Good, Nation, NationColor, OwnedBy, PlayerNation, RecruitmentCapacity,
-    RecruitmentQueue, ReservationSystem, Stockpile, Technologies, TrainingQueue, Treasury,
+    Allocations, Capital, Good, Nation, NationColor, NationColorSlot, NationId, OwnedBy,
+    PlayerNation, RecruitmentCapacity, RecruitmentQueue, ResearchProgress, ReservationSystem,
+    Stockpile, StockpileReserves, Technologies, Technology, TrainingQueue, Treasury, UpkeepPolicy,
     Workforce,
-    production::{Buildings, ProductionSettings},
+    production::{Buildings, ProductionResult, ProductionSettings},
 };
 use crate::map::province::{City, Province, ProvinceId};
 use crate::map::province_gen::generate_provinces;
-use crate::map::rendering::{BorderLine, MapVisualFor};
+use crate::map::rendering::{BorderLine, ColorPalette, MapVisualFor};
 use crate::map::tile_pos::{HexExt, TilePosExt}; // Trait methods: to_hex(), distance_to()
 use crate::map::tiles::TerrainType;
 use crate::resources::{DevelopmentLevel, TileResource};
+use crate::turn_system::HumanNations;
 use crate::ui::components::MapTilemap;
 
 /// Resource to enable map pruning for tests
 #[derive(Resource, Default)]
 pub struct TestMapConfig;
 
+/// Per-nation starting setup, keyed by nation color slot (0 is the player)
+#[derive(Resource, Default, Debug, Clone)]
+pub struct StartingConditions {
+    pub technologies: HashMap<usize, Vec<Technology>>,
+    /// Number of hotseat human players, taking the first slots (at least one)
+    pub human_players: usize,
+    /// Number of nations to create; derived from the province count when unset
+    pub nation_count: Option<usize>,
+    /// Seed for placing the first capital; the lowest province id is used when unset.
+    /// Assignment never depends on province spawn order, so a fixed seed always
+    /// reproduces the same map split.
+    pub assignment_seed: Option<u64>,
+}
+
+impl StartingConditions {
+    /// Grant `tech` to the nation in `slot` when it is created
+    pub fn grant_technology(&mut self, slot: usize, tech: Technology) -> &mut Self {
+        self.technologies.entry(slot).or_default().push(tech);
+        self
+    }
+
+    pub fn is_human(&self, slot: usize) -> bool {
+        slot < self.human_players.max(1)
+    }
+
+    /// Technologies the nation in `slot` starts the game with
+    pub fn technologies_for(&self, slot: usize) -> Technologies {
+        let mut technologies = Technologies::new();
+        for tech in self.technologies.get(&slot).into_iter().flatten() {
+            technologies.unlock(*tech);
+        }
+        technologies
+    }
+}
+
 /// Generate provinces after the tilemap is created
 pub fn generate_provinces_system(
     mut commands: Commands,
@@ -48,17 +88,24 @@ pub fn generate_provinces_system(
 pub fn assign_provinces_to_countries(
     mut commands: Commands,
     mut provinces: Query<(Entity, &mut Province)>,
-    mut next_civilian_id: ResMut<crate::civilians::types::NextCivilianId>,
+    mut next_civilian_id: ResMut<NextCivilianId>,
+    palette: Res<ColorPalette>,
+    starting_conditions: Option<Res<StartingConditions>>,
+    upkeep: Res<UpkeepPolicy>,
 ) {
+    let starting_conditions = starting_conditions.as_deref().cloned().unwrap_or_default();
+
     // Check if already assigned (provinces have owners)
     if provinces.iter().any(|(_, p)| p.owner.is_some()) {
         return;
     }
 
-    let province_list: Vec<(Entity, ProvinceId, TilePos)> = provinces
+    let mut province_list: Vec<(Entity, ProvinceId, TilePos)> = provinces
         .iter()
         .map(|(e, p)| (e, p.id, p.city_tile))
         .collect();
+    // Query order follows spawn order; sort so assignment only depends on the provinces
+    province_list.sort_by_key(|(_, id, _)| id.0);
 
     if province_list.is_empty() {
         return;
@@ -69,89 +116,54 @@ pub fn assign_provinces_to_countries(
         province_list.len()
     );
 
-    // Define number of countries (for now, let's say 3-5 based on province count)
-    let num_countries = (province_list.len() / 8).clamp(3, 5);
+    // Use the requested count, or 3-5 based on province count; every nation needs a capital
+    let num_countries = match starting_conditions.nation_count {
+        Some(count) => count.clamp(1, MAX_NATIONS),
+        None => (province_list.len() / 8).clamp(3, MAX_NATIONS),
+    }
+    .min(province_list.len());
 
-    // Define distinct nation colors
-    let nation_colors = [
-        Color::srgb(0.2, 0.4, 0.8), // Blue
-        Color::srgb(0.8, 0.2, 0.2), // Red
-        Color::srgb(0.2, 0.7, 0.3), // Green
-        Color::srgb(0.9, 0.7, 0.1), // Yellow
-        Color::srgb(0.7, 0.2, 0.7), // Purple
-    ];
+    // Nation names follow the standard palette slots; actual colors come from `ColorPalette`
+    let color_names = ["Blue", "Red", "Green", "Yellow", "Purple"];
 
     // Create countries
     let mut country_entities: Vec<Entity> = Vec::new();
     let mut capitals = Vec::new();
 
     for i in 0..num_countries {
-        let color = nation_colors[i % nation_colors.len()];
-        let color_name = match i % nation_colors.len() {
-            0 => "Blue",
-            1 => "Red",
-            2 => "Green",
-            3 => "Yellow",
-            4 => "Purple",
-            _ => "Unknown",
-        };
+        let color = palette.nation_color(i);
+        let color_name = color_names[i % color_names.len()];
 
-        let name = if i == 0 {
+        let is_human = starting_conditions.is_human(i);
+        let name = if is_human {
             format!("Player ({})", color_name)
         } else {
             format!("Nation {}", color_name)
         };
 
-        let stockpile = baseline_stockpile();
-
-        let country_builder = commands.spawn((
-            Nation,
-            Name::new(name),
-            NationColor(color),
-            Treasury::new(10_000),
-            stockpile,
-            Technologies::default(),
-            Allocations::default(),       // Simplified allocation tracking
-            ReservationSystem::default(), // Reservation tracking
-        ));
-
-        let country_entity = country_builder.id();
-
-        if i > 0 {
-            commands.entity(country_entity).insert(AiNation);
-        }
+        let country_entity = spawn_nation(
+            &mut commands,
+            i,
+            name,
+            color,
+            is_human,
+            starting_conditions.technologies_for(i),
+            upkeep.starting_treasury,
+        );
 
-        // Give every nation a basic industrial base so AI economies can function
-        let mut workforce = Workforce::new();
-        let starting_workers = if i == 0 { 5 } else { 3 };
-        workforce.add_untrained(starting_workers);
-        workforce.update_labor_pool();
-
-        commands.entity(country_entity).insert((
-            Buildings::with_all_initial(),
-            ProductionSettings::default(),
-            workforce,
-            RecruitmentCapacity::default(),
-            RecruitmentQueue::default(),
-            TrainingQueue::default(),
-        ));
-
-        // Note: Capitol and TradeSchool don't need separate Building entities
-        // They're always available and use the nation's Stockpile/Workforce directly
         country_entities.push(country_entity);
         info!("Created Nation {} with color", i + 1);
     }
 
-    // Set player nation reference
-    if let Some(&player_entity) = country_entities.first() {
-        commands.queue(move |world: &mut World| {
-            if let Some(player_nation) = PlayerNation::from_entity(world, player_entity) {
-                world.insert_resource(player_nation);
-            } else {
-                warn!("Failed to initialize player nation from entity {player_entity:?}");
-            }
-        });
-    }
+    let human_entities: Vec<Entity> = country_entities
+        .iter()
+        .enumerate()
+        .filter(|(slot, _)| starting_conditions.is_human(*slot))
+        .map(|(_, entity)| *entity)
+        .collect();
+
+    // Set player nation reference; the first human moves first
+    insert_human_nations(&mut commands, human_entities.clone());
 
     // Build adjacency map for provinces
     let adjacency_map = build_province_adjacency(&provinces);
@@ -166,16 +178,28 @@ pub fn assign_provinces_to_countries(
         .map(|&(entity, id, pos)| (id, (entity, pos)))
         .collect();
 
-    for &(_province_entity, province_id, _city_tile) in &province_list {
+    // Each nation grows from a capital province spread away from the others first
+    let first_capital = starting_conditions.assignment_seed.map_or(0, |seed| {
+        StdRng::seed_from_u64(seed).random_range(0..province_list.len())
+    });
+    let seeds = spaced_capital_seeds(&province_list, first_capital, num_countries);
+    let mut pending_seeds: HashSet<ProvinceId> = seeds.iter().copied().collect();
+    let growth_order = seeds
+        .into_iter()
+        .chain(province_list.iter().map(|&(_, id, _)| id));
+
+    for province_id in growth_order {
         if assigned.contains(&province_id) {
             continue;
         }
+        pending_seeds.remove(&province_id);
 
-        // Flood-fill to get connected provinces for this country
+        // Flood-fill to get connected provinces for this country, leaving other capitals alone
+        let blocked: HashSet<ProvinceId> = assigned.union(&pending_seeds).copied().collect();
         let connected_group = get_connected_provinces(
             province_id,
             &adjacency_map,
-            &assigned,
+            &blocked,
             province_list.len() / num_countries,
         );
 
@@ -220,14 +244,101 @@ pub fn assign_provinces_to_countries(
         }
     }
 
-    let player_entity = country_entities.first().copied();
+    spawn_starter_civilians(
+        &mut commands,
+        &mut next_civilian_id,
+        &capitals,
+        &human_entities,
+    );
+
+    info!("Province assignment complete!");
+}
 
-    // Spawn starter civilian roster for the player clustered around the capital
-    if let Some(player_entity) = player_entity
-        && let Some(player_capital) = capitals
+/// Spawn a nation with the standard starting economy
+pub(crate) fn spawn_nation(
+    commands: &mut Commands,
+    slot: usize,
+    name: String,
+    color: Color,
+    is_human: bool,
+    mut technologies: Technologies,
+    starting_treasury: i64,
+) -> Entity {
+    // Every nation can develop tiles one level past the base cap from the start
+    technologies.unlock(Technology::ImprovedTools);
+
+    let country_entity = commands
+        .spawn((
+            Nation,
+            Name::new(name),
+            NationColor(color),
+            NationColorSlot(slot),
+            NationId(slot as u32),
+            Treasury::from(starting_treasury),
+            baseline_stockpile(),
+            technologies,
+            ResearchProgress::default(),
+            Allocations::default(),       // Simplified allocation tracking
+            ReservationSystem::default(), // Reservation tracking
+            StockpileReserves::default(),
+        ))
+        .id();
+
+    if !is_human {
+        commands.entity(country_entity).insert(AiNation);
+    }
+
+    // Give every nation a basic industrial base so AI economies can function
+    let mut workforce = Workforce::new();
+    let starting_workers = if is_human { 5 } else { 3 };
+    workforce.add_untrained(starting_workers);
+    workforce.update_labor_pool();
+
+    commands.entity(country_entity).insert((
+        Buildings::with_all_initial(),
+        ProductionSettings::default(),
+        ProductionResult::default(),
+        workforce,
+        RecruitmentCapacity::default(),
+        RecruitmentQueue::default(),
+        TrainingQueue::default(),
+    ));
+
+    // Note: the Capitol doesn't need a separate Building entity; it is always available
+    // and uses the nation's Stockpile/Workforce directly. The Trade School lives in
+    // Buildings because its capacity caps training.
+    country_entity
+}
+
+/// Point `PlayerNation` at the first human and record the hotseat order
+pub(crate) fn insert_human_nations(commands: &mut Commands, humans: Vec<Entity>) {
+    commands.queue(move |world: &mut World| {
+        let instances: Vec<_> = humans
             .iter()
-            .find(|(entity, _)| *entity == player_entity)
-            .map(|(_, pos)| *pos)
+            .filter_map(|entity| PlayerNation::from_entity(world, *entity))
+            .map(|player| player.instance())
+            .collect();
+        let Some(&first) = instances.first() else {
+            warn!("Failed to initialize player nation from entities {humans:?}");
+            return;
+        };
+        world.insert_resource(PlayerNation::new(first));
+        world.insert_resource(HumanNations::new(instances));
+    });
+}
+
+/// Spawn the starter civilian roster around every capital
+pub(crate) fn spawn_starter_civilians(
+    commands: &mut Commands,
+    next_civilian_id: &mut NextCivilianId,
+    capitals: &[(Entity, TilePos)],
+    human_entities: &[Entity],
+) {
+    // Spawn starter civilian roster for each human player clustered around the capital
+    for (player_entity, player_capital) in capitals
+        .iter()
+        .copied()
+        .filter(|(entity, _)| human_entities.contains(entity))
     {
         let spawn_positions = gather_spawn_positions(player_capital, 6);
         let starter_units = [
@@ -249,6 +360,7 @@ pub fn assign_provinces_to_countries(
                     owner: player_entity,
                     civilian_id,
                     has_moved: false,
+                    experience: 0,
                 },
                 OwnedBy(player_entity),
                 Name::new(name.clone()),
@@ -268,7 +380,7 @@ pub fn assign_provinces_to_countries(
     for (nation_entity, capital_pos) in capitals
         .iter()
         .copied()
-        .filter(|(entity, _)| Some(*entity) != player_entity)
+        .filter(|(entity, _)| !human_entities.contains(entity))
     {
         let spawn_positions = gather_spawn_positions(capital_pos, ai_starter_units.len());
         for (kind, pos) in ai_starter_units.iter().zip(spawn_positions.iter()) {
@@ -281,6 +393,7 @@ pub fn assign_provinces_to_countries(
                     owner: nation_entity,
                     civilian_id,
                     has_moved: false,
+                    experience: 0,
                 },
                 AiControlledCivilian,
                 OwnedBy(nation_entity),
@@ -292,15 +405,13 @@ pub fn assign_provinces_to_countries(
             );
         }
     }
-
-    info!("Province assignment complete!");
 }
 
 /// Prune the map to only include the Red nation's territory for tests
 pub fn prune_to_test_map(
     mut commands: Commands,
     test_config: Option<Res<TestMapConfig>>,
-    nations: Query<(Entity, &NationColor)>,
+    nations: Query<(Entity, &NationColorSlot)>,
     owned_entities: Query<(Entity, &OwnedBy)>,
     provinces: Query<(Entity, &Province)>,
     tiles: Query<(Entity, &TilePos)>,
@@ -325,20 +436,11 @@ pub fn prune_to_test_map(
 
     info!("Pruning map to Red nation territory using relationships...");
 
-    // 1. Find the Red nation
-    let red_color = Color::srgb(0.8, 0.2, 0.2);
-    let mut red_nation_entity = None;
-
-    for (entity, color) in nations.iter() {
-        let linear = color.0.to_linear();
-        if (linear.red - red_color.to_linear().red).abs() < 0.01
-            && (linear.green - red_color.to_linear().green).abs() < 0.01
-            && (linear.blue - red_color.to_linear().blue).abs() < 0.01
-        {
-            red_nation_entity = Some(entity);
-            break;
-        }
-    }
+    // 1. Find the Red nation (slot 1 regardless of the active palette)
+    let red_nation_entity = nations
+        .iter()
+        .find(|(_, slot)| slot.0 == 1)
+        .map(|(entity, _)| entity);
 
     let Some(red_nation) = red_nation_entity else {
         warn!("Red nation not found for pruning!");
@@ -428,7 +530,7 @@ pub fn prune_to_test_map(
 }
 
 /// Assign a province to a country
-fn assign_province_to_country(
+pub(crate) fn assign_province_to_country(
     commands: &mut Commands,
     provinces: &mut Query<(Entity, &mut Province)>,
     province_entity: Entity,
@@ -601,13 +703,51 @@ pub fn calculate_adjacency(
         }
     }
 
-    // Convert to Vec for easier iteration
+    // Convert to Vec for easier iteration, sorted so flood fills are reproducible
     adjacency
         .into_iter()
-        .map(|(k, v)| (k, v.into_iter().collect()))
+        .map(|(k, v)| {
+            let mut neighbors: Vec<ProvinceId> = v.into_iter().collect();
+            neighbors.sort_by_key(|id| id.0);
+            (k, neighbors)
+        })
         .collect()
 }
 
+/// Pick `count` provinces whose city tiles are spread out, starting from the province
+/// at index `first` and repeatedly taking the province farthest from every one already picked
+fn spaced_capital_seeds(
+    provinces: &[(Entity, ProvinceId, TilePos)],
+    first: usize,
+    count: usize,
+) -> Vec<ProvinceId> {
+    let Some(&(_, first_id, first_tile)) = provinces.get(first) else {
+        return Vec::new();
+    };
+    let mut seeds = vec![(first_id, first_tile)];
+
+    while seeds.len() < count.min(provinces.len()) {
+        let farthest = provinces
+            .iter()
+            .filter(|(_, id, _)| seeds.iter().all(|(seed, _)| seed != id))
+            .max_by_key(|(_, id, tile)| {
+                let nearest = seeds
+                    .iter()
+                    .map(|(_, seed_tile)| tile.to_hex().distance_to(seed_tile.to_hex()))
+                    .min()
+                    .unwrap_or(0);
+                // Ties go to the lowest id so the layout is deterministic
+                (nearest, std::cmp::Reverse(id.0))
+            });
+        let Some(&(_, id, tile)) = farthest else {
+            break;
+        };
+        seeds.push((id, tile));
+    }
+
+    seeds.into_iter().map(|(id, _)| id).collect()
+}
+
 /// Get connected provinces using flood-fill
 fn get_connected_provinces(
     start: ProvinceId,
@@ -658,8 +798,17 @@ mod tests {
 
     use crate::ai::{AiControlledCivilian, AiNation};
     use crate::civilians::Civilian;
+    use crate::economy::transport::can_build_rail_on_terrain;
+    use crate::economy::{
+        Capital, NationColorSlot, PlayerNation, Technologies, Technology, UpkeepPolicy,
+    };
     use crate::map::province::{Province, ProvinceId};
-    use crate::map::province_setup::{assign_provinces_to_countries, boost_capital_food_tiles};
+    use crate::map::province_setup::{
+        StartingConditions, assign_provinces_to_countries, boost_capital_food_tiles,
+    };
+    use crate::map::rendering::ColorPalette;
+    use crate::map::tile_pos::TilePosExt;
+    use crate::map::tiles::TerrainType;
     use crate::resources::{DevelopmentLevel, ResourceType, TileResource};
 
     #[test]
@@ -709,6 +858,8 @@ mod tests {
         let mut world = World::new();
         // Removed ProvincesGenerated resource insertion
         world.insert_resource(crate::civilians::types::NextCivilianId::default());
+        world.init_resource::<UpkeepPolicy>();
+        world.init_resource::<ColorPalette>();
 
         let province_positions = [
             TilePos { x: 0, y: 0 },
@@ -757,4 +908,139 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn starting_conditions_grant_hill_rail_from_turn_one() {
+        let mut world = World::new();
+        world.insert_resource(crate::civilians::types::NextCivilianId::default());
+        world.init_resource::<UpkeepPolicy>();
+        world.init_resource::<ColorPalette>();
+        let mut conditions = StartingConditions::default();
+        conditions.grant_technology(1, Technology::HillGrading);
+        world.insert_resource(conditions);
+
+        for index in 0..3 {
+            let position = TilePos { x: index, y: 0 };
+            world.spawn(Province::new(ProvinceId(index), vec![position], position));
+        }
+
+        let _ = world.run_system_once(assign_provinces_to_countries);
+        world.flush();
+
+        let mut nations = world.query::<(&NationColorSlot, &Technologies)>();
+        let mut technologies_for = |world: &World, slot: usize| {
+            nations
+                .iter(world)
+                .find(|(color_slot, _)| color_slot.0 == slot)
+                .map(|(_, technologies)| technologies.clone())
+                .expect("nation should exist for slot")
+        };
+
+        let advanced = technologies_for(&world, 1);
+        let (buildable, _) = can_build_rail_on_terrain(&TerrainType::Hills, &advanced);
+        assert!(buildable, "granted nation can grade hills at game start");
+
+        let player = technologies_for(&world, 0);
+        let (buildable, reason) = can_build_rail_on_terrain(&TerrainType::Hills, &player);
+        assert!(!buildable, "other nations still need the technology");
+        assert_eq!(reason, Some("Hill Grading technology required"));
+    }
+
+    #[test]
+    fn same_seed_assigns_same_provinces_regardless_of_spawn_order() {
+        let assign = |spawn_order: &[u32]| -> Vec<(u32, usize)> {
+            let mut world = World::new();
+            world.insert_resource(crate::civilians::types::NextCivilianId::default());
+            world.init_resource::<UpkeepPolicy>();
+            world.init_resource::<ColorPalette>();
+            world.insert_resource(StartingConditions {
+                nation_count: Some(3),
+                assignment_seed: Some(42),
+                ..Default::default()
+            });
+            for &index in spawn_order {
+                let position = TilePos {
+                    x: (index % 4) * 2,
+                    y: (index / 4) * 2,
+                };
+                world.spawn(Province::new(ProvinceId(index), vec![position], position));
+            }
+
+            let _ = world.run_system_once(assign_provinces_to_countries);
+            world.flush();
+
+            let mut provinces = world.query::<&Province>();
+            let mut assignment: Vec<(u32, usize)> = provinces
+                .iter(&world)
+                .map(|province| {
+                    let owner = province.owner.expect("every province is assigned");
+                    let slot = world
+                        .get::<NationColorSlot>(owner)
+                        .expect("owner is a nation");
+                    (province.id.0, slot.0)
+                })
+                .collect();
+            assignment.sort();
+            assignment
+        };
+
+        let forward: Vec<u32> = (0..12).collect();
+        let shuffled = [7, 2, 11, 0, 5, 9, 3, 10, 1, 8, 6, 4];
+        let first = assign(&forward);
+        assert_eq!(first.len(), 12);
+        assert_eq!(first, assign(&forward));
+        assert_eq!(first, assign(&shuffled));
+    }
+
+    #[test]
+    fn requested_nation_count_spawns_spread_out_capitals_and_one_player() {
+        let mut world = World::new();
+        world.insert_resource(crate::civilians::types::NextCivilianId::default());
+        world.init_resource::<UpkeepPolicy>();
+        world.init_resource::<ColorPalette>();
+        world.insert_resource(StartingConditions {
+            nation_count: Some(4),
+            ..Default::default()
+        });
+
+        for index in 0..12u32 {
+            let position = TilePos {
+                x: (index % 4) * 3,
+                y: (index / 4) * 3,
+            };
+            world.spawn(Province::new(ProvinceId(index), vec![position], position));
+        }
+
+        let _ = world.run_system_once(assign_provinces_to_countries);
+        world.flush();
+
+        let mut nations = world.query::<(Entity, &Capital, Has<AiNation>)>();
+        let nations: Vec<(Entity, TilePos, bool)> = nations
+            .iter(&world)
+            .map(|(entity, capital, is_ai)| (entity, capital.0, is_ai))
+            .collect();
+        assert_eq!(nations.len(), 4);
+
+        let mut capitals: Vec<TilePos> = nations.iter().map(|(_, capital, _)| *capital).collect();
+        capitals.sort_by_key(|pos| (pos.x, pos.y));
+        capitals.dedup();
+        assert_eq!(capitals.len(), 4, "capitals must not overlap");
+        // Farthest-point spacing keeps capitals at least two grid steps apart
+        for (i, a) in capitals.iter().enumerate() {
+            for b in &capitals[i + 1..] {
+                assert!(
+                    a.to_hex().distance_to(b.to_hex()) >= 6,
+                    "capitals {a:?} and {b:?} are clustered"
+                );
+            }
+        }
+
+        let humans: Vec<Entity> = nations
+            .iter()
+            .filter(|(_, _, is_ai)| !is_ai)
+            .map(|(entity, _, _)| *entity)
+            .collect();
+        assert_eq!(humans.len(), 1);
+        assert_eq!(world.resource::<PlayerNation>().entity(), humans[0]);
+    }
 }
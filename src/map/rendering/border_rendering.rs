@@ -19,11 +19,13 @@ pub fn render_borders(
     provinces: Query<&Province>,
     provinces_changed: Query<Entity, Changed<Province>>,
     nations: Query<&NationColor>,
+    recolored_nations: Query<(), Changed<NationColor>>,
     existing_borders: Query<Entity, With<BorderLine>>,
     mut gizmos: Gizmos,
 ) {
-    // Only redraw if provinces have changed (ownership changes, etc.)
-    if provinces_changed.is_empty() && !existing_borders.is_empty() {
+    // Only redraw if provinces or nation colors have changed (ownership changes, palette swaps, etc.)
+    if provinces_changed.is_empty() && recolored_nations.is_empty() && !existing_borders.is_empty()
+    {
         return;
     }
 
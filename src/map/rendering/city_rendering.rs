@@ -2,11 +2,19 @@ use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::TilePos;
 
 use crate::assets::{capital_asset_path, town_asset_path};
-use crate::map::province::City;
+use crate::economy::NationColor;
+use crate::map::province::{City, Province};
 use crate::map::rendering::{MapVisual, MapVisualFor};
 use crate::map::tile_pos::TilePosExt;
 
 const CITY_SIZE: f32 = 64.0; // Match tile size
+const CITY_BANNER_SIZE: f32 = 12.0;
+const CITY_BANNER_OFFSET: Vec2 = Vec2::new(22.0, 22.0); // Top-right corner of the city sprite
+
+/// Small nation-colored banner drawn on a city sprite
+/// Points to the City entity whose owner determines the color
+#[derive(Component)]
+pub struct CityBanner(pub Entity);
 
 /// Create visual sprites for cities
 pub fn render_city_visuals(
@@ -42,6 +50,15 @@ pub fn render_city_visuals(
             },
             Transform::from_translation(pos.extend(2.0)), // Below civilians (z=3), above terrain
             MapVisualFor(city_entity),                    // Relationship: sprite -> city
+            children![(
+                Sprite {
+                    color: Color::WHITE, // Owner color applied by update_city_banner_colors
+                    custom_size: Some(Vec2::splat(CITY_BANNER_SIZE)),
+                    ..default()
+                },
+                Transform::from_translation(CITY_BANNER_OFFSET.extend(0.1)),
+                CityBanner(city_entity),
+            )],
         ));
     }
 }
@@ -62,3 +79,26 @@ pub fn update_city_visual_positions(
         }
     }
 }
+
+/// Keep city banners in sync with the owning nation's color (ownership changes, palette swaps)
+pub fn update_city_banner_colors(
+    mut banners: Query<(&CityBanner, &mut Sprite)>,
+    cities: Query<&City>,
+    provinces: Query<&Province>,
+    nations: Query<&NationColor>,
+) {
+    for (banner, mut sprite) in banners.iter_mut() {
+        let color = cities
+            .get(banner.0)
+            .ok()
+            .and_then(|city| provinces.get(city.province_entity).ok())
+            .and_then(|province| province.owner)
+            .and_then(|owner| nations.get(owner).ok())
+            .map(|nation_color| nation_color.0)
+            .unwrap_or(Color::WHITE);
+
+        if sprite.color != color {
+            sprite.color = color;
+        }
+    }
+}
@@ -65,6 +65,7 @@ pub fn render_improvement_markers(
             DevelopmentLevel::Lv1 => Color::srgb(0.6, 0.8, 0.4), // Light green
             DevelopmentLevel::Lv2 => Color::srgb(0.4, 0.9, 0.2), // Bright green
             DevelopmentLevel::Lv3 => Color::srgb(1.0, 0.85, 0.0), // Gold
+            DevelopmentLevel::Lv4 => Color::srgb(1.0, 0.55, 0.1), // Amber
             _ => Color::WHITE,
         };
 
@@ -101,6 +102,7 @@ pub fn update_improvement_markers(
             DevelopmentLevel::Lv1 => Color::srgb(0.6, 0.8, 0.4),
             DevelopmentLevel::Lv2 => Color::srgb(0.4, 0.9, 0.2),
             DevelopmentLevel::Lv3 => Color::srgb(1.0, 0.85, 0.0),
+            DevelopmentLevel::Lv4 => Color::srgb(1.0, 0.55, 0.1),
             _ => Color::WHITE,
         };
 
@@ -3,6 +3,7 @@ pub mod border_rendering;
 pub mod city_rendering;
 pub mod improvement_rendering;
 pub mod map_visual;
+pub mod palette;
 pub mod prospecting_markers;
 pub mod terrain_atlas;
 pub mod transport_debug;
@@ -17,6 +18,7 @@ pub use border_rendering::*;
 pub use city_rendering::*;
 pub use improvement_rendering::*;
 pub use map_visual::*;
+pub use palette::*;
 pub use prospecting_markers::*;
 pub use terrain_atlas::*;
 pub use transport_debug::*;
@@ -34,7 +36,8 @@ impl Plugin for MapRenderingPlugin {
         app.init_resource::<improvement_rendering::ConnectivityOverlaySettings>()
             .init_resource::<transport_debug::TransportDebugSettings>()
             .init_resource::<transport_debug::TransportDebugFont>()
-            .init_resource::<transport_rendering::HoveredTile>();
+            .init_resource::<transport_rendering::HoveredTile>()
+            .init_resource::<palette::ColorPalette>();
 
         // Terrain atlas loading
         app.add_systems(Startup, terrain_atlas::start_terrain_atlas_loading)
@@ -46,6 +49,12 @@ impl Plugin for MapRenderingPlugin {
             crate::map::setup_tilemap_rendering.run_if(in_state(AppState::InGame)),
         );
 
+        // Palette changes must reach nations in every game mode
+        app.add_systems(
+            Update,
+            palette::apply_color_palette.run_if(in_state(AppState::InGame)),
+        );
+
         // Core map rendering systems
         app.add_systems(
             Update,
@@ -53,6 +62,7 @@ impl Plugin for MapRenderingPlugin {
                 border_rendering::render_borders,
                 city_rendering::render_city_visuals,
                 city_rendering::update_city_visual_positions,
+                city_rendering::update_city_banner_colors,
                 improvement_rendering::render_improvement_markers,
                 improvement_rendering::update_improvement_markers,
                 improvement_rendering::cleanup_removed_improvement_markers,
@@ -60,14 +70,21 @@ impl Plugin for MapRenderingPlugin {
                 improvement_rendering::update_connectivity_overlay,
                 prospecting_markers::render_prospected_empty_markers,
                 prospecting_markers::render_prospected_mineral_markers,
-                transport_rendering::render_rails,
+                (
+                    transport_rendering::render_rails,
+                    transport_rendering::render_rail_construction,
+                ),
                 transport_rendering::update_depot_visuals,
                 transport_rendering::update_port_visuals,
                 transport_rendering::render_shadow_rail,
                 transport_debug::toggle_transport_debug,
                 transport_debug::render_transport_debug,
-                crate::civilians::rendering::render_civilian_visuals,
-                crate::civilians::rendering::update_civilian_visual_colors,
+                palette::toggle_color_palette,
+                (
+                    crate::civilians::rendering::render_civilian_visuals,
+                    crate::civilians::rendering::update_civilian_visual_colors,
+                    crate::civilians::rendering::update_civilian_job_progress_bars,
+                ),
             )
                 .run_if(in_state(AppState::InGame))
                 .run_if(in_state(GameMode::Map)),
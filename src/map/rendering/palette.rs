@@ -0,0 +1,186 @@
+use bevy::prelude::*;
+
+use crate::economy::{NationColor, NationColorSlot};
+
+/// Nation colors used by the default palette (Blue, Red, Green, Yellow, Purple)
+const STANDARD_NATION_COLORS: [Color; 5] = [
+    Color::srgb(0.2, 0.4, 0.8), // Blue
+    Color::srgb(0.8, 0.2, 0.2), // Red
+    Color::srgb(0.2, 0.7, 0.3), // Green
+    Color::srgb(0.9, 0.7, 0.1), // Yellow
+    Color::srgb(0.7, 0.2, 0.7), // Purple
+];
+
+/// Okabe-Ito colors ordered so that every prefix is as spread out as possible
+/// (each entry is the one farthest in Oklab from all entries before it).
+const COLORBLIND_NATION_COLORS: [Color; 7] = [
+    Color::srgb(0.0, 0.45, 0.70),  // Blue
+    Color::srgb(0.95, 0.90, 0.25), // Yellow
+    Color::srgb(0.80, 0.40, 0.0),  // Vermillion
+    Color::srgb(0.35, 0.70, 0.90), // Sky blue
+    Color::srgb(0.0, 0.60, 0.50),  // Bluish green
+    Color::srgb(0.80, 0.60, 0.70), // Reddish purple
+    Color::srgb(0.90, 0.60, 0.0),  // Orange
+];
+
+/// Color scheme used for nation borders, city banners and civilian tints.
+/// Switching the palette recolors every nation already on the map.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Resource)]
+pub enum ColorPalette {
+    #[default]
+    Standard,
+    /// Okabe-Ito based colors distinguishable with common color vision deficiencies
+    Colorblind,
+}
+
+impl ColorPalette {
+    /// All nation colors in slot order
+    pub fn nation_colors(&self) -> &'static [Color] {
+        match self {
+            ColorPalette::Standard => &STANDARD_NATION_COLORS,
+            ColorPalette::Colorblind => &COLORBLIND_NATION_COLORS,
+        }
+    }
+
+    /// Color for the nation occupying `slot` (wraps around if there are more nations than colors)
+    pub fn nation_color(&self, slot: usize) -> Color {
+        let colors = self.nation_colors();
+        colors[slot % colors.len()]
+    }
+
+    /// Tint applied to the selected civilian
+    pub fn civilian_selected_tint(&self) -> Color {
+        match self {
+            ColorPalette::Standard => Color::srgb(1.0, 0.8, 0.0),
+            ColorPalette::Colorblind => Color::srgb(0.95, 0.90, 0.25),
+        }
+    }
+
+    /// Base tint for civilians busy with a job (scaled by the blink factor)
+    pub fn civilian_working_tint(&self) -> Color {
+        match self {
+            ColorPalette::Standard => Color::srgb(0.3, 1.0, 0.3),
+            ColorPalette::Colorblind => Color::srgb(0.35, 0.70, 0.90),
+        }
+    }
+
+    /// Human readable name for menus
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorPalette::Standard => "Standard",
+            ColorPalette::Colorblind => "Colorblind",
+        }
+    }
+
+    /// The other palette
+    pub fn toggled(&self) -> Self {
+        match self {
+            ColorPalette::Standard => ColorPalette::Colorblind,
+            ColorPalette::Colorblind => ColorPalette::Standard,
+        }
+    }
+}
+
+/// Toggle between palettes with the V key
+pub fn toggle_color_palette(keys: Res<ButtonInput<KeyCode>>, mut palette: ResMut<ColorPalette>) {
+    if keys.just_pressed(KeyCode::KeyV) {
+        *palette = palette.toggled();
+        info!("Color palette: {}", palette.label());
+    }
+}
+
+/// Recolor nations when the palette changes or a nation (re)appears, e.g. after loading a save
+pub fn apply_color_palette(
+    palette: Res<ColorPalette>,
+    mut nations: Query<(Ref<NationColorSlot>, &mut NationColor)>,
+) {
+    for (slot, mut color) in nations.iter_mut() {
+        if !palette.is_changed() && !slot.is_added() {
+            continue;
+        }
+        let target = palette.nation_color(slot.0);
+        if color.0 != target {
+            color.0 = target;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::color::Oklaba;
+    use bevy::prelude::*;
+
+    use crate::constants::MAX_NATIONS;
+    use crate::economy::{NationColor, NationColorSlot};
+    use crate::map::rendering::palette::{ColorPalette, apply_color_palette};
+
+    fn oklab_distance(a: Color, b: Color) -> f32 {
+        let a = Oklaba::from(a);
+        let b = Oklaba::from(b);
+        Vec3::new(a.lightness - b.lightness, a.a - b.a, a.b - b.b).length()
+    }
+
+    fn min_distance_to(color: Color, others: &[Color]) -> f32 {
+        others
+            .iter()
+            .map(|other| oklab_distance(color, *other))
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    #[test]
+    fn colorblind_palette_is_maximally_distinct_for_nation_count() {
+        let colors = ColorPalette::Colorblind.nation_colors();
+        assert!(colors.len() >= MAX_NATIONS);
+
+        // Every slot must be the farthest remaining candidate from the slots before it,
+        // so any nation count gets the most spread-out subset available.
+        for n in 1..MAX_NATIONS {
+            let chosen = &colors[..n];
+            let picked = min_distance_to(colors[n], chosen);
+            for candidate in &colors[n + 1..] {
+                assert!(
+                    picked >= min_distance_to(*candidate, chosen),
+                    "slot {} is not the most distinct remaining color",
+                    n
+                );
+            }
+        }
+
+        for i in 0..MAX_NATIONS {
+            for j in (i + 1)..MAX_NATIONS {
+                assert!(
+                    oklab_distance(colors[i], colors[j]) > 0.15,
+                    "slots {} and {} are too similar",
+                    i,
+                    j
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn switching_palette_recolors_existing_nations() {
+        let mut app = App::new();
+        app.init_resource::<ColorPalette>()
+            .add_systems(Update, apply_color_palette);
+
+        let nation = app
+            .world_mut()
+            .spawn((NationColor(Color::BLACK), NationColorSlot(1)))
+            .id();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<NationColor>(nation).unwrap().0,
+            ColorPalette::Standard.nation_color(1)
+        );
+
+        *app.world_mut().resource_mut::<ColorPalette>() = ColorPalette::Colorblind;
+        app.update();
+        assert_eq!(
+            app.world().get::<NationColor>(nation).unwrap().0,
+            ColorPalette::Colorblind.nation_color(1)
+        );
+    }
+}
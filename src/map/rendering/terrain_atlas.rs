@@ -1,13 +1,16 @@
 //! Runtime terrain atlas builder
 //!
 //! Loads individual terrain BMP files and combines them into a single texture atlas
-//! for use with bevy_ecs_tilemap.
+//! for use with bevy_ecs_tilemap. Compositing runs on the async compute pool so the
+//! main thread never stalls while the atlas is assembled.
 
 use bevy::asset::RenderAssetUsages;
 use bevy::prelude::*;
 use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::tasks::{AsyncComputeTaskPool, Task, TaskPool, block_on, futures_lite::future};
 
-/// Resource that holds the terrain atlas once it's built
+/// Resource that holds the terrain atlas.
+/// Inserted as soon as compositing starts; `ready` flips once the texture exists.
 #[derive(Resource)]
 pub struct TerrainAtlas {
     pub texture: Handle<Image>,
@@ -19,6 +22,8 @@ pub struct TerrainAtlas {
 pub struct TerrainAtlasBuilder {
     pub terrain_handles: Vec<(usize, Handle<Image>)>, // (index, handle)
     pub loaded_count: usize,
+    /// Background compositing job, present while the atlas is being assembled
+    pub task: Option<Task<Vec<u8>>>,
 }
 
 const TILE_SIZE: u32 = 64;
@@ -48,79 +53,101 @@ pub fn start_terrain_atlas_loading(mut commands: Commands, asset_server: Res<Ass
     commands.insert_resource(builder);
 }
 
-/// Check if all terrain tiles are loaded and build the atlas
+/// Copy each tile's RGBA pixels into its slot of the atlas
+fn compose_atlas(tiles: Vec<(usize, Option<Vec<u8>>)>) -> Vec<u8> {
+    let atlas_width = TILE_SIZE * ATLAS_TILES_WIDE;
+    let atlas_height = TILE_SIZE * ATLAS_TILES_HIGH;
+    let mut atlas_data = vec![0u8; (atlas_width * atlas_height * 4) as usize]; // RGBA
+
+    for (index, pixel_data) in tiles {
+        let x_offset = (index % ATLAS_TILES_WIDE as usize) as u32 * TILE_SIZE;
+        let y_offset = (index / ATLAS_TILES_WIDE as usize) as u32 * TILE_SIZE;
+
+        let Some(pixel_data) = pixel_data else {
+            warn!("Tile {} has no pixel data!", index);
+            continue;
+        };
+
+        for y in 0..TILE_SIZE {
+            for x in 0..TILE_SIZE {
+                let src_idx = ((y * TILE_SIZE + x) * 4) as usize;
+                let dst_idx = (((y_offset + y) * atlas_width + (x_offset + x)) * 4) as usize;
+
+                if src_idx + 3 < pixel_data.len() && dst_idx + 3 < atlas_data.len() {
+                    atlas_data[dst_idx..dst_idx + 4]
+                        .copy_from_slice(&pixel_data[src_idx..src_idx + 4]);
+                }
+            }
+        }
+
+        debug!(
+            "Placed terrain tile {} at ({}, {})",
+            index, x_offset, y_offset
+        );
+    }
+
+    atlas_data
+}
+
+/// Once all terrain tiles are loaded, start compositing the atlas in the background
+/// and poll the job each frame until it finishes.
 pub fn build_terrain_atlas_when_ready(
     mut commands: Commands,
     mut images: ResMut<Assets<Image>>,
     builder: Option<ResMut<TerrainAtlasBuilder>>,
-    existing_atlas: Option<Res<TerrainAtlas>>,
+    atlas: Option<ResMut<TerrainAtlas>>,
 ) {
-    // Skip if atlas already exists or builder not initialized
-    if existing_atlas.is_some() || builder.is_none() {
+    let Some(mut builder) = builder else {
         return;
-    }
-
-    let builder = builder.unwrap();
-
-    // Check if all images are loaded
-    let mut all_loaded = true;
-    for (_, handle) in &builder.terrain_handles {
-        if images.get(handle).is_none() {
-            all_loaded = false;
-            break;
+    };
+
+    let Some(mut atlas) = atlas else {
+        // Check if all images are loaded
+        if builder
+            .terrain_handles
+            .iter()
+            .any(|(_, handle)| images.get(handle).is_none())
+        {
+            return;
         }
-    }
 
-    if !all_loaded {
+        info!("All terrain tiles loaded, building atlas in the background...");
+
+        let tiles: Vec<(usize, Option<Vec<u8>>)> = builder
+            .terrain_handles
+            .iter()
+            .map(|(index, handle)| (*index, images.get(handle).and_then(|i| i.data.clone())))
+            .collect();
+        builder.loaded_count = tiles.len();
+        builder.task = Some(
+            AsyncComputeTaskPool::get_or_init(TaskPool::default)
+                .spawn(async move { compose_atlas(tiles) }),
+        );
+
+        commands.insert_resource(TerrainAtlas {
+            texture: Handle::default(),
+            ready: false,
+        });
         return;
-    }
-
-    info!("All terrain tiles loaded, building atlas...");
+    };
 
-    // Create the atlas
-    let atlas_width = TILE_SIZE * ATLAS_TILES_WIDE;
-    let atlas_height = TILE_SIZE * ATLAS_TILES_HIGH;
-    let mut atlas_data = vec![0u8; (atlas_width * atlas_height * 4) as usize]; // RGBA
-
-    // Copy each tile into the atlas
-    for (index, handle) in &builder.terrain_handles {
-        if let Some(image) = images.get(handle) {
-            let x_offset = (index % ATLAS_TILES_WIDE as usize) as u32 * TILE_SIZE;
-            let y_offset = (index / ATLAS_TILES_WIDE as usize) as u32 * TILE_SIZE;
-
-            // Access image data - clone to get owned data
-            let src_data = image.clone().data;
-
-            // Copy pixel data if data exists
-            if let Some(pixel_data) = &src_data {
-                for y in 0..TILE_SIZE {
-                    for x in 0..TILE_SIZE {
-                        let src_idx = ((y * TILE_SIZE + x) * 4) as usize;
-                        let dst_idx =
-                            (((y_offset + y) * atlas_width + (x_offset + x)) * 4) as usize;
-
-                        if src_idx + 3 < pixel_data.len() && dst_idx + 3 < atlas_data.len() {
-                            atlas_data[dst_idx..dst_idx + 4]
-                                .copy_from_slice(&pixel_data[src_idx..src_idx + 4]);
-                        }
-                    }
-                }
-
-                info!(
-                    "Placed terrain tile {} at ({}, {})",
-                    index, x_offset, y_offset
-                );
-            } else {
-                warn!("Tile {} has no pixel data!", index);
-            }
-        }
+    if atlas.ready {
+        return;
     }
 
+    let Some(task) = builder.task.as_mut() else {
+        return;
+    };
+    let Some(atlas_data) = block_on(future::poll_once(task)) else {
+        return;
+    };
+    builder.task = None;
+
     // Create the atlas image
     let atlas_image = Image::new(
         Extent3d {
-            width: atlas_width,
-            height: atlas_height,
+            width: TILE_SIZE * ATLAS_TILES_WIDE,
+            height: TILE_SIZE * ATLAS_TILES_HIGH,
             depth_or_array_layers: 1,
         },
         TextureDimension::D2,
@@ -129,12 +156,74 @@ pub fn build_terrain_atlas_when_ready(
         RenderAssetUsages::default(),
     );
 
-    let atlas_handle = images.add(atlas_image);
-
-    commands.insert_resource(TerrainAtlas {
-        texture: atlas_handle,
-        ready: true,
-    });
+    atlas.texture = images.add(atlas_image);
+    atlas.ready = true;
 
     info!("Terrain atlas built successfully!");
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::RenderAssetUsages;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+    use crate::map::rendering::terrain_atlas::{
+        ATLAS_TILES_HIGH, ATLAS_TILES_WIDE, TILE_SIZE, TerrainAtlas, TerrainAtlasBuilder,
+        build_terrain_atlas_when_ready,
+    };
+
+    #[test]
+    fn atlas_becomes_ready_through_background_task() {
+        let mut world = World::new();
+        let mut images = Assets::<Image>::default();
+        let mut builder = TerrainAtlasBuilder::default();
+        for index in 0..(ATLAS_TILES_WIDE * ATLAS_TILES_HIGH) as usize {
+            let shade = index as u8 * 10;
+            let tile = Image::new_fill(
+                Extent3d {
+                    width: TILE_SIZE,
+                    height: TILE_SIZE,
+                    depth_or_array_layers: 1,
+                },
+                TextureDimension::D2,
+                &[shade, shade, shade, 255],
+                TextureFormat::Rgba8UnormSrgb,
+                RenderAssetUsages::default(),
+            );
+            builder.terrain_handles.push((index, images.add(tile)));
+        }
+        world.insert_resource(images);
+        world.insert_resource(builder);
+
+        // The first run only starts the job; keep polling until it completes
+        for _ in 0..1000 {
+            world
+                .run_system_once(build_terrain_atlas_when_ready)
+                .expect("atlas system should run");
+            if world
+                .get_resource::<TerrainAtlas>()
+                .is_some_and(|a| a.ready)
+            {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+
+        let atlas = world.resource::<TerrainAtlas>();
+        assert!(atlas.ready, "atlas never finished building");
+        let image = world
+            .resource::<Assets<Image>>()
+            .get(&atlas.texture)
+            .expect("atlas image should be stored");
+        assert_eq!(image.width(), TILE_SIZE * ATLAS_TILES_WIDE);
+        assert_eq!(image.height(), TILE_SIZE * ATLAS_TILES_HIGH);
+
+        // Tile 5 sits in the second row, second column
+        let data = image.data.as_ref().expect("atlas has pixel data");
+        let (x, y) = (TILE_SIZE + 1, TILE_SIZE + 1);
+        let idx = ((y * TILE_SIZE * ATLAS_TILES_WIDE + x) * 4) as usize;
+        assert_eq!(&data[idx..idx + 4], &[50, 50, 50, 255]);
+    }
+}
@@ -3,9 +3,10 @@ use bevy_ecs_tilemap::prelude::TilePos;
 use std::collections::HashSet;
 
 use crate::assets;
+use crate::civilians::Civilian;
 use crate::civilians::SelectedCivilian;
-use crate::civilians::{Civilian, CivilianKind};
-use crate::economy::{Depot, Port, Rails};
+use crate::economy::transport::ordered_edge;
+use crate::economy::{Depot, Port, RailConstruction, Rails};
 use crate::map::rendering::{MapVisual, MapVisualFor};
 use crate::map::tile_pos::TilePosExt;
 use crate::ui::components::MapTilemap;
@@ -20,11 +21,18 @@ pub struct RailLineVisual {
     pub edge: (TilePos, TilePos),
 }
 
+/// Marker for rail segments that are still being built
+#[derive(Component)]
+pub struct RailConstructionVisual {
+    pub edge: (TilePos, TilePos),
+}
+
 /// Marker for shadow rail preview visual
 #[derive(Component)]
 pub struct ShadowRailVisual;
 
 const RAIL_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
+const RAIL_CONSTRUCTION_COLOR: Color = Color::srgb(0.75, 0.55, 0.25);
 const SHADOW_RAIL_COLOR: Color = Color::srgba(0.7, 0.7, 0.7, 0.4); // Semi-transparent
 const DEPOT_CONNECTED_COLOR: Color = Color::srgb(0.2, 0.8, 0.2);
 const DEPOT_DISCONNECTED_COLOR: Color = Color::srgb(0.8, 0.2, 0.2);
@@ -228,6 +236,37 @@ pub fn render_rails(
     );
 }
 
+/// Draw rail segments under construction in their own color until they complete.
+/// Works off the `RailConstruction` entities, so progress restored from a save shows up too.
+pub fn render_rail_construction(
+    mut commands: Commands,
+    constructions: Query<&RailConstruction>,
+    changed: Query<(), Changed<RailConstruction>>,
+    mut removed: RemovedComponents<RailConstruction>,
+    existing: Query<(Entity, &RailConstructionVisual)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let removed_any = removed.read().count() > 0;
+    let edges: HashSet<(TilePos, TilePos)> = constructions
+        .iter()
+        .map(|construction| ordered_edge(construction.from, construction.to))
+        .collect();
+
+    sync_line_visuals(
+        &mut commands,
+        &edges,
+        !changed.is_empty() || removed_any,
+        &existing,
+        &mut meshes,
+        &mut materials,
+        RAIL_CONSTRUCTION_COLOR,
+        0.9,
+        |edge| RailConstructionVisual { edge },
+        |visual: &RailConstructionVisual| visual.edge,
+    );
+}
+
 /// Update depot visual colors based on connectivity
 /// Uses relationship pattern for O(1) sprite lookups and automatic cleanup
 pub fn update_depot_visuals(
@@ -284,7 +323,7 @@ pub fn render_shadow_rail(
     let selected_engineer = selected_civilian
         .map(|s| s.0)
         .and_then(|entity| civilians.get(entity).ok())
-        .filter(|(_, c)| c.kind == CivilianKind::Engineer)
+        .filter(|(_, c)| c.kind.capabilities().builds_rails)
         .map(|(_, c)| c);
 
     // Determine if we should show shadow rail
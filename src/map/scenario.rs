@@ -0,0 +1,290 @@
+//! Handcrafted scenarios loaded from RON files.
+//!
+//! A scenario replaces random province assignment: it names the nations, the
+//! provinces each one starts with, their technologies and the opening diplomacy.
+//! Provinces are still generated from the map; unlisted ones stay unowned.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use bevy::prelude::*;
+use bevy::scene::ron;
+use bevy_ecs_tilemap::prelude::TilePos;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::civilians::types::NextCivilianId;
+use crate::diplomacy::DiplomacyState;
+use crate::economy::{NationInstance, Technologies, Technology, UpkeepPolicy};
+use crate::map::editor::set_tile;
+use crate::map::province::{Province, ProvinceId};
+use crate::map::province_setup::{
+    assign_province_to_country, insert_human_nations, spawn_nation, spawn_starter_civilians,
+};
+use crate::map::rendering::ColorPalette;
+use crate::map::terrain_gen::TerrainParams;
+use crate::map::tiles::TerrainType;
+use crate::resources::ResourceType;
+
+/// Declarative description of a game's starting state
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Scenario {
+    pub nations: Vec<ScenarioNation>,
+    #[serde(default)]
+    pub relations: Vec<ScenarioRelation>,
+    /// Noise parameters for the generated map; the game's defaults when absent
+    #[serde(default)]
+    pub terrain_params: Option<TerrainParams>,
+    /// Hand-painted tiles overriding the generated terrain
+    #[serde(default)]
+    pub tiles: Vec<ScenarioTile>,
+}
+
+/// Terrain and resource of one edited tile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioTile {
+    pub x: u32,
+    pub y: u32,
+    pub terrain: TerrainType,
+    #[serde(default)]
+    pub resource: Option<ResourceType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioNation {
+    pub name: String,
+    #[serde(default)]
+    pub human: bool,
+    /// Province ids; the first one holds the capital
+    #[serde(default)]
+    pub provinces: Vec<u32>,
+    #[serde(default)]
+    pub technologies: Vec<Technology>,
+}
+
+/// Opening relation between two nations, referenced by their index in `nations`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScenarioRelation {
+    pub a: usize,
+    pub b: usize,
+    pub score: i32,
+    pub consulate: bool,
+    pub embassy: bool,
+    pub non_aggression_pact: bool,
+    pub alliance: bool,
+    pub trade_agreement: bool,
+    pub at_war: bool,
+}
+
+#[derive(Error, Debug)]
+pub enum ScenarioError {
+    #[error("Failed to read scenario file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse scenario: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+}
+
+impl Scenario {
+    pub fn from_ron(source: &str) -> Result<Self, ScenarioError> {
+        Ok(ron::from_str(source)?)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ScenarioError> {
+        Self::from_ron(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
+
+/// Scenario chosen for the next game; when present it replaces random province assignment
+#[derive(Resource, Debug, Clone)]
+pub struct SelectedScenario(pub Scenario);
+
+/// Build the scenario's nations, territories and diplomacy on the generated provinces
+pub fn apply_scenario(
+    mut commands: Commands,
+    mut provinces: Query<(Entity, &mut Province)>,
+    mut next_civilian_id: ResMut<NextCivilianId>,
+    palette: Res<ColorPalette>,
+    scenario: Res<SelectedScenario>,
+    upkeep: Res<UpkeepPolicy>,
+) {
+    let scenario = &scenario.0;
+
+    // Check if already assigned (provinces have owners)
+    if provinces.iter().any(|(_, p)| p.owner.is_some()) {
+        return;
+    }
+
+    let province_lookup: HashMap<ProvinceId, (Entity, TilePos)> = provinces
+        .iter()
+        .map(|(entity, province)| (province.id, (entity, province.city_tile)))
+        .collect();
+
+    let mut nation_entities = Vec::new();
+    let mut human_entities = Vec::new();
+    let mut capitals = Vec::new();
+
+    for (slot, nation) in scenario.nations.iter().enumerate() {
+        let mut technologies = Technologies::new();
+        for tech in &nation.technologies {
+            technologies.unlock(*tech);
+        }
+        let entity = spawn_nation(
+            &mut commands,
+            slot,
+            nation.name.clone(),
+            palette.nation_color(slot),
+            nation.human,
+            technologies,
+            upkeep.starting_treasury,
+        );
+        nation_entities.push(entity);
+        if nation.human {
+            human_entities.push(entity);
+        }
+
+        for &id in &nation.provinces {
+            let province_id = ProvinceId(id);
+            let Some(&(province_entity, city_tile)) = province_lookup.get(&province_id) else {
+                warn!(
+                    "Scenario nation {} lists unknown province {id}",
+                    nation.name
+                );
+                continue;
+            };
+            assign_province_to_country(
+                &mut commands,
+                &mut provinces,
+                province_entity,
+                province_id,
+                city_tile,
+                entity,
+                &mut capitals,
+            );
+        }
+    }
+
+    insert_human_nations(&mut commands, human_entities.clone());
+    spawn_starter_civilians(
+        &mut commands,
+        &mut next_civilian_id,
+        &capitals,
+        &human_entities,
+    );
+
+    let tiles = scenario.tiles.clone();
+    commands.queue(move |world: &mut World| {
+        for tile in tiles {
+            set_tile(
+                world,
+                TilePos {
+                    x: tile.x,
+                    y: tile.y,
+                },
+                tile.terrain,
+                tile.resource,
+            );
+        }
+    });
+
+    let relations = scenario.relations.clone();
+    commands.queue(move |world: &mut World| {
+        let instances: Vec<Option<NationInstance>> = nation_entities
+            .iter()
+            .map(|&entity| NationInstance::from_entity(world.entity(entity)))
+            .collect();
+        let mut state = world.get_resource_or_init::<DiplomacyState>();
+        for relation in relations {
+            let (Some(&Some(a)), Some(&Some(b))) =
+                (instances.get(relation.a), instances.get(relation.b))
+            else {
+                warn!(
+                    "Scenario relation references unknown nations {} and {}",
+                    relation.a, relation.b
+                );
+                continue;
+            };
+            state.adjust_score(a, b, relation.score);
+            state.set_treaty(a, b, |treaty| {
+                treaty.consulate = relation.consulate;
+                treaty.embassy = relation.embassy;
+                treaty.non_aggression_pact = relation.non_aggression_pact;
+                treaty.alliance = relation.alliance;
+                treaty.trade_agreement = relation.trade_agreement;
+                treaty.at_war = relation.at_war;
+            });
+        }
+    });
+
+    info!("Scenario applied with {} nations", scenario.nations.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use bevy_ecs_tilemap::prelude::TilePos;
+
+    use crate::civilians::types::NextCivilianId;
+    use crate::diplomacy::DiplomacyState;
+    use crate::economy::{Capital, Nation, NationInstance, Technologies, Technology, UpkeepPolicy};
+    use crate::map::province::{Province, ProvinceId};
+    use crate::map::rendering::ColorPalette;
+    use crate::map::scenario::{Scenario, SelectedScenario, apply_scenario};
+
+    const TWO_ALLIES: &str = r#"(
+        nations: [
+            (name: "Albion", human: true, provinces: [0], technologies: [HillGrading]),
+            (name: "Gallia", provinces: [1]),
+        ],
+        relations: [
+            (a: 0, b: 1, score: 60, embassy: true, alliance: true),
+        ],
+    )"#;
+
+    #[test]
+    fn scenario_with_preset_alliance_spawns_allied_nations() {
+        let scenario = Scenario::from_ron(TWO_ALLIES).expect("scenario parses");
+
+        let mut world = World::new();
+        world.init_resource::<NextCivilianId>();
+        world.init_resource::<UpkeepPolicy>();
+        world.init_resource::<ColorPalette>();
+        world.insert_resource(SelectedScenario(scenario));
+        for (id, x) in [(0, 2), (1, 10)] {
+            world.spawn(Province::new(
+                ProvinceId(id),
+                vec![TilePos { x, y: 2 }],
+                TilePos { x, y: 2 },
+            ));
+        }
+
+        let _ = world.run_system_once(apply_scenario);
+
+        let mut nations = world.query::<(NationInstance, &Name, &Capital, &Technologies)>();
+        let spawned: Vec<_> = nations
+            .iter(&world)
+            .map(|(nation, name, capital, techs)| {
+                (nation, name.as_str().to_string(), capital.0, techs.clone())
+            })
+            .collect();
+        assert_eq!(spawned.len(), 2);
+        let albion = spawned.iter().find(|n| n.1 == "Albion").unwrap();
+        let gallia = spawned.iter().find(|n| n.1 == "Gallia").unwrap();
+        assert_eq!(albion.2, TilePos { x: 2, y: 2 });
+        assert!(albion.3.has(Technology::HillGrading));
+        assert!(!gallia.3.has(Technology::HillGrading));
+
+        let state = world.resource::<DiplomacyState>();
+        let relation = state.relation(albion.0, gallia.0).expect("relation set");
+        assert!(relation.treaty.alliance);
+        assert!(relation.treaty.embassy);
+        assert_eq!(relation.score, 60);
+        assert!(state.are_allied(albion.0, gallia.0));
+        assert_eq!(world.query::<&Nation>().iter(&world).count(), 2);
+    }
+}
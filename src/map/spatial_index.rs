@@ -0,0 +1,198 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::TilePos;
+use std::collections::{HashMap, HashSet};
+
+use crate::civilians::types::Civilian;
+use crate::map::province::Province;
+use crate::map::tiles::TerrainType;
+
+/// Position and ownership lookups kept up to date incrementally, so hot queries
+/// (tile at a position, tiles or units of a nation) don't have to scan the whole map.
+#[derive(Resource, Default, Debug)]
+pub struct SpatialIndex {
+    tiles: HashMap<TilePos, Entity>,
+    tile_owners: HashMap<TilePos, Entity>,
+    nation_tiles: HashMap<Entity, HashSet<TilePos>>,
+    unit_locations: HashMap<Entity, (Entity, TilePos)>,
+    nation_units: HashMap<Entity, HashSet<Entity>>,
+    units_at: HashMap<TilePos, HashSet<Entity>>,
+}
+
+impl SpatialIndex {
+    /// Tile entity at a map position
+    pub fn tile_at(&self, pos: TilePos) -> Option<Entity> {
+        self.tiles.get(&pos).copied()
+    }
+
+    /// Nation owning the province that contains `pos`
+    pub fn owner_of(&self, pos: TilePos) -> Option<Entity> {
+        self.tile_owners.get(&pos).copied()
+    }
+
+    /// All tiles in provinces owned by `nation`
+    pub fn tiles_of(&self, nation: Entity) -> Option<&HashSet<TilePos>> {
+        self.nation_tiles.get(&nation)
+    }
+
+    /// All civilian units owned by `nation`
+    pub fn units_of(&self, nation: Entity) -> Option<&HashSet<Entity>> {
+        self.nation_units.get(&nation)
+    }
+
+    /// Civilian units standing on `pos`
+    pub fn units_at(&self, pos: TilePos) -> Option<&HashSet<Entity>> {
+        self.units_at.get(&pos)
+    }
+
+    pub fn insert_tile(&mut self, pos: TilePos, tile: Entity) {
+        self.tiles.insert(pos, tile);
+    }
+
+    /// Reassign a tile to a new owner (or none), keeping per-nation lists in sync
+    pub fn set_tile_owner(&mut self, pos: TilePos, owner: Option<Entity>) {
+        let previous = match owner {
+            Some(owner) => self.tile_owners.insert(pos, owner),
+            None => self.tile_owners.remove(&pos),
+        };
+        if previous == owner {
+            return;
+        }
+        if let Some(previous) = previous
+            && let Some(tiles) = self.nation_tiles.get_mut(&previous)
+        {
+            tiles.remove(&pos);
+        }
+        if let Some(owner) = owner {
+            self.nation_tiles.entry(owner).or_default().insert(pos);
+        }
+    }
+
+    /// Record a unit's current owner and position, moving it out of its old entries
+    pub fn set_unit(&mut self, unit: Entity, owner: Entity, pos: TilePos) {
+        if self.unit_locations.get(&unit) == Some(&(owner, pos)) {
+            return;
+        }
+        self.remove_unit(unit);
+        self.unit_locations.insert(unit, (owner, pos));
+        self.nation_units.entry(owner).or_default().insert(unit);
+        self.units_at.entry(pos).or_default().insert(unit);
+    }
+
+    pub fn remove_unit(&mut self, unit: Entity) {
+        let Some((owner, pos)) = self.unit_locations.remove(&unit) else {
+            return;
+        };
+        if let Some(units) = self.nation_units.get_mut(&owner) {
+            units.remove(&unit);
+        }
+        if let Some(units) = self.units_at.get_mut(&pos) {
+            units.remove(&unit);
+            if units.is_empty() {
+                self.units_at.remove(&pos);
+            }
+        }
+    }
+}
+
+/// Index newly spawned map tiles
+pub fn index_tiles(
+    mut index: ResMut<SpatialIndex>,
+    tiles: Query<(Entity, &TilePos), (Added<TilePos>, With<TerrainType>)>,
+) {
+    for (entity, pos) in tiles.iter() {
+        index.insert_tile(*pos, entity);
+    }
+}
+
+/// Track province ownership changes (conquest, cession, initial assignment)
+pub fn index_tile_ownership(
+    mut index: ResMut<SpatialIndex>,
+    provinces: Query<&Province, Changed<Province>>,
+) {
+    for province in provinces.iter() {
+        for &pos in &province.tiles {
+            index.set_tile_owner(pos, province.owner);
+        }
+    }
+}
+
+/// Track civilian movement, ownership and despawns
+pub fn index_units(
+    mut index: ResMut<SpatialIndex>,
+    units: Query<(Entity, &Civilian), Changed<Civilian>>,
+    mut removed: RemovedComponents<Civilian>,
+) {
+    for unit in removed.read() {
+        index.remove_unit(unit);
+    }
+    for (entity, civilian) in units.iter() {
+        index.set_unit(entity, civilian.owner, civilian.position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy_ecs_tilemap::prelude::TilePos;
+
+    use crate::map::province::{Province, ProvinceId};
+    use crate::map::spatial_index::{SpatialIndex, index_tile_ownership, index_tiles};
+    use crate::map::tiles::TerrainType;
+
+    #[test]
+    fn index_finds_tiles_and_follows_ownership_changes() {
+        let mut app = App::new();
+        app.init_resource::<SpatialIndex>()
+            .add_systems(Update, (index_tiles, index_tile_ownership));
+
+        let pos = TilePos::new(3, 4);
+        let tile = app.world_mut().spawn((pos, TerrainType::Grass)).id();
+        let first = app.world_mut().spawn_empty().id();
+        let second = app.world_mut().spawn_empty().id();
+
+        let mut province = Province::new(ProvinceId(0), vec![pos], pos);
+        province.owner = Some(first);
+        let province = app.world_mut().spawn(province).id();
+        app.update();
+
+        let index = app.world().resource::<SpatialIndex>();
+        assert_eq!(index.tile_at(pos), Some(tile));
+        assert_eq!(index.owner_of(pos), Some(first));
+        assert!(index.tiles_of(first).unwrap().contains(&pos));
+
+        app.world_mut().get_mut::<Province>(province).unwrap().owner = Some(second);
+        app.update();
+
+        let index = app.world().resource::<SpatialIndex>();
+        assert_eq!(index.owner_of(pos), Some(second));
+        assert!(!index.tiles_of(first).unwrap().contains(&pos));
+        assert!(index.tiles_of(second).unwrap().contains(&pos));
+    }
+
+    #[test]
+    fn released_province_clears_owner_of_all_its_tiles() {
+        let mut app = App::new();
+        app.init_resource::<SpatialIndex>()
+            .add_systems(Update, index_tile_ownership);
+
+        let tiles = vec![TilePos::new(0, 0), TilePos::new(1, 0)];
+        let nation = app.world_mut().spawn_empty().id();
+        let mut province = Province::new(ProvinceId(3), tiles.clone(), tiles[0]);
+        province.owner = Some(nation);
+        let province = app.world_mut().spawn(province).id();
+        app.update();
+        assert_eq!(
+            app.world().resource::<SpatialIndex>().owner_of(tiles[1]),
+            Some(nation)
+        );
+
+        app.world_mut().get_mut::<Province>(province).unwrap().owner = None;
+        app.update();
+
+        let index = app.world().resource::<SpatialIndex>();
+        for pos in tiles {
+            assert_eq!(index.owner_of(pos), None);
+        }
+        assert!(index.tiles_of(nation).unwrap().is_empty());
+    }
+}
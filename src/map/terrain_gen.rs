@@ -1,19 +1,60 @@
-use crate::map::tiles::TerrainType;
+use bevy::prelude::*;
 use noise::{NoiseFn, Perlin};
+use serde::{Deserialize, Serialize};
+
+use crate::map::tiles::TerrainType;
+
+/// Tunable terrain generation parameters.
+/// Noise values are normalized to [0, 1]; thresholds are compared against them.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TerrainParams {
+    /// Elevation below this is water; raise it for smaller continents
+    pub sea_level: f64,
+    /// Elevation above this is hills
+    pub hill_threshold: f64,
+    /// Elevation above this is mountains
+    pub mountain_threshold: f64,
+    /// Frequency of elevation features; higher means more, smaller landmasses
+    pub elevation_scale: f64,
+    /// Frequency of moisture features (forests, deserts, farmland)
+    pub moisture_scale: f64,
+    /// Frequency of temperature variation
+    pub temperature_scale: f64,
+}
+
+impl Default for TerrainParams {
+    fn default() -> Self {
+        Self {
+            sea_level: 0.3,
+            hill_threshold: 0.6,
+            mountain_threshold: 0.7,
+            elevation_scale: 4.0,
+            moisture_scale: 6.0,
+            temperature_scale: 8.0,
+        }
+    }
+}
 
 pub struct TerrainGenerator {
     elevation_noise: Perlin,
     moisture_noise: Perlin,
     temperature_noise: Perlin,
+    params: TerrainParams,
 }
 
 impl TerrainGenerator {
     pub fn new(seed: u32) -> Self {
+        Self::with_params(seed, TerrainParams::default())
+    }
+
+    pub fn with_params(seed: u32, params: TerrainParams) -> Self {
         Self {
             // Use different seeds for each noise layer for variety
             elevation_noise: Perlin::new(seed),
             moisture_noise: Perlin::new(seed.wrapping_add(1000)),
             temperature_noise: Perlin::new(seed.wrapping_add(2000)),
+            params,
         }
     }
 
@@ -30,10 +71,12 @@ impl TerrainGenerator {
         let norm_x = x as f64 / map_size_x as f64;
         let norm_y = y as f64 / map_size_y as f64;
 
-        // Different scales for varied terrain features
-        let elevation_scale = 4.0; // Larger features for elevation
-        let moisture_scale = 6.0; // Medium features for moisture
-        let temperature_scale = 8.0; // Smaller features for temperature variation
+        let TerrainParams {
+            elevation_scale,
+            moisture_scale,
+            temperature_scale,
+            ..
+        } = self.params;
 
         // Generate noise values (-1 to 1, then normalize to 0 to 1)
         let elevation = (self
@@ -59,18 +102,20 @@ impl TerrainGenerator {
 
     /// Classify terrain based on elevation, moisture, and temperature
     fn classify_terrain(&self, elevation: f64, moisture: f64, temperature: f64) -> TerrainType {
+        let params = &self.params;
+
         // Water: low elevation (more common)
-        if elevation < 0.3 {
+        if elevation < params.sea_level {
             return TerrainType::Water;
         }
 
         // Mountains: high elevation (less common but still present)
-        if elevation > 0.7 {
+        if elevation > params.mountain_threshold {
             return TerrainType::Mountain;
         }
 
         // Hills: moderate-high elevation
-        if elevation > 0.6 && elevation <= 0.7 {
+        if elevation > params.hill_threshold {
             return TerrainType::Hills;
         }
 
@@ -97,3 +142,38 @@ impl Default for TerrainGenerator {
         Self::new(42) // Default seed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::map::terrain_gen::{TerrainGenerator, TerrainParams};
+    use crate::map::tiles::TerrainType;
+
+    fn water_fraction(generator: &TerrainGenerator) -> f64 {
+        let size = 48;
+        let water = (0..size)
+            .flat_map(|x| (0..size).map(move |y| (x, y)))
+            .filter(|&(x, y)| generator.generate_terrain(x, y, size, size) == TerrainType::Water)
+            .count();
+        water as f64 / (size * size) as f64
+    }
+
+    #[test]
+    fn raising_sea_level_floods_more_tiles_on_the_same_seed() {
+        let seed = 42;
+        let default = TerrainGenerator::new(seed);
+        let flooded = TerrainGenerator::with_params(
+            seed,
+            TerrainParams {
+                sea_level: 0.5,
+                ..TerrainParams::default()
+            },
+        );
+
+        let base = water_fraction(&default);
+        let raised = water_fraction(&flooded);
+        assert!(
+            raised > base,
+            "sea level 0.5 gave {raised:.2} water, default gave {base:.2}"
+        );
+    }
+}
@@ -1,7 +1,25 @@
-use crate::constants::{MAP_SIZE, TILE_SIZE};
+use crate::constants::{MAP_SIZE, TILE_SIZE, hex_map_type};
 use bevy_ecs_tilemap::prelude::*;
 use hexx::Hex;
 
+/// Tile under a world position on the standard game map, if any
+pub fn tile_pos_from_world(world_pos: bevy::prelude::Vec2) -> Option<TilePos> {
+    TilePos::from_world_pos(
+        &world_pos,
+        &TilemapSize {
+            x: MAP_SIZE,
+            y: MAP_SIZE,
+        },
+        &crate::constants::get_hex_grid_size(),
+        &TilemapTileSize {
+            x: TILE_SIZE,
+            y: TILE_SIZE,
+        },
+        &hex_map_type(),
+        &TilemapAnchor::Center,
+    )
+}
+
 pub trait TilePosExt {
     fn to_hex(&self) -> Hex;
 
@@ -21,8 +39,8 @@ pub trait TilePosExt {
 
 impl TilePosExt for TilePos {
     fn to_hex(&self) -> Hex {
-        // When using HexCoordSystem::Row, TilePos is already in axial coordinates
-        // TilePos.x = q, TilePos.y = r
+        // HEX_COORD_SYSTEM is axial (Row or Column), so TilePos is already in
+        // axial coordinates: TilePos.x = q, TilePos.y = r
         // No conversion needed - just map directly
         Hex::new(self.x as i32, self.y as i32)
     }
@@ -63,7 +81,7 @@ impl TilePosExt for TilePos {
         };
 
         let grid_size = crate::constants::get_hex_grid_size();
-        let map_type = TilemapType::Hexagon(HexCoordSystem::Row);
+        let map_type = hex_map_type();
 
         let pos = self.center_in_world(
             &map_size,
@@ -83,8 +101,7 @@ pub trait HexExt {
 
 impl HexExt for Hex {
     fn to_tile_pos(&self) -> Option<TilePos> {
-        // When using HexCoordSystem::Row, TilePos is already in axial coordinates
-        // Hex.x = q -> TilePos.x, Hex.y = r -> TilePos.y
+        // HEX_COORD_SYSTEM is axial, so Hex.x = q -> TilePos.x, Hex.y = r -> TilePos.y
         // No conversion needed - just map directly
         if self.x >= 0 && self.y >= 0 {
             Some(TilePos {
@@ -180,6 +197,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn neighbors_and_world_positions_agree_with_configured_coord_system() {
+        let center = TilePos { x: 10, y: 12 };
+        let center_world = center.to_world_pos();
+        assert_eq!(tile_pos_from_world(center_world), Some(center));
+
+        let neighbors: Vec<TilePos> = center
+            .to_hex()
+            .all_neighbors()
+            .iter()
+            .filter_map(|h| h.to_tile_pos())
+            .collect();
+        assert_eq!(neighbors.len(), 6);
+
+        let neighbor_distance = neighbors
+            .iter()
+            .map(|n| n.to_world_pos().distance(center_world))
+            .fold(f32::MIN, f32::max);
+        for neighbor in &neighbors {
+            // World positions round-trip through the tilemap's own picking math
+            assert_eq!(
+                tile_pos_from_world(neighbor.to_world_pos()),
+                Some(*neighbor)
+            );
+        }
+
+        // Every tile that is not a hex neighbor lies farther away on screen
+        // than the hex neighbors do
+        for x in 7..=13 {
+            for y in 9..=15 {
+                let tile = TilePos { x, y };
+                if tile == center || neighbors.contains(&tile) {
+                    continue;
+                }
+                assert!(
+                    tile.to_world_pos().distance(center_world) > neighbor_distance,
+                    "{:?} is closer than a hex neighbor of {:?}",
+                    tile,
+                    center
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_adjacency_check() {
         // Test that distance_to correctly identifies adjacent tiles in axial coordinates
@@ -0,0 +1,281 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::map::prospecting::PotentialMineral;
+use crate::map::terrain_gen::{TerrainGenerator, TerrainParams};
+use crate::map::tiles::TerrainType;
+use crate::resources::{ResourceType, TileResource};
+
+/// How tile entities are spawned when a new map is created
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct TileSpawnSettings {
+    /// Spawn at most this many tiles per frame; `None` spawns the whole map at once.
+    /// Useful for very large maps where eager spawning causes a long first frame.
+    pub tiles_per_frame: Option<u32>,
+}
+
+/// Deterministic generator for map tiles.
+/// Tiles are produced column by column, so spawning in chunks yields exactly
+/// the same terrain and resources as spawning everything at once.
+pub struct TileSpawner {
+    map_size: TilemapSize,
+    terrain_gen: TerrainGenerator,
+    rng: StdRng,
+    next: u32,
+}
+
+impl TileSpawner {
+    pub fn new(map_size: TilemapSize, seed: u32) -> Self {
+        Self::with_terrain_params(map_size, seed, TerrainParams::default())
+    }
+
+    pub fn with_terrain_params(map_size: TilemapSize, seed: u32, params: TerrainParams) -> Self {
+        Self {
+            map_size,
+            terrain_gen: TerrainGenerator::with_params(seed, params),
+            // Use deterministic RNG for resource placement (based on the terrain seed)
+            rng: StdRng::seed_from_u64(seed as u64),
+            next: 0,
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.next >= self.map_size.count() as u32
+    }
+
+    /// Spawn up to `max_tiles` more tiles into `storage`; returns how many were spawned
+    pub fn spawn_chunk(
+        &mut self,
+        commands: &mut Commands,
+        tilemap_entity: Entity,
+        storage: &mut TileStorage,
+        max_tiles: u32,
+    ) -> u32 {
+        let mut spawned = 0;
+        while spawned < max_tiles && !self.is_done() {
+            let tile_pos = TilePos {
+                x: self.next / self.map_size.y,
+                y: self.next % self.map_size.y,
+            };
+            let tile_entity = self.spawn_tile(commands, tilemap_entity, tile_pos);
+            storage.set(&tile_pos, tile_entity);
+            self.next += 1;
+            spawned += 1;
+        }
+        spawned
+    }
+
+    fn spawn_tile(
+        &mut self,
+        commands: &mut Commands,
+        tilemap_entity: Entity,
+        tile_pos: TilePos,
+    ) -> Entity {
+        let rng = &mut self.rng;
+
+        // Generate terrain using noise functions
+        let terrain_type = self.terrain_gen.generate_terrain(
+            tile_pos.x,
+            tile_pos.y,
+            self.map_size.x,
+            self.map_size.y,
+        );
+
+        let mut tile_entity_commands = commands.spawn((
+            tile_pos,
+            TilemapId(tilemap_entity),
+            terrain_type, // Add the terrain type component
+        ));
+
+        // Assign resources based on terrain type
+        match terrain_type {
+            TerrainType::Farmland => {
+                // Farmland: Grain (70%), Cotton (20%), or Fruit (10%)
+                let roll = rng.random::<f32>();
+                let resource = if roll < 0.7 {
+                    ResourceType::Grain
+                } else if roll < 0.9 {
+                    ResourceType::Cotton
+                } else {
+                    ResourceType::Fruit
+                };
+                tile_entity_commands.insert(TileResource::visible(resource));
+            }
+            TerrainType::Grass => {
+                // Grassland: 40% chance of Wool or Livestock
+                if rng.random::<f32>() < 0.4 {
+                    let resource = if rng.random::<bool>() {
+                        ResourceType::Wool
+                    } else {
+                        ResourceType::Livestock
+                    };
+                    tile_entity_commands.insert(TileResource::visible(resource));
+                }
+            }
+            TerrainType::Forest => {
+                // Forest: Always has Timber
+                tile_entity_commands.insert(TileResource::visible(ResourceType::Timber));
+            }
+            TerrainType::Mountain => {
+                // Mountains: All can be prospected
+                // 60% chance of actual mineral: Coal, Iron, Gold, or Gems
+                let has_mineral = rng.random::<f32>() < 0.6;
+                let mineral_type = if has_mineral {
+                    let roll = rng.random::<f32>();
+                    if roll < 0.4 {
+                        Some(ResourceType::Coal)
+                    } else if roll < 0.7 {
+                        Some(ResourceType::Iron)
+                    } else if roll < 0.9 {
+                        Some(ResourceType::Gold)
+                    } else {
+                        Some(ResourceType::Gems)
+                    }
+                } else {
+                    None
+                };
+                tile_entity_commands.insert(PotentialMineral::new(mineral_type));
+            }
+            TerrainType::Hills => {
+                // Hills: All can be prospected
+                // 40% chance of actual mineral: Coal or Iron only (no gold/gems!)
+                let has_mineral = rng.random::<f32>() < 0.4;
+                let mineral_type = if has_mineral {
+                    let roll = rng.random::<f32>();
+                    if roll < 0.6 {
+                        Some(ResourceType::Coal)
+                    } else {
+                        Some(ResourceType::Iron)
+                    }
+                } else {
+                    None
+                };
+                tile_entity_commands.insert(PotentialMineral::new(mineral_type));
+            }
+            TerrainType::Desert => {
+                // Desert: All can be prospected for oil
+                // 15% chance of Oil
+                let has_oil = rng.random::<f32>() < 0.15;
+                let mineral_type = if has_oil {
+                    Some(ResourceType::Oil)
+                } else {
+                    None
+                };
+                tile_entity_commands.insert(PotentialMineral::new(mineral_type));
+            }
+            TerrainType::Water | TerrainType::Swamp => {
+                // Water and Swamp: No resources
+            }
+        }
+
+        tile_entity_commands.id()
+    }
+}
+
+/// Attached to the tilemap while its tiles are still being spawned across frames
+#[derive(Component)]
+pub struct PendingTileSpawn {
+    pub spawner: TileSpawner,
+    pub tiles_per_frame: u32,
+}
+
+/// Spawn the next chunk of tiles for maps created with chunked spawning
+pub fn spawn_pending_tile_chunks(
+    mut commands: Commands,
+    mut tilemaps: Query<(Entity, &mut TileStorage, &mut PendingTileSpawn)>,
+) {
+    for (tilemap_entity, mut storage, mut pending) in tilemaps.iter_mut() {
+        let tiles_per_frame = pending.tiles_per_frame;
+        pending
+            .spawner
+            .spawn_chunk(&mut commands, tilemap_entity, &mut storage, tiles_per_frame);
+
+        if pending.spawner.is_done() {
+            commands.entity(tilemap_entity).remove::<PendingTileSpawn>();
+            info!("Finished spawning map tiles");
+        }
+    }
+}
+
+/// True once the map exists and all of its tiles have been spawned
+pub fn map_tiles_spawned(
+    tilemaps: Query<(), (With<TileStorage>, Without<PendingTileSpawn>)>,
+) -> bool {
+    !tilemaps.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use bevy_ecs_tilemap::prelude::*;
+
+    use crate::map::tile_spawner::{PendingTileSpawn, TileSpawner, spawn_pending_tile_chunks};
+    use crate::map::tiles::TerrainType;
+    use crate::resources::TileResource;
+
+    fn describe_tiles(world: &World, tilemap: Entity) -> Vec<(TilePos, String)> {
+        let storage = world.get::<TileStorage>(tilemap).unwrap().clone();
+        let size = storage.size;
+        let mut tiles = Vec::new();
+        for x in 0..size.x {
+            for y in 0..size.y {
+                let pos = TilePos { x, y };
+                let tile = storage
+                    .get(&pos)
+                    .expect("every position should have a tile");
+                let terrain = world.get::<TerrainType>(tile).unwrap();
+                let resource = world.get::<TileResource>(tile);
+                tiles.push((pos, format!("{:?} {:?}", terrain, resource)));
+            }
+        }
+        tiles
+    }
+
+    #[test]
+    fn chunked_spawning_matches_eager_spawning() {
+        let size = TilemapSize { x: 12, y: 9 };
+        let seed = 42;
+
+        let mut eager_world = World::new();
+        let eager_map = eager_world.spawn_empty().id();
+        let mut storage = TileStorage::empty(size);
+        let mut spawner = TileSpawner::new(size, seed);
+        spawner.spawn_chunk(
+            &mut eager_world.commands(),
+            eager_map,
+            &mut storage,
+            u32::MAX,
+        );
+        eager_world.flush();
+        eager_world.entity_mut(eager_map).insert(storage);
+
+        let mut chunked_world = World::new();
+        let chunked_map = chunked_world
+            .spawn((
+                TileStorage::empty(size),
+                PendingTileSpawn {
+                    spawner: TileSpawner::new(size, seed),
+                    tiles_per_frame: 10,
+                },
+            ))
+            .id();
+
+        let mut frames = 0;
+        while chunked_world.get::<PendingTileSpawn>(chunked_map).is_some() {
+            chunked_world
+                .run_system_once(spawn_pending_tile_chunks)
+                .unwrap();
+            frames += 1;
+            assert!(frames <= 11, "chunked spawning never finished");
+        }
+        assert_eq!(frames, 11, "108 tiles at 10 per frame take 11 frames");
+
+        assert_eq!(
+            describe_tiles(&chunked_world, chunked_map),
+            describe_tiles(&eager_world, eager_map)
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Essential terrain types for gameplay
-#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
 #[reflect(Component)]
 pub enum TerrainType {
     Grass,    // Plains - basic terrain, good movement
@@ -50,4 +51,30 @@ impl TerrainType {
             TerrainType::Farmland => TileIndex::FARMLAND,
         }
     }
+
+    /// Extra turns construction and improvement work takes on this terrain
+    pub fn build_difficulty(self) -> u32 {
+        match self {
+            TerrainType::Hills | TerrainType::Swamp | TerrainType::Forest => 1,
+            TerrainType::Mountain => 2,
+            TerrainType::Grass
+            | TerrainType::Water
+            | TerrainType::Desert
+            | TerrainType::Farmland => 0,
+        }
+    }
+
+    /// Next terrain in declaration order, wrapping around; used by the map editor
+    pub fn next(self) -> Self {
+        match self {
+            TerrainType::Grass => TerrainType::Water,
+            TerrainType::Water => TerrainType::Mountain,
+            TerrainType::Mountain => TerrainType::Hills,
+            TerrainType::Hills => TerrainType::Forest,
+            TerrainType::Forest => TerrainType::Desert,
+            TerrainType::Desert => TerrainType::Swamp,
+            TerrainType::Swamp => TerrainType::Farmland,
+            TerrainType::Farmland => TerrainType::Grass,
+        }
+    }
 }
@@ -17,6 +17,12 @@ pub struct HireCivilian {
     pub kind: CivilianKind,
 }
 
+/// Message sent when a nation dismisses one of its civilian units.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DisbandCivilian {
+    pub civilian: Entity,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CivilianCommandError {
     MissingCivilian,
@@ -30,6 +36,11 @@ pub enum CivilianCommandError {
     MissingTileStorage,
     MissingTargetTile(TilePos),
     TargetTileOccupied,
+    ResourceNotProspected,
+    CannotImproveResource,
+    ResourceFullyDeveloped,
+    DevelopmentCapReached,
+    InvalidDepotTerrain,
 }
 
 impl CivilianCommandError {
@@ -48,6 +59,15 @@ impl CivilianCommandError {
             CivilianCommandError::MissingTileStorage => "no tile storage available",
             CivilianCommandError::MissingTargetTile(_) => "target tile does not exist",
             CivilianCommandError::TargetTileOccupied => "target tile is already occupied",
+            CivilianCommandError::ResourceNotProspected => {
+                "resource must be prospected before it can be improved"
+            }
+            CivilianCommandError::CannotImproveResource => "civilian cannot improve this resource",
+            CivilianCommandError::ResourceFullyDeveloped => "resource is already fully developed",
+            CivilianCommandError::DevelopmentCapReached => {
+                "further development requires new technology"
+            }
+            CivilianCommandError::InvalidDepotTerrain => "depots cannot be built on this terrain",
         }
     }
 }
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
-use crate::economy::NationInstance;
+use crate::economy::{Good, NationInstance};
+use crate::map::province::ProvinceId;
 
 /// Orders issued during the player turn or by future AI actors.
 #[derive(Event, Debug, Clone)]
@@ -13,13 +14,60 @@ pub struct DiplomaticOrder {
 #[derive(Debug, Clone)]
 pub enum DiplomaticOrderKind {
     DeclareWar,
-    OfferPeace,
+    /// `reparations` is paid by the target to the actor when the peace is accepted
+    OfferPeace {
+        reparations: i64,
+    },
     EstablishConsulate,
     OpenEmbassy,
     SignNonAggressionPact,
+    ProposeTradeAgreement,
     FormAlliance,
-    SendAid { amount: i32, locked: bool },
+    SendAid {
+        amount: i32,
+        locked: bool,
+    },
     CancelAid,
+    /// Offer the actor's province to the target
+    CedeProvince {
+        province: ProvinceId,
+    },
+    /// Barter `give` from the actor's stockpile for `receive` from the target's
+    ProposeGoodSwap {
+        give: (Good, u32),
+        receive: (Good, u32),
+    },
+    /// Gift research points from the actor to an ally
+    ShareResearch {
+        points: u32,
+    },
+}
+
+/// Transfer of a province between nations after an accepted cession
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ProvinceCession {
+    pub province: ProvinceId,
+    pub from: NationInstance,
+    pub to: NationInstance,
+}
+
+/// Exchange of goods between two stockpiles after an accepted swap
+#[derive(Event, Debug, Clone, Copy)]
+pub struct GoodSwapSettlement {
+    pub from: NationInstance,
+    pub to: NationInstance,
+    /// Goods moving from `from` to `to`
+    pub give: (Good, u32),
+    /// Goods moving from `to` to `from`
+    pub receive: (Good, u32),
+}
+
+/// Transfer of research points between allies after an accepted gift
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ResearchGift {
+    pub from: NationInstance,
+    pub to: NationInstance,
+    pub points: u32,
 }
 
 #[cfg(test)]
@@ -1,9 +1,11 @@
 use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::TilePos;
 
+use crate::economy::production::BuildingKind;
 use crate::economy::workforce::WorkerSkill;
 use crate::economy::{NationInstance, goods::Good};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum MarketInterest {
     Buy,
     Sell,
@@ -38,6 +40,55 @@ pub struct AdjustMarketOrder {
     pub requested: u32,
 }
 
+/// Withdraw a nation from the market for one good right away: sell reservations
+/// go back to the stockpile and buy interest is dropped, along with any
+/// adjustments still waiting in the orders queue.
+/// Triggered via `commands.trigger(CancelMarketOrder { ... })`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CancelMarketOrder {
+    pub nation: NationInstance,
+    pub good: Good,
+}
+
+/// Move a nation's capital to another of its province cities, for a fee.
+/// Triggered via `commands.trigger(RelocateCapital { ... })`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct RelocateCapital {
+    pub nation: NationInstance,
+    pub to: TilePos,
+}
+
+/// Rank a building for scarce inputs; higher priorities are served first.
+/// Triggered via `commands.trigger(SetBuildingPriority { ... })`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SetBuildingPriority {
+    pub nation: NationInstance,
+    pub building: BuildingKind,
+    pub priority: u8,
+}
+
+/// Keep at least `floor` units of a good out of reach of allocations and trading.
+/// Triggered via `commands.trigger(SetReserveFloor { ... })`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SetReserveFloor {
+    pub nation: NationInstance,
+    pub good: Good,
+    pub floor: u32,
+}
+
+/// Move goods from one of a nation's city stockpiles to another, using idle
+/// transport capacity. Triggered via `commands.trigger(TransferGoods { ... })`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TransferGoods {
+    pub nation: NationInstance,
+    /// City entity the goods leave from
+    pub from: Entity,
+    /// City entity the goods arrive at
+    pub to: Entity,
+    pub good: Good,
+    pub amount: u32,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::messages::*;
@@ -4,13 +4,18 @@ pub mod economy;
 pub mod transport;
 pub mod workforce;
 
-pub use civilians::{CivilianCommand, CivilianCommandError, CivilianCommandRejected, HireCivilian};
-pub use diplomacy::{DiplomaticOrder, DiplomaticOrderKind};
+pub use civilians::{
+    CivilianCommand, CivilianCommandError, CivilianCommandRejected, DisbandCivilian, HireCivilian,
+};
+pub use diplomacy::{
+    DiplomaticOrder, DiplomaticOrderKind, GoodSwapSettlement, ProvinceCession, ResearchGift,
+};
 pub use economy::{
-    AdjustMarketOrder, AdjustProduction, AdjustRecruitment, AdjustTraining, MarketInterest,
+    AdjustMarketOrder, AdjustProduction, AdjustRecruitment, AdjustTraining, CancelMarketOrder,
+    MarketInterest, RelocateCapital, SetBuildingPriority, SetReserveFloor, TransferGoods,
 };
-pub use transport::{PlaceImprovement, RecomputeConnectivity};
-pub use workforce::{RecruitWorkers, TrainWorker};
+pub use transport::{CancelRailConstruction, PlaceImprovement, RecomputeConnectivity};
+pub use workforce::{RecruitWorkers, TrainWorker, UpgradeCapitol};
 
 // Messages currently live alongside their originating subsystems. This module
 // re-exports them behind a unified namespace so that future AI systems can
@@ -28,10 +33,14 @@ mod tests {
         assert_send_sync_static::<AdjustTraining>();
         assert_send_sync_static::<AdjustProduction>();
         assert_send_sync_static::<AdjustMarketOrder>();
+        assert_send_sync_static::<CancelMarketOrder>();
         assert_send_sync_static::<RecruitWorkers>();
         assert_send_sync_static::<TrainWorker>();
         assert_send_sync_static::<PlaceImprovement>();
         assert_send_sync_static::<RecomputeConnectivity>();
+        assert_send_sync_static::<RelocateCapital>();
+        assert_send_sync_static::<TransferGoods>();
+        assert_send_sync_static::<SetBuildingPriority>();
         assert_send_sync_static::<DiplomaticOrder>();
         assert_send_sync_static::<CivilianCommand>();
         assert_send_sync_static::<CivilianCommandRejected>();
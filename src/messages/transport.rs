@@ -19,6 +19,13 @@ pub struct PlaceImprovement {
 #[derive(Event, Debug, Clone, Copy)]
 pub struct RecomputeConnectivity;
 
+/// Abandon a rail segment that is still under construction, freeing its engineer.
+/// Triggered via `commands.trigger(CancelRailConstruction { ... })`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CancelRailConstruction {
+    pub construction: Entity,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::messages::*;
@@ -29,5 +36,6 @@ mod tests {
 
         assert_message::<PlaceImprovement>();
         assert_message::<RecomputeConnectivity>();
+        assert_message::<CancelRailConstruction>();
     }
 }
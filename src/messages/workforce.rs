@@ -10,6 +10,13 @@ pub struct RecruitWorkers {
     pub count: u32,
 }
 
+/// Message to upgrade the Capitol, raising how many workers it can recruit per turn.
+/// Triggered via `commands.trigger(UpgradeCapitol { ... })`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct UpgradeCapitol {
+    pub nation: NationInstance,
+}
+
 /// Message to queue training of a worker at the Trade School.
 #[derive(Event, Debug, Clone, Copy)]
 pub struct TrainWorker {
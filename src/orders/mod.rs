@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::economy::goods::Good;
+use crate::economy::nation::NationId;
 use crate::economy::transport::PlaceImprovement;
 use crate::messages::{AdjustMarketOrder, AdjustProduction, AdjustRecruitment, AdjustTraining};
 
@@ -17,8 +19,17 @@ pub struct OrdersQueue {
 }
 
 impl OrdersQueue {
+    /// Queue a production order, replacing any pending order for the same
+    /// building and good so rapid adjustments collapse into one net target.
     pub fn queue_production(&mut self, order: AdjustProduction) {
-        self.production.push(order);
+        match self.production.iter_mut().find(|queued| {
+            queued.nation.entity() == order.nation.entity()
+                && queued.building == order.building
+                && queued.output_good == order.output_good
+        }) {
+            Some(queued) => queued.target_output = order.target_output,
+            None => self.production.push(order),
+        }
     }
 
     pub fn queue_recruitment(&mut self, order: AdjustRecruitment) {
@@ -61,6 +72,29 @@ impl OrdersQueue {
         &self.production
     }
 
+    /// Target of a production order queued but not yet executed
+    pub fn pending_production_target(
+        &self,
+        nation: Entity,
+        building: Entity,
+        output_good: Good,
+    ) -> Option<u32> {
+        self.production
+            .iter()
+            .find(|queued| {
+                queued.nation.entity() == nation
+                    && queued.building == building
+                    && queued.output_good == output_good
+            })
+            .map(|queued| queued.target_output)
+    }
+
+    /// Drop queued market orders for one nation and good
+    pub fn discard_market(&mut self, nation: Entity, good: Good) {
+        self.market
+            .retain(|queued| queued.nation.entity() != nation || queued.good != good);
+    }
+
     pub fn peek_market(&self) -> &[AdjustMarketOrder] {
         &self.market
     }
@@ -122,9 +156,47 @@ impl OrdersOut {
     }
 }
 
+/// Position of a nation in the emission order: its `NationId`, then its entity
+/// for nations that have none
+fn nation_rank(nation: Entity, nation_ids: &Query<&NationId>) -> (u32, Entity) {
+    let id = nation_ids.get(nation).map_or(u32::MAX, |id| id.0);
+    (id, nation)
+}
+
 /// Moves buffered AI orders into the shared queue so they can be executed alongside
 /// player-issued commands.
-pub fn flush_orders_to_queue(mut src: ResMut<OrdersOut>, mut dst: ResMut<OrdersQueue>) {
+///
+/// AI systems push orders in whatever order they iterate nations, so each kind of
+/// order is sorted by nation id and then by its own fields first; reservations
+/// made while executing them then come out the same on every run.
+pub fn flush_orders_to_queue(
+    mut src: ResMut<OrdersOut>,
+    mut dst: ResMut<OrdersQueue>,
+    nation_ids: Query<&NationId>,
+) {
+    let rank = |nation: Entity| nation_rank(nation, &nation_ids);
+    src.production.sort_by_key(|order| {
+        (
+            rank(order.nation.entity()),
+            order.building,
+            order.output_good,
+        )
+    });
+    src.recruitment
+        .sort_by_key(|order| rank(order.nation.entity()));
+    src.training
+        .sort_by_key(|order| (rank(order.nation.entity()), order.from_skill));
+    src.market
+        .sort_by_key(|order| (rank(order.nation.entity()), order.good, order.kind));
+    src.transport.sort_by_key(|order| {
+        (
+            order.nation.map(rank),
+            order.kind,
+            (order.a.x, order.a.y),
+            (order.b.x, order.b.y),
+        )
+    });
+
     for order in src.production.drain(..) {
         dst.queue_production(order);
     }
@@ -146,7 +218,7 @@ pub fn flush_orders_to_queue(mut src: ResMut<OrdersOut>, mut dst: ResMut<OrdersQ
 mod tests {
     use crate::orders::*;
     use bevy::ecs::system::RunSystemOnce;
-    use bevy::prelude::{ResMut, World};
+    use bevy::prelude::World;
     use moonshine_kind::Instance;
 
     use crate::economy::transport::{ImprovementKind, PlaceImprovement};
@@ -203,6 +275,40 @@ mod tests {
         assert!(queue.is_empty());
     }
 
+    #[test]
+    fn repeated_production_orders_coalesce() {
+        let mut world = World::new();
+        let nation_entity = world.spawn(Nation).id();
+        let nation = Instance::<Nation>::from_entity(world.entity(nation_entity)).unwrap();
+        let building = world.spawn_empty().id();
+
+        let mut queue = OrdersQueue::default();
+        for target in 1..=5 {
+            queue.queue_production(AdjustProduction {
+                nation,
+                building,
+                output_good: Good::Fabric,
+                target_output: target,
+            });
+        }
+        queue.queue_production(AdjustProduction {
+            nation,
+            building,
+            output_good: Good::Paper,
+            target_output: 1,
+        });
+
+        assert_eq!(queue.peek_production().len(), 2);
+        assert_eq!(
+            queue.pending_production_target(nation_entity, building, Good::Fabric),
+            Some(5)
+        );
+        assert_eq!(
+            queue.pending_production_target(nation_entity, building, Good::Lumber),
+            None
+        );
+    }
+
     #[test]
     fn clear_discards_orders() {
         let mut queue = OrdersQueue::default();
@@ -252,12 +358,59 @@ mod tests {
             });
         }
 
-        let _ = world.run_system_once(|out: ResMut<OrdersOut>, queue: ResMut<OrdersQueue>| {
-            flush_orders_to_queue(out, queue);
-        });
+        let _ = world.run_system_once(flush_orders_to_queue);
 
         let mut queue = world.resource_mut::<OrdersQueue>();
         assert_eq!(queue.take_market().len(), 1);
         assert_eq!(queue.take_transport().len(), 1);
     }
+
+    #[test]
+    fn flushed_orders_follow_nation_id_regardless_of_emission_order() {
+        let mut world = World::new();
+        // Spawn the higher id first so entity order and id order disagree
+        let second = world.spawn((Nation, NationId(2))).id();
+        let first = world.spawn((Nation, NationId(1))).id();
+        let instance =
+            |world: &World, entity| Instance::<Nation>::from_entity(world.entity(entity)).unwrap();
+        let orders: Vec<AdjustMarketOrder> = [
+            (second, Good::Coal, crate::messages::MarketInterest::Sell),
+            (first, Good::Wool, crate::messages::MarketInterest::Buy),
+            (second, Good::Coal, crate::messages::MarketInterest::Buy),
+            (first, Good::Coal, crate::messages::MarketInterest::Sell),
+        ]
+        .into_iter()
+        .map(|(nation, good, kind)| AdjustMarketOrder {
+            nation: instance(&world, nation),
+            good,
+            kind,
+            requested: 1,
+        })
+        .collect();
+
+        // Two turns emit the same orders, but iterate nations differently
+        let mut run_turn = |emitted: Vec<AdjustMarketOrder>| {
+            world.insert_resource(OrdersQueue::default());
+            world.insert_resource(OrdersOut::default());
+            {
+                let mut out = world.resource_mut::<OrdersOut>();
+                for order in emitted {
+                    out.queue_market(order);
+                }
+            }
+            let _ = world.run_system_once(flush_orders_to_queue);
+            world
+                .resource_mut::<OrdersQueue>()
+                .take_market()
+                .into_iter()
+                .map(|order| (order.nation.entity(), order.good, order.kind))
+                .collect::<Vec<_>>()
+        };
+        let forward = run_turn(orders.clone());
+        let reversed = run_turn(orders.into_iter().rev().collect());
+
+        assert_eq!(forward, reversed);
+        assert_eq!(forward[0].0, first, "lower nation id is flushed first");
+        assert_eq!(forward[0].1, Good::Coal, "then goods in a fixed order");
+    }
 }
@@ -1,9 +1,10 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::economy::goods::Good;
 
 /// Types of resources that can be found/developed on tiles
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 pub enum ResourceType {
     // Agriculture
     Grain,
@@ -61,6 +62,24 @@ pub const ALL_RESOURCES: &[ResourceType] = &[
 ];
 
 impl ResourceType {
+    /// Units a freshly discovered deposit holds before it runs dry.
+    /// `None` for renewable resources (farmland, pasture, forest, fisheries).
+    pub fn initial_reserves(self) -> Option<u32> {
+        match self {
+            ResourceType::Coal | ResourceType::Iron | ResourceType::Oil => Some(240),
+            ResourceType::Gold | ResourceType::Gems => Some(90),
+            _ => None,
+        }
+    }
+
+    /// Crops grown on farmland, the only yields hurt by industrial pollution
+    pub fn is_farmland(self) -> bool {
+        matches!(
+            self,
+            ResourceType::Grain | ResourceType::Fruit | ResourceType::Cotton
+        )
+    }
+
     /// Returns true when the resource provides a baseline yield without improvements.
     pub fn is_baseline_yield_eligible(self) -> bool {
         matches!(
@@ -76,15 +95,38 @@ impl ResourceType {
     }
 }
 
-/// Development level of a resource (0-3)
+/// Development level of a resource (0-4). Levels above Lv2 need technology,
+/// see [`Technologies::development_cap`](crate::economy::Technologies::development_cap)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Reflect)]
 pub enum DevelopmentLevel {
     Lv0 = 0, // Undeveloped
     Lv1 = 1, // Basic development
     Lv2 = 2, // Improved
-    Lv3 = 3, // Fully developed
+    Lv3 = 3, // Advanced
+    Lv4 = 4, // Fully developed
 }
 
+impl DevelopmentLevel {
+    pub const MAX: DevelopmentLevel = DevelopmentLevel::Lv4;
+
+    /// The level above this one, if any
+    pub fn next(self) -> Option<DevelopmentLevel> {
+        match self {
+            DevelopmentLevel::Lv0 => Some(DevelopmentLevel::Lv1),
+            DevelopmentLevel::Lv1 => Some(DevelopmentLevel::Lv2),
+            DevelopmentLevel::Lv2 => Some(DevelopmentLevel::Lv3),
+            DevelopmentLevel::Lv3 => Some(DevelopmentLevel::Lv4),
+            DevelopmentLevel::Lv4 => None,
+        }
+    }
+}
+
+/// Pollution needed to cost a farmland tile one unit of yield
+pub const POLLUTION_PER_LOST_YIELD: u32 = 3;
+
+/// Development at which a farm is irrigated and shares water with neighbouring farms
+pub const IRRIGATION_LEVEL: DevelopmentLevel = DevelopmentLevel::Lv2;
+
 /// Component marking a tile as having a resource
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 #[reflect(Component)]
@@ -92,6 +134,10 @@ pub struct TileResource {
     pub resource_type: ResourceType,
     pub development: DevelopmentLevel,
     pub discovered: bool, // Minerals start false, must be discovered by Prospector
+    /// Units left in a non-renewable deposit; `None` never runs out
+    pub reserves: Option<u32>,
+    /// Pollution from nearby heavy industry; lowers farmland yields until it decays
+    pub pollution: u32,
 }
 
 impl TileResource {
@@ -101,6 +147,8 @@ impl TileResource {
             resource_type,
             development: DevelopmentLevel::Lv0,
             discovered: true,
+            reserves: resource_type.initial_reserves(),
+            pollution: 0,
         }
     }
 
@@ -122,39 +170,87 @@ impl TileResource {
             resource_type,
             development: DevelopmentLevel::Lv0,
             discovered: false,
+            reserves: resource_type.initial_reserves(),
+            pollution: 0,
         }
     }
 
-    /// Get per-turn output based on resource type and development level
+    /// True once a non-renewable deposit has been mined out
+    pub fn is_exhausted(&self) -> bool {
+        self.reserves == Some(0)
+    }
+
+    /// Remove `amount` collected units from the deposit's reserves
+    pub fn deplete(&mut self, amount: u32) {
+        if let Some(reserves) = self.reserves.as_mut() {
+            *reserves = reserves.saturating_sub(amount);
+        }
+    }
+
+    /// Get per-turn output based on resource type and development level,
+    /// reduced by pollution on farmland and capped by whatever is left in the deposit
     pub fn get_output(&self) -> u32 {
+        let mut output = self.base_output();
+        if self.resource_type.is_farmland() {
+            output = output.saturating_sub(self.pollution / POLLUTION_PER_LOST_YIELD);
+        }
+        match self.reserves {
+            Some(reserves) => output.min(reserves),
+            None => output,
+        }
+    }
+
+    /// Extra yield from developed neighbours: an irrigated farm gains one unit when
+    /// an adjacent farm growing the same crop is irrigated too
+    pub fn adjacency_bonus<'a>(
+        &self,
+        neighbors: impl IntoIterator<Item = &'a TileResource>,
+    ) -> u32 {
+        if !self.resource_type.is_farmland()
+            || self.development < IRRIGATION_LEVEL
+            || self.get_output() == 0
+        {
+            return 0;
+        }
+        neighbors.into_iter().any(|neighbor| {
+            neighbor.discovered
+                && neighbor.resource_type == self.resource_type
+                && neighbor.development >= IRRIGATION_LEVEL
+        }) as u32
+    }
+
+    fn base_output(&self) -> u32 {
         if !self.discovered {
             return 0;
         }
 
         if self.resource_type.is_baseline_yield_eligible() {
-            // Food/fiber/timber: 1/2/3/4
+            // Food/fiber/timber: 1/2/3/4/5
             return match self.development {
                 DevelopmentLevel::Lv0 => 1,
                 DevelopmentLevel::Lv1 => 2,
                 DevelopmentLevel::Lv2 => 3,
                 DevelopmentLevel::Lv3 => 4,
+                DevelopmentLevel::Lv4 => 5,
             };
         }
 
         match self.resource_type {
-            // Coal/iron/oil: 0/2/4/6
+            // Coal/iron/oil: 0/2/4/6/8
             ResourceType::Coal | ResourceType::Iron | ResourceType::Oil => match self.development {
                 DevelopmentLevel::Lv0 => 0,
                 DevelopmentLevel::Lv1 => 2,
                 DevelopmentLevel::Lv2 => 4,
                 DevelopmentLevel::Lv3 => 6,
+                DevelopmentLevel::Lv4 => 8,
             },
-            // Gold/gems: 0/1/2/3
+            // Gold/gems: 0/1/2/3/4
             ResourceType::Gold | ResourceType::Gems => match self.development {
                 DevelopmentLevel::Lv0 => 0,
                 DevelopmentLevel::Lv1 => 1,
                 DevelopmentLevel::Lv2 => 2,
                 DevelopmentLevel::Lv3 => 3,
+                DevelopmentLevel::Lv4 => 4,
             },
             _ => 0,
         }
@@ -194,26 +290,19 @@ impl TileResource {
         matches!(self.resource_type, ResourceType::Oil)
     }
 
-    /// Improve development level (returns true if improved)
-    pub fn improve(&mut self) -> bool {
+    /// Improve development level without going past `cap` (returns true if improved)
+    pub fn improve(&mut self, cap: DevelopmentLevel) -> bool {
         if !self.discovered {
             return false;
         }
 
-        match self.development {
-            DevelopmentLevel::Lv0 => {
-                self.development = DevelopmentLevel::Lv1;
-                true
-            }
-            DevelopmentLevel::Lv1 => {
-                self.development = DevelopmentLevel::Lv2;
-                true
-            }
-            DevelopmentLevel::Lv2 => {
-                self.development = DevelopmentLevel::Lv3;
+        match self.development.next() {
+            Some(next) if next <= cap => {
+                self.development = next;
                 true
             }
-            DevelopmentLevel::Lv3 => false, // Already max level
+            // Already at the cap or the max level
+            _ => false,
         }
     }
 }
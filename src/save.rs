@@ -1,4 +1,5 @@
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use bevy::ecs::system::RunSystemOnce;
 use bevy::prelude::*;
@@ -6,30 +7,54 @@ use bevy_ecs_tilemap::prelude::{
     TileColor, TileFlip, TilePos, TilePosOld, TileTextureIndex, TileVisible, TilemapId,
 };
 use moonshine_save::prelude::*;
+use thiserror::Error;
 
 use crate::ai::markers::{AiControlledCivilian, AiNation};
+use crate::civilians::jobs::{JobInputOwners, reserve_job_inputs};
 use crate::civilians::{
-    ActionTurn, Civilian, CivilianId, CivilianJob, CivilianKind, CivilianOrder, CivilianOrderKind,
-    JobType, NextCivilianId, PreviousPosition, ProspectingKnowledge,
+    ActionTurn, AreaImprovementQueue, Civilian, CivilianId, CivilianJob, CivilianKind,
+    CivilianOrder, CivilianOrderKind, JobType, NextCivilianId, PreviousPosition,
+    ProspectingKnowledge,
 };
-use crate::economy::allocation::Allocations;
+use crate::diplomacy::persistence::{
+    SavedAllianceCall, SavedDiplomacy, SavedGrant, SavedOffer, SavedOfferKind, SavedRelation,
+};
+use crate::diplomacy::{DiplomacyState, DiplomaticOffers, ForeignAidLedger, TreatyState};
+use crate::economy::allocation::{AllocationSnapshot, Allocations};
+use crate::economy::allocation_systems::{
+    execute_queued_market_orders, execute_queued_production_orders,
+    execute_queued_recruitment_orders, execute_queued_training_orders,
+};
+use crate::economy::city_stockpile::CityStockpile;
 use crate::economy::goods::Good;
-use crate::economy::nation::{Capital, Nation, NationColor, PlayerNation};
-use crate::economy::production::{Building, BuildingKind, Buildings, ProductionSettings};
+use crate::economy::nation::{
+    Capital, CapitalLost, Nation, NationColor, NationColorSlot, NationId, NationInstance,
+    PlayerNation,
+};
+use crate::economy::production::{
+    Building, BuildingKind, Buildings, ProductionReport, ProductionResult, ProductionSettings,
+    fire_power_plants,
+};
 use crate::economy::reservation::{ReservationSystem, ResourcePool};
-use crate::economy::stockpile::Stockpile;
-use crate::economy::technology::{Technologies, Technology};
-use crate::economy::transport::{Depot, ImprovementKind, Port, RailConstruction, Rails};
+use crate::economy::stockpile::{Stockpile, StockpileReserves};
+use crate::economy::technology::{ResearchProgress, Technologies, Technology};
+use crate::economy::transport::{
+    Bridges, Depot, ImprovementKind, Port, RailConnectivityCache, RailConstruction, Rails,
+    RecomputeConnectivity,
+};
 use crate::economy::treasury::Treasury;
+use crate::economy::upkeep::UpkeepArrears;
 use crate::economy::workforce::{
-    RecruitmentCapacity, RecruitmentQueue, TrainingQueue, Worker, WorkerHealth, WorkerSkill,
-    Workforce,
+    FoodSupply, PopulationGrowth, RecruitmentCapacity, RecruitmentQueue, TrainingQueue, Worker,
+    WorkerHealth, WorkerSkill, Workforce,
 };
 use crate::economy::{Calendar, Season};
 use crate::map::province::{City, Province, ProvinceId, TileProvince};
 use crate::map::tiles::TerrainType;
+use crate::orders::OrdersQueue;
 use crate::resources::{DevelopmentLevel, ResourceType, TileResource};
-use crate::turn_system::{TurnCounter, TurnPhase};
+use crate::turn_system::{HumanNations, TurnCounter, TurnPhase};
+use crate::ui::city::dialogs::DialogLayout;
 use crate::ui::menu::AppState;
 
 /// Plugin that wires the moonshine save/load pipeline into the game.
@@ -75,6 +100,63 @@ pub struct LoadGameCompleted {
     pub path: PathBuf,
 }
 
+/// Notification emitted when a save file is rejected before loading.
+#[derive(Message, Clone)]
+pub struct LoadGameFailed {
+    pub path: PathBuf,
+    pub error: SaveFileError,
+}
+
+/// Reasons a save file is refused by the loader.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SaveFileError {
+    #[error("Failed to read save file: {0}")]
+    Io(String),
+    #[error("Save file has no checksum header")]
+    MissingChecksum,
+    #[error("Save file checksum mismatch (expected {expected:016x}, found {actual:016x})")]
+    ChecksumMismatch { expected: u64, actual: u64 },
+}
+
+/// First line of every save file; RON treats it as a comment.
+const CHECKSUM_HEADER: &str = "// checksum: ";
+
+/// FNV-1a over the serialized payload
+fn payload_checksum(payload: &[u8]) -> u64 {
+    payload.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Prepend the checksum header to a freshly written save file
+fn stamp_checksum(path: &Path) -> Result<(), SaveFileError> {
+    let payload = fs::read(path).map_err(|e| SaveFileError::Io(e.to_string()))?;
+    let mut contents =
+        format!("{CHECKSUM_HEADER}{:016x}\n", payload_checksum(&payload)).into_bytes();
+    contents.extend_from_slice(&payload);
+    fs::write(path, contents).map_err(|e| SaveFileError::Io(e.to_string()))
+}
+
+/// Check the header checksum against the payload that follows it
+fn verify_checksum(path: &Path) -> Result<(), SaveFileError> {
+    let contents = fs::read(path).map_err(|e| SaveFileError::Io(e.to_string()))?;
+    let newline = contents
+        .iter()
+        .position(|&byte| byte == b'\n')
+        .ok_or(SaveFileError::MissingChecksum)?;
+    let (header, payload) = (&contents[..newline], &contents[newline + 1..]);
+    let expected = std::str::from_utf8(header)
+        .ok()
+        .and_then(|line| line.strip_prefix(CHECKSUM_HEADER))
+        .and_then(|hex| u64::from_str_radix(hex.trim(), 16).ok())
+        .ok_or(SaveFileError::MissingChecksum)?;
+    let actual = payload_checksum(payload);
+    if actual != expected {
+        return Err(SaveFileError::ChecksumMismatch { expected, actual });
+    }
+    Ok(())
+}
+
 #[derive(Resource, Default)]
 struct PendingSave {
     path: Option<PathBuf>,
@@ -90,12 +172,14 @@ impl Plugin for GameSavePlugin {
         register_reflect_types(app);
 
         app.init_resource::<SaveSettings>()
+            .init_resource::<DialogLayout>()
             .init_resource::<PendingSave>()
             .init_resource::<PendingLoad>()
             .add_message::<SaveGameRequest>()
             .add_message::<LoadGameRequest>()
             .add_message::<SaveGameCompleted>()
             .add_message::<LoadGameCompleted>()
+            .add_message::<LoadGameFailed>()
             .add_observer(save_on_default_event)
             .add_observer(load_on_default_event)
             .add_observer(emit_save_completion)
@@ -112,14 +196,30 @@ fn register_reflect_types(app: &mut App) {
     app.register_type::<Nation>()
         .register_type::<Name>()
         .register_type::<NationColor>()
+        .register_type::<NationColorSlot>()
+        .register_type::<NationId>()
+        .register_type::<TreatyState>()
+        .register_type::<SavedRelation>()
+        .register_type::<SavedAllianceCall>()
+        .register_type::<SavedGrant>()
+        .register_type::<SavedOffer>()
+        .register_type::<SavedOfferKind>()
+        .register_type::<SavedDiplomacy>()
         .register_type::<Capital>()
+        .register_type::<CapitalLost>()
         .register_type::<Technology>()
         .register_type::<Technologies>()
+        .register_type::<ResearchProgress>()
         .register_type::<Good>()
         .register_type::<ResourcePool>()
+        .register_type::<AllocationSnapshot>()
         .register_type::<Stockpile>()
+        .register_type::<StockpileReserves>()
         .register_type::<Treasury>()
+        .register_type::<UpkeepArrears>()
         .register_type::<ProductionSettings>()
+        .register_type::<ProductionResult>()
+        .register_type::<ProductionReport>()
         .register_type::<Building>()
         .register_type::<Buildings>()
         .register_type::<BuildingKind>()
@@ -134,9 +234,12 @@ fn register_reflect_types(app: &mut App) {
         .register_type::<Worker>()
         .register_type::<WorkerSkill>()
         .register_type::<WorkerHealth>()
+        .register_type::<FoodSupply>()
+        .register_type::<PopulationGrowth>()
         .register_type::<Civilian>()
         .register_type::<CivilianOrder>()
         .register_type::<CivilianJob>()
+        .register_type::<AreaImprovementQueue>()
         .register_type::<PreviousPosition>()
         .register_type::<ActionTurn>()
         .register_type::<CivilianKind>()
@@ -145,14 +248,17 @@ fn register_reflect_types(app: &mut App) {
         .register_type::<ProspectingKnowledge>()
         .register_type::<CivilianId>()
         .register_type::<NextCivilianId>()
+        .register_type::<DialogLayout>()
         .register_type::<ProvinceId>()
         .register_type::<Province>()
         .register_type::<City>()
+        .register_type::<CityStockpile>()
         .register_type::<ImprovementKind>()
         .register_type::<Depot>()
         .register_type::<Port>()
         .register_type::<RailConstruction>()
         .register_type::<Rails>()
+        .register_type::<Bridges>()
         .register_type::<AiNation>()
         .register_type::<AiControlledCivilian>()
         .register_type::<TerrainType>()
@@ -174,6 +280,13 @@ fn process_save_requests(
     mut requests: MessageReader<SaveGameRequest>,
     settings: Res<SaveSettings>,
     mut pending: ResMut<PendingSave>,
+    diplomacy: (
+        Option<Res<DiplomacyState>>,
+        Option<Res<ForeignAidLedger>>,
+        Option<Res<DiplomaticOffers>>,
+    ),
+    nation_ids: Query<(NationInstance, &NationId)>,
+    allocations: Query<(Entity, &Allocations), With<Nation>>,
 ) {
     for request in requests.read() {
         let path = request
@@ -181,14 +294,31 @@ fn process_save_requests(
             .clone()
             .unwrap_or_else(|| settings.default_path.clone());
 
+        // Diplomacy is keyed by entity at runtime; persist it by NationId instead
+        if let (Some(state), Some(ledger), Some(offers)) = &diplomacy {
+            let ids = nation_ids
+                .iter()
+                .map(|(nation, id)| (nation, *id))
+                .collect();
+            commands.insert_resource(SavedDiplomacy::capture(state, ledger, offers, &ids));
+        }
+
+        // Reservations are not saved; keep the pending allocations as counts instead
+        for (nation, allocations) in allocations.iter() {
+            commands.entity(nation).insert(allocations.snapshot(nation));
+        }
+
         let event = SaveWorld::default_into_file(path.clone())
             .exclude_component::<Allocations>()
             .exclude_component::<ReservationSystem>()
             .include_resource::<Calendar>()
             .include_resource::<TurnCounter>()
             .include_resource::<Rails>()
+            .include_resource::<Bridges>()
             .include_resource::<ProspectingKnowledge>()
-            .include_resource::<NextCivilianId>();
+            .include_resource::<NextCivilianId>()
+            .include_resource::<DialogLayout>()
+            .include_resource::<SavedDiplomacy>();
 
         commands.trigger_save(event);
         pending.path = Some(path);
@@ -200,6 +330,7 @@ fn process_load_requests(
     mut requests: MessageReader<LoadGameRequest>,
     settings: Res<SaveSettings>,
     mut pending: ResMut<PendingLoad>,
+    mut failed: MessageWriter<LoadGameFailed>,
 ) {
     for request in requests.read() {
         let path = request
@@ -207,6 +338,13 @@ fn process_load_requests(
             .clone()
             .unwrap_or_else(|| settings.default_path.clone());
 
+        // Refuse corrupted or hand-edited files before the deserializer sees them
+        if let Err(error) = verify_checksum(&path) {
+            error!("Refusing to load {}: {error}", path.display());
+            failed.write(LoadGameFailed { path, error });
+            continue;
+        }
+
         commands.trigger_load(LoadWorld::default_from_file(path.clone()));
         pending.path = Some(path);
     }
@@ -214,10 +352,20 @@ fn process_load_requests(
 
 fn emit_save_completion(
     _: On<Saved>,
+    mut commands: Commands,
     mut pending: ResMut<PendingSave>,
     mut completed: MessageWriter<SaveGameCompleted>,
+    snapshots: Query<Entity, With<AllocationSnapshot>>,
 ) {
+    // The snapshots only exist to be written out; play continues from the live allocations
+    for nation in snapshots.iter() {
+        commands.entity(nation).remove::<AllocationSnapshot>();
+    }
+
     if let Some(path) = pending.path.take() {
+        if let Err(error) = stamp_checksum(&path) {
+            error!("Failed to checksum save {}: {error}", path.display());
+        }
         completed.write(SaveGameCompleted { path });
     }
 }
@@ -302,333 +450,131 @@ fn rebuild_runtime_state_after_load(
         });
     }
 
-    // Re-attach tile input observers once after a scene load.
+    // Hotseat order is keyed by pre-load entities; rebuild it from the loaded human nations
     commands.queue(|world: &mut World| {
-        let _ = world.run_system_once(crate::map::setup_tilemap_input);
+        let mut humans: Vec<(u32, NationInstance)> = world
+            .query_filtered::<(NationInstance, &NationId), Without<AiNation>>()
+            .iter(world)
+            .map(|(nation, id)| (id.0, nation))
+            .collect();
+        if humans.is_empty() {
+            world.remove_resource::<HumanNations>();
+            return;
+        }
+        humans.sort_by_key(|(id, _)| *id);
+        let humans = humans.into_iter().map(|(_, nation)| nation).collect();
+        let humans = match world.get_resource::<PlayerNation>() {
+            Some(player) => HumanNations::resuming(humans, player.instance()),
+            None => HumanNations::new(humans),
+        };
+        world.insert_resource(humans);
     });
-}
-
-#[cfg(test)]
-mod tests {
-    use std::fs;
-    use std::path::PathBuf;
-
-    use bevy::app::App;
-
-    use bevy::ecs::message::{MessageReader, MessageWriter};
-    use bevy::ecs::reflect::ReflectMapEntities;
-    use bevy::ecs::system::RunSystemOnce;
-    use bevy::prelude::{
-        AppExtStates, AppTypeRegistry, Color, Commands, Component, Entity, MinimalPlugins, Reflect,
-        ReflectComponent,
-    };
-
-    use bevy::state::app::StatesPlugin;
-    use bevy_ecs_tilemap::prelude::TilePos;
-
-    use moonshine_save::prelude::Save;
-
-    use crate::civilians::{Civilian, CivilianId, CivilianKind};
-    use crate::economy::allocation::Allocations;
-    use crate::economy::goods::Good;
-    use crate::economy::nation::{Capital, Nation, NationColor, PlayerNation};
-    use crate::economy::reservation::ReservationSystem;
-    use crate::economy::stockpile::Stockpile;
-    use crate::economy::technology::{Technologies, Technology};
-    use crate::economy::transport::Rails;
-    use crate::economy::treasury::Treasury;
-    use crate::economy::workforce::{RecruitmentQueue, TrainingQueue, Workforce};
-    use crate::economy::{Calendar, Season};
-    use crate::save::{
-        GameSavePlugin, LoadGameCompleted, LoadGameRequest, SaveGameCompleted, SaveGameRequest,
-    };
-    use crate::turn_system::TurnCounter;
-    use crate::ui::menu::AppState;
-    use bevy::prelude::Name;
-
-    #[derive(Component, Reflect, Default, Clone)]
-    #[reflect(Component)]
-    struct SerializableComponent {
-        value: i32,
-    }
 
-    fn temp_save_path(label: &str) -> PathBuf {
-        let mut path = std::env::temp_dir();
-        path.push(format!(
-            "rust_imperialism_{label}_{}.ron",
-            rand::random::<u64>()
-        ));
-        path
-    }
-
-    fn init_test_app() -> App {
-        let mut app = App::new();
-        app.add_plugins((MinimalPlugins, StatesPlugin));
-        app.insert_state(AppState::InGame);
-        app.add_plugins(GameSavePlugin);
-        app.register_type::<SerializableComponent>();
+    // Resolve saved diplomacy against the reloaded nation entities
+    commands.queue(|world: &mut World| {
+        let Some(saved) = world.remove_resource::<SavedDiplomacy>() else {
+            return;
+        };
+        let nations = world
+            .query::<(NationInstance, &NationId)>()
+            .iter(world)
+            .map(|(nation, id)| (*id, nation))
+            .collect();
+        let (state, ledger, offers) = saved.restore(&nations);
+        world.insert_resource(state);
+        world.insert_resource(ledger);
+        world.insert_resource(offers);
+    });
 
-        {
-            let world = app.world_mut();
-            world.insert_resource(Calendar::default());
-            world.insert_resource(TurnCounter::default());
-            world.insert_resource(Rails::default());
+    // Cached reachability is keyed by pre-load nation entities; rebuild it from the loaded rails
+    commands.queue(|world: &mut World| {
+        if let Some(mut cache) = world.get_resource_mut::<RailConnectivityCache>() {
+            cache.invalidate();
         }
+        world.trigger(RecomputeConnectivity);
+    });
 
-        app
-    }
-
-    #[test]
-    fn save_request_creates_file_and_completion_message() {
-        let mut app = init_test_app();
-        let path = temp_save_path("save_request");
-
-        let request_path = path.clone();
-        let _ = app.world_mut().run_system_once(
-            move |mut commands: Commands, mut writer: MessageWriter<SaveGameRequest>| {
-                commands.spawn((SerializableComponent { value: 42 }, Save));
-                writer.write(SaveGameRequest {
-                    path: Some(request_path.clone()),
-                });
-            },
-        );
-
-        app.update();
-        app.update();
-
-        let completions = app
-            .world_mut()
-            .run_system_once(|mut reader: MessageReader<SaveGameCompleted>| {
-                reader.read().cloned().collect::<Vec<_>>()
-            })
-            .unwrap();
-        assert_eq!(completions.len(), 1);
-        assert_eq!(completions[0].path, path);
-
-        let contents = fs::read_to_string(&completions[0].path).unwrap();
-        assert!(contents.contains("SerializableComponent"));
-        assert!(contents.contains("42"));
-
-        fs::remove_file(completions[0].path.clone()).unwrap();
-    }
-
-    #[test]
-    fn nation_entities_are_marked_for_save() {
-        let mut app = init_test_app();
-        let nation = app.world_mut().spawn(Nation).id();
-
-        app.update();
-
-        assert!(app.world().entity(nation).contains::<Save>());
-    }
-
-    #[test]
-    fn civilian_component_registers_map_entities() {
-        let app = init_test_app();
-        let registry = app.world().resource::<AppTypeRegistry>().read();
-        let registration = registry
-            .get(std::any::TypeId::of::<Civilian>())
-            .expect("civilian type registered");
-        assert!(registration.data::<ReflectMapEntities>().is_some());
-    }
-
-    #[test]
-    fn civilian_owner_is_remapped_when_loading_scene() {
-        // This test verifies that Civilian's MapEntities derive is properly registered
-        // and that moonshine-save will use it during load.
-        // The actual remapping behavior is tested by saving_and_loading_persists_core_state.
-        let registry = AppTypeRegistry::default();
-        {
-            let mut writer = registry.write();
-            writer.register::<Civilian>();
-            writer.register::<Nation>();
+    // Reserved amounts in the loaded stockpiles were held by reservations that no
+    // longer exist; release them and replay the saved allocations to reserve afresh
+    commands.queue(|world: &mut World| {
+        let mut nations = world.query_filtered::<(
+            NationInstance,
+            Option<&mut Stockpile>,
+            Option<&mut Workforce>,
+            Option<&mut Treasury>,
+            Option<&AllocationSnapshot>,
+        ), With<Nation>>();
+        let mut snapshots = Vec::new();
+        for (nation, stockpile, workforce, treasury, snapshot) in nations.iter_mut(world) {
+            if let Some(mut stockpile) = stockpile {
+                stockpile.clear_reserved();
+            }
+            if let Some(mut workforce) = workforce {
+                workforce.labor_pool.reserved = 0;
+            }
+            if let Some(mut treasury) = treasury {
+                treasury.clear_reserved();
+            }
+            if let Some(snapshot) = snapshot {
+                snapshots.push((nation, snapshot.clone()));
+            }
         }
 
-        // Verify MapEntities is registered for Civilian
-        let reader = registry.read();
-        let registration = reader
-            .get(std::any::TypeId::of::<Civilian>())
-            .expect("Civilian type registered");
-        assert!(
-            registration.data::<ReflectMapEntities>().is_some(),
-            "Civilian should have MapEntities reflection data"
-        );
-    }
+        // Power plants fired at the start of the turn hold their fuel until it ends
+        run_after_load(world, fire_power_plants);
 
-    #[test]
-    fn load_request_rebuilds_player_nation_runtime_state() {
-        let mut app = init_test_app();
-        let path = temp_save_path("load_request");
-
-        let save_request_path = path.clone();
-        let _ = app.world_mut().run_system_once(
-            move |mut commands: Commands, mut writer: MessageWriter<SaveGameRequest>| {
-                commands.spawn((
-                    Save,
-                    Nation,
-                    Name::new("Player"),
-                    Allocations::default(),
-                    ReservationSystem::default(),
-                ));
-                writer.write(SaveGameRequest {
-                    path: Some(save_request_path.clone()),
-                });
-            },
-        );
-
-        app.update();
-        app.update();
-
-        assert!(fs::metadata(&path).is_ok());
-
-        let mut app = init_test_app();
-        let load_request_path = path.clone();
-        let _ =
-            app.world_mut()
-                .run_system_once(move |mut writer: MessageWriter<LoadGameRequest>| {
-                    writer.write(LoadGameRequest {
-                        path: Some(load_request_path.clone()),
-                    });
-                });
-
-        app.update();
-        app.update();
-        app.update();
-
-        let completions = app
-            .world_mut()
-            .run_system_once(|mut reader: MessageReader<LoadGameCompleted>| {
-                reader.read().cloned().collect::<Vec<_>>()
+        // So do civilian jobs started this turn; older jobs already committed theirs
+        let current_turn = world.get_resource::<TurnCounter>().map(|turn| turn.current);
+        let jobs: Vec<(Entity, Entity, JobType)> = world
+            .query::<(Entity, &Civilian, &CivilianJob, &ActionTurn)>()
+            .iter(world)
+            .filter(|(_, _, job, action_turn)| {
+                Some(action_turn.0) == current_turn && !job.job_type.inputs().is_empty()
             })
-            .unwrap();
-        assert_eq!(completions.len(), 1);
-        assert_eq!(completions[0].path, path);
-
-        let player_nation_entity = app.world().resource::<PlayerNation>().entity();
-        let entity = app.world().entity(player_nation_entity);
-        assert!(entity.contains::<Nation>());
-        assert!(entity.contains::<Allocations>());
-        assert!(entity.contains::<ReservationSystem>());
-
-        fs::remove_file(completions[0].path.clone()).unwrap();
-    }
-
-    #[test]
-    fn saving_and_loading_persists_core_state() {
-        let mut app = init_test_app();
-        let path = temp_save_path("core_state");
-
-        {
-            let mut calendar = app.world_mut().resource_mut::<Calendar>();
-            calendar.season = Season::Autumn;
-            calendar.year = 1822;
-        }
-
-        {
-            let mut turn_counter = app.world_mut().resource_mut::<TurnCounter>();
-            turn_counter.current = 5;
+            .map(|(entity, civilian, job, _)| (entity, civilian.owner, job.job_type))
+            .collect();
+        if !jobs.is_empty() {
+            run_after_load(world, move |mut owners: JobInputOwners| {
+                for &(civilian, owner, job_type) in &jobs {
+                    if let Err(error) = reserve_job_inputs(civilian, owner, job_type, &mut owners) {
+                        warn!(
+                            "Could not reserve inputs for {:?}'s {:?} after load: {:?}",
+                            civilian, job_type, error
+                        );
+                    }
+                }
+            });
         }
 
-        let nation_entity = app
-            .world_mut()
-            .spawn((
-                Nation,
-                Name::new("Rustonia"),
-                NationColor(Color::srgb(0.3, 0.4, 0.8)),
-                Capital(TilePos { x: 4, y: 9 }),
-                Treasury::new(1_234),
-                Stockpile::default(),
-                Technologies::default(),
-                Workforce::default(),
-                RecruitmentQueue::default(),
-                TrainingQueue::default(),
-            ))
-            .id();
-
-        {
-            let mut entity = app.world_mut().entity_mut(nation_entity);
-            let mut stockpile = entity.get_mut::<Stockpile>().unwrap();
-            stockpile.add(Good::Steel, 5);
-            stockpile.add(Good::Grain, 12);
-
-            let mut techs = entity.get_mut::<Technologies>().unwrap();
-            techs.unlock(Technology::MountainEngineering);
+        if snapshots.is_empty() {
+            return;
         }
 
-        app.world_mut().spawn(Civilian {
-            kind: CivilianKind::Engineer,
-            position: TilePos { x: 4, y: 9 },
-            owner: nation_entity,
-            civilian_id: CivilianId(1),
-            has_moved: false,
-        });
-
-        let save_request_path = path.clone();
-        let _ =
-            app.world_mut()
-                .run_system_once(move |mut writer: MessageWriter<SaveGameRequest>| {
-                    writer.write(SaveGameRequest {
-                        path: Some(save_request_path.clone()),
-                    });
-                });
-
-        app.update();
-        app.update();
-        assert!(fs::metadata(&path).is_ok());
-
-        let mut app = init_test_app();
-        {
-            app.world_mut().resource_mut::<Calendar>().year = 1900;
-            app.world_mut().resource_mut::<TurnCounter>().current = 1;
+        world.init_resource::<OrdersQueue>();
+        for (nation, snapshot) in snapshots {
+            world
+                .entity_mut(nation.entity())
+                .remove::<AllocationSnapshot>();
+            snapshot.queue_orders(nation, &mut world.resource_mut::<OrdersQueue>());
         }
+        run_after_load(world, execute_queued_recruitment_orders);
+        run_after_load(world, execute_queued_training_orders);
+        run_after_load(world, execute_queued_production_orders);
+        run_after_load(world, execute_queued_market_orders);
+    });
 
-        let load_request_path = path.clone();
-        let _ =
-            app.world_mut()
-                .run_system_once(move |mut writer: MessageWriter<LoadGameRequest>| {
-                    writer.write(LoadGameRequest {
-                        path: Some(load_request_path.clone()),
-                    });
-                });
-
-        app.update();
-        app.update();
-        app.update();
-
-        let calendar = app.world().resource::<Calendar>();
-        assert_eq!(calendar.year, 1822);
-        assert_eq!(calendar.season, Season::Autumn);
-
-        let turn_counter = app.world().resource::<TurnCounter>();
-        assert_eq!(turn_counter.current, 5);
-
-        {
-            let world = app.world_mut();
-            let mut nation_query = world.query::<(Entity, &Name, &Treasury, &Stockpile)>();
-            let (nation_entity, name, treasury, stockpile) = nation_query
-                .iter(world)
-                .find(|(_, name, _, _)| name.as_str() == "Rustonia")
-                .expect("nation restored");
-
-            assert_eq!(name.as_str(), "Rustonia");
-            assert_eq!(treasury.total(), 1_234i64);
-            assert_eq!(stockpile.get(Good::Steel), 5u32);
-            assert_eq!(stockpile.get(Good::Grain), 12u32);
-
-            let mut tech_query = world.query::<&Technologies>();
-            let techs = tech_query
-                .get(world, nation_entity)
-                .expect("technologies restored");
-            assert!(techs.has(Technology::MountainEngineering));
-
-            let mut civilian_query = world.query::<&Civilian>();
-            let civilian = civilian_query
-                .iter(world)
-                .find(|civilian| civilian.owner == nation_entity)
-                .expect("civilian restored");
-            assert_eq!(civilian.kind, CivilianKind::Engineer);
-        }
+    // Re-attach tile input observers once after a scene load.
+    commands.queue(|world: &mut World| {
+        run_after_load(world, crate::map::setup_tilemap_input);
+    });
+}
 
-        fs::remove_file(path).unwrap();
+/// Run one step of the post-load rebuild, reporting a failure instead of dropping it
+fn run_after_load<M>(world: &mut World, system: impl IntoSystem<(), (), M>) {
+    if let Err(error) = world.run_system_once(system) {
+        error!("Failed to rebuild state after load: {error}");
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,923 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::app::App;
+
+use bevy::ecs::message::{MessageReader, MessageWriter};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::{
+    AppExtStates, AppTypeRegistry, Color, Commands, Component, Entity, MinimalPlugins, Reflect,
+    ReflectComponent, With,
+};
+
+use bevy::state::app::StatesPlugin;
+use bevy_ecs_tilemap::prelude::TilePos;
+
+use moonshine_save::prelude::Save;
+
+use crate::ai::markers::AiNation;
+use crate::civilians::{
+    ActionTurn, Civilian, CivilianId, CivilianJob, CivilianKind, IMPROVEMENT_TIMBER, JobType,
+};
+use crate::diplomacy::{DiplomacyState, DiplomaticOffers, ForeignAidLedger, RecurringGrant};
+use crate::economy::allocation::{AllocationSnapshot, Allocations};
+use crate::economy::allocation_systems::execute_queued_production_orders;
+use crate::economy::goods::Good;
+use crate::economy::nation::{
+    Capital, Nation, NationColor, NationId, NationInstance, PlayerNation,
+};
+use crate::economy::production::{Building, Buildings, POWER_PLANT_FUEL_PER_TURN};
+use crate::economy::reservation::ReservationSystem;
+use crate::economy::stockpile::{Stockpile, StockpileReserves};
+use crate::economy::technology::{Technologies, Technology};
+use crate::economy::transport::{
+    Bridges, Depot, RailConnectivityCache, RailConstruction, Rails, RecomputeConnectivity,
+    advance_rail_construction, compute_rail_connectivity, ordered_edge,
+};
+use crate::economy::treasury::Treasury;
+use crate::economy::workforce::{RecruitmentQueue, TrainingQueue, Workforce};
+use crate::economy::{Calendar, Season};
+use crate::messages::AdjustProduction;
+use crate::orders::OrdersQueue;
+use crate::save::{
+    GameSavePlugin, LoadGameCompleted, LoadGameFailed, LoadGameRequest, SaveFileError,
+    SaveGameCompleted, SaveGameRequest,
+};
+use crate::turn_system::{HumanNations, TurnCounter};
+use crate::ui::menu::AppState;
+use bevy::prelude::Name;
+
+#[derive(Component, Reflect, Default, Clone)]
+#[reflect(Component)]
+struct SerializableComponent {
+    value: i32,
+}
+
+fn temp_save_path(label: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!(
+        "rust_imperialism_{label}_{}.ron",
+        rand::random::<u64>()
+    ));
+    path
+}
+
+fn init_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin));
+    app.insert_state(AppState::InGame);
+    app.add_plugins(GameSavePlugin);
+    app.register_type::<SerializableComponent>();
+
+    {
+        let world = app.world_mut();
+        world.insert_resource(Calendar::default());
+        world.insert_resource(TurnCounter::default());
+        world.insert_resource(Rails::default());
+        world.insert_resource(Bridges::default());
+    }
+
+    app
+}
+
+/// Write `app`'s world to `path` and let the save finish
+fn save_game(app: &mut App, path: &Path) {
+    let path = path.to_path_buf();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<SaveGameRequest>| {
+            writer.write(SaveGameRequest {
+                path: Some(path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+}
+
+/// Load `path` into a fresh test app and let the post-load rebuild run
+fn load_game(path: &Path) -> App {
+    let mut app = init_test_app();
+    let path = path.to_path_buf();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<LoadGameRequest>| {
+            writer.write(LoadGameRequest {
+                path: Some(path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+    app.update();
+    app
+}
+
+#[test]
+fn save_request_creates_file_and_completion_message() {
+    let mut app = init_test_app();
+    let path = temp_save_path("save_request");
+
+    let request_path = path.clone();
+    let _ = app.world_mut().run_system_once(
+        move |mut commands: Commands, mut writer: MessageWriter<SaveGameRequest>| {
+            commands.spawn((SerializableComponent { value: 42 }, Save));
+            writer.write(SaveGameRequest {
+                path: Some(request_path.clone()),
+            });
+        },
+    );
+
+    app.update();
+    app.update();
+
+    let completions = app
+        .world_mut()
+        .run_system_once(|mut reader: MessageReader<SaveGameCompleted>| {
+            reader.read().cloned().collect::<Vec<_>>()
+        })
+        .unwrap();
+    assert_eq!(completions.len(), 1);
+    assert_eq!(completions[0].path, path);
+
+    let contents = fs::read_to_string(&completions[0].path).unwrap();
+    assert!(contents.contains("SerializableComponent"));
+    assert!(contents.contains("42"));
+
+    fs::remove_file(completions[0].path.clone()).unwrap();
+}
+
+#[test]
+fn nation_entities_are_marked_for_save() {
+    let mut app = init_test_app();
+    let nation = app.world_mut().spawn(Nation).id();
+
+    app.update();
+
+    assert!(app.world().entity(nation).contains::<Save>());
+}
+
+#[test]
+fn civilian_component_registers_map_entities() {
+    let app = init_test_app();
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    let registration = registry
+        .get(std::any::TypeId::of::<Civilian>())
+        .expect("civilian type registered");
+    assert!(registration.data::<ReflectMapEntities>().is_some());
+}
+
+#[test]
+fn civilian_owner_is_remapped_when_loading_scene() {
+    // This test verifies that Civilian's MapEntities derive is properly registered
+    // and that moonshine-save will use it during load.
+    // The actual remapping behavior is tested by saving_and_loading_persists_core_state.
+    let registry = AppTypeRegistry::default();
+    {
+        let mut writer = registry.write();
+        writer.register::<Civilian>();
+        writer.register::<Nation>();
+    }
+
+    // Verify MapEntities is registered for Civilian
+    let reader = registry.read();
+    let registration = reader
+        .get(std::any::TypeId::of::<Civilian>())
+        .expect("Civilian type registered");
+    assert!(
+        registration.data::<ReflectMapEntities>().is_some(),
+        "Civilian should have MapEntities reflection data"
+    );
+}
+
+#[test]
+fn load_request_rebuilds_player_nation_runtime_state() {
+    let mut app = init_test_app();
+    let path = temp_save_path("load_request");
+
+    let save_request_path = path.clone();
+    let _ = app.world_mut().run_system_once(
+        move |mut commands: Commands, mut writer: MessageWriter<SaveGameRequest>| {
+            commands.spawn((
+                Save,
+                Nation,
+                Name::new("Player"),
+                Allocations::default(),
+                ReservationSystem::default(),
+                StockpileReserves::default(),
+            ));
+            writer.write(SaveGameRequest {
+                path: Some(save_request_path.clone()),
+            });
+        },
+    );
+
+    app.update();
+    app.update();
+
+    assert!(fs::metadata(&path).is_ok());
+
+    let mut app = init_test_app();
+    let load_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<LoadGameRequest>| {
+            writer.write(LoadGameRequest {
+                path: Some(load_request_path.clone()),
+            });
+        });
+
+    app.update();
+    app.update();
+    app.update();
+
+    let completions = app
+        .world_mut()
+        .run_system_once(|mut reader: MessageReader<LoadGameCompleted>| {
+            reader.read().cloned().collect::<Vec<_>>()
+        })
+        .unwrap();
+    assert_eq!(completions.len(), 1);
+    assert_eq!(completions[0].path, path);
+
+    let player_nation_entity = app.world().resource::<PlayerNation>().entity();
+    let entity = app.world().entity(player_nation_entity);
+    assert!(entity.contains::<Nation>());
+    assert!(entity.contains::<Allocations>());
+    assert!(entity.contains::<ReservationSystem>());
+
+    fs::remove_file(completions[0].path.clone()).unwrap();
+}
+
+#[test]
+fn saving_and_loading_persists_core_state() {
+    let mut app = init_test_app();
+    let path = temp_save_path("core_state");
+
+    {
+        let mut calendar = app.world_mut().resource_mut::<Calendar>();
+        calendar.season = Season::Autumn;
+        calendar.year = 1822;
+    }
+
+    {
+        let mut turn_counter = app.world_mut().resource_mut::<TurnCounter>();
+        turn_counter.current = 5;
+    }
+
+    let nation_entity = app
+        .world_mut()
+        .spawn((
+            Nation,
+            Name::new("Rustonia"),
+            NationColor(Color::srgb(0.3, 0.4, 0.8)),
+            Capital(TilePos { x: 4, y: 9 }),
+            Treasury::new(1_234),
+            Stockpile::default(),
+            Technologies::default(),
+            Workforce::default(),
+            RecruitmentQueue::default(),
+            TrainingQueue::default(),
+        ))
+        .id();
+
+    {
+        let mut entity = app.world_mut().entity_mut(nation_entity);
+        let mut stockpile = entity.get_mut::<Stockpile>().unwrap();
+        stockpile.add(Good::Steel, 5);
+        stockpile.add(Good::Grain, 12);
+
+        let mut techs = entity.get_mut::<Technologies>().unwrap();
+        techs.unlock(Technology::MountainEngineering);
+    }
+
+    app.world_mut().spawn(Civilian {
+        kind: CivilianKind::Engineer,
+        position: TilePos { x: 4, y: 9 },
+        owner: nation_entity,
+        civilian_id: CivilianId(1),
+        has_moved: false,
+        experience: 4,
+    });
+
+    let save_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<SaveGameRequest>| {
+            writer.write(SaveGameRequest {
+                path: Some(save_request_path.clone()),
+            });
+        });
+
+    app.update();
+    app.update();
+    assert!(fs::metadata(&path).is_ok());
+
+    let mut app = init_test_app();
+    {
+        app.world_mut().resource_mut::<Calendar>().year = 1900;
+        app.world_mut().resource_mut::<TurnCounter>().current = 1;
+    }
+
+    let load_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<LoadGameRequest>| {
+            writer.write(LoadGameRequest {
+                path: Some(load_request_path.clone()),
+            });
+        });
+
+    app.update();
+    app.update();
+    app.update();
+
+    let calendar = app.world().resource::<Calendar>();
+    assert_eq!(calendar.year, 1822);
+    assert_eq!(calendar.season, Season::Autumn);
+
+    let turn_counter = app.world().resource::<TurnCounter>();
+    assert_eq!(turn_counter.current, 5);
+
+    {
+        let world = app.world_mut();
+        let mut nation_query = world.query::<(Entity, &Name, &Treasury, &Stockpile)>();
+        let (nation_entity, name, treasury, stockpile) = nation_query
+            .iter(world)
+            .find(|(_, name, _, _)| name.as_str() == "Rustonia")
+            .expect("nation restored");
+
+        assert_eq!(name.as_str(), "Rustonia");
+        assert_eq!(treasury.total(), 1_234i64);
+        assert_eq!(stockpile.get(Good::Steel), 5u32);
+        assert_eq!(stockpile.get(Good::Grain), 12u32);
+
+        let mut tech_query = world.query::<&Technologies>();
+        let techs = tech_query
+            .get(world, nation_entity)
+            .expect("technologies restored");
+        assert!(techs.has(Technology::MountainEngineering));
+
+        let mut civilian_query = world.query::<&Civilian>();
+        let civilian = civilian_query
+            .iter(world)
+            .find(|civilian| civilian.owner == nation_entity)
+            .expect("civilian restored");
+        assert_eq!(civilian.kind, CivilianKind::Engineer);
+        assert_eq!(civilian.experience, 4);
+    }
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn diplomacy_survives_reload_keyed_by_nation_id() {
+    let mut app = init_test_app();
+    let path = temp_save_path("diplomacy");
+
+    let first = app
+        .world_mut()
+        .spawn((Nation, Name::new("First"), NationId(0)))
+        .id();
+    let second = app
+        .world_mut()
+        .spawn((Nation, Name::new("Second"), NationId(1)))
+        .id();
+    let instance = |app: &App, entity| {
+        NationInstance::from_entity(app.world().entity(entity)).expect("nation instance")
+    };
+    let (first, second) = (instance(&app, first), instance(&app, second));
+
+    let mut state = DiplomacyState::default();
+    state.adjust_score(first, second, 55);
+    state.set_treaty(first, second, |t| {
+        t.embassy = true;
+        t.alliance = true;
+    });
+    let mut ledger = ForeignAidLedger::default();
+    ledger.upsert(RecurringGrant {
+        from: first,
+        to: second,
+        amount: 300,
+    });
+    app.world_mut().insert_resource(state);
+    app.world_mut().insert_resource(ledger);
+    app.world_mut().insert_resource(DiplomaticOffers::default());
+
+    let save_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<SaveGameRequest>| {
+            writer.write(SaveGameRequest {
+                path: Some(save_request_path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+    assert!(fs::metadata(&path).is_ok());
+
+    let mut app = init_test_app();
+    // Shift entity ids so reloaded nations cannot line up with the saved ones by chance
+    for _ in 0..8 {
+        app.world_mut().spawn_empty();
+    }
+    let load_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<LoadGameRequest>| {
+            writer.write(LoadGameRequest {
+                path: Some(load_request_path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+    app.update();
+
+    let world = app.world_mut();
+    let nations: HashMap<u32, NationInstance> = world
+        .query::<(NationInstance, &NationId)>()
+        .iter(world)
+        .map(|(nation, id)| (id.0, nation))
+        .collect();
+    let (first, second) = (nations[&0], nations[&1]);
+
+    let state = world.resource::<DiplomacyState>();
+    let relation = state.relation(first, second).expect("relation restored");
+    assert_eq!(relation.score, 55);
+    assert!(relation.treaty.alliance);
+    assert!(relation.treaty.embassy);
+    assert!(state.are_allied(first, second));
+
+    let ledger = world.resource::<ForeignAidLedger>();
+    assert!(ledger.has_recurring(first, second));
+    assert_eq!(ledger.all()[0].amount, 300);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn hotseat_order_is_rebuilt_from_loaded_human_nations() {
+    let mut app = init_test_app();
+    let path = temp_save_path("hotseat");
+
+    let world = app.world_mut();
+    let player = world.spawn((Nation, Name::new("Player"), NationId(2))).id();
+    world.spawn((Nation, Name::new("Rival"), NationId(1), AiNation));
+    let other = world.spawn((Nation, Name::new("Guest"), NationId(0))).id();
+    let instances: Vec<NationInstance> = [other, player]
+        .into_iter()
+        .map(|entity| NationInstance::from_entity(world.entity(entity)).unwrap())
+        .collect();
+    world.insert_resource(HumanNations::new(instances));
+
+    let save_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<SaveGameRequest>| {
+            writer.write(SaveGameRequest {
+                path: Some(save_request_path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+
+    let mut app = init_test_app();
+    for _ in 0..8 {
+        app.world_mut().spawn_empty();
+    }
+    let load_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<LoadGameRequest>| {
+            writer.write(LoadGameRequest {
+                path: Some(load_request_path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+    app.update();
+
+    let world = app.world_mut();
+    let nations: HashMap<u32, NationInstance> = world
+        .query::<(NationInstance, &NationId)>()
+        .iter(world)
+        .map(|(nation, id)| (id.0, nation))
+        .collect();
+    let humans = world.resource::<HumanNations>();
+    assert_eq!(humans.nations, vec![nations[&0], nations[&2]]);
+    // The saving player keeps the turn
+    assert_eq!(humans.active(), Some(nations[&2]));
+    assert_eq!(world.resource::<PlayerNation>().instance(), nations[&2]);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn rail_network_connectivity_survives_reload() {
+    fn snapshot(app: &mut App) -> (Vec<(TilePos, TilePos)>, Vec<(TilePos, bool)>) {
+        let mut rails: Vec<_> = app.world().resource::<Rails>().0.iter().copied().collect();
+        rails.sort_by_key(|(a, b)| (a.x, a.y, b.x, b.y));
+        let world = app.world_mut();
+        let mut depots: Vec<_> = world
+            .query::<&Depot>()
+            .iter(world)
+            .map(|depot| (depot.position, depot.connected))
+            .collect();
+        depots.sort_by_key(|(pos, _)| (pos.x, pos.y));
+        (rails, depots)
+    }
+
+    let mut app = init_test_app();
+    app.init_resource::<RailConnectivityCache>();
+    app.add_observer(compute_rail_connectivity);
+    let path = temp_save_path("rail_network");
+
+    let nation = app
+        .world_mut()
+        .spawn((
+            Nation,
+            Name::new("Railland"),
+            Capital(TilePos { x: 0, y: 0 }),
+        ))
+        .id();
+    {
+        let mut rails = app.world_mut().resource_mut::<Rails>();
+        for x in 0..3 {
+            rails.0.insert(ordered_edge(
+                TilePos { x, y: 0 },
+                TilePos { x: x + 1, y: 0 },
+            ));
+        }
+    }
+    for position in [TilePos { x: 3, y: 0 }, TilePos { x: 6, y: 6 }] {
+        app.world_mut().spawn(Depot {
+            position,
+            owner: nation,
+            connected: false,
+        });
+    }
+    app.world_mut().trigger(RecomputeConnectivity);
+    let before = snapshot(&mut app);
+    assert_eq!(
+        before.1.iter().filter(|(_, connected)| *connected).count(),
+        1
+    );
+
+    let save_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<SaveGameRequest>| {
+            writer.write(SaveGameRequest {
+                path: Some(save_request_path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+    assert!(fs::metadata(&path).is_ok());
+
+    let mut app = init_test_app();
+    app.init_resource::<RailConnectivityCache>();
+    app.add_observer(compute_rail_connectivity);
+    // Shift entity ids so the depot owner has to be remapped
+    for _ in 0..8 {
+        app.world_mut().spawn_empty();
+    }
+    let load_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<LoadGameRequest>| {
+            writer.write(LoadGameRequest {
+                path: Some(load_request_path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+    app.update();
+
+    assert_eq!(snapshot(&mut app), before);
+    let world = app.world_mut();
+    let reloaded_nation = world
+        .query_filtered::<Entity, With<Nation>>()
+        .single(world)
+        .expect("nation restored");
+    assert!(
+        world
+            .resource::<RailConnectivityCache>()
+            .reachable
+            .contains_key(&reloaded_nation)
+    );
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn rail_under_construction_resumes_after_reload() {
+    let mut app = init_test_app();
+    app.init_resource::<RailConnectivityCache>();
+    let path = temp_save_path("rail_construction");
+
+    let from = TilePos { x: 1, y: 1 };
+    let to = TilePos { x: 2, y: 1 };
+    let nation = app
+        .world_mut()
+        .spawn((Nation, Name::new("Railland"), Capital(from)))
+        .id();
+    let engineer = app
+        .world_mut()
+        .spawn(Civilian {
+            kind: CivilianKind::Engineer,
+            position: from,
+            owner: nation,
+            civilian_id: CivilianId(1),
+            has_moved: true,
+            experience: 0,
+        })
+        .id();
+    app.world_mut().spawn(RailConstruction {
+        from,
+        to,
+        turns_remaining: 3,
+        owner: nation,
+        engineer,
+    });
+
+    // One turn of progress before saving
+    let _ = app.world_mut().run_system_once(advance_rail_construction);
+
+    let save_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<SaveGameRequest>| {
+            writer.write(SaveGameRequest {
+                path: Some(save_request_path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+    assert!(fs::metadata(&path).is_ok());
+
+    let mut app = init_test_app();
+    app.init_resource::<RailConnectivityCache>();
+    for _ in 0..8 {
+        app.world_mut().spawn_empty();
+    }
+    let load_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<LoadGameRequest>| {
+            writer.write(LoadGameRequest {
+                path: Some(load_request_path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+    app.update();
+
+    {
+        let world = app.world_mut();
+        let reloaded_nation = world
+            .query_filtered::<Entity, With<Nation>>()
+            .single(world)
+            .expect("nation restored");
+        let reloaded_engineer = world
+            .query_filtered::<Entity, With<Civilian>>()
+            .single(world)
+            .expect("engineer restored");
+        let construction = world
+            .query::<&RailConstruction>()
+            .single(world)
+            .expect("construction restored");
+        assert_eq!(construction.turns_remaining, 2);
+        assert_eq!(construction.owner, reloaded_nation);
+        assert_eq!(construction.engineer, reloaded_engineer);
+    }
+
+    // Two more turns finish the rail, as they would have without the reload
+    let _ = app.world_mut().run_system_once(advance_rail_construction);
+    assert!(app.world().resource::<Rails>().0.is_empty());
+    let _ = app.world_mut().run_system_once(advance_rail_construction);
+    assert!(
+        app.world()
+            .resource::<Rails>()
+            .0
+            .contains(&ordered_edge(from, to))
+    );
+    let world = app.world_mut();
+    assert!(
+        world
+            .query::<&RailConstruction>()
+            .iter(world)
+            .next()
+            .is_none()
+    );
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn corrupted_save_fails_with_checksum_error() {
+    let mut app = init_test_app();
+    let path = temp_save_path("corrupted");
+
+    let save_request_path = path.clone();
+    let _ = app.world_mut().run_system_once(
+        move |mut commands: Commands, mut writer: MessageWriter<SaveGameRequest>| {
+            commands.spawn((SerializableComponent { value: 42 }, Save));
+            writer.write(SaveGameRequest {
+                path: Some(save_request_path.clone()),
+            });
+        },
+    );
+    app.update();
+    app.update();
+
+    // Flip one byte of the payload, leaving the header intact
+    let mut contents = fs::read(&path).unwrap();
+    let payload_start = contents.iter().position(|&byte| byte == b'\n').unwrap() + 1;
+    let index = contents[payload_start..]
+        .iter()
+        .position(|&byte| byte == b'4')
+        .unwrap()
+        + payload_start;
+    contents[index] = b'7';
+    fs::write(&path, contents).unwrap();
+
+    let mut app = init_test_app();
+    let load_request_path = path.clone();
+    let _ = app
+        .world_mut()
+        .run_system_once(move |mut writer: MessageWriter<LoadGameRequest>| {
+            writer.write(LoadGameRequest {
+                path: Some(load_request_path.clone()),
+            });
+        });
+    app.update();
+    app.update();
+    app.update();
+
+    let failures = app
+        .world_mut()
+        .run_system_once(|mut reader: MessageReader<LoadGameFailed>| {
+            reader.read().cloned().collect::<Vec<_>>()
+        })
+        .unwrap();
+    assert_eq!(failures.len(), 1);
+    assert!(matches!(
+        failures[0].error,
+        SaveFileError::ChecksumMismatch { .. }
+    ));
+
+    let completions = app
+        .world_mut()
+        .run_system_once(|mut reader: MessageReader<LoadGameCompleted>| reader.read().count())
+        .unwrap();
+    assert_eq!(completions, 0);
+    let world = app.world_mut();
+    assert_eq!(
+        world.query::<&SerializableComponent>().iter(world).count(),
+        0
+    );
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn pending_production_survives_save_and_load_without_leaking_reservations() {
+    let mut app = init_test_app();
+    let path = temp_save_path("allocations");
+
+    let nation = {
+        let world = app.world_mut();
+        let mut stockpile = Stockpile::default();
+        stockpile.add(Good::Cotton, 10);
+        let mut workforce = Workforce::new();
+        workforce.add_untrained(5);
+        workforce.update_labor_pool();
+        let nation = world
+            .spawn((
+                Nation,
+                Name::new("Player"),
+                Buildings::with_all_initial(),
+                stockpile,
+                workforce,
+                Treasury::new(500),
+                Allocations::default(),
+                ReservationSystem::default(),
+                StockpileReserves::default(),
+                Save,
+            ))
+            .id();
+        let instance = NationInstance::from_entity(world.entity(nation)).unwrap();
+        let mut orders = OrdersQueue::default();
+        orders.queue_production(AdjustProduction {
+            nation: instance,
+            building: nation,
+            output_good: Good::Fabric,
+            target_output: 3,
+        });
+        world.insert_resource(orders);
+        let _ = world.run_system_once(execute_queued_production_orders);
+        nation
+    };
+    assert_eq!(
+        app.world()
+            .get::<Allocations>(nation)
+            .unwrap()
+            .production_count(nation, Good::Fabric),
+        3
+    );
+
+    save_game(&mut app, &path);
+    assert!(
+        app.world().get::<AllocationSnapshot>(nation).is_none(),
+        "the save snapshot is dropped once written"
+    );
+
+    let mut app = load_game(&path);
+
+    let world = app.world_mut();
+    let (loaded, allocations, reservations, stockpile, workforce) = world
+        .query_filtered::<(
+            Entity,
+            &Allocations,
+            &ReservationSystem,
+            &Stockpile,
+            &Workforce,
+        ), With<Nation>>()
+        .single(world)
+        .unwrap();
+    assert_eq!(allocations.production_count(loaded, Good::Fabric), 3);
+    assert_eq!(reservations.count(), 3);
+    assert_eq!(stockpile.get(Good::Cotton), 10);
+    assert_eq!(stockpile.get_reserved(Good::Cotton), 6);
+    assert_eq!(workforce.labor_pool.available(), 2);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn job_inputs_and_power_plant_fuel_are_reserved_again_after_reload() {
+    let mut app = init_test_app();
+    let path = temp_save_path("job_inputs");
+
+    {
+        let world = app.world_mut();
+        let mut stockpile = Stockpile::default();
+        stockpile.add(Good::Timber, 3);
+        stockpile.add(Good::Fuel, 3);
+        let mut workforce = Workforce::new();
+        workforce.add_untrained(2);
+        workforce.update_labor_pool();
+        let mut buildings = Buildings::new();
+        buildings.insert(Building::power_plant(1));
+        let nation = world
+            .spawn((
+                Nation,
+                Name::new("Player"),
+                buildings,
+                stockpile,
+                workforce,
+                Treasury::new(500),
+                Allocations::default(),
+                ReservationSystem::default(),
+                StockpileReserves::default(),
+            ))
+            .id();
+        world.insert_resource(TurnCounter::new(3));
+        let target = TilePos { x: 1, y: 1 };
+        // One job started this turn still holds its timber; an older one spent it already
+        for (id, started) in [(1, 3), (2, 2)] {
+            world.spawn((
+                Civilian {
+                    kind: CivilianKind::Engineer,
+                    position: target,
+                    owner: nation,
+                    civilian_id: CivilianId(id),
+                    has_moved: true,
+                    experience: 0,
+                },
+                CivilianJob::new(JobType::ImprovingTile, target),
+                ActionTurn(started),
+            ));
+        }
+    }
+
+    save_game(&mut app, &path);
+
+    let mut app = load_game(&path);
+
+    let world = app.world_mut();
+    let (allocations, stockpile) = world
+        .query_filtered::<(&Allocations, &Stockpile), With<Nation>>()
+        .single(world)
+        .unwrap();
+    assert_eq!(allocations.jobs.len(), 1);
+    assert!(allocations.power_plant.is_some());
+    assert_eq!(stockpile.get_reserved(Good::Timber), IMPROVEMENT_TIMBER);
+    assert_eq!(
+        stockpile.get_reserved(Good::Fuel),
+        POWER_PLANT_FUEL_PER_TURN
+    );
+
+    fs::remove_file(path).unwrap();
+}
@@ -7,6 +7,7 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 
+use crate::constants::hex_map_type;
 use crate::map::tiles::TerrainType;
 use crate::turn_system::TurnCounter;
 use crate::ui::state::UIState;
@@ -54,7 +55,7 @@ pub fn create_test_tilemap(world: &mut World, width: u32, height: u32) -> (Entit
     // Configure the tilemap entity
     world.entity_mut(tilemap_entity).insert((
         TilemapGridSize { x: 16.0, y: 16.0 },
-        TilemapType::Hexagon(HexCoordSystem::Row),
+        hex_map_type(),
         map_size,
         tile_storage.clone(),
         TilemapTexture::Single(Handle::default()),
@@ -1,7 +1,8 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
 use crate::diplomacy::DiplomaticOffers;
-use crate::economy::{Calendar, PlayerNation, Season};
+use crate::economy::{Calendar, NationInstance, PlayerNation, Season};
 use crate::ui::menu::AppState;
 use crate::ui::mode::GameMode;
 
@@ -26,6 +27,87 @@ impl TurnCounter {
     }
 }
 
+/// Enemy-turn work spread over several frames (e.g. AI civilian orders dispatched under
+/// a per-frame budget). While `pending` is non-zero the enemy turn stays open; it ends on
+/// the first frame the backlog is empty.
+#[derive(Resource, Debug, Default)]
+pub struct EnemyTurnBacklog {
+    pub pending: usize,
+    deferred: bool,
+}
+
+/// Human-controlled nations in hotseat order. Each takes its own `PlayerTurn`, with
+/// `PlayerNation` pointing at it, before processing and the AI turn begin.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct HumanNations {
+    pub nations: Vec<NationInstance>,
+    active: usize,
+}
+
+impl HumanNations {
+    pub fn new(nations: Vec<NationInstance>) -> Self {
+        Self { nations, active: 0 }
+    }
+
+    /// Hotseat order resuming at `current`'s turn, e.g. after loading a save
+    pub fn resuming(nations: Vec<NationInstance>, current: NationInstance) -> Self {
+        let active = nations
+            .iter()
+            .position(|&nation| nation == current)
+            .unwrap_or(0);
+        Self { nations, active }
+    }
+
+    /// The human whose turn it currently is
+    pub fn active(&self) -> Option<NationInstance> {
+        self.nations.get(self.active).copied()
+    }
+
+    pub fn contains(&self, nation: NationInstance) -> bool {
+        self.nations.contains(&nation)
+    }
+
+    /// Hand the turn to the next human; `None` once every human has moved this turn
+    fn advance(&mut self) -> Option<NationInstance> {
+        if self.active + 1 >= self.nations.len() {
+            return None;
+        }
+        self.active += 1;
+        self.active()
+    }
+
+    fn restart(&mut self) -> Option<NationInstance> {
+        self.active = 0;
+        self.active()
+    }
+}
+
+/// Optional time limit for the player turn (hotseat/timed play). When it runs out the
+/// turn ends as if the player had pressed End Turn, committing whatever is queued.
+/// Counts virtual time, so pausing the game stops the clock.
+#[derive(Resource, Debug, Clone)]
+pub struct TurnTimer {
+    pub seconds: u32,
+    elapsed: f32,
+}
+
+impl TurnTimer {
+    pub fn new(seconds: u32) -> Self {
+        Self {
+            seconds,
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn remaining_secs(&self) -> f32 {
+        (self.seconds as f32 - self.elapsed).max(0.0)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.elapsed >= self.seconds as f32
+    }
+}
+
 /// Turn phase as a Bevy State. Transitions fire OnEnter/OnExit exactly once.
 #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
 pub enum TurnPhase {
@@ -38,6 +120,45 @@ pub enum TurnPhase {
     EnemyTurn,
 }
 
+/// Read-only view of the turn number, phase and calendar, so callers ask
+/// questions like "is it the player's turn?" instead of matching on the state
+#[derive(SystemParam)]
+pub struct TurnSystem<'w> {
+    counter: Res<'w, TurnCounter>,
+    phase: Res<'w, State<TurnPhase>>,
+    calendar: Option<Res<'w, Calendar>>,
+}
+
+impl TurnSystem<'_> {
+    pub fn current_turn(&self) -> u32 {
+        self.counter.current
+    }
+
+    pub fn phase(&self) -> TurnPhase {
+        *self.phase.get()
+    }
+
+    pub fn is_player_turn(&self) -> bool {
+        self.phase() == TurnPhase::PlayerTurn
+    }
+
+    pub fn is_processing(&self) -> bool {
+        self.phase() == TurnPhase::Processing
+    }
+
+    pub fn is_enemy_turn(&self) -> bool {
+        self.phase() == TurnPhase::EnemyTurn
+    }
+
+    /// "Turn 3 - Autumn, 1815", or just the turn when there is no calendar
+    pub fn date_string(&self) -> String {
+        match self.calendar.as_deref() {
+            Some(calendar) => format!("Turn {} - {}", self.current_turn(), calendar.display()),
+            None => format!("Turn {}", self.current_turn()),
+        }
+    }
+}
+
 // ============================================================================
 // System Sets for Turn Phase Ordering
 // ============================================================================
@@ -105,6 +226,7 @@ impl Plugin for TurnSystemPlugin {
         // Register state and resources
         app.init_state::<TurnPhase>()
             .insert_resource(TurnCounter::new(1))
+            .init_resource::<EnemyTurnBacklog>()
             .add_message::<EndPlayerTurn>();
 
         // Configure system set ordering for PlayerTurn
@@ -172,6 +294,12 @@ impl Plugin for TurnSystemPlugin {
             transition_to_next_turn.after(EnemyTurnSet::Orders),
         );
 
+        // Ends enemy turns that were deferred because AI work was still queued
+        app.add_systems(
+            Update,
+            finish_deferred_enemy_turn.run_if(in_state(TurnPhase::EnemyTurn)),
+        );
+
         // Input handling (runs every frame during gameplay)
         app.add_systems(
             Update,
@@ -180,6 +308,22 @@ impl Plugin for TurnSystemPlugin {
                 .run_if(in_state(TurnPhase::PlayerTurn)),
         );
 
+        // Hotseat: the first human moves first in every turn
+        app.add_systems(
+            OnEnter(TurnPhase::PlayerTurn),
+            start_first_human_turn.before(PlayerTurnSet::Collection),
+        );
+
+        // Optional turn time limit
+        app.add_systems(OnEnter(TurnPhase::PlayerTurn), reset_turn_timer);
+        app.add_systems(
+            Update,
+            tick_turn_timer
+                .before(handle_end_player_turn)
+                .run_if(in_state(AppState::InGame))
+                .run_if(in_state(TurnPhase::PlayerTurn)),
+        );
+
         // Transition command handler for player ending turn
         app.add_systems(
             Update,
@@ -249,15 +393,71 @@ fn handle_end_turn_input(
 // ============================================================================
 
 fn handle_end_player_turn(
+    mut commands: Commands,
     mut messages: MessageReader<EndPlayerTurn>,
     mut next_state: ResMut<NextState<TurnPhase>>,
+    mut humans: Option<ResMut<HumanNations>>,
+    mut timer: Option<ResMut<TurnTimer>>,
 ) {
     for _ in messages.read() {
+        if let Some(next) = humans.as_mut().and_then(|humans| humans.advance()) {
+            info!("Player turn ended, handing control to the next human player...");
+            commands.insert_resource(PlayerNation::new(next));
+            if let Some(timer) = timer.as_mut() {
+                timer.elapsed = 0.0;
+            }
+            continue;
+        }
+
         info!("Player turn ended, beginning processing...");
         next_state.set(TurnPhase::Processing);
     }
 }
 
+/// Give control back to the first human at the start of a turn.
+fn start_first_human_turn(
+    mut commands: Commands,
+    humans: Option<ResMut<HumanNations>>,
+    player: Option<Res<PlayerNation>>,
+) {
+    let Some(first) = humans.and_then(|mut humans| humans.restart()) else {
+        return;
+    };
+    if player.is_none_or(|player| player.instance() != first) {
+        commands.insert_resource(PlayerNation::new(first));
+    }
+}
+
+/// Restart the turn clock at the beginning of each player turn.
+fn reset_turn_timer(timer: Option<ResMut<TurnTimer>>) {
+    if let Some(mut timer) = timer {
+        timer.elapsed = 0.0;
+    }
+}
+
+/// Count down the turn clock and end the turn once it runs out.
+pub fn tick_turn_timer(
+    time: Res<Time>,
+    timer: Option<ResMut<TurnTimer>>,
+    mut end_turn_events: MessageWriter<EndPlayerTurn>,
+) {
+    let Some(mut timer) = timer else {
+        return;
+    };
+    if timer.is_expired() {
+        return;
+    }
+
+    timer.elapsed += time.delta_secs();
+    if timer.is_expired() {
+        info!(
+            "Turn time limit of {}s reached, ending turn.",
+            timer.seconds
+        );
+        end_turn_events.write(EndPlayerTurn);
+    }
+}
+
 // ============================================================================
 // Auto-Transition Systems
 // ============================================================================
@@ -271,10 +471,37 @@ fn transition_to_enemy_turn(mut next_state: ResMut<NextState<TurnPhase>>) {
 
 /// Automatically transitions from EnemyTurn to next PlayerTurn.
 /// Runs at the end of OnEnter(EnemyTurn) after all AI systems complete.
+/// If AI work is still queued, the transition is left to `finish_deferred_enemy_turn`.
 fn transition_to_next_turn(
     mut next_state: ResMut<NextState<TurnPhase>>,
     mut turn: ResMut<TurnCounter>,
+    mut backlog: ResMut<EnemyTurnBacklog>,
 ) {
+    if backlog.pending > 0 {
+        info!(
+            "Enemy turn has {} queued AI actions, finishing over the next frames",
+            backlog.pending
+        );
+        backlog.deferred = true;
+        return;
+    }
+    advance_to_next_turn(&mut next_state, &mut turn);
+}
+
+/// Ends a deferred enemy turn once its backlog has drained.
+pub fn finish_deferred_enemy_turn(
+    mut next_state: ResMut<NextState<TurnPhase>>,
+    mut turn: ResMut<TurnCounter>,
+    mut backlog: ResMut<EnemyTurnBacklog>,
+) {
+    if !backlog.deferred || backlog.pending > 0 {
+        return;
+    }
+    backlog.deferred = false;
+    advance_to_next_turn(&mut next_state, &mut turn);
+}
+
+fn advance_to_next_turn(next_state: &mut NextState<TurnPhase>, turn: &mut TurnCounter) {
     turn.increment();
     info!("Enemy turn complete, beginning turn {}...", turn.current);
     next_state.set(TurnPhase::PlayerTurn);
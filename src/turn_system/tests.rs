@@ -1,4 +1,14 @@
-use crate::turn_system::{TurnCounter, TurnPhase};
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::economy::{Calendar, Nation, NationInstance, PlayerNation, Season};
+use crate::turn_system::{
+    EndPlayerTurn, HumanNations, TurnCounter, TurnPhase, TurnSystem, TurnTimer,
+    handle_end_player_turn, start_first_human_turn, tick_turn_timer,
+};
 
 #[test]
 fn test_turn_counter_default() {
@@ -47,3 +57,145 @@ fn test_turn_phase_copy() {
     let copied = phase;
     assert_eq!(phase, copied);
 }
+
+#[test]
+fn test_turn_system_reports_phase_and_turn_after_transition() {
+    use bevy::ecs::system::RunSystemOnce;
+
+    let mut app = App::new();
+    app.add_plugins(StatesPlugin)
+        .init_state::<TurnPhase>()
+        .insert_resource(TurnCounter::new(3))
+        .insert_resource(Calendar {
+            season: Season::Autumn,
+            year: 1815,
+        });
+    app.update();
+
+    let report = |app: &mut App| {
+        app.world_mut()
+            .run_system_once(|turns: TurnSystem| {
+                (
+                    turns.current_turn(),
+                    turns.phase(),
+                    turns.is_player_turn(),
+                    turns.is_processing(),
+                    turns.is_enemy_turn(),
+                    turns.date_string(),
+                )
+            })
+            .expect("turn system should be readable")
+    };
+
+    assert_eq!(
+        report(&mut app),
+        (
+            3,
+            TurnPhase::PlayerTurn,
+            true,
+            false,
+            false,
+            "Turn 3 - Autumn, 1815".to_string()
+        )
+    );
+
+    app.world_mut()
+        .resource_mut::<NextState<TurnPhase>>()
+        .set(TurnPhase::Processing);
+    app.update();
+
+    assert_eq!(
+        report(&mut app),
+        (
+            3,
+            TurnPhase::Processing,
+            false,
+            true,
+            false,
+            "Turn 3 - Autumn, 1815".to_string()
+        )
+    );
+}
+
+#[test]
+fn test_turn_timer_auto_ends_player_turn() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            200,
+        )))
+        .insert_resource(TurnTimer::new(1))
+        .init_state::<TurnPhase>()
+        .add_message::<EndPlayerTurn>()
+        .add_systems(
+            Update,
+            (
+                tick_turn_timer.run_if(in_state(TurnPhase::PlayerTurn)),
+                handle_end_player_turn,
+            )
+                .chain(),
+        );
+
+    let phase = |app: &App| *app.world().resource::<State<TurnPhase>>().get();
+
+    // A paused game does not run the clock down
+    app.world_mut().resource_mut::<Time<Virtual>>().pause();
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(phase(&app), TurnPhase::PlayerTurn);
+    app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+
+    // Well short of one second of play
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(phase(&app), TurnPhase::PlayerTurn);
+
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(phase(&app), TurnPhase::Processing);
+}
+
+#[test]
+fn test_hotseat_hands_turn_to_each_human_before_processing() {
+    let mut app = App::new();
+    app.add_plugins(StatesPlugin)
+        .init_state::<TurnPhase>()
+        .add_message::<EndPlayerTurn>()
+        .add_systems(OnEnter(TurnPhase::PlayerTurn), start_first_human_turn)
+        .add_systems(Update, handle_end_player_turn);
+
+    let first = app.world_mut().spawn(Nation).id();
+    let second = app.world_mut().spawn(Nation).id();
+    let instance = |app: &App, entity| {
+        NationInstance::from_entity(app.world().entity(entity)).expect("nation instance")
+    };
+    let (first, second) = (instance(&app, first), instance(&app, second));
+    app.insert_resource(HumanNations::new(vec![first, second]));
+    app.update();
+
+    let active = |app: &App| app.world().resource::<PlayerNation>().instance();
+    let phase = |app: &App| *app.world().resource::<State<TurnPhase>>().get();
+    assert_eq!(active(&app), first);
+
+    // Ending the first human's turn hands control to the second
+    app.world_mut().write_message(EndPlayerTurn);
+    app.update();
+    assert_eq!(active(&app), second);
+    assert_eq!(phase(&app), TurnPhase::PlayerTurn);
+
+    // Once the last human ends their turn, processing begins
+    app.world_mut().write_message(EndPlayerTurn);
+    app.update();
+    app.update();
+    assert_eq!(phase(&app), TurnPhase::Processing);
+
+    // The next turn starts with the first human again
+    app.world_mut()
+        .resource_mut::<NextState<TurnPhase>>()
+        .set(TurnPhase::PlayerTurn);
+    app.update();
+    assert_eq!(active(&app), first);
+}
@@ -1,15 +1,25 @@
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::prelude::*;
 use bevy::ui_widgets::{Activate, observe};
 
+use crate::economy::allocation_systems::{
+    TRAINING_COST, TRAINING_LABOR, calculate_inputs_for_one_unit,
+};
+use crate::economy::workforce::calculate_recruitment_cap;
 use crate::economy::{
-    Allocations, Good, PlayerNation, Stockpile, Treasury,
+    Allocations, Good, NationInstance, PlayerNation, RecruitmentCapacity, Stockpile, Treasury,
+    Workforce,
     production::{Buildings, building_for_output, input_requirement_per_unit},
 };
+use crate::map::province::Province;
 use crate::messages::{
     AdjustMarketOrder, AdjustProduction, AdjustRecruitment, AdjustTraining, MarketInterest,
 };
+use crate::orders::OrdersQueue;
 use crate::ui::city::allocation_widgets::{
     AllocationBar, AllocationStepperDisplay, AllocationSummary, AllocationType,
+    AllocationValueInput, FocusedAllocationInput, MaximizeAllocation, SetAllocationTarget,
 };
 
 fn allocation_value(alloc: &Allocations, allocation_type: AllocationType) -> u32 {
@@ -78,99 +88,368 @@ fn allocation_requirement(
 // Input Layer: Unified stepper button handler
 // ============================================================================
 
+/// Trigger the adjustment event that moves `allocation_type` to an absolute `target`.
+/// Adjustment events carry the desired total, so a single event covers any delta.
+fn trigger_allocation_target(
+    commands: &mut Commands,
+    nation: NationInstance,
+    allocation_type: AllocationType,
+    target: u32,
+) {
+    match allocation_type {
+        AllocationType::Recruitment => {
+            commands.trigger(AdjustRecruitment {
+                nation,
+                requested: target,
+            });
+        }
+        AllocationType::Training(from_skill) => {
+            commands.trigger(AdjustTraining {
+                nation,
+                from_skill,
+                requested: target,
+            });
+        }
+        AllocationType::Production(building_entity, output_good) => {
+            commands.trigger(AdjustProduction {
+                nation,
+                building: building_entity,
+                output_good,
+                target_output: target,
+            });
+        }
+        AllocationType::MarketBuy(good) => {
+            commands.trigger(AdjustMarketOrder {
+                nation,
+                good,
+                kind: MarketInterest::Buy,
+                requested: target,
+            });
+        }
+        AllocationType::MarketSell(good) => {
+            commands.trigger(AdjustMarketOrder {
+                nation,
+                good,
+                kind: MarketInterest::Sell,
+                requested: target,
+            });
+        }
+    }
+}
+
 /// Creates an observer that adjusts allocation when a stepper button is activated
 pub fn adjust_allocation_on_click(allocation_type: AllocationType, delta: i32) -> impl Bundle {
     observe(
         move |_activate: On<Activate>,
               mut commands: Commands,
+              mut orders: ResMut<OrdersQueue>,
               player_nation: Option<Res<PlayerNation>>,
               allocations: Query<&Allocations>| {
             let Some(player) = player_nation else {
                 return;
             };
 
-            let player_entity = player.entity();
-            let player_instance = player.instance();
-
-            let Ok(alloc) = allocations.get(player_entity) else {
+            let Ok(alloc) = allocations.get(player.entity()) else {
                 return;
             };
 
+            // Production steps build on any order still waiting in the queue, and are
+            // queued directly so several clicks in one frame coalesce into one net order
+            let current = match allocation_type {
+                AllocationType::Production(building, good) => orders
+                    .pending_production_target(player.entity(), building, good)
+                    .unwrap_or_else(|| allocation_value(alloc, allocation_type)),
+                _ => allocation_value(alloc, allocation_type),
+            };
+            let new_requested = (current as i32 + delta).max(0) as u32;
             match allocation_type {
-                AllocationType::Recruitment => {
-                    let current = allocation_value(alloc, allocation_type);
-                    let new_requested = (current as i32 + delta).max(0) as u32;
-                    commands.trigger(AdjustRecruitment {
-                        nation: player_instance,
-                        requested: new_requested,
+                AllocationType::Production(building, output_good) => {
+                    orders.queue_production(AdjustProduction {
+                        nation: player.instance(),
+                        building,
+                        output_good,
+                        target_output: new_requested,
                     });
-                    info!(
-                        "Recruitment: {} -> {} (delta: {})",
-                        current, new_requested, delta
-                    );
                 }
+                _ => trigger_allocation_target(
+                    &mut commands,
+                    player.instance(),
+                    allocation_type,
+                    new_requested,
+                ),
+            }
+            info!(
+                "{:?}: {} -> {} (delta: {})",
+                allocation_type, current, new_requested, delta
+            );
+        },
+    )
+}
 
-                AllocationType::Training(from_skill) => {
-                    let current = allocation_value(alloc, allocation_type);
-                    let new_requested = (current as i32 + delta).max(0) as u32;
-                    commands.trigger(AdjustTraining {
-                        nation: player_instance,
-                        from_skill,
-                        requested: new_requested,
-                    });
-                    info!(
-                        "Training ({:?}): {} -> {} (delta: {})",
-                        from_skill, current, new_requested, delta
-                    );
-                }
+/// Upper bound for an allocation, if one applies (building capacity, recruitment cap, workers)
+fn allocation_capacity(
+    allocation_type: AllocationType,
+    nation: Entity,
+    allocations: &Query<&Allocations>,
+    buildings: &Query<&Buildings>,
+    workforces: &Query<&Workforce>,
+    provinces: &Query<&Province>,
+    recruitment_capacity: &Query<&RecruitmentCapacity>,
+) -> Option<u32> {
+    match allocation_type {
+        AllocationType::Recruitment => {
+            let province_count = provinces
+                .iter()
+                .filter(|province| province.owner == Some(nation))
+                .count() as u32;
+            let upgraded = recruitment_capacity
+                .get(nation)
+                .map(|capacity| capacity.upgraded)
+                .unwrap_or(false);
+            let capitol_capacity = buildings
+                .get(nation)
+                .ok()
+                .and_then(Buildings::capitol_capacity);
+            Some(calculate_recruitment_cap(
+                province_count,
+                upgraded,
+                capitol_capacity,
+            ))
+        }
+        AllocationType::Training(skill) => {
+            let workers = workforces.get(nation).ok()?.count_by_skill(skill);
+            let school = buildings
+                .get(nation)
+                .ok()
+                .and_then(Buildings::training_capacity)
+                .unwrap_or(0);
+            let other_trainees = allocations
+                .get(nation)
+                .map_or(0, |allocations| allocations.other_training_count(skill));
+            Some(workers.min(school.saturating_sub(other_trainees as u32)))
+        }
+        AllocationType::Production(building_entity, output_good) => {
+            let kind = building_for_output(output_good)?;
+            buildings
+                .get(building_entity)
+                .ok()
+                .and_then(|collection| collection.get(kind))
+                .map(|building| building.capacity)
+        }
+        AllocationType::MarketBuy(_) => Some(1),
+        AllocationType::MarketSell(_) => None,
+    }
+}
 
-                AllocationType::Production(building_entity, output_good) => {
-                    let current = allocation_value(alloc, allocation_type);
-                    let new_target = (current as i32 + delta).max(0) as u32;
-                    commands.trigger(AdjustProduction {
-                        nation: player_instance,
-                        building: building_entity,
-                        output_good,
-                        target_output: new_target,
-                    });
-                    info!(
-                        "Production ({:?}): {} -> {} (delta: {})",
-                        output_good, current, new_target, delta
-                    );
+/// Creates an observer that gives keyboard focus to an exact-value input when clicked
+pub fn focus_allocation_input_on_click() -> impl Bundle {
+    observe(
+        |activate: On<Activate>,
+         mut commands: Commands,
+         focused: Query<Entity, With<FocusedAllocationInput>>,
+         mut inputs: Query<(&mut AllocationValueInput, &mut Text)>| {
+            for entity in focused.iter() {
+                commands.entity(entity).remove::<FocusedAllocationInput>();
+                if let Ok((mut input, mut text)) = inputs.get_mut(entity) {
+                    input.buffer.clear();
+                    text.0 = "...".to_string();
                 }
+            }
 
-                AllocationType::MarketBuy(good) => {
-                    let current = allocation_value(alloc, allocation_type);
-                    let new_requested = (current as i32 + delta).max(0) as u32;
-                    commands.trigger(AdjustMarketOrder {
-                        nation: player_instance,
-                        good,
-                        kind: MarketInterest::Buy,
-                        requested: new_requested,
-                    });
-                    info!(
-                        "Market buy ({:?}): {} -> {} (delta: {})",
-                        good, current, new_requested, delta
-                    );
-                }
+            let entity = activate.entity;
+            if let Ok((mut input, mut text)) = inputs.get_mut(entity) {
+                input.buffer.clear();
+                text.0 = "_".to_string();
+                commands.entity(entity).insert(FocusedAllocationInput);
+            }
+        },
+    )
+}
 
-                AllocationType::MarketSell(good) => {
-                    let current = allocation_value(alloc, allocation_type);
-                    let new_requested = (current as i32 + delta).max(0) as u32;
-                    commands.trigger(AdjustMarketOrder {
-                        nation: player_instance,
-                        good,
-                        kind: MarketInterest::Sell,
-                        requested: new_requested,
+/// Maximum number of digits accepted by an exact-value input
+const MAX_INPUT_DIGITS: usize = 4;
+
+/// Feed keyboard input into the focused exact-value field (Input Layer)
+/// Digits append, Backspace deletes, Enter commits, Escape cancels
+pub fn type_into_allocation_input(
+    mut keyboard: MessageReader<KeyboardInput>,
+    mut commands: Commands,
+    mut focused: Query<
+        (Entity, &mut AllocationValueInput, &mut Text),
+        With<FocusedAllocationInput>,
+    >,
+) {
+    let Ok((entity, mut input, mut text)) = focused.single_mut() else {
+        keyboard.clear();
+        return;
+    };
+
+    for event in keyboard.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Character(chars) => {
+                for ch in chars.chars().filter(char::is_ascii_digit) {
+                    if input.buffer.len() < MAX_INPUT_DIGITS {
+                        input.buffer.push(ch);
+                    }
+                }
+            }
+            Key::Backspace => {
+                input.buffer.pop();
+            }
+            Key::Enter => {
+                if let Ok(target) = input.buffer.parse::<u32>() {
+                    commands.trigger(SetAllocationTarget {
+                        allocation_type: input.allocation_type,
+                        target,
                     });
-                    info!(
-                        "Market sell ({:?}): {} -> {} (delta: {})",
-                        good, current, new_requested, delta
-                    );
                 }
+                input.buffer.clear();
+                text.0 = "...".to_string();
+                commands.entity(entity).remove::<FocusedAllocationInput>();
+                return;
             }
-        },
-    )
+            Key::Escape => {
+                input.buffer.clear();
+                text.0 = "...".to_string();
+                commands.entity(entity).remove::<FocusedAllocationInput>();
+                return;
+            }
+            _ => {}
+        }
+    }
+
+    text.0 = format!("{}_", input.buffer);
+}
+
+/// Creates an observer that clears an allocation when activated
+pub fn set_allocation_zero_on_click(allocation_type: AllocationType) -> impl Bundle {
+    observe(move |_activate: On<Activate>, mut commands: Commands| {
+        commands.trigger(SetAllocationTarget {
+            allocation_type,
+            target: 0,
+        });
+    })
+}
+
+/// Creates an observer that maximizes an allocation when activated
+pub fn set_allocation_max_on_click(allocation_type: AllocationType) -> impl Bundle {
+    observe(move |_activate: On<Activate>, mut commands: Commands| {
+        commands.trigger(MaximizeAllocation { allocation_type });
+    })
+}
+
+/// How many more units the nation's unreserved goods, labor and cash can cover
+fn affordable_additional_units(
+    allocation_type: AllocationType,
+    stockpile: &Stockpile,
+    workforce: &Workforce,
+    treasury: &Treasury,
+) -> u32 {
+    let goods_limit = |inputs: &[(Good, u32)]| {
+        inputs
+            .iter()
+            .filter(|(_, amount)| *amount > 0)
+            .map(|(good, amount)| stockpile.get_available(*good) / amount)
+            .min()
+            .unwrap_or(u32::MAX)
+    };
+
+    match allocation_type {
+        AllocationType::Recruitment => goods_limit(&[
+            (
+                Good::CannedFood,
+                recruitment_cost_per_unit(Good::CannedFood),
+            ),
+            (Good::Clothing, recruitment_cost_per_unit(Good::Clothing)),
+            (Good::Furniture, recruitment_cost_per_unit(Good::Furniture)),
+        ]),
+        AllocationType::Training(_) => {
+            let cash_limit = (treasury.available().max(0) / TRAINING_COST as i64) as u32;
+            let staff_limit = workforce.labor_pool.available() / TRAINING_LABOR;
+            goods_limit(&[(Good::Paper, training_cost_per_unit(Good::Paper))])
+                .min(cash_limit)
+                .min(staff_limit)
+        }
+        AllocationType::Production(_, output_good) => {
+            let Some(kind) = building_for_output(output_good) else {
+                return 0;
+            };
+            let inputs = calculate_inputs_for_one_unit(kind, output_good, stockpile);
+            // One labor point per unit produced
+            goods_limit(&inputs).min(workforce.labor_pool.available())
+        }
+        AllocationType::MarketBuy(_) => 1,
+        AllocationType::MarketSell(good) => stockpile.get_available(good),
+    }
+}
+
+/// Raise an allocation to the capacity- and resource-bounded maximum (Input Layer)
+pub fn apply_allocation_max(
+    trigger: On<MaximizeAllocation>,
+    mut commands: Commands,
+    player_nation: Option<Res<PlayerNation>>,
+    nations: Query<(&Allocations, &Stockpile, &Workforce, &Treasury)>,
+) {
+    let Some(player) = player_nation else {
+        return;
+    };
+    let Ok((alloc, stockpile, workforce, treasury)) = nations.get(player.entity()) else {
+        return;
+    };
+
+    let allocation_type = trigger.event().allocation_type;
+    let current = allocation_value(alloc, allocation_type);
+    let affordable = affordable_additional_units(allocation_type, stockpile, workforce, treasury);
+
+    // Capacity clamping happens when the target is applied
+    commands.trigger(SetAllocationTarget {
+        allocation_type,
+        target: current.saturating_add(affordable),
+    });
+}
+
+/// Set an allocation to an exact value, clamped to its capacity (Input Layer)
+/// Emits a single adjustment instead of one stepper event per unit
+pub fn apply_allocation_target(
+    trigger: On<SetAllocationTarget>,
+    mut commands: Commands,
+    player_nation: Option<Res<PlayerNation>>,
+    allocations: Query<&Allocations>,
+    buildings: Query<&Buildings>,
+    workforces: Query<&Workforce>,
+    provinces: Query<&Province>,
+    recruitment_capacity: Query<&RecruitmentCapacity>,
+) {
+    let Some(player) = player_nation else {
+        return;
+    };
+
+    let event = trigger.event();
+    let capacity = allocation_capacity(
+        event.allocation_type,
+        player.entity(),
+        &allocations,
+        &buildings,
+        &workforces,
+        &provinces,
+        &recruitment_capacity,
+    );
+    let target = capacity.map_or(event.target, |cap| event.target.min(cap));
+
+    trigger_allocation_target(
+        &mut commands,
+        player.instance(),
+        event.allocation_type,
+        target,
+    );
+    info!(
+        "{:?}: set to {} (requested {})",
+        event.allocation_type, target, event.target
+    );
 }
 
 // ============================================================================
@@ -189,8 +468,8 @@ pub fn update_all_stepper_displays(
         return;
     };
 
-    // Only run if allocations changed OR new displays were added
-    if allocations_changed.is_empty() && new_displays.is_empty() {
+    // Only run if allocations changed, new displays were added, or another player took over
+    if !player.is_changed() && allocations_changed.is_empty() && new_displays.is_empty() {
         return;
     }
 
@@ -222,8 +501,8 @@ pub fn update_all_allocation_bars(
         return;
     };
 
-    // Only run if allocations changed OR new bars were added
-    if allocations_changed.is_empty() && new_bars.is_empty() {
+    // Only run if allocations changed, new bars were added, or another player took over
+    if !player.is_changed() && allocations_changed.is_empty() && new_bars.is_empty() {
         return;
     }
 
@@ -288,8 +567,8 @@ pub fn update_all_allocation_summaries(
         return;
     };
 
-    // Only run if allocations changed OR new summaries were added
-    if allocations_changed.is_empty() && new_summaries.is_empty() {
+    // Only run if allocations changed, new summaries were added, or another player took over
+    if !player.is_changed() && allocations_changed.is_empty() && new_summaries.is_empty() {
         return;
     }
 
@@ -325,12 +604,16 @@ pub fn update_all_allocation_summaries(
                     }
                 }
 
-                AllocationType::Production(_, output_good) => {
+                AllocationType::Production(building, output_good) => {
                     let allocated = allocation_value(alloc, summary.allocation_type);
-                    if allocated > 0 {
+                    let planned = if allocated > 0 {
                         format!("-> Will produce {} {:?} next turn", allocated, output_good)
                     } else {
                         "-> No production planned".to_string()
+                    };
+                    match alloc.production_blocked.get(&(building, output_good)) {
+                        Some(reason) => format!("{planned}\n   Can't add more: {reason}"),
+                        None => planned,
                     }
                 }
 
@@ -356,3 +639,224 @@ pub fn update_all_allocation_summaries(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use bevy::ui_widgets::Activate;
+
+    use crate::economy::allocation_systems::{
+        apply_production_adjustments, execute_queued_production_orders,
+    };
+    use crate::economy::production::Buildings;
+    use crate::economy::workforce::WorkerSkill;
+    use crate::economy::{
+        Allocations, Good, Nation, NationInstance, PlayerNation, ReservationSystem, Stockpile,
+        StockpileReserves, Treasury, Workforce,
+    };
+    use crate::messages::{AdjustProduction, AdjustTraining};
+    use crate::orders::OrdersQueue;
+    use crate::ui::city::allocation_ui_unified::{
+        adjust_allocation_on_click, apply_allocation_max, apply_allocation_target,
+    };
+    use crate::ui::city::allocation_widgets::{
+        AllocationType, MaximizeAllocation, SetAllocationTarget,
+    };
+
+    fn setup_world() -> (World, Entity) {
+        let mut world = World::new();
+        world.insert_resource(OrdersQueue::default());
+        world.add_observer(apply_allocation_target);
+        world.add_observer(apply_allocation_max);
+        world.add_observer(apply_production_adjustments);
+
+        let mut stockpile = Stockpile::default();
+        stockpile.add(Good::Cotton, 40);
+        let mut workforce = Workforce::new();
+        workforce.add_untrained(20);
+        workforce.update_labor_pool();
+
+        let nation = world
+            .spawn((
+                Nation,
+                Allocations::default(),
+                ReservationSystem::default(),
+                StockpileReserves::default(),
+                stockpile,
+                workforce,
+                Treasury::new(0),
+                Buildings::with_all_initial(),
+            ))
+            .id();
+        let player = PlayerNation::from_entity(&world, nation).unwrap();
+        world.insert_resource(player);
+        (world, nation)
+    }
+
+    fn fabric_allocation(world: &World, nation: Entity) -> usize {
+        world
+            .get::<Allocations>(nation)
+            .unwrap()
+            .production_count(nation, Good::Fabric)
+    }
+
+    #[test]
+    fn exact_target_emits_single_order() {
+        let (mut world, nation) = setup_world();
+        let instance = NationInstance::from_entity(world.entity(nation)).unwrap();
+
+        world.trigger(AdjustProduction {
+            nation: instance,
+            building: nation,
+            output_good: Good::Fabric,
+            target_output: 2,
+        });
+        world
+            .run_system_once(execute_queued_production_orders)
+            .unwrap();
+        assert_eq!(fabric_allocation(&world, nation), 2);
+
+        world.trigger(SetAllocationTarget {
+            allocation_type: AllocationType::Production(nation, Good::Fabric),
+            target: 5,
+        });
+        world.flush();
+
+        let queued = world.resource::<OrdersQueue>().peek_production();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].target_output, 5);
+
+        world
+            .run_system_once(execute_queued_production_orders)
+            .unwrap();
+        assert_eq!(fabric_allocation(&world, nation), 5);
+    }
+
+    #[test]
+    fn exact_target_is_clamped_to_capacity() {
+        let (mut world, nation) = setup_world();
+
+        world.trigger(SetAllocationTarget {
+            allocation_type: AllocationType::Production(nation, Good::Fabric),
+            target: 50,
+        });
+        world.flush();
+
+        // Textile mill capacity is 8
+        let queued = world.resource::<OrdersQueue>().peek_production();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].target_output, 8);
+    }
+
+    #[test]
+    fn training_target_leaves_room_for_trainees_at_other_skills() {
+        #[derive(Resource, Default)]
+        struct Requested(Vec<u32>);
+
+        let (mut world, nation) = setup_world();
+        world.init_resource::<Requested>();
+        world.add_observer(
+            |trigger: On<AdjustTraining>, mut requested: ResMut<Requested>| {
+                requested.0.push(trigger.event().requested);
+            },
+        );
+
+        // One of the Trade School's two places is taken by a trained worker
+        let mut nation_data = world.query::<(
+            &mut Allocations,
+            &mut ReservationSystem,
+            &mut Stockpile,
+            &mut Workforce,
+            &mut Treasury,
+        )>();
+        let (mut allocations, mut reservations, mut stockpile, mut workforce, mut treasury) =
+            nation_data.get_mut(&mut world, nation).unwrap();
+        let reservation = reservations
+            .try_reserve(
+                Vec::<(Good, u32)>::new(),
+                0,
+                0,
+                &mut stockpile,
+                &mut workforce,
+                &mut treasury,
+            )
+            .expect("empty reservation");
+        allocations
+            .training
+            .entry(WorkerSkill::Trained)
+            .or_default()
+            .push(reservation);
+
+        world.trigger(SetAllocationTarget {
+            allocation_type: AllocationType::Training(WorkerSkill::Untrained),
+            target: 5,
+        });
+        world.flush();
+
+        assert_eq!(world.resource::<Requested>().0, vec![1]);
+    }
+
+    #[test]
+    fn max_reserves_resource_limit_and_zero_releases_everything() {
+        let (mut world, nation) = setup_world();
+        let fabric = AllocationType::Production(nation, Good::Fabric);
+
+        // 7 cotton covers three units (2 each), below the mill's capacity of 8
+        {
+            let mut stockpile = world.get_mut::<Stockpile>(nation).unwrap();
+            let extra = stockpile.get(Good::Cotton) - 7;
+            stockpile.take_up_to(Good::Cotton, extra);
+        }
+
+        world.trigger(MaximizeAllocation {
+            allocation_type: fabric,
+        });
+        world.flush();
+        world
+            .run_system_once(execute_queued_production_orders)
+            .unwrap();
+
+        assert_eq!(fabric_allocation(&world, nation), 3);
+        let stockpile = world.get::<Stockpile>(nation).unwrap();
+        assert_eq!(stockpile.get_reserved(Good::Cotton), 6);
+
+        world.trigger(SetAllocationTarget {
+            allocation_type: fabric,
+            target: 0,
+        });
+        world.flush();
+        world
+            .run_system_once(execute_queued_production_orders)
+            .unwrap();
+
+        assert_eq!(fabric_allocation(&world, nation), 0);
+        let stockpile = world.get::<Stockpile>(nation).unwrap();
+        assert_eq!(stockpile.get_reserved(Good::Cotton), 0);
+        assert_eq!(stockpile.get_available(Good::Cotton), 7);
+    }
+
+    #[test]
+    fn rapid_stepper_clicks_coalesce_into_one_order() {
+        let (mut world, nation) = setup_world();
+        let fabric = AllocationType::Production(nation, Good::Fabric);
+        let plus = world.spawn(adjust_allocation_on_click(fabric, 1)).id();
+        world.flush();
+
+        // Five clicks before the queue is executed
+        for _ in 0..5 {
+            world.trigger(Activate { entity: plus });
+        }
+        world.flush();
+
+        let queued = world.resource::<OrdersQueue>().peek_production();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].target_output, 5);
+
+        world
+            .run_system_once(execute_queued_production_orders)
+            .unwrap();
+        assert_eq!(fabric_allocation(&world, nation), 5);
+        assert!(world.resource::<OrdersQueue>().is_empty());
+    }
+}
@@ -32,6 +32,30 @@ pub struct AllocationStepperButton {
     pub delta: i32,
 }
 
+/// Numeric entry that sets an allocation to an exact value
+#[derive(Component, Clone, Debug)]
+pub struct AllocationValueInput {
+    pub allocation_type: AllocationType,
+    pub buffer: String,
+}
+
+/// Marks the value input currently receiving keyboard input
+#[derive(Component)]
+pub struct FocusedAllocationInput;
+
+/// Event: set an allocation to an absolute value (clamped to capacity)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SetAllocationTarget {
+    pub allocation_type: AllocationType,
+    pub target: u32,
+}
+
+/// Event: raise an allocation to the most that capacity and resources allow
+#[derive(Event, Debug, Clone, Copy)]
+pub struct MaximizeAllocation {
+    pub allocation_type: AllocationType,
+}
+
 /// Generic allocation bar (for resource requirements)
 #[derive(Component, Clone)]
 pub struct AllocationBar {
@@ -82,9 +106,12 @@ macro_rules! spawn_allocation_stepper {
         use bevy::ui::widget::Button as OldButton;
         use bevy::ui_widgets::Button;
         use $crate::ui::button_style::*;
-        use $crate::ui::city::allocation_ui_unified::adjust_allocation_on_click;
+        use $crate::ui::city::allocation_ui_unified::{
+            adjust_allocation_on_click, focus_allocation_input_on_click,
+            set_allocation_max_on_click, set_allocation_zero_on_click,
+        };
         use $crate::ui::city::allocation_widgets::{
-            AllocationStepperButton, AllocationStepperDisplay,
+            AllocationStepperButton, AllocationStepperDisplay, AllocationValueInput,
         };
 
         // Label
@@ -112,6 +139,30 @@ macro_rules! spawn_allocation_stepper {
                 ..default()
             })
             .with_children(|row| {
+                // Clear allocation
+                row.spawn((
+                    Button,
+                    OldButton,
+                    Node {
+                        padding: UiRect::all(Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor::all(Color::srgba(0.5, 0.5, 0.6, 0.8)),
+                    set_allocation_zero_on_click($allocation_type),
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("0"),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                    ));
+                });
+
                 // Only -1 button
                 row.spawn((
                     Button,
@@ -185,6 +236,75 @@ macro_rules! spawn_allocation_stepper {
                         TextColor(Color::srgb(0.9, 0.9, 1.0)),
                     ));
                 });
+
+                // Allocate as much as capacity and resources allow
+                row.spawn((
+                    Button,
+                    OldButton,
+                    Node {
+                        padding: UiRect::all(Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor::all(Color::srgba(0.5, 0.5, 0.6, 0.8)),
+                    set_allocation_max_on_click($allocation_type),
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("Max"),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                    ));
+                });
+            });
+
+        // Exact value row: click the field, type a number, press Enter
+        $parent
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                margin: UiRect::top(Val::Px(4.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new("Set to:"),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                ));
+
+                row.spawn((
+                    Button,
+                    OldButton,
+                    Node {
+                        min_width: Val::Px(60.0),
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.12, 0.9)),
+                    BorderColor::all(Color::srgba(0.5, 0.5, 0.6, 0.8)),
+                    Text::new("..."),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                    AllocationValueInput {
+                        allocation_type: $allocation_type,
+                        buffer: String::new(),
+                    },
+                    focus_allocation_input_on_click(),
+                ));
             });
     }};
 }
@@ -38,6 +38,16 @@ pub struct WarehouseHUD;
 #[derive(Component)]
 pub struct WarehouseStockDisplay;
 
+/// Display for goods held in the player's other cities (updates live)
+#[derive(Component)]
+pub struct CityStoresDisplay;
+
+/// Display for the player's reserve floor of one good (updates live)
+#[derive(Component)]
+pub struct ReserveFloorDisplay {
+    pub good: Good,
+}
+
 /// Top left: Province resources HUD
 #[derive(Component)]
 pub struct ProvinceResourcesHUD;
@@ -46,6 +56,14 @@ pub struct ProvinceResourcesHUD;
 #[derive(Component)]
 pub struct ProvinceResourcesDisplay;
 
+/// Bottom right: Connected resources panel
+#[derive(Component)]
+pub struct ConnectedResourcesPanel;
+
+/// Display for raw resources reaching the capital (updates live)
+#[derive(Component)]
+pub struct ConnectedResourcesDisplay;
+
 // ============ Building Grid Components ============
 
 /// Marker for the building grid container
@@ -70,6 +88,18 @@ pub struct ProductionLaborDisplay {
     pub output_good: Good,
 }
 
+/// Production dialog line projecting next turn's output from pending allocations
+#[derive(Component)]
+pub struct ProductionPreviewDisplay {
+    pub building_kind: BuildingKind,
+}
+
+/// Production dialog line showing the building's rank for scarce inputs
+#[derive(Component)]
+pub struct BuildingPriorityDisplay {
+    pub building_kind: BuildingKind,
+}
+
 /// Marker for Capitol dialog requirement displays
 #[derive(Component)]
 pub struct CapitolRequirementDisplay {
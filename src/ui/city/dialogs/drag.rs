@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
-use crate::ui::city::dialogs::types::{DialogDragHandle, DialogDragState};
+use crate::ui::city::dialogs::types::{
+    BuildingDialog, DialogDragHandle, DialogDragState, DialogLayout,
+};
 
 /// Start dragging when clicking on the dialog header (Input Layer)
 pub fn start_dialog_drag(
@@ -50,16 +52,21 @@ pub fn start_dialog_drag(
 }
 
 /// Update dialog position while dragging (Logic Layer)
+/// Stores the final position in `DialogLayout` when the drag ends
 pub fn update_dialog_drag(
     mouse_button: Res<ButtonInput<MouseButton>>,
     window: Query<&Window, With<PrimaryWindow>>,
-    mut dialogs: Query<(&mut DialogDragState, &mut Node)>,
+    mut layout: ResMut<DialogLayout>,
+    mut dialogs: Query<(&mut DialogDragState, &mut Node, Option<&BuildingDialog>)>,
 ) {
     // Stop dragging if mouse button is released
     if !mouse_button.pressed(MouseButton::Left) {
-        for (mut drag_state, _) in dialogs.iter_mut() {
+        for (mut drag_state, node, dialog) in dialogs.iter_mut() {
             if drag_state.is_dragging {
                 drag_state.is_dragging = false;
+                if let Some(dialog) = dialog {
+                    layout.remember_node(dialog.building_kind, &node);
+                }
             }
         }
         return;
@@ -74,7 +81,7 @@ pub fn update_dialog_drag(
     };
 
     // Update position of any dialogs being dragged
-    for (drag_state, mut node) in dialogs.iter_mut() {
+    for (drag_state, mut node, _) in dialogs.iter_mut() {
         if drag_state.is_dragging {
             let new_left = cursor_position.x - drag_state.drag_offset.x;
             let new_top = cursor_position.y - drag_state.drag_offset.y;
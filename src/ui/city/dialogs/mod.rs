@@ -8,7 +8,10 @@ pub mod window;
 
 // Re-export key types and functions
 pub use drag::{start_dialog_drag, update_dialog_drag, update_drag_handle_cursor};
-pub use production::{populate_production_dialog, update_production_labor_display};
+pub use production::{
+    populate_production_dialog, update_building_priority_display, update_production_labor_display,
+    update_production_preview_display,
+};
 pub use special::{
     populate_special_dialog, update_capitol_capacity_display, update_capitol_requirement_displays,
     update_trade_school_paper_display, update_trade_school_workforce_display,
@@ -16,6 +19,6 @@ pub use special::{
 pub use systems::{close_building_dialogs, open_building_dialogs};
 pub use types::{
     BuildingDialog, CloseBuildingDialog, DialogCloseButton, DialogContentArea, DialogDragHandle,
-    DialogDragState, DialogZIndexCounter, OpenBuildingDialog,
+    DialogDragState, DialogLayout, DialogZIndexCounter, OpenBuildingDialog,
 };
 pub use window::{spawn_dialog_frame, update_close_button_visuals};
@@ -1,12 +1,19 @@
 use bevy::prelude::*;
+use bevy::ui::widget::Button as OldButton;
+use bevy::ui_widgets::{Activate, Button, observe};
 
 use crate::economy::production::{
-    Building, BuildingKind, Buildings, ProductionSettings, production_recipe,
+    Building, BuildingKind, Buildings, ProductionPreview, ProductionReport, ProductionResult,
+    ProductionSettings, preview_production, production_recipe,
 };
 use crate::economy::transport::state::TransportCommodity;
 use crate::economy::{Good, PlayerNation, Stockpile, Workforce};
+use crate::messages::SetBuildingPriority;
+use crate::ui::button_style::*;
 use crate::ui::city::allocation_widgets::AllocationType;
-use crate::ui::city::components::ProductionLaborDisplay;
+use crate::ui::city::components::{
+    BuildingPriorityDisplay, ProductionLaborDisplay, ProductionPreviewDisplay,
+};
 
 use crate::ui::city::dialogs::types::BuildingDialog;
 
@@ -17,6 +24,7 @@ pub fn populate_production_dialog(
     new_dialogs: Query<&BuildingDialog, Added<BuildingDialog>>,
     buildings_collections: Query<&Buildings>,
     settings_query: Query<&ProductionSettings>,
+    results_query: Query<&ProductionResult>,
     player_nation: Option<Res<PlayerNation>>,
     stockpiles: Query<&Stockpile>,
     workforces: Query<&Workforce>,
@@ -63,6 +71,10 @@ pub fn populate_production_dialog(
         };
 
         let content_entity = dialog.content_entity;
+        let last_report = results_query
+            .get(player.entity())
+            .ok()
+            .and_then(|results| results.get(dialog.building_kind));
 
         // Populate content based on building kind
         spawn_production_content(
@@ -71,6 +83,7 @@ pub fn populate_production_dialog(
             dialog.building_entity,
             &building,
             settings,
+            last_report,
             stockpile,
             workforce,
             &asset_server,
@@ -85,6 +98,7 @@ fn spawn_production_content(
     building_entity: Entity,
     building: &Building,
     _settings: &ProductionSettings,
+    last_report: Option<&ProductionReport>,
     stockpile: &Stockpile,
     workforce: &Workforce,
     asset_server: &AssetServer,
@@ -130,6 +144,78 @@ fn spawn_production_content(
                 ..default()
             },
         ));
+
+        // Efficiency readout: actual vs target output last turn
+        let (efficiency_text, efficiency_color) = efficiency_readout(last_report);
+        content.spawn((
+            Text::new(efficiency_text),
+            TextFont {
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(efficiency_color),
+            Node {
+                margin: UiRect::bottom(Val::Px(8.0)),
+                ..default()
+            },
+        ));
+
+        // Projection of the pending allocation, filled in by update_production_preview_display
+        content.spawn((
+            Text::new(preview_readout(None)),
+            TextFont {
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.8, 0.95)),
+            Node {
+                margin: UiRect::bottom(Val::Px(8.0)),
+                ..default()
+            },
+            ProductionPreviewDisplay {
+                building_kind: building.kind,
+            },
+        ));
+
+        // Input priority: higher-ranked buildings reserve and use scarce inputs first
+        content
+            .spawn(Node {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(6.0),
+                margin: UiRect::bottom(Val::Px(8.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new(priority_readout(0)),
+                    TextFont {
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    BuildingPriorityDisplay { building_kind },
+                ));
+                for (label, delta) in [("-", -1), ("+", 1)] {
+                    row.spawn((
+                        Button,
+                        OldButton,
+                        Node {
+                            padding: UiRect::axes(Val::Px(8.0), Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        priority_button(building_kind, delta),
+                        children![(
+                            Text::new(label),
+                            TextFont {
+                                font_size: 13.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                        )],
+                    ));
+                }
+            });
     });
 
     // For each output good, show a production section
@@ -147,6 +233,89 @@ fn spawn_production_content(
     }
 }
 
+fn priority_readout(priority: u8) -> String {
+    format!("Input priority: {}", priority)
+}
+
+/// Raise or lower the player's priority for `building_kind` by `delta`
+fn priority_button(building_kind: BuildingKind, delta: i8) -> impl Bundle {
+    observe(
+        move |_activate: On<Activate>,
+              player: Option<Res<PlayerNation>>,
+              nations: Query<&Buildings>,
+              mut commands: Commands| {
+            let Some(player) = player else {
+                return;
+            };
+            let Ok(buildings) = nations.get(player.entity()) else {
+                return;
+            };
+            commands.trigger(SetBuildingPriority {
+                nation: player.instance(),
+                building: building_kind,
+                priority: buildings
+                    .priority(building_kind)
+                    .saturating_add_signed(delta),
+            });
+        },
+    )
+}
+
+/// Text and color describing how well a building met its target last turn
+fn efficiency_readout(report: Option<&ProductionReport>) -> (String, Color) {
+    let Some(report) = report else {
+        return (
+            "Last turn: no production".to_string(),
+            Color::srgb(0.7, 0.7, 0.7),
+        );
+    };
+
+    let summary = format!(
+        "Last turn: {}/{} ({:.0}%)",
+        report.produced,
+        report.target,
+        report.efficiency() * 100.0
+    );
+
+    if !report.is_below_target() {
+        return (summary, Color::srgb(0.7, 0.9, 0.7));
+    }
+
+    let reason = match report.limiting_input {
+        Some(good) => format!("short on {}", good),
+        None => "limited by labor or capacity".to_string(),
+    };
+    (
+        format!("{} - {}", summary, reason),
+        Color::srgb(1.0, 0.6, 0.3),
+    )
+}
+
+/// Text projecting what a building's pending allocation yields next turn
+fn preview_readout(preview: Option<&ProductionPreview>) -> String {
+    let Some(preview) = preview.filter(|preview| preview.report.target > 0) else {
+        return "Next turn: nothing allocated".to_string();
+    };
+
+    let goods = |amounts: &[(Good, u32)]| {
+        if amounts.is_empty() {
+            return "nothing".to_string();
+        }
+        amounts
+            .iter()
+            .map(|(good, amount)| format!("{} {}", amount, good))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    format!(
+        "Next turn: {} from {} ({} labor)",
+        goods(&preview.outputs),
+        goods(&preview.inputs),
+        preview.labor
+    )
+}
+
 /// Spawn a single production section (recipe + allocation UI) for one output
 fn spawn_production_section(
     commands: &mut Commands,
@@ -363,6 +532,69 @@ pub fn update_production_labor_display(
     }
 }
 
+/// Update the next-turn projection in open production dialogs (Rendering Layer)
+/// Recomputed only when the player's allocations change or a dialog opens
+pub fn update_production_preview_display(
+    player_nation: Option<Res<PlayerNation>>,
+    nations: Query<(
+        Ref<crate::economy::Allocations>,
+        Ref<Buildings>,
+        &Stockpile,
+        &Workforce,
+    )>,
+    new_displays: Query<(), Added<ProductionPreviewDisplay>>,
+    mut display_query: Query<(&mut Text, &ProductionPreviewDisplay)>,
+) {
+    let Some(player) = player_nation else {
+        return;
+    };
+
+    let Ok((allocations, buildings, stockpile, workforce)) = nations.get(player.entity()) else {
+        return;
+    };
+
+    // Priorities decide which building gets scarce inputs, so they change the projection too
+    if !allocations.is_changed() && !buildings.is_changed() && new_displays.is_empty() {
+        return;
+    }
+
+    let previews = preview_production(&allocations, &buildings, stockpile, workforce);
+    for (mut text, display) in display_query.iter_mut() {
+        let preview = previews
+            .iter()
+            .find(|preview| preview.kind == display.building_kind);
+        let readout = preview_readout(preview);
+        if **text != readout {
+            **text = readout;
+        }
+    }
+}
+
+/// Show each production dialog's building priority, refreshed when the player changes it
+pub fn update_building_priority_display(
+    player_nation: Option<Res<PlayerNation>>,
+    nations: Query<Ref<Buildings>>,
+    new_displays: Query<(), Added<BuildingPriorityDisplay>>,
+    mut display_query: Query<(&mut Text, &BuildingPriorityDisplay)>,
+) {
+    let Some(player) = player_nation else {
+        return;
+    };
+    let Ok(buildings) = nations.get(player.entity()) else {
+        return;
+    };
+    if !buildings.is_changed() && new_displays.is_empty() {
+        return;
+    }
+
+    for (mut text, display) in display_query.iter_mut() {
+        let readout = priority_readout(buildings.priority(display.building_kind));
+        if **text != readout {
+            **text = readout;
+        }
+    }
+}
+
 /// Get recipe for a building and choice
 /// Get recipe for a specific output good
 /// Returns (inputs, output) where inputs shows ALL possible alternatives
@@ -1,11 +1,17 @@
 use bevy::prelude::*;
+use bevy::ui::widget::Button as OldButton;
+use bevy::ui_widgets::{Activate, Button, observe};
 
-use crate::economy::production::BuildingKind;
-use crate::economy::workforce::calculate_recruitment_cap;
+use crate::economy::production::{
+    BuildingKind, Buildings, POWER_PLANT_FUEL_PER_TURN, POWER_PLANT_LABOR_BONUS_PERCENT,
+};
+use crate::economy::workforce::{CAPITOL_UPGRADE_COST, calculate_recruitment_cap};
 use crate::economy::{
     Good, PlayerNation, RecruitmentCapacity, RecruitmentQueue, Stockpile, WorkerSkill, Workforce,
 };
 use crate::map::province::Province;
+use crate::messages::UpgradeCapitol;
+use crate::ui::button_style::*;
 use crate::ui::city::allocation_widgets::AllocationType;
 use crate::ui::city::components::{
     CapitolCapacityDisplay, CapitolRequirementDisplay, TradeSchoolPaperDisplay,
@@ -24,6 +30,7 @@ pub fn populate_special_dialog(
     recruitment_caps: Query<&RecruitmentCapacity>,
     recruitment_queues: Query<&RecruitmentQueue>,
     provinces: Query<&Province>,
+    buildings: Query<&Buildings>,
 ) {
     let Some(player) = player_nation else {
         return;
@@ -57,13 +64,16 @@ pub fn populate_special_dialog(
                     province_count,
                     recruitment_caps.get(player_entity).ok(),
                     recruitment_queues.get(player_entity).ok(),
+                    buildings
+                        .get(player_entity)
+                        .ok()
+                        .and_then(Buildings::capitol_capacity),
                 );
             }
             BuildingKind::TradeSchool => {
                 spawn_trade_school_content(&mut commands, content_entity, stockpile, workforce);
             }
             BuildingKind::PowerPlant => {
-                // TODO: Power Plant needs different handling - fuel conversion
                 spawn_power_plant_content(&mut commands, content_entity, stockpile);
             }
             _ => continue, // Not a special building
@@ -79,9 +89,10 @@ fn spawn_capitol_content(
     province_count: u32,
     recruitment_cap: Option<&RecruitmentCapacity>,
     recruitment_queue: Option<&RecruitmentQueue>,
+    capitol_capacity: Option<u32>,
 ) {
     let upgraded = recruitment_cap.map(|c| c.upgraded).unwrap_or(false);
-    let cap = calculate_recruitment_cap(province_count, upgraded);
+    let cap = calculate_recruitment_cap(province_count, upgraded, capitol_capacity);
     let queued = recruitment_queue.map(|q| q.queued).unwrap_or(0);
     let _remaining = cap.saturating_sub(queued);
 
@@ -198,6 +209,28 @@ fn spawn_capitol_content(
             CapitolCapacityDisplay,
         ));
 
+        if !upgraded {
+            content.spawn((
+                Button,
+                OldButton,
+                Node {
+                    align_self: AlignSelf::Start,
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(NORMAL_BUTTON),
+                upgrade_capitol_button(),
+                children![(
+                    Text::new(format!("Upgrade Capitol (${})", CAPITOL_UPGRADE_COST)),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                )],
+            ));
+        }
+
         // NEW: Allocation stepper (using macro)
         crate::spawn_allocation_stepper!(content, "Allocate Workers", AllocationType::Recruitment);
 
@@ -229,6 +262,20 @@ fn spawn_capitol_content(
     });
 }
 
+/// Pay for a larger Capitol so more workers can be recruited each turn
+fn upgrade_capitol_button() -> impl Bundle {
+    observe(
+        |_activate: On<Activate>, player: Option<Res<PlayerNation>>, mut commands: Commands| {
+            let Some(player) = player else {
+                return;
+            };
+            commands.trigger(UpgradeCapitol {
+                nation: player.instance(),
+            });
+        },
+    )
+}
+
 /// Spawn Trade School dialog content (worker training)
 fn spawn_trade_school_content(
     commands: &mut Commands,
@@ -417,7 +464,7 @@ fn spawn_power_plant_content(
             .with_children(|section| {
                 section.spawn((
                     Text::new(
-                        "The Power Plant converts fuel into additional labor points each turn.",
+                        "The Power Plant burns fuel to raise the labor every building can use.",
                     ),
                     TextFont {
                         font_size: 14.0,
@@ -440,7 +487,10 @@ fn spawn_power_plant_content(
                 ));
 
                 section.spawn((
-                    Text::new("Conversion: 1 fuel -> 2 labor points"),
+                    Text::new(format!(
+                        "Conversion: {} fuel per turn -> +{}% labor",
+                        POWER_PLANT_FUEL_PER_TURN, POWER_PLANT_LABOR_BONUS_PERCENT
+                    )),
                     TextFont {
                         font_size: 13.0,
                         ..default()
@@ -449,7 +499,7 @@ fn spawn_power_plant_content(
                 ));
 
                 section.spawn((
-                    Text::new("Note: Conversion happens automatically during production phase."),
+                    Text::new("Note: Fuel is set aside at the start of each turn and burned when it ends."),
                     TextFont {
                         font_size: 12.0,
                         ..default()
@@ -500,12 +550,13 @@ pub fn update_capitol_requirement_displays(
     }
 }
 
-/// Update Capitol capacity display when recruitment queue changes
+/// Update Capitol capacity display when the recruitment queue or the Capitol changes
 pub fn update_capitol_capacity_display(
     player_nation: Option<Res<PlayerNation>>,
-    recruitment_cap_query: Query<&RecruitmentCapacity>,
-    recruitment_queue_query: Query<&RecruitmentQueue, Changed<RecruitmentQueue>>,
+    recruitment_cap_query: Query<Ref<RecruitmentCapacity>>,
+    recruitment_queue_query: Query<Ref<RecruitmentQueue>>,
     provinces: Query<&Province>,
+    buildings: Query<&Buildings>,
     mut display_query: Query<(&mut Text, &mut TextColor), With<CapitolCapacityDisplay>>,
 ) {
     let Some(player) = player_nation else {
@@ -514,9 +565,15 @@ pub fn update_capitol_capacity_display(
 
     let player_entity = player.entity();
 
-    let Ok(_queue) = recruitment_queue_query.get(player_entity) else {
+    let Ok(queue) = recruitment_queue_query.get(player_entity) else {
         return;
     };
+    let capacity_changed = recruitment_cap_query
+        .get(player_entity)
+        .is_ok_and(|capacity| capacity.is_changed());
+    if !queue.is_changed() && !capacity_changed {
+        return;
+    }
 
     let province_count = provinces
         .iter()
@@ -527,7 +584,11 @@ pub fn update_capitol_capacity_display(
         .get(player_entity)
         .map(|c| c.upgraded)
         .unwrap_or(false);
-    let cap = calculate_recruitment_cap(province_count, upgraded);
+    let capitol_capacity = buildings
+        .get(player_entity)
+        .ok()
+        .and_then(Buildings::capitol_capacity);
+    let cap = calculate_recruitment_cap(province_count, upgraded, capitol_capacity);
     let queued = recruitment_queue_query
         .get(player_entity)
         .map(|q| q.queued)
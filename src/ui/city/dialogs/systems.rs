@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use crate::economy::production::BuildingKind;
 
 use crate::ui::city::dialogs::types::{
-    BuildingDialog, CloseBuildingDialog, DialogZIndexCounter, OpenBuildingDialog,
+    BuildingDialog, CloseBuildingDialog, DialogLayout, DialogZIndexCounter, OpenBuildingDialog,
 };
 use crate::ui::city::dialogs::window::spawn_dialog_frame;
 
@@ -13,6 +13,7 @@ pub fn open_building_dialogs(
     trigger: On<OpenBuildingDialog>,
     mut commands: Commands,
     mut z_counter: ResMut<DialogZIndexCounter>,
+    layout: Res<DialogLayout>,
     city_screen: Query<Entity, With<crate::ui::city::components::CityScreen>>,
     existing_dialogs: Query<&BuildingDialog>,
 ) {
@@ -61,6 +62,7 @@ pub fn open_building_dialogs(
         event.building_entity,
         event.building_kind,
         z_index,
+        layout.position(event.building_kind),
     );
 
     info!(
@@ -73,15 +75,18 @@ pub fn open_building_dialogs(
 
 /// Close building dialogs (Logic Layer)
 /// Despawns dialog windows when CloseBuildingDialog messages are received
+/// and remembers where they were so reopening restores the position
 pub fn close_building_dialogs(
     trigger: On<CloseBuildingDialog>,
     mut commands: Commands,
-    dialogs: Query<(Entity, &BuildingDialog)>,
+    mut layout: ResMut<DialogLayout>,
+    dialogs: Query<(Entity, &BuildingDialog, &Node)>,
 ) {
     let event = trigger.event();
     // Find and despawn the dialog (children will be despawned automatically)
-    for (entity, dialog) in dialogs.iter() {
+    for (entity, dialog, node) in dialogs.iter() {
         if dialog.building_entity == event.building_entity {
+            layout.remember_node(dialog.building_kind, node);
             // In Bevy 0.17, despawn() on parent with with_children hierarchy
             // will clean up children automatically
             commands.entity(entity).despawn();
@@ -89,3 +94,63 @@ pub fn close_building_dialogs(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::economy::production::BuildingKind;
+    use crate::ui::city::components::CityScreen;
+    use crate::ui::city::dialogs::systems::{close_building_dialogs, open_building_dialogs};
+    use crate::ui::city::dialogs::types::{
+        BuildingDialog, CloseBuildingDialog, DialogLayout, DialogZIndexCounter, OpenBuildingDialog,
+    };
+
+    fn dialog_position(world: &mut World) -> (Val, Val) {
+        let mut query = world.query::<(&BuildingDialog, &Node)>();
+        let (_, node) = query.single(world).expect("dialog should be open");
+        (node.left, node.top)
+    }
+
+    #[test]
+    fn reopening_dialog_restores_moved_position() {
+        let mut app = App::new();
+        app.init_resource::<DialogZIndexCounter>()
+            .init_resource::<DialogLayout>()
+            .add_observer(open_building_dialogs)
+            .add_observer(close_building_dialogs);
+
+        app.world_mut().spawn((Node::default(), CityScreen));
+        let building_entity = app.world_mut().spawn_empty().id();
+        let open = OpenBuildingDialog {
+            building_entity,
+            building_kind: BuildingKind::SteelMill,
+        };
+
+        app.world_mut().trigger(open);
+        app.world_mut().flush();
+
+        // Simulate the player dragging the dialog somewhere else
+        let mut query = app.world_mut().query::<(&BuildingDialog, &mut Node)>();
+        let (_, mut node) = query.single_mut(app.world_mut()).unwrap();
+        node.left = Val::Px(42.0);
+        node.top = Val::Px(17.0);
+
+        app.world_mut()
+            .trigger(CloseBuildingDialog { building_entity });
+        app.world_mut().flush();
+        assert_eq!(
+            app.world()
+                .resource::<DialogLayout>()
+                .position(BuildingKind::SteelMill),
+            Some(Vec2::new(42.0, 17.0))
+        );
+
+        app.world_mut().trigger(open);
+        app.world_mut().flush();
+        assert_eq!(
+            dialog_position(app.world_mut()),
+            (Val::Px(42.0), Val::Px(17.0))
+        );
+    }
+}
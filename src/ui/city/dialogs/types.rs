@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 use crate::economy::production::BuildingKind;
 
@@ -44,6 +45,31 @@ impl DialogZIndexCounter {
     }
 }
 
+/// Last known on-screen position (left, top) of each building's dialog.
+/// Restored when the dialog is reopened and saved with the game.
+#[derive(Resource, Default, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct DialogLayout {
+    positions: HashMap<BuildingKind, Vec2>,
+}
+
+impl DialogLayout {
+    pub fn position(&self, kind: BuildingKind) -> Option<Vec2> {
+        self.positions.get(&kind).copied()
+    }
+
+    pub fn remember(&mut self, kind: BuildingKind, position: Vec2) {
+        self.positions.insert(kind, position);
+    }
+
+    /// Record the position of a dialog node if it is placed in pixels
+    pub fn remember_node(&mut self, kind: BuildingKind, node: &Node) {
+        if let (Val::Px(left), Val::Px(top)) = (node.left, node.top) {
+            self.remember(kind, Vec2::new(left, top));
+        }
+    }
+}
+
 /// Marker for the content area inside a dialog
 #[derive(Component)]
 pub struct DialogContentArea;
@@ -7,6 +7,7 @@ use crate::ui::city::dialogs::types::{
 };
 
 /// Spawn a dialog window frame (Rendering Layer)
+/// Uses `position` (left, top) when given, otherwise cascades based on z-index.
 /// Returns the entity ID of the dialog
 pub fn spawn_dialog_frame(
     commands: &mut Commands,
@@ -15,7 +16,14 @@ pub fn spawn_dialog_frame(
     building_entity: Entity,
     building_kind: crate::economy::production::BuildingKind,
     z_index: i32,
+    position: Option<Vec2>,
 ) -> Entity {
+    // Position dialogs in a cascading pattern based on z-index unless a position was stored
+    let position = position.unwrap_or(Vec2::new(
+        300.0 + (z_index as f32 * 30.0),
+        200.0 + (z_index as f32 * 30.0),
+    ));
+
     let mut dialog_entity = Entity::PLACEHOLDER;
     let mut content_entity = Entity::PLACEHOLDER;
     let mut header_entity = Entity::PLACEHOLDER;
@@ -25,9 +33,8 @@ pub fn spawn_dialog_frame(
             .spawn((
                 Node {
                     position_type: PositionType::Absolute,
-                    // Position dialogs in a cascading pattern based on z-index
-                    left: Val::Px(300.0 + (z_index as f32 * 30.0)),
-                    top: Val::Px(200.0 + (z_index as f32 * 30.0)),
+                    left: Val::Px(position.x),
+                    top: Val::Px(position.y),
                     width: Val::Px(380.0),
                     min_height: Val::Px(400.0),
                     flex_direction: FlexDirection::Column,
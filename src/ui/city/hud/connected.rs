@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+
+use crate::economy::{ConnectedProduction, PlayerNation};
+use crate::resources::ALL_RESOURCES;
+use crate::ui::city::components::{ConnectedResourcesDisplay, ConnectedResourcesPanel};
+
+/// Spawn the connected resources panel (bottom right) (Rendering Layer)
+/// Takes the parent entity and commands to spawn the panel
+pub fn spawn_connected_resources_panel(commands: &mut Commands, parent_entity: Entity) {
+    commands.entity(parent_entity).with_children(|parent| {
+        parent
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(10.0),
+                    bottom: Val::Px(10.0),
+                    width: Val::Px(200.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    row_gap: Val::Px(8.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.1, 0.12, 0.15, 0.95)),
+                BorderColor::all(Color::srgba(0.4, 0.45, 0.6, 0.9)),
+                ConnectedResourcesPanel,
+            ))
+            .with_children(|panel| {
+                // Title
+                panel.spawn((
+                    Text::new("REACHING CAPITAL"),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(8.0)),
+                        ..default()
+                    },
+                ));
+
+                // Per-good totals (updates live)
+                panel.spawn((
+                    Text::new("Nothing connected"),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                    ConnectedResourcesDisplay,
+                ));
+            });
+    });
+}
+
+/// Update connected resources display (Rendering Layer)
+/// Only rebuilds the text when connected production changes or the panel is (re)spawned
+pub fn update_connected_resources_display(
+    player_nation: Option<Res<PlayerNation>>,
+    production: Res<ConnectedProduction>,
+    new_displays: Query<(), Added<ConnectedResourcesDisplay>>,
+    mut display_text: Query<&mut Text, With<ConnectedResourcesDisplay>>,
+) {
+    let Some(player) = player_nation else {
+        return;
+    };
+
+    if !production.is_changed() && !player.is_changed() && new_displays.is_empty() {
+        return;
+    }
+
+    let lines: Vec<String> = ALL_RESOURCES
+        .iter()
+        .map(|resource| resource.to_good())
+        .filter_map(|good| {
+            let amount = production.available(player.entity(), good);
+            (amount > 0).then(|| format!("{}: {}", good, amount))
+        })
+        .collect();
+    let summary = if lines.is_empty() {
+        "Nothing connected".to_string()
+    } else {
+        lines.join("\n")
+    };
+
+    for mut text in display_text.iter_mut() {
+        if **text != summary {
+            **text = summary.clone();
+        }
+    }
+}
@@ -1,6 +1,11 @@
 use bevy::prelude::*;
+use bevy::ui::widget::Button as OldButton;
+use bevy::ui_widgets::{Activate, Button, observe};
 
-use crate::economy::{PlayerNation, WorkerSkill, Workforce};
+use crate::economy::allocation_systems::auto_assign_idle_labor;
+use crate::economy::production::Buildings;
+use crate::economy::{Allocations, PlayerNation, Stockpile, WorkerSkill, Workforce};
+use crate::ui::button_style::*;
 use crate::ui::city::components::{AvailableLaborDisplay, LaborPoolPanel, WorkforceCountDisplay};
 
 /// Spawn the labor pool panel (left border) (Rendering Layer)
@@ -71,14 +76,64 @@ pub fn spawn_labor_pool_panel(commands: &mut Commands, parent_entity: Entity) {
                     TextColor(Color::srgb(0.9, 0.9, 0.9)),
                     WorkforceCountDisplay,
                 ));
+
+                panel.spawn((
+                    Button,
+                    OldButton,
+                    Node {
+                        align_self: AlignSelf::Start,
+                        margin: UiRect::top(Val::Px(6.0)),
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    auto_assign_button(),
+                    children![(
+                        Text::new("Auto-assign"),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                    )],
+                ));
             });
     });
 }
 
+/// Put the player's idle labor to work in buildings with spare capacity and inputs
+fn auto_assign_button() -> impl Bundle {
+    observe(
+        |_activate: On<Activate>,
+         player: Option<Res<PlayerNation>>,
+         nations: Query<(&Workforce, &Allocations, &Buildings, &Stockpile)>,
+         mut commands: Commands| {
+            let Some(player) = player else {
+                return;
+            };
+            let Ok((workforce, allocations, buildings, stockpile)) = nations.get(player.entity())
+            else {
+                return;
+            };
+            for request in auto_assign_idle_labor(
+                player.instance(),
+                workforce,
+                allocations,
+                buildings,
+                stockpile,
+            ) {
+                commands.trigger(request);
+            }
+        },
+    )
+}
+
 /// Update available labor display (Rendering Layer)
+/// Only rebuilds the text when the player's Workforce changes or the panel is (re)spawned
 pub fn update_labor_display(
     player_nation: Option<Res<PlayerNation>>,
-    workforce_query: Query<&Workforce>,
+    workforce_query: Query<Ref<Workforce>>,
+    new_displays: Query<(), Added<AvailableLaborDisplay>>,
     mut labor_text: Query<&mut Text, With<AvailableLaborDisplay>>,
 ) {
     let Some(player) = player_nation else {
@@ -89,17 +144,25 @@ pub fn update_labor_display(
         return;
     };
 
-    let available = workforce.available_labor();
+    if !workforce.is_changed() && !player.is_changed() && new_displays.is_empty() {
+        return;
+    }
+
+    let available = format!("Available: {} labor", workforce.available_labor());
 
     for mut text in labor_text.iter_mut() {
-        **text = format!("Available: {} labor", available);
+        if **text != available {
+            **text = available.clone();
+        }
     }
 }
 
 /// Update workforce counts display (Rendering Layer)
+/// Only rebuilds the text when the player's Workforce changes or the panel is (re)spawned
 pub fn update_workforce_display(
     player_nation: Option<Res<PlayerNation>>,
-    workforce_query: Query<&Workforce>,
+    workforce_query: Query<Ref<Workforce>>,
+    new_displays: Query<(), Added<WorkforceCountDisplay>>,
     mut count_text: Query<&mut Text, With<WorkforceCountDisplay>>,
 ) {
     let Some(player) = player_nation else {
@@ -110,14 +173,21 @@ pub fn update_workforce_display(
         return;
     };
 
+    if !workforce.is_changed() && !player.is_changed() && new_displays.is_empty() {
+        return;
+    }
+
     let untrained = workforce.count_by_skill(WorkerSkill::Untrained);
     let trained = workforce.count_by_skill(WorkerSkill::Trained);
     let expert = workforce.count_by_skill(WorkerSkill::Expert);
 
+    let counts = format!(
+        "Untrained: {}\nTrained: {}\nExpert: {}",
+        untrained, trained, expert
+    );
     for mut text in count_text.iter_mut() {
-        **text = format!(
-            "Untrained: {}\nTrained: {}\nExpert: {}",
-            untrained, trained, expert
-        );
+        if **text != counts {
+            **text = counts.clone();
+        }
     }
 }
@@ -1,7 +1,17 @@
 use bevy::prelude::*;
+use bevy::ui::widget::Button as OldButton;
+use bevy::ui_widgets::{Activate, Button, observe};
 
-use crate::economy::{Good, PlayerNation, Stockpile};
-use crate::ui::city::components::{WarehouseHUD, WarehouseStockDisplay};
+use crate::economy::{CityStockpile, Good, PlayerNation, Stockpile, StockpileReserves};
+use crate::map::province::{City, Province};
+use crate::messages::{SetReserveFloor, TransferGoods};
+use crate::ui::button_style::*;
+use crate::ui::city::components::{
+    CityStoresDisplay, ReserveFloorDisplay, WarehouseHUD, WarehouseStockDisplay,
+};
+
+/// Goods whose reserve floor the player can set from the warehouse
+const RESERVE_GOODS: [Good; 4] = [Good::Grain, Good::Fruit, Good::Livestock, Good::Coal];
 
 /// Spawn the warehouse HUD (top center) (Rendering Layer)
 /// Takes the parent entity and commands to spawn the panel
@@ -57,25 +67,195 @@ pub fn spawn_warehouse_hud(commands: &mut Commands, parent_entity: Entity) {
                         ..default()
                     },
                 ));
+
+                // Goods waiting in other cities, and a button to ship them home
+                hud.spawn(Node {
+                    align_self: AlignSelf::Center,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new("Other cities: empty"),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                        CityStoresDisplay,
+                    ));
+                    row.spawn((
+                        Button,
+                        OldButton,
+                        Node {
+                            padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        ship_to_capital_button(),
+                        children![(
+                            Text::new("Ship to capital"),
+                            TextFont {
+                                font_size: 13.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                        )],
+                    ));
+                });
+
+                // Reserve floors: stock that allocations and trading leave untouched
+                hud.spawn(Node {
+                    align_self: AlignSelf::Center,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new("Keep in reserve:"),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                        Node {
+                            margin: UiRect::right(Val::Px(4.0)),
+                            ..default()
+                        },
+                    ));
+                    for good in RESERVE_GOODS {
+                        row.spawn((
+                            Text::new(format!("{} 0", good)),
+                            TextFont {
+                                font_size: 13.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                            ReserveFloorDisplay { good },
+                        ));
+                        for (label, step) in [("-", -1), ("+", 1)] {
+                            row.spawn((
+                                Button,
+                                OldButton,
+                                Node {
+                                    padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(NORMAL_BUTTON),
+                                reserve_step_button(good, step),
+                                children![(
+                                    Text::new(label),
+                                    TextFont {
+                                        font_size: 13.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                                )],
+                            ));
+                        }
+                    }
+                });
             });
     });
 }
 
+/// Raise or lower the player's reserve floor for `good` by `step` units
+fn reserve_step_button(good: Good, step: i32) -> impl Bundle {
+    observe(
+        move |_activate: On<Activate>,
+              player: Option<Res<PlayerNation>>,
+              reserves: Query<&StockpileReserves>,
+              mut commands: Commands| {
+            let Some(player) = player else {
+                return;
+            };
+            let Ok(reserves) = reserves.get(player.entity()) else {
+                return;
+            };
+            commands.trigger(SetReserveFloor {
+                nation: player.instance(),
+                good,
+                floor: reserves.floor(good).saturating_add_signed(step),
+            });
+        },
+    )
+}
+
+/// The player's cities other than the capital, and the capital city itself
+fn player_cities<'a>(
+    player: Entity,
+    cities: impl Iterator<Item = (Entity, &'a City, &'a CityStockpile)>,
+    provinces: &Query<&Province>,
+) -> (Option<Entity>, Vec<(Entity, &'a CityStockpile)>) {
+    let mut capital = None;
+    let mut others = Vec::new();
+    for (entity, city, stockpile) in cities {
+        let owned = provinces
+            .get(city.province_entity)
+            .is_ok_and(|province| province.owner == Some(player));
+        if !owned {
+            continue;
+        }
+        if city.is_capital {
+            capital = Some(entity);
+        } else {
+            others.push((entity, stockpile));
+        }
+    }
+    (capital, others)
+}
+
+/// Move everything stored in the player's other cities into the capital's warehouse,
+/// as far as idle transport capacity allows
+fn ship_to_capital_button() -> impl Bundle {
+    observe(
+        |_activate: On<Activate>,
+         player: Option<Res<PlayerNation>>,
+         cities: Query<(Entity, &City, &CityStockpile)>,
+         provinces: Query<&Province>,
+         mut commands: Commands| {
+            let Some(player) = player else {
+                return;
+            };
+            let (Some(capital), others) = player_cities(player.entity(), cities.iter(), &provinces)
+            else {
+                return;
+            };
+            for (city, stockpile) in others {
+                for entry in stockpile.0.entries().filter(|entry| entry.available > 0) {
+                    commands.trigger(TransferGoods {
+                        nation: player.instance(),
+                        from: city,
+                        to: capital,
+                        good: entry.good,
+                        amount: entry.available,
+                    });
+                }
+            }
+        },
+    )
+}
+
 /// Update warehouse stock display (Rendering Layer)
-/// Only runs when Stockpile component actually changes (reactive)
+/// Only runs when the Stockpile or the active player changes (reactive)
 pub fn update_warehouse_display(
     player_nation: Option<Res<PlayerNation>>,
-    changed_stockpiles: Query<&Stockpile, Changed<Stockpile>>,
+    stockpiles: Query<Ref<Stockpile>>,
     mut stock_text: Query<&mut Text, With<WarehouseStockDisplay>>,
 ) {
     let Some(player) = player_nation else {
         return;
     };
 
-    // Only update if the player's stockpile changed
-    let Ok(stockpile) = changed_stockpiles.get(player.entity()) else {
+    let Ok(stockpile) = stockpiles.get(player.entity()) else {
         return;
     };
+    // Only update if the player's stockpile changed or another player took over
+    if !stockpile.is_changed() && !player.is_changed() {
+        return;
+    }
 
     // Show key commodities in compact format
     let wool = stockpile.get(Good::Wool);
@@ -96,3 +276,62 @@ pub fn update_warehouse_display(
         );
     }
 }
+
+/// Update the summary of goods held outside the capital (Rendering Layer)
+/// Only runs when a city stockpile or the active player changes (reactive)
+pub fn update_city_stores_display(
+    player_nation: Option<Res<PlayerNation>>,
+    cities: Query<(Entity, &City, &CityStockpile)>,
+    changed: Query<(), Changed<CityStockpile>>,
+    provinces: Query<&Province>,
+    mut stores_text: Query<&mut Text, With<CityStoresDisplay>>,
+) {
+    let Some(player) = player_nation else {
+        return;
+    };
+    if changed.is_empty() && !player.is_changed() {
+        return;
+    }
+
+    let (_, others) = player_cities(player.entity(), cities.iter(), &provinces);
+    let goods: u32 = others
+        .iter()
+        .flat_map(|(_, stockpile)| stockpile.0.entries())
+        .map(|entry| entry.total)
+        .sum();
+    let holding = others
+        .iter()
+        .filter(|(_, stockpile)| stockpile.0.entries().any(|entry| entry.total > 0))
+        .count();
+
+    for mut text in stores_text.iter_mut() {
+        **text = if goods == 0 {
+            "Other cities: empty".to_string()
+        } else {
+            format!("Other cities: {} goods in {} cities", goods, holding)
+        };
+    }
+}
+
+/// Update the reserve floor labels (Rendering Layer)
+/// Only runs when the player's reserves change, the player switches, or labels are spawned
+pub fn update_reserve_floor_display(
+    player_nation: Option<Res<PlayerNation>>,
+    reserves: Query<Ref<StockpileReserves>>,
+    new_displays: Query<(), Added<ReserveFloorDisplay>>,
+    mut texts: Query<(&mut Text, &ReserveFloorDisplay)>,
+) {
+    let Some(player) = player_nation else {
+        return;
+    };
+    let Ok(reserves) = reserves.get(player.entity()) else {
+        return;
+    };
+    if !reserves.is_changed() && !player.is_changed() && new_displays.is_empty() {
+        return;
+    }
+
+    for (mut text, display) in texts.iter_mut() {
+        **text = format!("{} {}", display.good, reserves.floor(display.good));
+    }
+}
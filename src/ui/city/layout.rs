@@ -43,6 +43,7 @@ pub fn ensure_city_screen_visible(
     crate::ui::city::hud::spawn_food_demand_panel(&mut commands, city_screen_entity);
     crate::ui::city::hud::spawn_warehouse_hud(&mut commands, city_screen_entity);
     crate::ui::city::hud::spawn_province_resources_panel(&mut commands, city_screen_entity);
+    crate::ui::city::hud::spawn_connected_resources_panel(&mut commands, city_screen_entity);
 
     // Spawn building grid
     crate::ui::city::buildings::spawn_building_grid(&mut commands, city_screen_entity);
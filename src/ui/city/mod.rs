@@ -4,6 +4,7 @@ use bevy::prelude::*;
 pub use components::*;
 pub use layout::ensure_city_screen_visible;
 
+use crate::turn_system::TurnPhase;
 use crate::ui::mode::GameMode;
 
 // Module declarations
@@ -24,8 +25,11 @@ pub struct CityUIPlugin;
 impl Plugin for CityUIPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<dialogs::DialogZIndexCounter>()
+            .init_resource::<dialogs::DialogLayout>()
             .add_observer(dialogs::open_building_dialogs)
             .add_observer(dialogs::close_building_dialogs)
+            .add_observer(allocation_ui_unified::apply_allocation_target)
+            .add_observer(allocation_ui_unified::apply_allocation_max)
             .add_systems(OnEnter(GameMode::City), layout::ensure_city_screen_visible)
             .add_systems(
                 OnExit(GameMode::City),
@@ -39,7 +43,10 @@ impl Plugin for CityUIPlugin {
                     hud::update_workforce_display,
                     hud::update_food_demand_display,
                     hud::update_warehouse_display,
+                    hud::update_city_stores_display,
+                    hud::update_reserve_floor_display,
                     hud::update_province_resources_display,
+                    hud::update_connected_resources_display,
                 )
                     .run_if(in_state(GameMode::City)),
             )
@@ -60,6 +67,9 @@ impl Plugin for CityUIPlugin {
                     dialogs::populate_special_dialog,
                     // Dialog content updates
                     dialogs::update_production_labor_display,
+                    dialogs::update_production_preview_display
+                        .run_if(in_state(TurnPhase::PlayerTurn)),
+                    dialogs::update_building_priority_display,
                     dialogs::update_capitol_requirement_displays,
                     dialogs::update_capitol_capacity_display,
                     dialogs::update_trade_school_workforce_display,
@@ -76,6 +86,12 @@ impl Plugin for CityUIPlugin {
                     allocation_ui_unified::update_all_allocation_summaries,
                 )
                     .run_if(in_state(GameMode::City)),
+            )
+            .add_systems(
+                Update,
+                // Exact-value inputs are spawned by both city dialogs and the market screen
+                allocation_ui_unified::type_into_allocation_input
+                    .run_if(in_state(GameMode::City).or(in_state(GameMode::Market))),
             );
     }
 }
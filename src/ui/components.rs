@@ -16,6 +16,10 @@ pub struct CalendarDisplay;
 #[derive(Component)]
 pub struct TreasuryDisplay;
 
+/// Marker for the HUD text listing the player's current advisories
+#[derive(Component)]
+pub struct AdvisoryDisplay;
+
 /// Marker for tilemap entities that should only be visible in Map mode
 #[derive(Component, Default)]
 pub struct MapTilemap;
@@ -0,0 +1,410 @@
+use std::collections::{BTreeMap, HashMap};
+
+use bevy::prelude::*;
+use bevy::ui::widget::Button as OldButton;
+use bevy::ui_widgets::Button;
+
+use crate::diplomacy::DiplomacyState;
+use crate::economy::production::ProductionResult;
+use crate::economy::trade::TradeLedger;
+use crate::economy::transport::TransportCapacity;
+use crate::economy::{
+    Allocations, Good, NationId, NationInstance, NationMetrics, PlayerNation, Treasury, Workforce,
+};
+use crate::turn_system::{PlayerTurnSet, TurnPhase};
+use crate::ui::button_style::*;
+use crate::ui::generic_systems::hide_screen;
+use crate::ui::mode::{GameMode, switch_to_mode};
+use crate::ui::status::format_currency;
+
+#[derive(Component)]
+pub struct DashboardScreen;
+
+#[derive(Component)]
+struct DashboardText;
+
+/// Treasury totals recorded at the start of each turn, oldest first.
+/// Keyed by [`NationId`] so the history still lines up after a reload respawns the nations.
+#[derive(Resource, Default, Debug)]
+pub struct TreasuryHistory {
+    nations: HashMap<NationId, Vec<i64>>,
+}
+
+impl TreasuryHistory {
+    /// Turns of history kept per nation
+    pub const TURNS_KEPT: usize = 8;
+
+    pub fn record(&mut self, nation: NationId, total: i64) {
+        let history = self.nations.entry(nation).or_default();
+        history.push(total);
+        if history.len() > Self::TURNS_KEPT {
+            history.remove(0);
+        }
+    }
+
+    /// Treasury recorded at the start of the current turn
+    pub fn latest(&self, nation: NationId) -> Option<i64> {
+        self.nations.get(&nation).and_then(|h| h.last().copied())
+    }
+
+    /// Change between the two most recent turn starts (0 until two are recorded)
+    pub fn trend(&self, nation: NationId) -> i64 {
+        match self.nations.get(&nation).map(Vec::as_slice) {
+            Some([.., previous, latest]) => latest - previous,
+            _ => 0,
+        }
+    }
+}
+
+/// Treaties a nation holds, counted across all of its relations
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TreatyCounts {
+    pub wars: u32,
+    pub alliances: u32,
+    pub non_aggression_pacts: u32,
+    pub trade_agreements: u32,
+    pub embassies: u32,
+    pub consulates: u32,
+}
+
+/// Everything the dashboard shows about one nation
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DashboardData {
+    pub treasury: i64,
+    pub treasury_trend: i64,
+    pub population: u32,
+    /// Output planned this turn, per good
+    pub production: BTreeMap<Good, u32>,
+    pub metrics: NationMetrics,
+    pub treaties: TreatyCounts,
+    pub transport_used: u32,
+    pub transport_total: u32,
+}
+
+/// Assemble the dashboard figures for `nation` from the components and resources that own them
+pub fn collect_dashboard_data(
+    nation: NationInstance,
+    nation_id: NationId,
+    treasury: &Treasury,
+    workforce: Option<&Workforce>,
+    allocations: Option<&Allocations>,
+    last_production: Option<&ProductionResult>,
+    diplomacy: &DiplomacyState,
+    transport: &TransportCapacity,
+    ledger: &TradeLedger,
+    history: &TreasuryHistory,
+) -> DashboardData {
+    let mut production = BTreeMap::new();
+    for ((_, good), units) in allocations.into_iter().flat_map(|a| a.production.iter()) {
+        if !units.is_empty() {
+            *production.entry(*good).or_default() += units.len() as u32;
+        }
+    }
+
+    let mut treaties = TreatyCounts::default();
+    for (_, relation) in diplomacy.relations_for(nation) {
+        let treaty = &relation.treaty;
+        treaties.wars += treaty.at_war as u32;
+        treaties.alliances += treaty.alliance as u32;
+        treaties.non_aggression_pacts += treaty.non_aggression_pact as u32;
+        treaties.trade_agreements += treaty.trade_agreement as u32;
+        treaties.embassies += treaty.embassy as u32;
+        treaties.consulates += treaty.consulate as u32;
+    }
+
+    let metrics = NationMetrics::collect(
+        nation.entity(),
+        treasury,
+        history
+            .latest(nation_id)
+            .unwrap_or_else(|| treasury.total()),
+        last_production,
+        workforce,
+        ledger,
+    );
+    let capacity = transport.snapshot(nation.entity());
+    DashboardData {
+        treasury: treasury.total(),
+        treasury_trend: history.trend(nation_id),
+        population: metrics.population,
+        production,
+        metrics,
+        treaties,
+        transport_used: capacity.used,
+        transport_total: capacity.total,
+    }
+}
+
+impl DashboardData {
+    fn describe(&self) -> String {
+        let trend = if self.treasury_trend >= 0 {
+            format!("+{}", format_currency(self.treasury_trend))
+        } else {
+            format_currency(self.treasury_trend)
+        };
+        let production = if self.production.is_empty() {
+            "nothing scheduled".to_string()
+        } else {
+            self.production
+                .iter()
+                .map(|(good, units)| format!("{good} {units}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let t = &self.treaties;
+        let m = &self.metrics;
+        format!(
+            "Treasury: {} ({} since last turn)\n\
+             Population: {} workers\n\
+             Production: {}\n\
+             Last turn: {} units net output, {} units net trade ({})\n\
+             Treaties: {} alliances, {} non-aggression pacts, {} trade agreements, {} at war\n\
+             Missions: {} embassies, {} consulates\n\
+             Transport: {} / {} in use",
+            format_currency(self.treasury),
+            trend,
+            self.population,
+            production,
+            m.net_output,
+            m.net_trade_units,
+            format_currency(m.net_trade_income),
+            t.alliances,
+            t.non_aggression_pacts,
+            t.trade_agreements,
+            t.wars,
+            t.embassies,
+            t.consulates,
+            self.transport_used,
+            self.transport_total
+        )
+    }
+}
+
+pub struct DashboardUIPlugin;
+
+impl Plugin for DashboardUIPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TreasuryHistory>()
+            .add_systems(
+                OnEnter(TurnPhase::PlayerTurn),
+                record_treasury_history.in_set(PlayerTurnSet::Ui),
+            )
+            .add_systems(
+                OnEnter(GameMode::Dashboard),
+                ensure_dashboard_screen_visible,
+            )
+            .add_systems(OnExit(GameMode::Dashboard), hide_screen::<DashboardScreen>)
+            .add_systems(
+                Update,
+                update_dashboard_text.run_if(in_state(GameMode::Dashboard)),
+            );
+    }
+}
+
+fn record_treasury_history(
+    mut history: ResMut<TreasuryHistory>,
+    treasuries: Query<(&NationId, &Treasury)>,
+) {
+    for (&nation, treasury) in treasuries.iter() {
+        history.record(nation, treasury.total());
+    }
+}
+
+fn ensure_dashboard_screen_visible(
+    mut commands: Commands,
+    mut roots: Query<&mut Visibility, With<DashboardScreen>>,
+) {
+    if let Ok(mut vis) = roots.single_mut() {
+        *vis = Visibility::Visible;
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                top: Val::Px(0.0),
+                bottom: Val::Px(0.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.06, 0.06, 0.06, 0.92)),
+            DashboardScreen,
+            Visibility::Visible,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("State of the Nation"),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.95, 0.85)),
+            ));
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.9, 1.0)),
+                DashboardText,
+            ));
+
+            // Back to Map button
+            parent
+                .spawn((
+                    Button,
+                    OldButton,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(16.0),
+                        right: Val::Px(16.0),
+                        padding: UiRect::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    switch_to_mode(GameMode::Map),
+                ))
+                .with_children(|b| {
+                    b.spawn((
+                        Text::new("Back to Map"),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                    ));
+                });
+        });
+}
+
+fn update_dashboard_text(
+    player: Option<Res<PlayerNation>>,
+    nations: Query<(
+        &NationId,
+        &Treasury,
+        Option<&Workforce>,
+        Option<&Allocations>,
+        Option<&ProductionResult>,
+    )>,
+    diplomacy: Option<Res<DiplomacyState>>,
+    transport: Option<Res<TransportCapacity>>,
+    ledger: Option<Res<TradeLedger>>,
+    history: Res<TreasuryHistory>,
+    mut texts: Query<&mut Text, With<DashboardText>>,
+) {
+    let (Some(player), Some(diplomacy), Some(transport), Some(ledger)) =
+        (player, diplomacy, transport, ledger)
+    else {
+        return;
+    };
+    let Ok((&nation_id, treasury, workforce, allocations, last_production)) =
+        nations.get(player.entity())
+    else {
+        return;
+    };
+
+    let description = collect_dashboard_data(
+        player.instance(),
+        nation_id,
+        treasury,
+        workforce,
+        allocations,
+        last_production,
+        &diplomacy,
+        &transport,
+        &ledger,
+        &history,
+    )
+    .describe();
+    for mut text in texts.iter_mut() {
+        if text.0 != description {
+            text.0 = description.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use moonshine_kind::Instance;
+
+    use crate::diplomacy::DiplomacyState;
+    use crate::economy::trade::TradeLedger;
+    use crate::economy::transport::TransportCapacity;
+    use crate::economy::{Nation, NationId, NationInstance, Treasury, Workforce};
+    use crate::ui::dashboard::{TreasuryHistory, TreatyCounts, collect_dashboard_data};
+
+    #[test]
+    fn collector_reports_treasury_population_and_treaties() {
+        let mut world = World::new();
+        let mut workforce = Workforce::new();
+        workforce.add_untrained(6);
+        let player = world
+            .spawn((Nation, NationId(0), Treasury::new(1_250), workforce))
+            .id();
+        let ally = world.spawn(Nation).id();
+        let enemy = world.spawn(Nation).id();
+        let instance = |entity| -> NationInstance {
+            Instance::<Nation>::from_entity(world.entity(entity)).expect("nation")
+        };
+        let (player_inst, ally_inst, enemy_inst) =
+            (instance(player), instance(ally), instance(enemy));
+
+        let mut diplomacy = DiplomacyState::default();
+        diplomacy.set_treaty(player_inst, ally_inst, |t| {
+            t.alliance = true;
+            t.consulate = true;
+            t.embassy = true;
+        });
+        diplomacy.set_treaty(player_inst, enemy_inst, |t| t.at_war = true);
+        // Treaties between other nations do not count
+        diplomacy.set_treaty(ally_inst, enemy_inst, |t| t.trade_agreement = true);
+
+        let mut history = TreasuryHistory::default();
+        history.record(NationId(0), 1_400);
+        history.record(NationId(0), 1_250);
+        // Another nation's history does not leak into the player's trend
+        history.record(NationId(1), 9_000);
+
+        let mut transport = TransportCapacity::default();
+        transport.snapshot_mut(player).used = 3;
+
+        let data = collect_dashboard_data(
+            player_inst,
+            NationId(0),
+            world.get::<Treasury>(player).unwrap(),
+            world.get::<Workforce>(player),
+            None,
+            None,
+            &diplomacy,
+            &transport,
+            &TradeLedger::default(),
+            &history,
+        );
+
+        assert_eq!(data.treasury, 1_250);
+        assert_eq!(data.treasury_trend, -150);
+        assert_eq!(data.population, 6);
+        assert_eq!(
+            data.treaties,
+            TreatyCounts {
+                wars: 1,
+                alliances: 1,
+                non_aggression_pacts: 0,
+                trade_agreements: 0,
+                embassies: 1,
+                consulates: 1,
+            }
+        );
+        assert_eq!(data.transport_used, 3);
+        assert_eq!(data.metrics.treasury_delta, 0);
+        assert!(data.production.is_empty());
+    }
+}
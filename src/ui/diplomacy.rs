@@ -6,10 +6,11 @@ use bevy::ui_widgets::{Activate, Button, observe};
 
 use crate::diplomacy::{
     DiplomacySelection, DiplomacyState, DiplomaticOffer, DiplomaticOfferKind, DiplomaticOffers,
-    DiplomaticOrder, DiplomaticOrderKind, DiplomaticRelation, ForeignAidLedger, RelationshipBand,
-    resolve_offer_response,
+    DiplomaticOrder, DiplomaticOrderKind, DiplomaticRelation, ForeignAidLedger, IntelReports,
+    IntelSource, RelationshipBand, peace_reparations, resolve_offer_response,
 };
 use crate::economy::{NationInstance, PlayerNation, Treasury};
+use crate::map::province::Province;
 use crate::ui::button_style::{
     AccentButton, DangerButton, NORMAL_ACCENT, NORMAL_BUTTON, NORMAL_DANGER,
 };
@@ -43,6 +44,12 @@ struct SelectedAidText;
 #[derive(Component)]
 struct SelectedRelationSummaryText;
 
+#[derive(Component)]
+struct SelectedIntelText;
+
+#[derive(Component)]
+struct PowerRankingText;
+
 #[derive(Component)]
 struct DiplomacyActionButton {
     action: DiplomaticAction,
@@ -62,6 +69,7 @@ enum DiplomaticAction {
     Consulate,
     Embassy,
     Pact,
+    TradeAgreement,
     Alliance,
     AidOnce(i32),
     AidLocked(i32),
@@ -75,7 +83,9 @@ fn execute_diplomatic_action(action: DiplomaticAction) -> impl Bundle {
         move |_activate: On<Activate>,
               mut commands: Commands,
               selection: Res<DiplomacySelection>,
-              player: Option<Res<PlayerNation>>| {
+              player: Option<Res<PlayerNation>>,
+              provinces: Query<&Province>,
+              treasuries: Query<&Treasury>| {
             let Some(selected) = selection.selected else {
                 return;
             };
@@ -94,7 +104,14 @@ fn execute_diplomatic_action(action: DiplomaticAction) -> impl Bundle {
                 DiplomaticAction::OfferPeace => DiplomaticOrder {
                     actor: player_instance,
                     target: selected,
-                    kind: DiplomaticOrderKind::OfferPeace,
+                    kind: DiplomaticOrderKind::OfferPeace {
+                        reparations: peace_reparations(
+                            player_instance,
+                            selected,
+                            &provinces,
+                            &treasuries,
+                        ),
+                    },
                 },
                 DiplomaticAction::Consulate => DiplomaticOrder {
                     actor: player_instance,
@@ -111,6 +128,11 @@ fn execute_diplomatic_action(action: DiplomaticAction) -> impl Bundle {
                     target: selected,
                     kind: DiplomaticOrderKind::SignNonAggressionPact,
                 },
+                DiplomaticAction::TradeAgreement => DiplomaticOrder {
+                    actor: player_instance,
+                    target: selected,
+                    kind: DiplomaticOrderKind::ProposeTradeAgreement,
+                },
                 DiplomaticAction::Alliance => DiplomaticOrder {
                     actor: player_instance,
                     target: selected,
@@ -160,6 +182,8 @@ impl Plugin for DiplomacyUIPlugin {
                 update_nation_buttons,
                 update_action_buttons,
                 update_pending_offers,
+                update_intel_panel,
+                update_power_ranking,
             )
                 .run_if(in_state(GameMode::Diplomacy)),
         );
@@ -309,6 +333,28 @@ fn setup_diplomacy_screen(
                                 TextColor(Color::srgb(0.75, 0.75, 0.8)),
                             ));
                         }
+
+                        list.spawn((
+                            Text::new("Economic Power"),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.9, 0.92, 0.98)),
+                            Node {
+                                margin: UiRect::top(Val::Px(12.0)),
+                                ..default()
+                            },
+                        ));
+                        list.spawn((
+                            Text::new(""),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.85, 0.9, 1.0)),
+                            PowerRankingText,
+                        ));
                     });
 
                     // Detail panel
@@ -373,6 +419,16 @@ fn setup_diplomacy_screen(
                             SelectedAidText,
                         ));
 
+                        detail.spawn((
+                            Text::new("Intel: none"),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.78, 0.82, 0.88)),
+                            SelectedIntelText,
+                        ));
+
                         // War / peace actions
                         detail
                             .spawn((Node {
@@ -518,6 +574,31 @@ fn setup_diplomacy_screen(
                                     ));
                                 });
 
+                                row.spawn((
+                                    Button,
+                                    OldButton,
+                                    Node {
+                                        padding: UiRect::all(Val::Px(8.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(NORMAL_BUTTON),
+                                    DiplomacyActionButton {
+                                        action: DiplomaticAction::TradeAgreement,
+                                        target: None,
+                                    },
+                                    execute_diplomatic_action(DiplomaticAction::TradeAgreement),
+                                ))
+                                .with_children(|button| {
+                                    button.spawn((
+                                        Text::new("Trade Agreement".to_string()),
+                                        TextFont {
+                                            font_size: 14.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::srgb(0.92, 0.95, 1.0)),
+                                    ));
+                                });
+
                                 row.spawn((
                                     Button,
                                     OldButton,
@@ -745,6 +826,7 @@ fn update_nation_buttons(
             Without<SelectedRelationSummaryText>,
             Without<SelectedTreatyText>,
             Without<SelectedAidText>,
+            Without<SelectedIntelText>,
         ),
     >,
 ) {
@@ -885,6 +967,9 @@ fn update_detail_panel(
             if relation.treaty.alliance {
                 flags.push("Alliance");
             }
+            if relation.treaty.trade_agreement {
+                flags.push("Trade agreement");
+            }
             text.0 = format!("Treaties: {}", flags.join(", "));
         } else {
             text.0 = "Treaties: none".to_string();
@@ -908,6 +993,92 @@ fn update_detail_panel(
     }
 }
 
+/// Show what the player's consulate or embassy reports about the selected nation
+fn update_intel_panel(
+    selection: Res<DiplomacySelection>,
+    intel: Res<IntelReports>,
+    player: Option<Res<PlayerNation>>,
+    mut text: Query<&mut Text, With<SelectedIntelText>>,
+    added: Query<(), Added<SelectedIntelText>>,
+) {
+    let player_changed = player.as_ref().is_some_and(|player| player.is_changed());
+    if !selection.is_changed() && !intel.is_changed() && !player_changed && added.is_empty() {
+        return;
+    }
+    let Ok(mut text) = text.single_mut() else {
+        return;
+    };
+    let report = player
+        .as_ref()
+        .zip(selection.selected)
+        .and_then(|(player, selected)| intel.get(player.instance(), selected));
+    let Some(report) = report else {
+        text.0 = "Intel: open a consulate to gather reports".to_string();
+        return;
+    };
+
+    let source = match report.source {
+        IntelSource::Consulate => "consulate estimate",
+        IntelSource::Embassy => "embassy report",
+    };
+    let goods = if report.top_goods.is_empty() {
+        "no notable stockpiles".to_string()
+    } else {
+        report
+            .top_goods
+            .iter()
+            .map(|(good, amount)| format!("{:?} {}", good, amount))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    text.0 = format!(
+        "Intel ({}): treasury {}; military {}; stockpiles: {}",
+        source,
+        report.treasury_label(),
+        report.military_label(),
+        goods
+    );
+}
+
+/// Rank the nations the player has a consulate or embassy with by estimated economic power
+fn update_power_ranking(
+    intel: Res<IntelReports>,
+    player: Option<Res<PlayerNation>>,
+    names: Query<(NationInstance, &Name)>,
+    mut text: Query<&mut Text, With<PowerRankingText>>,
+) {
+    let Ok(mut text) = text.single_mut() else {
+        return;
+    };
+    let ranking = player
+        .map(|player| intel.power_ranking(player.instance()))
+        .unwrap_or_default();
+    let description = if ranking.is_empty() {
+        "Open consulates to compare rival economies.".to_string()
+    } else {
+        ranking
+            .iter()
+            .enumerate()
+            .map(|(index, (nation, report))| {
+                let name = names
+                    .iter()
+                    .find(|(instance, _)| instance == nation)
+                    .map(|(_, name)| name.to_string())
+                    .unwrap_or_else(|| "Unknown".to_string());
+                let source = match report.source {
+                    IntelSource::Consulate => " (est.)",
+                    IntelSource::Embassy => "",
+                };
+                format!("{}. {} {}{}", index + 1, name, report.power_label(), source)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    if text.0 != description {
+        text.0 = description;
+    }
+}
+
 fn relation_summary(relation: &DiplomaticRelation) -> &'static str {
     match relation.band() {
         RelationshipBand::Hostile => "Open hostility — expect reprisals.",
@@ -954,6 +1125,11 @@ fn update_action_buttons(
                 relation.treaty.consulate && !relation.treaty.embassy && !relation.treaty.at_war
             }
             DiplomaticAction::Pact => relation.treaty.embassy && !relation.treaty.at_war,
+            DiplomaticAction::TradeAgreement => {
+                relation.treaty.consulate
+                    && !relation.treaty.trade_agreement
+                    && !relation.treaty.at_war
+            }
             DiplomaticAction::Alliance => relation.treaty.embassy && !relation.treaty.at_war,
             DiplomaticAction::AidOnce(_) => !relation.treaty.at_war,
             DiplomaticAction::AidLocked(_) => !relation.treaty.at_war,
@@ -1053,7 +1229,8 @@ fn update_pending_offers(
                                     mut state: ResMut<DiplomacyState>,
                                     mut ledger: ResMut<ForeignAidLedger>,
                                     nations: Query<(NationInstance, &Name)>,
-                                    mut treasuries: Query<&mut Treasury>| {
+                                    mut treasuries: Query<&mut Treasury>,
+                                    mut commands: Commands| {
                                     if let Some(offer) = offers.take(offer_id) {
                                         resolve_offer_response(
                                             offer,
@@ -1062,6 +1239,7 @@ fn update_pending_offers(
                                             &mut ledger,
                                             &nations,
                                             &mut treasuries,
+                                            &mut commands,
                                         );
                                     }
                                 }),
@@ -1091,7 +1269,8 @@ fn update_pending_offers(
                                     mut state: ResMut<DiplomacyState>,
                                     mut ledger: ResMut<ForeignAidLedger>,
                                     nations: Query<(NationInstance, &Name)>,
-                                    mut treasuries: Query<&mut Treasury>| {
+                                    mut treasuries: Query<&mut Treasury>,
+                                    mut commands: Commands| {
                                     if let Some(offer) = offers.take(offer_id) {
                                         resolve_offer_response(
                                             offer,
@@ -1100,6 +1279,7 @@ fn update_pending_offers(
                                             &mut ledger,
                                             &nations,
                                             &mut treasuries,
+                                            &mut commands,
                                         );
                                     }
                                 }),
@@ -1132,7 +1312,14 @@ fn clear_children_recursive(entity: Entity, commands: &mut Commands, children: &
 
 fn describe_offer(offer: &DiplomaticOffer, names: &HashMap<NationInstance, String>) -> String {
     match &offer.kind {
-        DiplomaticOfferKind::OfferPeace => {
+        DiplomaticOfferKind::OfferPeace { reparations } if *reparations > 0 => {
+            format!(
+                "{} offers peace if you pay ${} in reparations.",
+                format_name(names, offer.from),
+                reparations
+            )
+        }
+        DiplomaticOfferKind::OfferPeace { .. } => {
             format!("{} requests peace.", format_name(names, offer.from))
         }
         DiplomaticOfferKind::Alliance => {
@@ -1177,6 +1364,36 @@ fn describe_offer(offer: &DiplomaticOffer, names: &HashMap<NationInstance, Strin
                 )
             }
         }
+        DiplomaticOfferKind::TradeAgreement => {
+            format!(
+                "{} proposes a trade agreement to share shipping.",
+                format_name(names, offer.from)
+            )
+        }
+        DiplomaticOfferKind::CedeProvince { province } => {
+            format!(
+                "{} offers to cede province {} to you.",
+                format_name(names, offer.from),
+                province.0
+            )
+        }
+        DiplomaticOfferKind::GoodSwap { give, receive } => {
+            format!(
+                "{} offers {} {} in exchange for {} {}.",
+                format_name(names, offer.from),
+                give.1,
+                give.0,
+                receive.1,
+                receive.0
+            )
+        }
+        DiplomaticOfferKind::ShareResearch { points } => {
+            format!(
+                "{} offers to share {} research points.",
+                format_name(names, offer.from),
+                points
+            )
+        }
     }
 }
 
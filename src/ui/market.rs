@@ -7,7 +7,7 @@ use crate::economy::{
     Allocations, Good, MARKET_RESOURCES, MarketPriceModel, MarketVolume, PlayerNation, Stockpile,
     TradeCapacity, Treasury,
 };
-use crate::messages::{AdjustMarketOrder, MarketInterest};
+use crate::messages::{AdjustMarketOrder, CancelMarketOrder, MarketInterest};
 use crate::ui::button_style::*;
 use crate::ui::city::allocation_ui_unified::{
     update_all_allocation_bars, update_all_allocation_summaries, update_all_stepper_displays,
@@ -351,7 +351,11 @@ fn update_market_treasury_text(
         return;
     };
 
-    if treasury_changed.is_empty() && allocations_changed.is_empty() && new_texts.is_empty() {
+    if !player.is_changed()
+        && treasury_changed.is_empty()
+        && allocations_changed.is_empty()
+        && new_texts.is_empty()
+    {
         return;
     }
 
@@ -380,7 +384,7 @@ fn update_market_trade_capacity_text(
         return;
     };
 
-    if !capacity.is_changed() && new_texts.is_empty() {
+    if !capacity.is_changed() && !player.is_changed() && new_texts.is_empty() {
         return;
     }
 
@@ -408,7 +412,11 @@ fn update_market_inventory_texts(
         return;
     };
 
-    if stockpile_changed.is_empty() && allocations_changed.is_empty() && new_texts.is_empty() {
+    if !player.is_changed()
+        && stockpile_changed.is_empty()
+        && allocations_changed.is_empty()
+        && new_texts.is_empty()
+    {
         return;
     }
 
@@ -521,21 +529,11 @@ fn market_mode_button_clicked(
             // Sell quantity is managed by steppers
         }
         None => {
-            // Clear both modes
-            if has_buy {
-                commands.trigger(AdjustMarketOrder {
-                    nation: player.instance(),
-                    good,
-                    kind: MarketInterest::Buy,
-                    requested: 0,
-                });
-            }
-            if has_sell {
-                commands.trigger(AdjustMarketOrder {
+            // Clear both modes, handing reserved goods back straight away
+            if has_buy || has_sell {
+                commands.trigger(CancelMarketOrder {
                     nation: player.instance(),
                     good,
-                    kind: MarketInterest::Sell,
-                    requested: 0,
                 });
             }
         }
@@ -566,7 +564,7 @@ fn update_buy_interest_indicators(
         return;
     };
 
-    if allocations_changed.is_empty() && new_indicators.is_empty() {
+    if !player.is_changed() && allocations_changed.is_empty() && new_indicators.is_empty() {
         return;
     }
 
@@ -591,7 +589,7 @@ fn update_sell_controls_visibility(
         return;
     };
 
-    if allocations_changed.is_empty() && new_controls.is_empty() {
+    if !player.is_changed() && allocations_changed.is_empty() && new_controls.is_empty() {
         return;
     }
 
@@ -3,6 +3,7 @@ use bevy::prelude::*;
 use bevy::ui::widget::Button as OldButton;
 use bevy::ui_widgets::{Activate, Button, observe};
 
+use crate::map::rendering::ColorPalette;
 use crate::ui::button_style::*;
 use crate::ui::generic_systems::hide_screen;
 
@@ -20,6 +21,29 @@ pub enum AppState {
 #[derive(Component)]
 pub struct MainMenuRoot;
 
+/// Marker for the text of the color palette toggle button
+#[derive(Component)]
+pub struct PaletteToggleLabel;
+
+fn palette_label(palette: ColorPalette) -> String {
+    format!("Colors: {}", palette.label())
+}
+
+/// Creates an observer that switches between the standard and colorblind palettes
+pub fn toggle_palette() -> impl Bundle {
+    observe(
+        |_activate: On<Activate>,
+         mut palette: ResMut<ColorPalette>,
+         mut labels: Query<&mut Text, With<PaletteToggleLabel>>| {
+            *palette = palette.toggled();
+            info!("Color palette switched to {}", palette.label());
+            for mut text in labels.iter_mut() {
+                text.0 = palette_label(*palette);
+            }
+        },
+    )
+}
+
 /// Creates an observer that quits the application when button is activated
 pub fn quit_game() -> impl Bundle {
     observe(
@@ -34,7 +58,8 @@ pub struct MenuUIPlugin;
 
 impl Plugin for MenuUIPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::MainMenu), ensure_main_menu_visible)
+        app.init_resource::<ColorPalette>()
+            .add_systems(OnEnter(AppState::MainMenu), ensure_main_menu_visible)
             .add_systems(OnExit(AppState::MainMenu), hide_screen::<MainMenuRoot>);
     }
 }
@@ -42,6 +67,7 @@ impl Plugin for MenuUIPlugin {
 fn ensure_main_menu_visible(
     mut commands: Commands,
     mut existing: Query<&mut Visibility, With<MainMenuRoot>>,
+    palette: Res<ColorPalette>,
 ) {
     if let Ok(mut vis) = existing.single_mut() {
         *vis = Visibility::Visible;
@@ -103,6 +129,25 @@ fn ensure_main_menu_visible(
                     TextColor(Color::srgb(0.9, 0.9, 1.0)),
                 )],
             ),
+            (
+                Button,
+                OldButton,
+                Node {
+                    padding: UiRect::axes(Val::Px(20.0), Val::Px(10.0)),
+                    ..default()
+                },
+                BackgroundColor(NORMAL_BUTTON),
+                toggle_palette(),
+                children![(
+                    Text::new(palette_label(*palette)),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                    PaletteToggleLabel,
+                )],
+            ),
             (
                 Button,
                 OldButton,
@@ -1,6 +1,7 @@
 pub mod button_style;
 pub mod city;
 pub mod components;
+pub mod dashboard;
 pub mod diplomacy;
 pub mod generic_systems;
 pub mod market;
@@ -25,6 +26,7 @@ impl Plugin for GameUIPlugin {
             transport::TransportUIPlugin,
             market::MarketUIPlugin,
             diplomacy::DiplomacyUIPlugin,
+            dashboard::DashboardUIPlugin,
             menu::MenuUIPlugin,
         ))
         .insert_resource(state::UIState::default())
@@ -46,6 +48,15 @@ impl Plugin for GameUIPlugin {
                 generic_systems::hide_screen::<components::MapTilemap>,
             ),
         )
+        // The editor paints directly on the map
+        .add_systems(
+            OnEnter(mode::GameMode::Editor),
+            generic_systems::show_screen::<components::MapTilemap>,
+        )
+        .add_systems(
+            OnExit(mode::GameMode::Editor),
+            generic_systems::hide_screen::<components::MapTilemap>,
+        )
         .add_systems(
             Update,
             (
@@ -56,6 +67,7 @@ impl Plugin for GameUIPlugin {
                 status::update_turn_display.after(state::notify_ui_state_changes),
                 status::update_calendar_display,
                 status::update_treasury_display,
+                status::show_advisories,
                 status::update_tile_info_display,
                 // Button interaction visual feedback (standard Button widget handles mode switching via observers)
                 button_style::button_interaction_system,
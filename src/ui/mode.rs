@@ -17,6 +17,10 @@ pub enum GameMode {
     Market,
     /// Diplomacy/influence screen
     Diplomacy,
+    /// Overview of the player's nation
+    Dashboard,
+    /// Scenario editor: paint terrain and resources on the map
+    Editor,
 }
 
 /// Creates an observer that switches to the specified mode when button is activated
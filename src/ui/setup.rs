@@ -4,7 +4,7 @@ use bevy::ui_widgets::Button;
 
 use crate::ui::button_style::*;
 use crate::ui::components::{
-    CalendarDisplay, GameplayUIRoot, TileInfoDisplay, TreasuryDisplay, TurnDisplay,
+    AdvisoryDisplay, CalendarDisplay, GameplayUIRoot, TileInfoDisplay, TreasuryDisplay, TurnDisplay,
 };
 
 pub fn setup_ui(mut commands: Commands) {
@@ -60,6 +60,15 @@ pub fn setup_ui(mut commands: Commands) {
                         },
                         TextColor(Color::srgb(0.9, 0.9, 0.9)),
                         TreasuryDisplay,
+                    ),
+                    (
+                        Text::new(""),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.6, 0.5)),
+                        AdvisoryDisplay,
                     )
                 ],
             ),
@@ -188,5 +197,24 @@ pub fn setup_ui(mut commands: Commands) {
                     TextColor(Color::srgb(0.9, 0.9, 1.0)),
                 )],
             ));
+            // Nation overview button
+            sidebar.spawn((
+                Button,
+                OldButton,
+                Node {
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(NORMAL_BUTTON),
+                switch_to_mode(GameMode::Dashboard),
+                children![(
+                    Text::new("Overview"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                )],
+            ));
         });
 }
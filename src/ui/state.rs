@@ -1,4 +1,4 @@
-use crate::turn_system::{TurnCounter, TurnPhase};
+use crate::turn_system::{TurnPhase, TurnSystem};
 use bevy::prelude::*;
 
 /// Centralized UI state that consolidates all game state needed by UI systems
@@ -47,13 +47,9 @@ impl UIState {
 }
 
 /// System to collect game state and update the centralized UIState resource
-pub fn collect_ui_state(
-    mut ui_state: ResMut<UIState>,
-    turn_counter: Res<TurnCounter>,
-    phase: Res<State<TurnPhase>>,
-) {
-    let current_turn = turn_counter.current;
-    let current_phase = *phase.get();
+pub fn collect_ui_state(mut ui_state: ResMut<UIState>, turns: TurnSystem) {
+    let current_turn = turns.current_turn();
+    let current_phase = turns.phase();
 
     // Only update if something has changed to avoid unnecessary UI updates
     if ui_state.needs_update(current_turn, current_phase) {
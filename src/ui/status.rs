@@ -1,13 +1,21 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::TileStorage;
 
+use crate::civilians::Civilian;
 use crate::civilians::SelectedCivilian;
-use crate::civilians::{Civilian, CivilianKind};
-use crate::economy::{Calendar, PlayerNation, Technologies, Technology, Treasury};
+use crate::economy::allocation_systems::IdleLabor;
+use crate::economy::transport::IdleTransportCapacity;
+use crate::economy::{
+    Bankruptcy, Calendar, FinancialRejection, PlayerNation, Technologies, Technology, Treasury,
+};
 use crate::map::province::{City, Province, TileProvince};
 use crate::map::rendering::transport_rendering::HoveredTile;
+use crate::map::spatial_index::SpatialIndex;
 use crate::map::tiles::TerrainType;
-use crate::ui::components::{CalendarDisplay, TileInfoDisplay, TreasuryDisplay, TurnDisplay};
+use crate::turn_system::TurnCounter;
+use crate::ui::components::{
+    AdvisoryDisplay, CalendarDisplay, TileInfoDisplay, TreasuryDisplay, TurnDisplay,
+};
 use crate::ui::state::{UIState, UIStateUpdated};
 
 /// Update turn display using centralized UI state
@@ -41,7 +49,7 @@ pub fn update_calendar_display(
     }
 }
 
-fn format_currency(value: i64) -> String {
+pub(crate) fn format_currency(value: i64) -> String {
     // naive thousands separator with commas
     let mut s = value.abs().to_string();
     let mut i = s.len() as isize - 3;
@@ -57,25 +65,169 @@ fn format_currency(value: i64) -> String {
 }
 
 /// Update treasury HUD text based on the active player's nation
-/// Only runs when Treasury component actually changes (reactive)
+/// Only rebuilds the text when the player's Treasury changes, the player switches
+/// nations, or a new treasury display is spawned (reactive)
 pub fn update_treasury_display(
     player: Option<Res<PlayerNation>>,
-    changed_treasuries: Query<&Treasury, Changed<Treasury>>,
+    treasuries: Query<Ref<Treasury>>,
+    new_displays: Query<(), Added<TreasuryDisplay>>,
     mut q: Query<&mut Text, With<TreasuryDisplay>>,
 ) {
     let Some(player) = player else {
         return;
     };
+    let Ok(treasury) = treasuries.get(player.entity()) else {
+        return;
+    };
 
-    // Only update if the player's treasury changed
-    if let Ok(treasury) = changed_treasuries.get(player.entity()) {
-        let s = format_currency(treasury.total());
-        for mut text in q.iter_mut() {
+    if !treasury.is_changed() && !player.is_changed() && new_displays.is_empty() {
+        return;
+    }
+
+    let s = format_currency(treasury.total());
+    for mut text in q.iter_mut() {
+        if text.0 != s {
             text.0 = s.clone();
         }
     }
 }
 
+/// Most advisories shown in the HUD at once; older ones drop off
+const MAX_ADVISORIES: usize = 3;
+
+/// A message telling a nation about something it could act on.
+/// Every advisory for the player goes through [`show_advisories`].
+pub trait Advisory: Message {
+    /// Nation the advice is meant for
+    fn nation(&self) -> Entity;
+    /// One HUD line describing the problem
+    fn advice(&self) -> String;
+}
+
+impl Advisory for FinancialRejection {
+    fn nation(&self) -> Entity {
+        self.nation
+    }
+
+    fn advice(&self) -> String {
+        format!(
+            "Not enough money to {}: it costs {}, {} short",
+            self.action.label(),
+            format_currency(self.cost),
+            format_currency(self.shortfall)
+        )
+    }
+}
+
+impl Advisory for Bankruptcy {
+    fn nation(&self) -> Entity {
+        self.nation
+    }
+
+    fn advice(&self) -> String {
+        format!(
+            "Bankrupt: {} of upkeep written off and every civilian dismissed",
+            format_currency(self.debt)
+        )
+    }
+}
+
+impl Advisory for IdleLabor {
+    fn nation(&self) -> Entity {
+        self.nation
+    }
+
+    fn advice(&self) -> String {
+        let buildings = self
+            .buildings
+            .iter()
+            .map(|kind| format!("{:?}", kind))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "{} labor is idle while {} could produce more (try Auto-assign)",
+            self.idle, buildings
+        )
+    }
+}
+
+impl Advisory for IdleTransportCapacity {
+    fn nation(&self) -> Entity {
+        self.nation
+    }
+
+    fn advice(&self) -> String {
+        let stranded = self
+            .stranded
+            .iter()
+            .map(|commodity| format!("{:?}", commodity))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "{} transport capacity is idle while {} goes uncollected (try Auto-balance)",
+            self.idle, stranded
+        )
+    }
+}
+
+/// Add the player's advisories of one kind to `lines`; returns whether any were new
+fn read_advisories<M: Advisory>(
+    reader: &mut MessageReader<M>,
+    player: Option<&PlayerNation>,
+    lines: &mut Vec<String>,
+) -> bool {
+    let mut added = false;
+    for advisory in reader.read() {
+        if player.is_none_or(|player| player.entity() != advisory.nation()) {
+            continue;
+        }
+        let line = advisory.advice();
+        warn!("{}", line);
+        if !lines.contains(&line) {
+            lines.push(line);
+            added = true;
+        }
+    }
+    added
+}
+
+/// Show the player's advisories (refused payments, bankruptcy, idle labor and transport) in the HUD.
+/// They stay until the next turn starts.
+pub fn show_advisories(
+    player: Option<Res<PlayerNation>>,
+    turn: Option<Res<TurnCounter>>,
+    mut rejections: MessageReader<FinancialRejection>,
+    mut bankruptcies: MessageReader<Bankruptcy>,
+    mut idle_labor: MessageReader<IdleLabor>,
+    mut idle_transport: MessageReader<IdleTransportCapacity>,
+    new_displays: Query<(), Added<AdvisoryDisplay>>,
+    mut displays: Query<&mut Text, With<AdvisoryDisplay>>,
+    mut shown: Local<Vec<String>>,
+) {
+    let mut changed = !new_displays.is_empty();
+    if turn.as_ref().is_some_and(|turn| turn.is_changed()) && !shown.is_empty() {
+        shown.clear();
+        changed = true;
+    }
+    let player = player.as_deref();
+    changed |= read_advisories(&mut rejections, player, &mut shown);
+    changed |= read_advisories(&mut bankruptcies, player, &mut shown);
+    changed |= read_advisories(&mut idle_labor, player, &mut shown);
+    changed |= read_advisories(&mut idle_transport, player, &mut shown);
+    if !changed {
+        return;
+    }
+
+    let excess = shown.len().saturating_sub(MAX_ADVISORIES);
+    shown.drain(..excess);
+    let advisories = shown.join("\n");
+    for mut text in displays.iter_mut() {
+        if text.0 != advisories {
+            text.0 = advisories.clone();
+        }
+    }
+}
+
 /// Update tile info display based on hovered tile
 pub fn update_tile_info_display(
     hovered_tile: Res<HoveredTile>,
@@ -85,6 +237,7 @@ pub fn update_tile_info_display(
     tile_provinces: Query<&TileProvince>,
     provinces: Query<&Province>,
     cities: Query<&City>,
+    spatial_index: Res<SpatialIndex>,
     nations_query: Query<(Entity, &Name, &Technologies)>,
     civilians: Query<(Entity, &Civilian)>,
     player: Option<Res<PlayerNation>>,
@@ -120,20 +273,14 @@ pub fn update_tile_info_display(
                     if let Ok(tile_prov) = tile_provinces.get(tile_entity) {
                         tile_info.push_str(&format!("\nProvince: {}", tile_prov.province_id.0));
 
-                        // Find the province entity and its owner
+                        if let Some(owner_entity) = spatial_index.owner_of(tile_pos)
+                            && let Ok((_, name, _)) = nations_query.get(owner_entity)
+                        {
+                            tile_info.push_str(&format!("\nOwner: {}", name.as_str()));
+                        }
+
                         for province in provinces.iter() {
                             if province.id == tile_prov.province_id {
-                                if let Some(owner_entity) = province.owner {
-                                    // Find the owner name
-                                    for (nation_entity, name, _) in nations_query.iter() {
-                                        if nation_entity == owner_entity {
-                                            tile_info
-                                                .push_str(&format!("\nOwner: {}", name.as_str()));
-                                            break;
-                                        }
-                                    }
-                                }
-
                                 // Check if this tile has a city
                                 if province.city_tile == tile_pos {
                                     for city in cities.iter() {
@@ -157,7 +304,7 @@ pub fn update_tile_info_display(
                         .and_then(|selected| {
                             civilians.iter().find(|(entity, _)| *entity == selected)
                         })
-                        .filter(|(_, c)| c.kind == CivilianKind::Engineer);
+                        .filter(|(_, c)| c.kind.capabilities().builds_rails);
 
                     if selected_engineer.is_some()
                         && let Some(player) = &player
@@ -183,28 +330,149 @@ pub fn update_tile_info_display(
 
 /// Check if a tile is buildable for rails with current technologies
 fn check_buildability(terrain: &TerrainType, technologies: &Technologies) -> String {
-    match terrain {
-        TerrainType::Mountain => {
-            if technologies.has(Technology::MountainEngineering) {
-                "Can build rails".to_string()
-            } else {
-                "⚠ Need Mountain Engineering".to_string()
-            }
-        }
-        TerrainType::Hills => {
-            if technologies.has(Technology::HillGrading) {
-                "Can build rails".to_string()
-            } else {
-                "⚠ Need Hill Grading".to_string()
-            }
-        }
-        TerrainType::Swamp => {
-            if technologies.has(Technology::SwampDrainage) {
-                "Can build rails".to_string()
-            } else {
-                "⚠ Need Swamp Drainage".to_string()
-            }
-        }
+    match Technology::required_for_rail(*terrain) {
+        Some(tech) if !technologies.has(tech) => format!("⚠ Need {}", tech.definition().name),
         _ => "Can build rails".to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::economy::allocation_systems::IdleLabor;
+    use crate::economy::production::BuildingKind;
+    use crate::economy::transport::{IdleTransportCapacity, TransportCommodity};
+    use crate::economy::{
+        Bankruptcy, FinancialAction, FinancialRejection, PlayerNation, Treasury, nation::Nation,
+    };
+    use crate::ui::components::{AdvisoryDisplay, TreasuryDisplay};
+    use crate::ui::status::{show_advisories, update_treasury_display};
+
+    #[test]
+    fn financial_rejections_of_the_player_are_shown_in_the_hud() {
+        let mut app = App::new();
+        app.add_message::<FinancialRejection>();
+        app.add_message::<IdleLabor>();
+        app.add_message::<IdleTransportCapacity>();
+        app.add_message::<Bankruptcy>();
+        app.add_systems(Update, show_advisories);
+
+        let nation = app.world_mut().spawn((Nation, Treasury::new(40))).id();
+        let rival = app.world_mut().spawn((Nation, Treasury::new(0))).id();
+        let player = PlayerNation::from_entity(app.world(), nation).unwrap();
+        app.insert_resource(player);
+        let display = app.world_mut().spawn((Text::new(""), AdvisoryDisplay)).id();
+
+        app.world_mut().write_message(FinancialRejection::new(
+            rival,
+            FinancialAction::Training,
+            100,
+            0,
+        ));
+        app.update();
+        assert_eq!(app.world().get::<Text>(display).unwrap().0, "");
+
+        app.world_mut().write_message(FinancialRejection::new(
+            nation,
+            FinancialAction::Training,
+            100,
+            40,
+        ));
+        app.update();
+        assert_eq!(
+            app.world().get::<Text>(display).unwrap().0,
+            "Not enough money to train a worker: it costs $100, $60 short"
+        );
+    }
+
+    #[test]
+    fn idle_labor_joins_the_same_advisory_list() {
+        let mut app = App::new();
+        app.add_message::<FinancialRejection>();
+        app.add_message::<IdleLabor>();
+        app.add_message::<IdleTransportCapacity>();
+        app.add_message::<Bankruptcy>();
+        app.add_systems(Update, show_advisories);
+
+        let nation = app.world_mut().spawn((Nation, Treasury::new(0))).id();
+        let player = PlayerNation::from_entity(app.world(), nation).unwrap();
+        app.insert_resource(player);
+        let display = app.world_mut().spawn((Text::new(""), AdvisoryDisplay)).id();
+
+        app.world_mut().write_message(FinancialRejection::new(
+            nation,
+            FinancialAction::Training,
+            100,
+            0,
+        ));
+        app.world_mut().write_message(IdleLabor {
+            nation,
+            idle: 4,
+            buildings: vec![BuildingKind::TextileMill],
+        });
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Text>(display).unwrap().0,
+            "Not enough money to train a worker: it costs $100, $100 short\n\
+             4 labor is idle while TextileMill could produce more (try Auto-assign)"
+        );
+    }
+
+    #[test]
+    fn idle_transport_capacity_of_the_player_is_shown_in_the_hud() {
+        let mut app = App::new();
+        app.add_message::<FinancialRejection>();
+        app.add_message::<IdleLabor>();
+        app.add_message::<IdleTransportCapacity>();
+        app.add_message::<Bankruptcy>();
+        app.add_systems(Update, show_advisories);
+
+        let nation = app.world_mut().spawn((Nation, Treasury::new(0))).id();
+        let player = PlayerNation::from_entity(app.world(), nation).unwrap();
+        app.insert_resource(player);
+        let display = app.world_mut().spawn((Text::new(""), AdvisoryDisplay)).id();
+
+        app.world_mut().write_message(IdleTransportCapacity {
+            nation,
+            idle: 3,
+            stranded: vec![TransportCommodity::Coal, TransportCommodity::Iron],
+        });
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Text>(display).unwrap().0,
+            "3 transport capacity is idle while Coal, Iron goes uncollected (try Auto-balance)"
+        );
+    }
+
+    #[test]
+    fn treasury_display_is_not_rewritten_when_treasury_is_unchanged() {
+        let mut app = App::new();
+        app.add_systems(Update, update_treasury_display);
+
+        let nation = app.world_mut().spawn((Nation, Treasury::new(1500))).id();
+        let player = PlayerNation::from_entity(app.world(), nation).unwrap();
+        app.insert_resource(player);
+        let display = app.world_mut().spawn((Text::new(""), TreasuryDisplay)).id();
+
+        app.update();
+        let text = app.world().entity(display).get_ref::<Text>().unwrap();
+        assert_eq!(text.0, "$1,500");
+        let written_at = text.last_changed();
+
+        app.update();
+        let text = app.world().entity(display).get_ref::<Text>().unwrap();
+        assert_eq!(text.last_changed(), written_at);
+
+        app.world_mut()
+            .get_mut::<Treasury>(nation)
+            .unwrap()
+            .add(250);
+        app.update();
+        let text = app.world().entity(display).get_ref::<Text>().unwrap();
+        assert_eq!(text.0, "$1,750");
+        assert_ne!(text.last_changed(), written_at);
+    }
+}
@@ -8,7 +8,8 @@ use bevy_ecs_tilemap::prelude::TilePos;
 use crate::economy::nation::PlayerNation;
 use crate::economy::transport::{
     TransportAdjustAllocation, TransportAllocations, TransportCapacity, TransportCommodity,
-    TransportDemandSnapshot, transport_capacity, transport_demand, transport_slot,
+    TransportDemandSnapshot, TransportSetPriority, auto_balance_transport, transport_capacity,
+    transport_demand, transport_slot,
 };
 use crate::economy::{ImprovementKind, PlaceImprovement};
 use crate::ui::button_style::*;
@@ -73,6 +74,12 @@ struct TransportAdjustButton {
     delta: i32,
 }
 
+/// Label of the toggle pinning a commodity ahead of the others
+#[derive(Component)]
+struct TransportPriorityText {
+    commodity: TransportCommodity,
+}
+
 const RESOURCE_COMMODITIES: &[TransportCommodity] = &[
     TransportCommodity::Grain,
     TransportCommodity::Fruit,
@@ -121,6 +128,7 @@ impl Plugin for TransportUIPlugin {
                     update_transport_satisfaction_bars,
                     update_transport_capacity_display,
                     update_transport_button_states,
+                    update_transport_priority_texts,
                 )
                     .run_if(in_state(GameMode::Transport)),
             );
@@ -274,6 +282,26 @@ fn setup_transport_screen(
                                 TransportCapacityFill,
                             ));
                         });
+
+                    capacity.spawn((
+                        Button,
+                        OldButton,
+                        Node {
+                            align_self: AlignSelf::Start,
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                            ..default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        auto_balance_button(nation),
+                        children![(
+                            Text::new("Auto-balance"),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.9, 0.9, 1.0)),
+                        )],
+                    ));
                 });
 
             parent.spawn((
@@ -459,6 +487,31 @@ fn spawn_commodity_row(
             });
 
             spawn_adjust_button_column(row, commodity, nation, &[1]);
+
+            row.spawn((
+                Button,
+                OldButton,
+                Node {
+                    width: Val::Px(56.0),
+                    height: Val::Px(22.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(NORMAL_BUTTON),
+                transport_priority_button(commodity, nation),
+            ))
+            .with_children(|button| {
+                button.spawn((
+                    Text::new("Pin"),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.75, 0.78, 0.85)),
+                    TransportPriorityText { commodity },
+                ));
+            });
         });
 }
 
@@ -545,6 +598,36 @@ fn transport_adjustment_button(
     )
 }
 
+/// Toggle whether a commodity is served before unpinned ones when capacity is scarce
+fn transport_priority_button(commodity: TransportCommodity, nation: Entity) -> impl Bundle {
+    observe(
+        move |_activate: On<Activate>,
+              allocations: Res<TransportAllocations>,
+              mut commands: Commands| {
+            let slot = transport_slot(&allocations, nation, commodity);
+            commands.trigger(TransportSetPriority {
+                nation,
+                commodity,
+                priority: if slot.priority > 0 { 0 } else { 1 },
+            });
+        },
+    )
+}
+
+/// Split capacity across commodities in proportion to demand
+fn auto_balance_button(nation: Entity) -> impl Bundle {
+    observe(
+        move |_activate: On<Activate>,
+              capacity: Res<TransportCapacity>,
+              demand_snapshot: Res<TransportDemandSnapshot>,
+              mut commands: Commands| {
+            for request in auto_balance_transport(&capacity, &demand_snapshot, nation) {
+                commands.trigger(request);
+            }
+        },
+    )
+}
+
 fn adjust_requested(current: u32, delta: i32) -> u32 {
     if delta < 0 {
         current.saturating_sub((-delta) as u32)
@@ -707,6 +790,32 @@ fn update_transport_satisfaction_bars(
     }
 }
 
+fn update_transport_priority_texts(
+    player: Option<Res<PlayerNation>>,
+    allocations: Res<TransportAllocations>,
+    mut texts: Query<(&mut Text, &mut TextColor, &TransportPriorityText)>,
+) {
+    if !allocations.is_changed() {
+        return;
+    }
+
+    let Some(player) = player else {
+        return;
+    };
+    let nation = player.entity();
+
+    for (mut text, mut color, priority_text) in texts.iter_mut() {
+        let slot = transport_slot(&allocations, nation, priority_text.commodity);
+        if slot.priority > 0 {
+            text.0 = "Pinned".to_string();
+            color.0 = Color::srgb(0.95, 0.8, 0.35);
+        } else {
+            text.0 = "Pin".to_string();
+            color.0 = Color::srgb(0.75, 0.78, 0.85);
+        }
+    }
+}
+
 fn update_transport_capacity_display(
     player: Option<Res<PlayerNation>>,
     capacity: Res<TransportCapacity>,
@@ -59,6 +59,7 @@ fn test_ai_move_command_executes() {
             owner: nation,
             civilian_id: rust_imperialism::civilians::CivilianId(0),
             has_moved: false,
+            experience: 0,
         })
         .id();
 
@@ -155,6 +156,7 @@ fn test_illegal_rail_command_rejected() {
             owner: player,
             civilian_id: rust_imperialism::civilians::CivilianId(0),
             has_moved: false,
+            experience: 0,
         })
         .id();
 
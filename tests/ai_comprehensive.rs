@@ -24,7 +24,7 @@ use rust_imperialism::economy::{
     goods::Good,
     nation::{Capital, Nation},
     production::Buildings,
-    stockpile::Stockpile,
+    stockpile::{Stockpile, StockpileReserves},
     technology::Technologies,
     transport::Depot,
     treasury::Treasury,
@@ -106,7 +106,7 @@ fn test_comprehensive_ai_capabilities() {
 
             let tile_entity = app
                 .world_mut()
-                .spawn((TileProvince { province_id }, terrain))
+                .spawn((pos, TileProvince { province_id }, terrain))
                 .id();
 
             tile_storage.set(&pos, tile_entity);
@@ -160,6 +160,7 @@ fn test_comprehensive_ai_capabilities() {
         .world_mut()
         .spawn((
             AiNation,
+            StockpileReserves::default(),
             Nation,
             Capital(capital_pos),
             Stockpile::default(),
@@ -188,6 +189,7 @@ fn test_comprehensive_ai_capabilities() {
                 owner: ai_nation,
                 civilian_id: rust_imperialism::civilians::CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             AiControlledCivilian,
         ))
@@ -202,6 +204,7 @@ fn test_comprehensive_ai_capabilities() {
                 owner: ai_nation,
                 civilian_id: rust_imperialism::civilians::CivilianId(1),
                 has_moved: false,
+                experience: 0,
             },
             AiControlledCivilian,
         ))
@@ -216,6 +219,7 @@ fn test_comprehensive_ai_capabilities() {
                 owner: ai_nation,
                 civilian_id: rust_imperialism::civilians::CivilianId(2),
                 has_moved: false,
+                experience: 0,
             },
             AiControlledCivilian,
         ))
@@ -230,6 +234,7 @@ fn test_comprehensive_ai_capabilities() {
                 owner: ai_nation,
                 civilian_id: rust_imperialism::civilians::CivilianId(3),
                 has_moved: false,
+                experience: 0,
             },
             AiControlledCivilian,
         ))
@@ -0,0 +1,172 @@
+//! Headless multi-turn tests for AI engineers extending rail lines.
+//!
+//! Each simulated turn re-plans the nation from a fresh snapshot, applies the engineer's
+//! task (finished rails join the network, the engineer ends up on the new tile) and checks
+//! that the engineer only ever moves forward along the line.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use bevy::prelude::Entity;
+use bevy_ecs_tilemap::prelude::TilePos;
+
+use rust_imperialism::ai::planner::{CivilianTask, plan_nation};
+use rust_imperialism::ai::snapshot::{
+    AiSnapshot, CivilianSnapshot, NationSnapshot, SuggestedDepot,
+};
+use rust_imperialism::civilians::CivilianKind;
+use rust_imperialism::economy::technology::Technologies;
+use rust_imperialism::economy::transport::ordered_edge;
+use rust_imperialism::map::tile_pos::{HexExt, TilePosExt};
+use rust_imperialism::map::tiles::TerrainType;
+
+fn engineer() -> Entity {
+    Entity::from_bits(2)
+}
+
+struct Scenario {
+    snapshot: AiSnapshot,
+    nation: NationSnapshot,
+    engineer_pos: TilePos,
+}
+
+impl Scenario {
+    /// A nation owning exactly `route`, with its capital at the first tile and a depot
+    /// planned on the last one.
+    fn along(route: &[TilePos]) -> Self {
+        let capital_pos = route[0];
+        let depot = *route.last().unwrap();
+        let owned_tiles: HashSet<TilePos> = route.iter().copied().collect();
+
+        let nation = NationSnapshot {
+            entity: Entity::from_bits(1),
+            capital_pos,
+            treasury: 0,
+            stockpile: HashMap::new(),
+            civilians: vec![],
+            connected_tiles: HashSet::new(),
+            unconnected_depots: vec![],
+            suggested_depots: vec![SuggestedDepot {
+                position: depot,
+                covers_count: 7,
+                distance_from_capital: capital_pos.to_hex().distance_to(depot.to_hex()) as u32,
+            }],
+            improvable_tiles: vec![],
+            tile_terrain: owned_tiles
+                .iter()
+                .map(|&pos| (pos, TerrainType::Grass))
+                .collect(),
+            owned_tiles,
+            depot_positions: HashSet::new(),
+            prospectable_tiles: vec![],
+            technologies: Technologies::new(),
+            rail_constructions: vec![],
+            trade_capacity_total: 3,
+            trade_capacity_used: 0,
+            has_trade_partner: false,
+            buildings: HashMap::new(),
+        };
+
+        Self {
+            snapshot: AiSnapshot::default(),
+            nation,
+            engineer_pos: capital_pos,
+        }
+    }
+
+    /// Plan one turn and apply the engineer's task as if its rail segment had completed.
+    fn play_turn(&mut self) -> Option<CivilianTask> {
+        self.nation.connected_tiles = self.connected_tiles();
+        self.nation.civilians = vec![CivilianSnapshot {
+            entity: engineer(),
+            kind: CivilianKind::Engineer,
+            position: self.engineer_pos,
+            has_moved: false,
+        }];
+        self.snapshot.occupied_tiles = [self.engineer_pos].into_iter().collect();
+
+        let plan = plan_nation(&self.nation, &self.snapshot);
+        let task = plan.civilian_tasks.get(&engineer()).cloned();
+        match task {
+            Some(CivilianTask::BuildRailTo { target }) => {
+                self.snapshot
+                    .rails
+                    .insert(ordered_edge(self.engineer_pos, target));
+                self.engineer_pos = target;
+            }
+            Some(CivilianTask::MoveTo { target }) => self.engineer_pos = target,
+            _ => {}
+        }
+        task
+    }
+
+    /// Owned tiles reachable from the capital over rails
+    fn connected_tiles(&self) -> HashSet<TilePos> {
+        let capital = self.nation.capital_pos;
+        let mut connected = HashSet::from([capital]);
+        let mut queue = VecDeque::from([capital]);
+        while let Some(current) = queue.pop_front() {
+            for neighbor in current
+                .to_hex()
+                .all_neighbors()
+                .into_iter()
+                .filter_map(|hex| hex.to_tile_pos())
+            {
+                if self.nation.owned_tiles.contains(&neighbor)
+                    && self
+                        .snapshot
+                        .rails
+                        .contains(&ordered_edge(current, neighbor))
+                    && connected.insert(neighbor)
+                {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        connected
+    }
+}
+
+/// Runs one rail segment per turn along `route` and checks the engineer never backtracks.
+fn assert_builds_route_in_order(route: &[TilePos]) {
+    let mut scenario = Scenario::along(route);
+
+    for (turn, window) in route.windows(2).enumerate() {
+        let task = scenario.play_turn();
+        assert!(
+            matches!(task, Some(CivilianTask::BuildRailTo { target }) if target == window[1]),
+            "turn {}: expected rail {:?} -> {:?}, got {:?}",
+            turn + 1,
+            window[0],
+            window[1],
+            task
+        );
+    }
+
+    assert!(
+        matches!(scenario.play_turn(), Some(CivilianTask::BuildDepot)),
+        "engineer should build the depot once the line reaches it"
+    );
+    assert_eq!(scenario.snapshot.rails.len(), route.len() - 1);
+}
+
+#[test]
+fn engineer_builds_three_segment_line_in_consecutive_turns() {
+    let route: Vec<TilePos> = (2..=5).map(|x| TilePos::new(x, 4)).collect();
+    assert_builds_route_in_order(&route);
+}
+
+#[test]
+fn engineer_follows_a_detour_without_stepping_back() {
+    // The direct tiles toward the depot are foreign, so the line has to swing away first.
+    // The first segment ends farther from the depot in straight-line terms than the
+    // capital, which used to send the engineer back to the capital every other turn.
+    let route = [
+        TilePos::new(2, 2),
+        TilePos::new(1, 3),
+        TilePos::new(1, 4),
+        TilePos::new(2, 4),
+        TilePos::new(3, 3),
+        TilePos::new(4, 2),
+    ];
+    assert_builds_route_in_order(&route);
+}
@@ -17,7 +17,7 @@ fn test_ai_resource_discovery_and_collection() {
     use rust_imperialism::economy::{
         nation::{Capital, Nation},
         production::Buildings,
-        stockpile::Stockpile,
+        stockpile::{Stockpile, StockpileReserves},
         technology::Technologies,
         transport::Depot,
         treasury::Treasury,
@@ -25,6 +25,7 @@ fn test_ai_resource_discovery_and_collection() {
     use rust_imperialism::map::{
         prospecting::{PotentialMineral, ProspectedMineral},
         province::{Province, ProvinceId, TileProvince},
+        tiles::TerrainType,
     };
     use rust_imperialism::resources::{ResourceType, TileResource};
     use rust_imperialism::turn_system::TurnPhase;
@@ -59,7 +60,10 @@ fn test_ai_resource_discovery_and_collection() {
     for x in 3..8 {
         for y in 3..8 {
             let pos = TilePos { x, y };
-            let tile_entity = app.world_mut().spawn(TileProvince { province_id }).id();
+            let tile_entity = app
+                .world_mut()
+                .spawn((pos, TileProvince { province_id }, TerrainType::Grass))
+                .id();
             tile_storage.set(&pos, tile_entity);
             province_tiles.push(pos);
 
@@ -84,6 +88,7 @@ fn test_ai_resource_discovery_and_collection() {
         .world_mut()
         .spawn((
             AiNation,
+            StockpileReserves::default(),
             Nation,
             Capital(capital_pos),
             Stockpile::default(),
@@ -111,6 +116,7 @@ fn test_ai_resource_discovery_and_collection() {
                 owner: ai_nation,
                 civilian_id: rust_imperialism::civilians::CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             AiControlledCivilian,
         ))
@@ -125,6 +131,7 @@ fn test_ai_resource_discovery_and_collection() {
                 owner: ai_nation,
                 civilian_id: rust_imperialism::civilians::CivilianId(1),
                 has_moved: false,
+                experience: 0,
             },
             AiControlledCivilian,
         ))
@@ -139,6 +146,7 @@ fn test_ai_resource_discovery_and_collection() {
                 owner: ai_nation,
                 civilian_id: rust_imperialism::civilians::CivilianId(2),
                 has_moved: false,
+                experience: 0,
             },
             AiControlledCivilian,
         ))
@@ -343,7 +351,7 @@ fn test_ai_respects_terrain_constraints() {
     use rust_imperialism::economy::{
         nation::{Capital, Nation},
         production::Buildings,
-        stockpile::Stockpile,
+        stockpile::{Stockpile, StockpileReserves},
         technology::Technologies,
         treasury::Treasury,
     };
@@ -397,7 +405,7 @@ fn test_ai_respects_terrain_constraints() {
 
             let tile_entity = app
                 .world_mut()
-                .spawn((TileProvince { province_id }, terrain))
+                .spawn((pos, TileProvince { province_id }, terrain))
                 .id();
             tile_storage.set(&pos, tile_entity);
             province_tiles.push(pos);
@@ -419,6 +427,7 @@ fn test_ai_respects_terrain_constraints() {
         .world_mut()
         .spawn((
             AiNation,
+            StockpileReserves::default(),
             Nation,
             Capital(capital_pos),
             Stockpile::default(),
@@ -446,6 +455,7 @@ fn test_ai_respects_terrain_constraints() {
                 owner: ai_nation,
                 civilian_id: rust_imperialism::civilians::CivilianId(0),
                 has_moved: false,
+                experience: 0,
             },
             AiControlledCivilian,
         ))
@@ -532,7 +542,7 @@ fn test_two_engineers_splitting_paths() {
     use rust_imperialism::economy::{
         nation::{Capital, Nation},
         production::Buildings,
-        stockpile::Stockpile,
+        stockpile::{Stockpile, StockpileReserves},
         technology::Technologies,
         transport::{Depot, Rails},
         treasury::Treasury,
@@ -570,7 +580,7 @@ fn test_two_engineers_splitting_paths() {
             let pos = TilePos { x, y };
             let tile_entity = app
                 .world_mut()
-                .spawn((TileProvince { province_id }, TerrainType::Grass))
+                .spawn((pos, TileProvince { province_id }, TerrainType::Grass))
                 .id();
             tile_storage.set(&pos, tile_entity);
             province_tiles.push(pos);
@@ -583,6 +593,7 @@ fn test_two_engineers_splitting_paths() {
         .world_mut()
         .spawn((
             AiNation,
+            StockpileReserves::default(),
             Nation,
             Capital(capital_pos),
             Stockpile::default(),
@@ -620,6 +631,7 @@ fn test_two_engineers_splitting_paths() {
                 owner: ai_nation,
                 civilian_id: rust_imperialism::civilians::CivilianId(i),
                 has_moved: false,
+                experience: 0,
             },
             AiControlledCivilian,
         ));
@@ -646,6 +658,7 @@ fn test_two_engineers_splitting_paths() {
                     owner: ai_nation,
                     civilian_id: rust_imperialism::civilians::CivilianId(dummy_id),
                     has_moved: false,
+                    experience: 0,
                 },
                 AiControlledCivilian,
             ));
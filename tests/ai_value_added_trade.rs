@@ -16,8 +16,8 @@ fn test_ai_climbs_value_chain_when_hardware_is_profitable() {
     use rust_imperialism::economy::{
         goods::Good,
         nation::{Capital, Nation},
-        production::{Buildings, ProductionSettings},
-        stockpile::Stockpile,
+        production::{Buildings, ProductionResult, ProductionSettings},
+        stockpile::{Stockpile, StockpileReserves},
         technology::Technologies,
         treasury::Treasury,
     };
@@ -64,7 +64,7 @@ fn test_ai_climbs_value_chain_when_hardware_is_profitable() {
             let pos = TilePos { x, y };
             let tile_entity = app
                 .world_mut()
-                .spawn((TileProvince { province_id }, TerrainType::Grass))
+                .spawn((pos, TileProvince { province_id }, TerrainType::Grass))
                 .id();
             tile_storage.set(&pos, tile_entity);
             province_tiles.push(pos);
@@ -78,6 +78,7 @@ fn test_ai_climbs_value_chain_when_hardware_is_profitable() {
         .world_mut()
         .spawn((
             AiNation,
+            StockpileReserves::default(),
             Nation,
             Capital(capital_pos),
             Stockpile::default(),
@@ -85,6 +86,7 @@ fn test_ai_climbs_value_chain_when_hardware_is_profitable() {
             Technologies::default(),
             Buildings::with_all_initial(),
             ProductionSettings::default(),
+            ProductionResult::default(),
         ))
         .id();
 
@@ -222,3 +224,141 @@ fn test_ai_climbs_value_chain_when_hardware_is_profitable() {
         plan.production_orders
     );
 }
+
+#[test]
+fn test_ai_short_on_clothing_runs_its_textile_chain() {
+    use bevy::prelude::*;
+    use bevy::state::app::StatesPlugin;
+    use bevy_ecs_tilemap::prelude::{TilePos, TileStorage, TilemapSize};
+
+    use rust_imperialism::LogicPlugins;
+    use rust_imperialism::ai::{AiNation, AiSnapshot, planner::plan_nation};
+    use rust_imperialism::civilians::types::ProspectingKnowledge;
+    use rust_imperialism::economy::{
+        Allocations, ReservationSystem, Workforce,
+        goods::Good,
+        nation::{Capital, Nation},
+        production::{Buildings, ProductionResult, ProductionSettings},
+        stockpile::{Stockpile, StockpileReserves},
+        technology::Technologies,
+        treasury::Treasury,
+    };
+    use rust_imperialism::map::province::{Province, ProvinceId, TileProvince};
+    use rust_imperialism::map::tiles::TerrainType;
+    use rust_imperialism::turn_system::TurnPhase;
+    use rust_imperialism::ui::menu::AppState;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin));
+    app.add_plugins(LogicPlugins);
+    app.insert_state(AppState::InGame);
+    app.init_resource::<ProspectingKnowledge>();
+
+    let map_size = TilemapSize { x: 3, y: 3 };
+    let mut tile_storage = TileStorage::empty(map_size);
+    let capital_pos = TilePos { x: 1, y: 1 };
+    let province_id = ProvinceId(1);
+    let mut province_tiles = vec![];
+    for x in 0..3 {
+        for y in 0..3 {
+            let pos = TilePos { x, y };
+            let tile_entity = app
+                .world_mut()
+                .spawn((pos, TileProvince { province_id }, TerrainType::Grass))
+                .id();
+            tile_storage.set(&pos, tile_entity);
+            province_tiles.push(pos);
+        }
+    }
+    app.world_mut().spawn((tile_storage, map_size));
+
+    // Plenty of cotton and workers, but no fabric or clothing at all
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Cotton, 20);
+    let mut workforce = Workforce::new();
+    workforce.add_untrained(10);
+    workforce.update_labor_pool();
+
+    let ai_nation = app
+        .world_mut()
+        .spawn((
+            AiNation,
+            Nation,
+            Capital(capital_pos),
+            stockpile,
+            workforce,
+            Treasury::new(10_000),
+            Technologies::default(),
+            Buildings::with_all_initial(),
+            ProductionSettings::default(),
+            ProductionResult::default(),
+            Allocations::default(),
+            ReservationSystem::default(),
+            StockpileReserves::default(),
+        ))
+        .id();
+    app.world_mut().spawn(Province {
+        id: province_id,
+        owner: Some(ai_nation),
+        tiles: province_tiles,
+        city_tile: capital_pos,
+    });
+
+    app.update();
+    transition_to_phase(&mut app, TurnPhase::Processing);
+    transition_to_phase(&mut app, TurnPhase::EnemyTurn);
+
+    // The plan produces clothing and the fabric feeding it instead of buying clothing
+    let snapshot = app.world().resource::<AiSnapshot>();
+    let plan = plan_nation(
+        snapshot
+            .get_nation(ai_nation)
+            .expect("AI nation in snapshot"),
+        snapshot,
+    );
+    let ordered = |good: Good| plan.production_orders.iter().any(|o| o.output == good);
+    assert!(ordered(Good::Clothing), "{:?}", plan.production_orders);
+    assert!(ordered(Good::Fabric), "{:?}", plan.production_orders);
+    assert!(
+        !plan
+            .market_buys
+            .iter()
+            .any(|(good, _)| *good == Good::Clothing),
+        "finished goods are made, not bought: {:?}",
+        plan.market_buys
+    );
+
+    // Fabric is allocated straight away; clothing follows once the fabric exists
+    let mut fabric_allocated_turn = None;
+    let mut clothing_made_turn = None;
+    for turn in 1..=5 {
+        let allocations = app.world().get::<Allocations>(ai_nation).unwrap();
+        if fabric_allocated_turn.is_none()
+            && allocations.production_count(ai_nation, Good::Fabric) > 0
+        {
+            fabric_allocated_turn = Some(turn);
+        }
+        if clothing_made_turn.is_none()
+            && (allocations.production_count(ai_nation, Good::Clothing) > 0
+                || app
+                    .world()
+                    .get::<Stockpile>(ai_nation)
+                    .unwrap()
+                    .get(Good::Clothing)
+                    > 0)
+        {
+            clothing_made_turn = Some(turn);
+        }
+
+        transition_to_phase(&mut app, TurnPhase::PlayerTurn);
+        transition_to_phase(&mut app, TurnPhase::Processing);
+        transition_to_phase(&mut app, TurnPhase::EnemyTurn);
+    }
+
+    let fabric_turn = fabric_allocated_turn.expect("AI should allocate fabric production");
+    let clothing_turn = clothing_made_turn.expect("AI should go on to produce clothing");
+    assert!(
+        fabric_turn < clothing_turn,
+        "clothing needs fabric made on an earlier turn (fabric {fabric_turn}, clothing {clothing_turn})"
+    );
+}
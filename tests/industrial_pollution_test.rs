@@ -0,0 +1,136 @@
+//! Integration test for industrial pollution.
+//! Verifies that steel allocated during the player turn is made when the turn ends
+//! and that the output pollutes the farmland around the producing nation's capital.
+
+mod common;
+use common::transition_to_phase;
+
+#[test]
+fn test_allocated_steel_pollutes_farms_around_the_capital() {
+    use bevy::prelude::*;
+    use bevy::state::app::StatesPlugin;
+    use bevy_ecs_tilemap::prelude::{TilePos, TileStorage, TilemapSize};
+
+    use rust_imperialism::LogicPlugins;
+    use rust_imperialism::civilians::types::ProspectingKnowledge;
+    use rust_imperialism::economy::{
+        allocation::Allocations,
+        goods::Good,
+        nation::{Capital, Nation, NationInstance},
+        production::{BuildingKind, Buildings, ProductionResult, ProductionSettings},
+        reservation::ReservationSystem,
+        stockpile::{Stockpile, StockpileReserves},
+        technology::Technologies,
+        treasury::Treasury,
+        workforce::{RecruitmentQueue, TrainingQueue, Workforce},
+    };
+    use rust_imperialism::map::tiles::TerrainType;
+    use rust_imperialism::messages::AdjustProduction;
+    use rust_imperialism::resources::{DevelopmentLevel, ResourceType, TileResource};
+    use rust_imperialism::turn_system::TurnPhase;
+    use rust_imperialism::ui::menu::AppState;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin));
+    app.add_plugins(LogicPlugins);
+    app.insert_state(AppState::InGame);
+    app.init_resource::<ProspectingKnowledge>();
+
+    // A 3x3 map with a grain farm next to the capital
+    let map_size = TilemapSize { x: 3, y: 3 };
+    let mut tile_storage = TileStorage::empty(map_size);
+    let capital_pos = TilePos { x: 1, y: 1 };
+    let farm_pos = TilePos { x: 1, y: 2 };
+    let mut farm = None;
+    for x in 0..3 {
+        for y in 0..3 {
+            let pos = TilePos { x, y };
+            let tile = if pos == farm_pos {
+                let mut grain = TileResource::visible(ResourceType::Grain);
+                grain.development = DevelopmentLevel::Lv1;
+                let entity = app
+                    .world_mut()
+                    .spawn((pos, TerrainType::Farmland, grain))
+                    .id();
+                farm = Some(entity);
+                entity
+            } else {
+                app.world_mut().spawn((pos, TerrainType::Grass)).id()
+            };
+            tile_storage.set(&pos, tile);
+        }
+    }
+    let farm = farm.expect("farm tile spawned");
+    app.world_mut().spawn((tile_storage, map_size));
+
+    let mut stockpile = Stockpile::default();
+    stockpile.add(Good::Iron, 4);
+    stockpile.add(Good::Coal, 4);
+    let mut workforce = Workforce::new();
+    workforce.add_untrained(5);
+    workforce.update_labor_pool();
+    let nation = app
+        .world_mut()
+        .spawn((
+            Nation,
+            Capital(capital_pos),
+            stockpile,
+            workforce,
+            Treasury::new(1_000),
+            Technologies::default(),
+            Buildings::with_all_initial(),
+            ProductionSettings::default(),
+            ProductionResult::default(),
+            Allocations::default(),
+            ReservationSystem::default(),
+            StockpileReserves::default(),
+            RecruitmentQueue::default(),
+            TrainingQueue::default(),
+        ))
+        .id();
+
+    app.update();
+
+    // The player allocates the steel mill to full capacity
+    let instance = NationInstance::from_entity(app.world().entity(nation))
+        .expect("failed to build nation instance");
+    app.world_mut().trigger(AdjustProduction {
+        nation: instance,
+        building: nation,
+        output_good: Good::Steel,
+        target_output: 4,
+    });
+    app.update();
+    assert_eq!(
+        app.world()
+            .get::<Allocations>(nation)
+            .unwrap()
+            .production_count(nation, Good::Steel),
+        4
+    );
+    assert_eq!(
+        app.world().get::<TileResource>(farm).unwrap().pollution,
+        0,
+        "allocating alone pollutes nothing"
+    );
+
+    // Ending the turn makes the steel and the mill's smoke reaches the farm
+    transition_to_phase(&mut app, TurnPhase::Processing);
+
+    let report = app
+        .world()
+        .get::<ProductionResult>(nation)
+        .unwrap()
+        .get(BuildingKind::SteelMill)
+        .copied()
+        .expect("steel mill result recorded");
+    assert_eq!(report.produced, 4);
+    assert_eq!(
+        app.world()
+            .get::<Stockpile>(nation)
+            .unwrap()
+            .get(Good::Steel),
+        4
+    );
+    assert_eq!(app.world().get::<TileResource>(farm).unwrap().pollution, 4);
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use bevy::state::app::StatesPlugin;
 use bevy_ecs_tilemap::prelude::*;
+use rust_imperialism::constants::hex_map_type;
 use rust_imperialism::economy::nation::NationColor;
 use rust_imperialism::map::TerrainType;
 use rust_imperialism::map::province::{City, Province};
@@ -27,6 +28,8 @@ fn test_map_pruning_to_red_nation() {
     // Add resources normally provided by plugins
     app.init_resource::<rust_imperialism::civilians::types::NextCivilianId>();
     app.insert_resource(rust_imperialism::economy::transport::Rails::default());
+    app.init_resource::<rust_imperialism::economy::UpkeepPolicy>();
+    app.init_resource::<rust_imperialism::map::rendering::ColorPalette>();
 
     // Adding only the systems we need to test map generation and pruning
     app.add_systems(
@@ -138,7 +141,7 @@ fn setup_mock_tilemap(mut commands: Commands, tilemap_query: Query<&TileStorage>
 
     commands.entity(tilemap_entity).insert((
         TilemapGridSize { x: 16.0, y: 16.0 },
-        TilemapType::Hexagon(HexCoordSystem::Row),
+        hex_map_type(),
         map_size,
         tile_storage,
         TilemapTileSize { x: 16.0, y: 16.0 },